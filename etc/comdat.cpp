@@ -0,0 +1,8 @@
+// g++ -c -O0 -fno-exceptions -fno-asynchronous-unwind-tables -fno-ident -o comdat.o comdat.cpp
+// then dumped into comdat.rs
+
+template <typename T> T twice(T x) { return x + x; }
+
+inline int answer() { return 42; }
+
+int use() { return twice(answer()) + twice<long>(1); }
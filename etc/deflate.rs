@@ -0,0 +1,180 @@
+// zlib.compressobj(level, zlib.DEFLATED, 15, 9, strategy) of `tests::input()`
+vec![
+    // level 1
+    vec![
+        0x78, 0x01, 0x95, 0x93, 0x59, 0x6e, 0xd5, 0x30, 0x18, 0x85, 0xb7, 0x72, 0x17, 0x00, 0xec, 0x21,
+        0x1e, 0x7e, 0xdb, 0x89, 0x13, 0xcf, 0x76, 0xe2, 0x21, 0xf6, 0x2d, 0x14, 0xa8, 0x04, 0x05, 0xb5,
+        0xfb, 0x97, 0xb8, 0xaf, 0xa8, 0x17, 0xa4, 0xbe, 0x9f, 0x87, 0x33, 0x7c, 0xe7, 0xdb, 0xaf, 0x87,
+        0x1f, 0x4f, 0xcf, 0x97, 0xdf, 0xd7, 0x97, 0xd7, 0xc7, 0xd7, 0xcb, 0xe3, 0x8f, 0xaf, 0x1f, 0x2e,
+        0x3f, 0xaf, 0x9f, 0xbf, 0x7f, 0xfc, 0x75, 0xb9, 0x3e, 0x7f, 0xb9, 0xfc, 0x7e, 0xbc, 0x3c, 0x3c,
+        0x3d, 0x5f, 0x5f, 0x9e, 0x1e, 0x5f, 0x3f, 0x5d, 0xbe, 0xdd, 0x93, 0x22, 0x07, 0x06, 0x63, 0x02,
+        0x54, 0x2d, 0x87, 0x48, 0xb1, 0x11, 0xab, 0xac, 0x37, 0xa9, 0xe9, 0x13, 0x43, 0xa0, 0xd3, 0xe9,
+        0xd6, 0x75, 0xde, 0x5c, 0xc7, 0x36, 0x8a, 0x9c, 0x56, 0x10, 0x52, 0x93, 0xb6, 0x01, 0x88, 0x9e,
+        0x8e, 0x29, 0x17, 0x4e, 0x88, 0x54, 0xf5, 0x20, 0x19, 0x4a, 0xcb, 0x33, 0x84, 0xd9, 0x36, 0xe2,
+        0x94, 0x88, 0x95, 0x29, 0x75, 0xf8, 0x40, 0x0b, 0xb3, 0x63, 0x15, 0x9d, 0xbb, 0x1e, 0xac, 0x41,
+        0xcd, 0x80, 0x15, 0x05, 0xef, 0xd1, 0xd6, 0x24, 0x7d, 0x67, 0x14, 0x23, 0xf5, 0x1e, 0xaf, 0xf7,
+        0x62, 0xc9, 0xe2, 0x9c, 0x57, 0x93, 0x82, 0xf3, 0x1c, 0x39, 0x12, 0xab, 0x51, 0x05, 0x7a, 0xa2,
+        0x73, 0xde, 0xa4, 0xf4, 0x51, 0x1b, 0x65, 0x46, 0xea, 0xb6, 0x88, 0xa2, 0x17, 0xee, 0x79, 0x75,
+        0x30, 0xe4, 0x50, 0x74, 0x13, 0xab, 0x3d, 0xf7, 0xe0, 0x29, 0x5e, 0x36, 0xca, 0x96, 0x75, 0x3a,
+        0xa0, 0xed, 0xcd, 0x35, 0xb6, 0xa0, 0x39, 0xf1, 0xee, 0xcc, 0xa2, 0x61, 0x5d, 0xe3, 0x2c, 0x10,
+        0xcc, 0x62, 0xed, 0xb5, 0x26, 0xd6, 0x50, 0x67, 0xa7, 0xae, 0x3d, 0x2e, 0xd1, 0xf6, 0xb4, 0x88,
+        0x5e, 0x4b, 0x45, 0xb2, 0xfc, 0xab, 0xd7, 0x7b, 0x5e, 0xef, 0x4d, 0x30, 0xe4, 0x62, 0xb8, 0x2c,
+        0xde, 0x84, 0x02, 0xe1, 0x34, 0x82, 0x8f, 0xad, 0xa6, 0x91, 0x52, 0xdc, 0x55, 0x18, 0x68, 0x42,
+        0x80, 0xbb, 0xc8, 0x2c, 0xa3, 0xd9, 0xa1, 0xb2, 0x2e, 0xc8, 0x85, 0x6a, 0x99, 0xbe, 0x09, 0x11,
+        0x42, 0x4a, 0xf4, 0x4d, 0x2a, 0x1f, 0xd5, 0x18, 0x88, 0xc2, 0x21, 0x6e, 0x96, 0x62, 0x8b, 0xf3,
+        0x08, 0x20, 0x6b, 0xec, 0xd5, 0xfb, 0x59, 0xb4, 0x89, 0xf7, 0x29, 0xb2, 0x4a, 0xfa, 0x02, 0x26,
+        0x63, 0xd2, 0xcd, 0xca, 0xb5, 0x68, 0x94, 0x07, 0x8e, 0xd5, 0x61, 0x2c, 0xc4, 0x77, 0xe2, 0xf2,
+        0x26, 0x56, 0x3c, 0xba, 0xae, 0x88, 0x6f, 0x70, 0xc3, 0xa0, 0xb6, 0x4c, 0xa3, 0x62, 0x22, 0xa9,
+        0xea, 0x28, 0x8f, 0x43, 0xb2, 0x81, 0xf5, 0x9e, 0x49, 0xdd, 0x2d, 0x9b, 0xca, 0x32, 0x18, 0x4d,
+        0x33, 0x66, 0x89, 0x9e, 0x5b, 0x5d, 0xb4, 0xd9, 0xa8, 0xc8, 0x5a, 0x73, 0xeb, 0xb3, 0x23, 0x8e,
+        0x66, 0x09, 0x4d, 0xa1, 0x78, 0x2b, 0xdc, 0x48, 0x91, 0xa9, 0x59, 0xca, 0x4e, 0x61, 0xa2, 0x41,
+        0x1b, 0x26, 0xa3, 0xc1, 0x66, 0xf0, 0x3c, 0x6e, 0x73, 0xba, 0x1a, 0x02, 0x90, 0xe2, 0x5b, 0x99,
+        0x69, 0xcf, 0xae, 0x9b, 0xff, 0xa0, 0xfd, 0xc6, 0xeb, 0xbd, 0x17, 0xb0, 0x88, 0x83, 0xdc, 0xcf,
+        0x48, 0x79, 0x01, 0x8f, 0x1a, 0x4c, 0x4e, 0x60, 0x8d, 0xf6, 0x7c, 0x23, 0x5d, 0x6c, 0xa4, 0x11,
+        0x40, 0x54, 0xa1, 0x35, 0x9b, 0x4e, 0x4e, 0x4c, 0xe9, 0x71, 0x24, 0xdc, 0x0c, 0x65, 0xaa, 0x83,
+        0xdc, 0xa2, 0x80, 0xdc, 0xd7, 0x6a, 0xd7, 0xa9, 0xcf, 0x9a, 0x98, 0x05, 0xf3, 0x4e, 0xf5, 0x8a,
+        0xe5, 0x08, 0xde, 0x50, 0xaa, 0x70, 0x6f, 0x5a, 0xe9, 0x14, 0xa4, 0x05, 0xb4, 0x01, 0x3f, 0x53,
+        0xa7, 0x96, 0x12, 0x3f, 0x2a, 0xb2, 0xc7, 0xb4, 0xe9, 0x71, 0x23, 0x8b, 0x90, 0xf6, 0xfe, 0xc7,
+        0xfe, 0x1d, 0xeb, 0x0f, 0xd5, 0xef, 0x4e, 0x6e,
+    ],
+    // level 9, with a full flush halfway
+    vec![
+        0x78, 0xda, 0x94, 0xce, 0x5b, 0x52, 0xc3, 0x20, 0x14, 0x00, 0xd0, 0xad, 0x64, 0x01, 0xea, 0x1e,
+        0x72, 0x93, 0x5c, 0x42, 0x80, 0x10, 0x20, 0x40, 0x13, 0x9e, 0xad, 0x56, 0xed, 0x8c, 0x56, 0xc7,
+        0xee, 0x7f, 0x46, 0x17, 0xd0, 0x1f, 0xff, 0xcf, 0xc7, 0x79, 0xfb, 0x3a, 0x7d, 0x5c, 0xae, 0xcd,
+        0xf7, 0xf1, 0xe7, 0x76, 0xbe, 0x35, 0xe7, 0x8f, 0xd7, 0x87, 0xe6, 0xf3, 0xf8, 0xfc, 0xfe, 0xf8,
+        0xd5, 0x1c, 0xaf, 0x2f, 0xcd, 0xf7, 0xb9, 0x39, 0x5d, 0xae, 0xc7, 0x9f, 0xcb, 0xf9, 0xf6, 0xd4,
+        0xbc, 0xdd, 0xa3, 0x60, 0x50, 0x75, 0x5d, 0x8f, 0x83, 0x64, 0x1b, 0xf5, 0x2e, 0xf5, 0x5a, 0xea,
+        0x55, 0xf9, 0xb4, 0xe4, 0x0e, 0xed, 0xd0, 0x66, 0x23, 0xc4, 0x34, 0x9b, 0xd2, 0x69, 0x47, 0x77,
+        0x2f, 0x90, 0xf2, 0xa5, 0x4f, 0x33, 0x22, 0x2d, 0x7e, 0x6b, 0xf7, 0x30, 0xf6, 0x3d, 0x97, 0x71,
+        0xeb, 0x77, 0x0c, 0x69, 0x9f, 0xd0, 0x4e, 0x3a, 0xf5, 0x46, 0x52, 0x17, 0x89, 0x94, 0xdb, 0x6a,
+        0x87, 0x40, 0x74, 0x15, 0xb4, 0x8c, 0xa6, 0x58, 0xad, 0x20, 0x29, 0xd4, 0x34, 0x74, 0x07, 0xa7,
+        0xa3, 0xe7, 0x6b, 0x21, 0x43, 0x07, 0xf2, 0x3f, 0xd7, 0x7b, 0x94, 0x07, 0x63, 0x56, 0xd9, 0x4a,
+        0xcc, 0xb9, 0xee, 0xae, 0xd7, 0x0b, 0x44, 0x1c, 0x32, 0xe4, 0x69, 0xe6, 0x7c, 0x75, 0x8b, 0x92,
+        0xaa, 0xfa, 0xa2, 0x03, 0x0d, 0x0b, 0x1b, 0xd7, 0x31, 0x1a, 0xac, 0xbc, 0xca, 0x61, 0xa6, 0x42,
+        0xe7, 0x83, 0x5d, 0x87, 0x8e, 0xcd, 0x03, 0x61, 0xa2, 0xdd, 0x30, 0x1d, 0x92, 0x49, 0x84, 0xc1,
+        0xe4, 0xc7, 0x62, 0x14, 0x5b, 0x50, 0x08, 0x37, 0x51, 0xc0, 0x89, 0x8a, 0x12, 0xa3, 0x27, 0x09,
+        0x0a, 0xc9, 0x4b, 0x2c, 0x8e, 0x39, 0x5d, 0x3c, 0xa3, 0x25, 0x86, 0x08, 0x3c, 0xfc, 0xe7, 0x7a,
+        0x8f, 0x56, 0xce, 0xd4, 0xc8, 0xc3, 0xaa, 0x6c, 0x40, 0x9b, 0x15, 0x1d, 0xeb, 0x1c, 0x7d, 0xf5,
+        0xde, 0x1d, 0xa4, 0xad, 0xd0, 0x02, 0x76, 0x85, 0xee, 0x64, 0x87, 0xc9, 0x40, 0x10, 0x0c, 0x8c,
+        0x8d, 0x9a, 0x2c, 0x7f, 0x10, 0x00, 0x24, 0x2d, 0x33, 0xff, 0x05, 0x00, 0x00, 0xff, 0xff, 0x95,
+        0xce, 0x5b, 0x72, 0x83, 0x20, 0x14, 0x00, 0xd0, 0xad, 0xb8, 0x80, 0xb6, 0x7b, 0x10, 0xb8, 0x17,
+        0x50, 0x14, 0xf0, 0x81, 0x8a, 0x20, 0x98, 0x36, 0x6d, 0x33, 0x93, 0x26, 0x99, 0x64, 0xff, 0x33,
+        0xdd, 0x40, 0x7e, 0xf2, 0x7f, 0x3e, 0x8e, 0x1e, 0x9c, 0xce, 0x99, 0x00, 0x2e, 0x52, 0xa6, 0xe0,
+        0xa2, 0xab, 0xf2, 0x88, 0x2a, 0xb8, 0x14, 0x86, 0xa1, 0x92, 0xb1, 0x14, 0xa9, 0x74, 0x3c, 0xb0,
+        0x54, 0xa3, 0xf5, 0x94, 0x25, 0xdb, 0x08, 0x23, 0x23, 0x88, 0x51, 0x50, 0xbd, 0xd8, 0x0e, 0xdd,
+        0x5b, 0xf1, 0xb7, 0x7f, 0xfe, 0xbe, 0x5f, 0x8b, 0xfd, 0xf2, 0x55, 0xdc, 0x8e, 0xc5, 0xe1, 0x74,
+        0xd9, 0xef, 0xa7, 0xe3, 0xe3, 0xa3, 0xf8, 0xb9, 0x1e, 0xce, 0xa7, 0x4b, 0x71, 0xdb, 0xef, 0x8f,
+        0xe3, 0xa3, 0x38, 0x9e, 0xbf, 0x9f, 0x52, 0xb7, 0x24, 0x13, 0x88, 0x68, 0x51, 0x87, 0x25, 0x44,
+        0x0f, 0x4e, 0x73, 0x39, 0xe9, 0xd0, 0x83, 0x70, 0x59, 0xf1, 0x4c, 0xcd, 0xec, 0x59, 0x98, 0x3b,
+        0x5e, 0xae, 0x75, 0xe6, 0x30, 0x55, 0x94, 0x4f, 0xb0, 0xb5, 0xa1, 0x36, 0xb6, 0x05, 0xe9, 0x8d,
+        0x11, 0xdd, 0xe0, 0x7b, 0xd6, 0x83, 0x57, 0x18, 0x35, 0x71, 0x92, 0xa0, 0x55, 0xd2, 0x83, 0xad,
+        0xd7, 0x19, 0xb0, 0x84, 0xd1, 0x58, 0xae, 0x9c, 0xa5, 0x36, 0x0b, 0x9f, 0x9d, 0xb1, 0x7d, 0x18,
+        0x47, 0x64, 0xeb, 0x10, 0xd7, 0x0a, 0x92, 0xef, 0x93, 0x7d, 0xe5, 0xfa, 0x8c, 0x72, 0x47, 0x47,
+        0x35, 0x6f, 0x0e, 0xc4, 0x8a, 0x03, 0x89, 0x58, 0xf6, 0x92, 0x1a, 0x32, 0x7b, 0x94, 0x4a, 0xb6,
+        0x2c, 0x32, 0x24, 0xa0, 0x49, 0xe3, 0x6d, 0x62, 0x1b, 0x05, 0x58, 0x96, 0x89, 0x46, 0x0b, 0x5c,
+        0x27, 0x54, 0xad, 0x93, 0xe8, 0x53, 0x13, 0xba, 0xa6, 0x4c, 0x95, 0x61, 0xb6, 0xa6, 0x22, 0x81,
+        0x69, 0xa8, 0xca, 0xe3, 0x60, 0x01, 0x34, 0x4d, 0xd1, 0x68, 0x33, 0x8d, 0xaa, 0x43, 0xd2, 0xa2,
+        0xd8, 0xa6, 0x04, 0x1d, 0xb0, 0x21, 0x07, 0xd2, 0x2d, 0x65, 0x6b, 0x32, 0x56, 0x92, 0xb1, 0xf8,
+        0xca, 0xf5, 0x19, 0xfd, 0x07, 0xd5, 0xef, 0x4e, 0x6e,
+    ],
+    // huffman only
+    vec![
+        0x78, 0x01, 0x05, 0xc1, 0x09, 0xae, 0x83, 0x20, 0x14, 0x00, 0xc0, 0xab, 0xbc, 0x03, 0xb4, 0xff,
+        0x0e, 0xa2, 0xa0, 0xb8, 0x21, 0x2e, 0x58, 0x15, 0x45, 0x6c, 0x69, 0x4b, 0x62, 0xd1, 0xc8, 0xfd,
+        0x93, 0x3f, 0xf3, 0x39, 0xb6, 0xdd, 0x3a, 0x38, 0xf5, 0xe5, 0x8d, 0x07, 0xb3, 0xbf, 0x6f, 0xf0,
+        0xd3, 0xcf, 0xef, 0xfd, 0x00, 0xed, 0x5e, 0x70, 0x1a, 0xd8, 0xac, 0xd3, 0x97, 0x35, 0xfe, 0x0f,
+        0x3e, 0xc7, 0xb6, 0x5b, 0x07, 0xa7, 0xbe, 0xbc, 0xf1, 0x60, 0xf6, 0xf7, 0x0d, 0x7e, 0x1a, 0x35,
+        0x84, 0x87, 0x61, 0x44, 0x30, 0xcb, 0x06, 0xda, 0x8b, 0x39, 0xaa, 0x59, 0xdd, 0xf2, 0x7e, 0xae,
+        0x96, 0x90, 0x74, 0x38, 0x58, 0x9a, 0xa2, 0x48, 0xcb, 0x46, 0x85, 0xb5, 0xa0, 0x63, 0x5f, 0x10,
+        0x9a, 0x57, 0xd1, 0x5c, 0x12, 0x42, 0x55, 0x3f, 0x04, 0xe3, 0x94, 0x44, 0x51, 0xce, 0xe4, 0x10,
+        0x8d, 0x64, 0x9a, 0xc7, 0x94, 0x74, 0x69, 0x3d, 0x47, 0x0d, 0xa3, 0x42, 0xc6, 0x8c, 0x0d, 0x6d,
+        0x87, 0xa7, 0xb8, 0x5e, 0x0b, 0xaa, 0x92, 0x46, 0x75, 0x35, 0x47, 0x33, 0x27, 0x35, 0x9d, 0xc2,
+        0x87, 0xa8, 0x65, 0x9f, 0xb7, 0x2a, 0xc6, 0x21, 0x62, 0xfa, 0xf9, 0xbd, 0x1f, 0xa0, 0xdd, 0x0b,
+        0x4e, 0x03, 0x9b, 0x75, 0xfa, 0xb2, 0xc6, 0xff, 0xc1, 0xe7, 0xd8, 0x76, 0xeb, 0xe0, 0xd4, 0x97,
+        0x37, 0x1e, 0xcc, 0xfe, 0xbe, 0xc1, 0x4f, 0x3f, 0xbf, 0xf7, 0x03, 0xb4, 0x7b, 0xc1, 0x69, 0x60,
+        0xb3, 0x4e, 0x5f, 0xd6, 0xf8, 0x7c, 0x6a, 0x9a, 0x96, 0x05, 0x8c, 0x2c, 0xcb, 0x3a, 0x8a, 0xa8,
+        0xae, 0x90, 0x24, 0x78, 0x41, 0x4b, 0x5a, 0xe6, 0x79, 0x2b, 0x2a, 0xce, 0xf8, 0xda, 0xab, 0x7a,
+        0xa2, 0x53, 0x95, 0x25, 0x6d, 0x22, 0x1b, 0xb2, 0xe6, 0x2b, 0xc3, 0x25, 0x2d, 0xea, 0xe5, 0xd1,
+        0xb5, 0x38, 0xcc, 0x4a, 0x1c, 0x67, 0x45, 0x30, 0x90, 0xf9, 0x31, 0x37, 0x73, 0x9c, 0xa1, 0xb4,
+        0x4f, 0x54, 0xc3, 0xb3, 0x8a, 0x14, 0x85, 0x48, 0x29, 0x22, 0x29, 0x2d, 0x94, 0x94, 0x7d, 0x3c,
+        0x23, 0x15, 0x2f, 0x95, 0x54, 0x22, 0x13, 0xb5, 0xea, 0x33, 0xaa, 0xe4, 0x24, 0x51, 0x3e, 0xf9,
+        0x3f, 0xf8, 0x1c, 0xdb, 0x6e, 0x1d, 0x9c, 0xfa, 0xf2, 0xc6, 0x83, 0xd9, 0xdf, 0x37, 0xf8, 0xe9,
+        0xe7, 0xf7, 0x7e, 0x80, 0x76, 0x2f, 0x38, 0x0d, 0x6c, 0xd6, 0xe9, 0xcb, 0x1a, 0xff, 0x07, 0x9f,
+        0x63, 0xdb, 0xad, 0x83, 0x53, 0x5f, 0xde, 0x78, 0x30, 0xfb, 0xfb, 0xb6, 0xe6, 0x19, 0x4f, 0xf2,
+        0xa9, 0xe5, 0xdd, 0x44, 0xba, 0x85, 0xd3, 0x64, 0x2d, 0x65, 0xbf, 0xf6, 0xbd, 0x78, 0xb0, 0x6e,
+        0x45, 0x01, 0x22, 0xa1, 0xa2, 0x63, 0x3c, 0xa2, 0xb4, 0x41, 0x53, 0x91, 0xa1, 0xa6, 0x93, 0x75,
+        0x5c, 0x75, 0x0b, 0x47, 0x08, 0x31, 0xaa, 0xca, 0x9c, 0xb5, 0x82, 0xad, 0x2b, 0xc2, 0x64, 0xa0,
+        0x54, 0x49, 0x31, 0x8b, 0x74, 0xed, 0x48, 0x2e, 0x85, 0x92, 0x6d, 0x9b, 0xd2, 0x39, 0x48, 0x54,
+        0x20, 0x62, 0x19, 0xa9, 0x8c, 0xf0, 0x31, 0x8c, 0x14, 0x2f, 0x92, 0x8a, 0xce, 0x38, 0xe9, 0x92,
+        0x90, 0x0d, 0xbc, 0x26, 0xe2, 0x06, 0x3f, 0xfd, 0xfc, 0xde, 0x0f, 0xd0, 0xee, 0x05, 0xa7, 0x81,
+        0xcd, 0x3a, 0x7d, 0x59, 0xe3, 0xff, 0xe0, 0x73, 0x6c, 0xbb, 0x75, 0x70, 0xea, 0xcb, 0x1b, 0x0f,
+        0x66, 0x7f, 0xdf, 0xe0, 0xa7, 0x9f, 0xdf, 0xfb, 0x01, 0xda, 0xbd, 0xe0, 0x34, 0xb0, 0x59, 0xa7,
+        0x2f, 0x31, 0xa8, 0x4a, 0xa2, 0xa4, 0x24, 0x4c, 0x0e, 0x72, 0x1e, 0xb1, 0x60, 0x31, 0xed, 0x99,
+        0x6c, 0x70, 0x22, 0xd6, 0x3c, 0x5e, 0xc3, 0xea, 0x31, 0x46, 0xf2, 0x51, 0xc7, 0xc1, 0x94, 0xad,
+        0x31, 0xee, 0xd3, 0x30, 0xee, 0xf1, 0x52, 0xca, 0xac, 0xe2, 0x25, 0xa6, 0x63, 0x55, 0x25, 0x75,
+        0x3b, 0x36, 0x51, 0x83, 0xc7, 0x9c, 0xcc, 0x0c, 0x09, 0x8a, 0x08, 0xcf, 0xe9, 0x88, 0x79, 0x36,
+        0x3d, 0x30, 0x09, 0x70, 0x57, 0xf1, 0x38, 0x17, 0x3c, 0xe4, 0x6b, 0x32, 0xae, 0xa2, 0xe2, 0x8d,
+        0xec, 0x3a, 0x12, 0x4d, 0xed, 0x3c, 0xa5, 0x58, 0x8d, 0x8d, 0xe2, 0x97, 0x35, 0xfe, 0x0f, 0x3e,
+        0xc7, 0xb6, 0x5b, 0x07, 0xa7, 0xbe, 0xbc, 0xf1, 0x60, 0xf6, 0xf7, 0x0d, 0x7e, 0xfa, 0xf9, 0xbd,
+        0x1f, 0xa0, 0xdd, 0x0b, 0x4e, 0x03, 0x9b, 0x75, 0xfa, 0xb2, 0xc6, 0xff, 0xc1, 0xe7, 0xd8, 0x76,
+        0xeb, 0xe0, 0xd4, 0x97, 0x37, 0x1e, 0x4c, 0x2c, 0xc2, 0x2e, 0x7f, 0x2c, 0x02, 0x27, 0x13, 0x69,
+        0xd1, 0x4c, 0x82, 0x86, 0x86, 0x15, 0x7a, 0x8c, 0x84, 0xe6, 0xb4, 0x8c, 0xe6, 0x88, 0x20, 0xcc,
+        0x50, 0x31, 0x72, 0x15, 0x2d, 0x21, 0xc6, 0xc3, 0xd0, 0x87, 0x33, 0xc7, 0x31, 0x53, 0x24, 0x2f,
+        0x05, 0x25, 0xa3, 0x2a, 0x64, 0x5d, 0x04, 0x2a, 0xad, 0x22, 0x9e, 0x85, 0x89, 0xc2, 0x55, 0x11,
+        0xe6, 0x6b, 0xd7, 0x72, 0x8c, 0x59, 0xa8, 0xe6, 0x8a, 0x55, 0x7d, 0x97, 0xd7, 0x04, 0x95, 0x24,
+        0x59, 0x7a, 0x85, 0x6b, 0x1c, 0xb5, 0xab, 0x44, 0xf5, 0x10, 0x94, 0xd5, 0x4a, 0x52, 0x1a, 0x45,
+        0xb3, 0xd9, 0xdf, 0x37, 0xf8, 0xe9, 0xe7, 0xf7, 0x7e, 0x80, 0x76, 0x2f, 0x38, 0x0d, 0x6c, 0xd6,
+        0xe9, 0xcb, 0x1a, 0xff, 0x07, 0x9f, 0x63, 0xdb, 0xad, 0x83, 0x53, 0x5f, 0xde, 0x78, 0x30, 0xfb,
+        0xfb, 0x06, 0x3f, 0xfd, 0xfc, 0xde, 0x0f, 0xd0, 0xee, 0x05, 0xa7, 0x81, 0xcd, 0xfe, 0x03, 0xd5,
+        0xef, 0x4e, 0x6e,
+    ],
+    // fixed huffman codes
+    vec![
+        0x78, 0x01, 0x4b, 0xcf, 0x4f, 0xca, 0xc9, 0xcc, 0x53, 0x28, 0x48, 0x2c, 0x2a, 0x4e, 0x2d, 0x56,
+        0x48, 0xcd, 0x49, 0xd3, 0x51, 0xc8, 0x4d, 0x4c, 0xce, 0xd0, 0xcd, 0x57, 0x48, 0xcc, 0x4b, 0x51,
+        0x28, 0x48, 0x55, 0x48, 0xca, 0xcc, 0x4b, 0x2c, 0xca, 0x4c, 0x2d, 0xd6, 0x53, 0x48, 0xc7, 0xa6,
+        0xd4, 0x29, 0xd8, 0x2d, 0xd0, 0xd9, 0xd9, 0xc5, 0xcd, 0xd5, 0xdf, 0x3b, 0xd2, 0x33, 0x3c, 0x2c,
+        0xd6, 0x25, 0xc8, 0x3f, 0x28, 0x24, 0x30, 0x3c, 0x36, 0x20, 0xce, 0xd9, 0x2d, 0xd4, 0xd5, 0x31,
+        0x2e, 0xd8, 0xd7, 0xd7, 0xcb, 0x2f, 0x38, 0xde, 0x39, 0x28, 0xcc, 0x33, 0x2a, 0xdc, 0xd7, 0xcd,
+        0xd3, 0x27, 0xc0, 0x25, 0xd6, 0xcf, 0xcd, 0xcd, 0x33, 0x3e, 0x3c, 0xd2, 0x31, 0x2a, 0xda, 0xc3,
+        0xc5, 0xc5, 0xc7, 0x3f, 0x26, 0xd2, 0x25, 0xca, 0x2d, 0x3a, 0x36, 0xca, 0xcb, 0x2d, 0xd4, 0x2b,
+        0x28, 0xd6, 0x25, 0xd8, 0xdf, 0x33, 0x2c, 0xc6, 0xdd, 0xdf, 0x3f, 0x32, 0x24, 0xd4, 0x35, 0xda,
+        0x3d, 0x28, 0xc1, 0xd7, 0x33, 0xde, 0x23, 0x38, 0x3e, 0x34, 0x28, 0xd0, 0x29, 0x36, 0xd0, 0x2d,
+        0xc8, 0x33, 0xda, 0x39, 0x22, 0x2c, 0x28, 0x26, 0xdc, 0x27, 0x24, 0xde, 0xdd, 0xd5, 0xd9, 0xc9,
+        0x9f, 0x14, 0xb7, 0x62, 0x53, 0xea, 0x13, 0x1d, 0x1c, 0x1c, 0xe2, 0xef, 0xe8, 0xef, 0x16, 0x17,
+        0x97, 0x10, 0x15, 0xe6, 0x12, 0x14, 0xe0, 0x14, 0xe3, 0xe6, 0x1a, 0xe7, 0x14, 0xe7, 0xe5, 0xe7,
+        0xe3, 0x13, 0x12, 0x16, 0x10, 0xe8, 0x1f, 0x98, 0x10, 0x1e, 0x1f, 0x14, 0xed, 0x19, 0x1d, 0xe0,
+        0xed, 0x11, 0xe2, 0x11, 0x13, 0xec, 0x96, 0xe0, 0x93, 0xe0, 0xef, 0xea, 0xe7, 0xe9, 0x1b, 0x14,
+        0x17, 0x11, 0x1a, 0xe2, 0xea, 0xec, 0xed, 0xe7, 0xea, 0xee, 0xed, 0xeb, 0x18, 0xe9, 0x16, 0x1b,
+        0x11, 0x1b, 0x1c, 0xeb, 0xee, 0xed, 0xe4, 0x15, 0xee, 0x11, 0x1f, 0x1c, 0xe8, 0x1d, 0xe0, 0xe6,
+        0xeb, 0x1b, 0xe6, 0xe5, 0xe9, 0xe4, 0xe6, 0xe5, 0xe9, 0x1b, 0x1f, 0x13, 0x13, 0xee, 0x1e, 0xeb,
+        0x14, 0xef, 0x1e, 0x17, 0x10, 0x13, 0x1f, 0xe6, 0x1d, 0x16, 0x14, 0x1f, 0xee, 0xed, 0x19, 0x1f,
+        0x13, 0x1d, 0xe3, 0xe4, 0x13, 0x4d, 0x8a, 0x5b, 0xb1, 0x29, 0x4d, 0xf0, 0xf1, 0x0e, 0xf4, 0xf0,
+        0x89, 0x0e, 0x09, 0x0c, 0x8d, 0x76, 0x0b, 0x8d, 0x0b, 0xf4, 0xf4, 0x48, 0xf0, 0x8b, 0x09, 0x4f,
+        0x08, 0x0f, 0x0f, 0x8b, 0xf0, 0x0f, 0x4d, 0x70, 0x72, 0x74, 0x72, 0x73, 0x8e, 0xf7, 0x8c, 0x72,
+        0x8f, 0x72, 0xf2, 0x0a, 0x76, 0x8a, 0xf6, 0xf5, 0x76, 0x0a, 0x0e, 0x8d, 0x09, 0x72, 0x0f, 0x00,
+        0x2a, 0x74, 0x72, 0x72, 0xf2, 0xf7, 0x8c, 0xf7, 0xf3, 0xf1, 0x0f, 0x09, 0xf3, 0x4f, 0x48, 0x70,
+        0x72, 0x75, 0x8b, 0xf4, 0x04, 0x3a, 0x29, 0x2c, 0x36, 0xcc, 0x2b, 0x21, 0xd4, 0xcd, 0x27, 0x26,
+        0x2c, 0x3e, 0x26, 0x24, 0xc4, 0xcb, 0x33, 0xd6, 0xd1, 0x23, 0xde, 0x31, 0xcc, 0x3d, 0xc6, 0x25,
+        0xde, 0xdb, 0x2d, 0x30, 0xca, 0xd9, 0x25, 0x3e, 0xd0, 0xd7, 0x23, 0xc0, 0x33, 0xd6, 0xd5, 0x23,
+        0xd4, 0xc3, 0xd9, 0x3f, 0x32, 0x30, 0xc8, 0x2d, 0x8c, 0xc4, 0xe4, 0x82, 0xa1, 0x34, 0x2c, 0x32,
+        0x3e, 0x20, 0xc6, 0xc9, 0xc3, 0xcf, 0x0d, 0x98, 0x0c, 0x62, 0x62, 0xa3, 0x5c, 0xc3, 0xfc, 0xdd,
+        0x3d, 0xc3, 0xfd, 0x63, 0x82, 0x5d, 0x3d, 0xc2, 0x12, 0x7c, 0xdc, 0x13, 0x9c, 0x03, 0x22, 0xa2,
+        0x5c, 0x62, 0x22, 0x82, 0xdc, 0x1d, 0xa3, 0xbd, 0x13, 0xdc, 0x5d, 0xc3, 0xbd, 0x9c, 0xdd, 0xc3,
+        0x5d, 0xe3, 0xfc, 0x62, 0xbc, 0x03, 0x02, 0xfd, 0x5c, 0x3d, 0xa3, 0x02, 0x02, 0x3c, 0x82, 0x42,
+        0xa2, 0x82, 0x5d, 0x82, 0x5d, 0xa3, 0x7c, 0xdc, 0x62, 0xfd, 0x9d, 0xc2, 0x80, 0x01, 0x1e, 0xe8,
+        0xe3, 0x19, 0xe5, 0x1a, 0xe8, 0x1d, 0x1d, 0xe1, 0xea, 0xe6, 0xe8, 0x1a, 0x1a, 0x10, 0xe8, 0xee,
+        0x13, 0x16, 0xe8, 0x1c, 0x98, 0xe0, 0x11, 0x95, 0x00, 0x8c, 0xce, 0xe0, 0x98, 0xd0, 0x50, 0x37,
+        0x97, 0xe8, 0x90, 0xd8, 0x68, 0x2f, 0xd7, 0xf8, 0xa8, 0xe0, 0xf8, 0x40, 0x12, 0x93, 0x0b, 0x86,
+        0x52, 0xf7, 0x30, 0xe7, 0x50, 0x9f, 0x88, 0xb8, 0x30, 0x57, 0x8f, 0x68, 0xb7, 0x10, 0xa7, 0x58,
+        0x37, 0xc7, 0x60, 0x4f, 0xe7, 0x00, 0xa7, 0x88, 0x28, 0x60, 0x4a, 0xf7, 0xf4, 0x73, 0x89, 0x75,
+        0x71, 0x73, 0x72, 0xf5, 0x77, 0xf2, 0x8d, 0x0a, 0x8c, 0x77, 0x89, 0x73, 0x76, 0x75, 0x8d, 0x8c,
+        0x0c, 0x77, 0x8e, 0x0d, 0x74, 0x75, 0xf7, 0x8f, 0x77, 0xf3, 0xf1, 0x0b, 0xf3, 0x74, 0x8b, 0x8a,
+        0xf7, 0x8d, 0x09, 0xf2, 0x75, 0x8c, 0xf7, 0x0a, 0x70, 0x09, 0xf4, 0x76, 0xf6, 0x88, 0x77, 0x0d,
+        0xf0, 0x75, 0xf6, 0x49, 0x08, 0x0d, 0x09, 0x74, 0x75, 0xf5, 0x77, 0x8e, 0x8f, 0x0d, 0xf0, 0x0f,
+        0x08, 0x0f, 0xf5, 0x09, 0x72, 0x73, 0xf2, 0x73, 0xf3, 0x88, 0x0b, 0x8f, 0x77, 0x0d, 0x72, 0x75,
+        0x09, 0x49, 0x88, 0x71, 0x0a, 0x8a, 0x74, 0xf4, 0x0b, 0x48, 0x00, 0xa6, 0x2c, 0x17, 0x97, 0x58,
+        0xd2, 0x73, 0x2c, 0xaa, 0x52, 0x00, 0xd5, 0xef, 0x4e, 0x6e,
+    ],
+]
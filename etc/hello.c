@@ -0,0 +1,21 @@
+// gcc -O2 -D_FORTIFY_SOURCE=2 -fstack-protector-all -fPIE -pie -fno-asynchronous-unwind-tables \
+//     -Wl,-z,relro,-z,now,-z,noexecstack,-z,noseparate-code,--enable-new-dtags,-rpath,'$ORIGIN/lib',--build-id=none,--hash-style=gnu \
+//     -o hello hello.c && strip --strip-debug -R .comment hello
+// then dumped into hello.rs
+
+#include <stdio.h>
+#include <string.h>
+
+__thread int counter = 42;
+__thread char scratch[16];
+
+int greet(const char *name) {
+    char buf[32];
+    memcpy(buf, name, strlen(name) + 1);
+    counter++;
+    return printf("hello %s %d\n", buf, counter);
+}
+
+int main(int argc, char **argv) {
+    return greet(argc > 1 ? argv[1] : "goblin");
+}
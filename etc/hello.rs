@@ -0,0 +1 @@
+vec![0x7F,0x45,0x4C,0x46,0x2,0x1,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x3E,0x0,0x1,0x0,0x0,0x0,0x80,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB0,0x16,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x40,0x0,0x38,0x0,0xC,0x0,0x40,0x0,0x1E,0x0,0x1D,0x0,0x6,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x40,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xA0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0xA0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0xE0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0xE0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0xE0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x1C,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1C,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB8,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0xB8,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x80,0xD,0x0,0x0,0x0,0x0,0x0,0x0,0x80,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x80,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x90,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x98,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0xA0,0xD,0x0,0x0,0x0,0x0,0x0,0x0,0xA0,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0xA0,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x20,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x80,0xD,0x0,0x0,0x0,0x0,0x0,0x0,0x80,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x80,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x53,0xE5,0x74,0x64,0x4,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x50,0xE5,0x74,0x64,0x4,0x0,0x0,0x0,0xC,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0xC,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0xC,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0x24,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x24,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x51,0xE5,0x74,0x64,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x52,0xE5,0x74,0x64,0x4,0x0,0x0,0x0,0x80,0xD,0x0,0x0,0x0,0x0,0x0,0x0,0x80,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x80,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x80,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x80,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2F,0x6C,0x69,0x62,0x36,0x34,0x2F,0x6C,0x64,0x2D,0x6C,0x69,0x6E,0x75,0x78,0x2D,0x78,0x38,0x36,0x2D,0x36,0x34,0x2E,0x73,0x6F,0x2E,0x32,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0x47,0x4E,0x55,0x0,0x2,0x80,0x0,0xC0,0x4,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x47,0x4E,0x55,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x9,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x81,0x0,0x0,0x0,0x0,0x0,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xD1,0x65,0xCE,0x6D,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x33,0x0,0x0,0x0,0x12,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x97,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1F,0x0,0x0,0x0,0x12,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x12,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x26,0x0,0x0,0x0,0x12,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB3,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x12,0x0,0x0,0x0,0x12,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xC2,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x45,0x0,0x0,0x0,0x22,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x5F,0x5F,0x73,0x74,0x61,0x63,0x6B,0x5F,0x63,0x68,0x6B,0x5F,0x66,0x61,0x69,0x6C,0x0,0x5F,0x5F,0x70,0x72,0x69,0x6E,0x74,0x66,0x5F,0x63,0x68,0x6B,0x0,0x73,0x74,0x72,0x6C,0x65,0x6E,0x0,0x5F,0x5F,0x6D,0x65,0x6D,0x63,0x70,0x79,0x5F,0x63,0x68,0x6B,0x0,0x5F,0x5F,0x6C,0x69,0x62,0x63,0x5F,0x73,0x74,0x61,0x72,0x74,0x5F,0x6D,0x61,0x69,0x6E,0x0,0x5F,0x5F,0x63,0x78,0x61,0x5F,0x66,0x69,0x6E,0x61,0x6C,0x69,0x7A,0x65,0x0,0x6C,0x69,0x62,0x63,0x2E,0x73,0x6F,0x2E,0x36,0x0,0x47,0x4C,0x49,0x42,0x43,0x5F,0x32,0x2E,0x33,0x2E,0x34,0x0,0x47,0x4C,0x49,0x42,0x43,0x5F,0x32,0x2E,0x34,0x0,0x47,0x4C,0x49,0x42,0x43,0x5F,0x32,0x2E,0x32,0x2E,0x35,0x0,0x47,0x4C,0x49,0x42,0x43,0x5F,0x32,0x2E,0x33,0x34,0x0,0x24,0x4F,0x52,0x49,0x47,0x49,0x4E,0x2F,0x6C,0x69,0x62,0x0,0x5F,0x49,0x54,0x4D,0x5F,0x64,0x65,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,0x54,0x4D,0x43,0x6C,0x6F,0x6E,0x65,0x54,0x61,0x62,0x6C,0x65,0x0,0x5F,0x5F,0x67,0x6D,0x6F,0x6E,0x5F,0x73,0x74,0x61,0x72,0x74,0x5F,0x5F,0x0,0x5F,0x49,0x54,0x4D,0x5F,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,0x54,0x4D,0x43,0x6C,0x6F,0x6E,0x65,0x54,0x61,0x62,0x6C,0x65,0x0,0x0,0x0,0x2,0x0,0x1,0x0,0x3,0x0,0x4,0x0,0x5,0x0,0x1,0x0,0x5,0x0,0x1,0x0,0x3,0x0,0x1,0x0,0x4,0x0,0x54,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x74,0x19,0x69,0x9,0x0,0x0,0x5,0x0,0x5E,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x14,0x69,0x69,0xD,0x0,0x0,0x4,0x0,0x6A,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x75,0x1A,0x69,0x9,0x0,0x0,0x3,0x0,0x74,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0xB4,0x91,0x96,0x6,0x0,0x0,0x2,0x0,0x80,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x90,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x60,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x98,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0xD8,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xE0,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xE8,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF0,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF8,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB8,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xC0,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xC8,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xD0,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x48,0x83,0xEC,0x8,0x48,0x8B,0x5,0x25,0x19,0x0,0x0,0x48,0x85,0xC0,0x74,0x2,0xFF,0xD0,0x48,0x83,0xC4,0x8,0xC3,0x0,0xFF,0x35,0xD2,0x18,0x0,0x0,0xFF,0x25,0xD4,0x18,0x0,0x0,0xF,0x1F,0x40,0x0,0xFF,0x25,0xD2,0x18,0x0,0x0,0x68,0x0,0x0,0x0,0x0,0xE9,0xE0,0xFF,0xFF,0xFF,0xFF,0x25,0xCA,0x18,0x0,0x0,0x68,0x1,0x0,0x0,0x0,0xE9,0xD0,0xFF,0xFF,0xFF,0xFF,0x25,0xC2,0x18,0x0,0x0,0x68,0x2,0x0,0x0,0x0,0xE9,0xC0,0xFF,0xFF,0xFF,0xFF,0x25,0xBA,0x18,0x0,0x0,0x68,0x3,0x0,0x0,0x0,0xE9,0xB0,0xFF,0xFF,0xFF,0xFF,0x25,0xD2,0x18,0x0,0x0,0x66,0x90,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x48,0x83,0xEC,0x18,0x89,0xF8,0x48,0x8D,0x3D,0xC8,0x1,0x0,0x0,0x64,0x48,0x8B,0x14,0x25,0x28,0x0,0x0,0x0,0x48,0x89,0x54,0x24,0x8,0x31,0xD2,0x83,0xF8,0x1,0x7E,0x4,0x48,0x8B,0x7E,0x8,0x48,0x8B,0x44,0x24,0x8,0x64,0x48,0x2B,0x4,0x25,0x28,0x0,0x0,0x0,0x75,0x9,0x48,0x83,0xC4,0x18,0xE9,0x1,0x1,0x0,0x0,0xE8,0x7C,0xFF,0xFF,0xFF,0x66,0x2E,0xF,0x1F,0x84,0x0,0x0,0x0,0x0,0x0,0x66,0x90,0x31,0xED,0x49,0x89,0xD1,0x5E,0x48,0x89,0xE2,0x48,0x83,0xE4,0xF0,0x50,0x54,0x45,0x31,0xC0,0x31,0xC9,0x48,0x8D,0x3D,0x95,0xFF,0xFF,0xFF,0xFF,0x15,0x37,0x18,0x0,0x0,0xF4,0x66,0x2E,0xF,0x1F,0x84,0x0,0x0,0x0,0x0,0x0,0xF,0x1F,0x40,0x0,0x48,0x8D,0x3D,0x59,0x18,0x0,0x0,0x48,0x8D,0x5,0x52,0x18,0x0,0x0,0x48,0x39,0xF8,0x74,0x15,0x48,0x8B,0x5,0x16,0x18,0x0,0x0,0x48,0x85,0xC0,0x74,0x9,0xFF,0xE0,0xF,0x1F,0x80,0x0,0x0,0x0,0x0,0xC3,0xF,0x1F,0x80,0x0,0x0,0x0,0x0,0x48,0x8D,0x3D,0x29,0x18,0x0,0x0,0x48,0x8D,0x35,0x22,0x18,0x0,0x0,0x48,0x29,0xFE,0x48,0x89,0xF0,0x48,0xC1,0xEE,0x3F,0x48,0xC1,0xF8,0x3,0x48,0x1,0xC6,0x48,0xD1,0xFE,0x74,0x14,0x48,0x8B,0x5,0xE5,0x17,0x0,0x0,0x48,0x85,0xC0,0x74,0x8,0xFF,0xE0,0x66,0xF,0x1F,0x44,0x0,0x0,0xC3,0xF,0x1F,0x80,0x0,0x0,0x0,0x0,0xF3,0xF,0x1E,0xFA,0x80,0x3D,0xE5,0x17,0x0,0x0,0x0,0x75,0x2B,0x55,0x48,0x83,0x3D,0xC2,0x17,0x0,0x0,0x0,0x48,0x89,0xE5,0x74,0xC,0x48,0x8B,0x3D,0xC6,0x17,0x0,0x0,0xE8,0xD9,0xFE,0xFF,0xFF,0xE8,0x64,0xFF,0xFF,0xFF,0xC6,0x5,0xBD,0x17,0x0,0x0,0x1,0x5D,0xC3,0xF,0x1F,0x0,0xC3,0xF,0x1F,0x80,0x0,0x0,0x0,0x0,0xF3,0xF,0x1E,0xFA,0xE9,0x77,0xFF,0xFF,0xFF,0xF,0x1F,0x80,0x0,0x0,0x0,0x0,0x53,0x48,0x89,0xFB,0x48,0x83,0xEC,0x30,0x64,0x48,0x8B,0x4,0x25,0x28,0x0,0x0,0x0,0x48,0x89,0x44,0x24,0x28,0x31,0xC0,0xE8,0x53,0xFE,0xFF,0xFF,0x48,0x89,0xE7,0xB9,0x20,0x0,0x0,0x0,0x48,0x89,0xDE,0x48,0x8D,0x50,0x1,0xE8,0x5F,0xFE,0xFF,0xFF,0x48,0x8D,0x35,0x50,0x0,0x0,0x0,0x48,0x89,0xC7,0x64,0x8B,0x4,0x25,0xE0,0xFF,0xFF,0xFF,0x48,0x89,0xFA,0xBF,0x1,0x0,0x0,0x0,0x8D,0x48,0x1,0x31,0xC0,0x64,0x89,0xC,0x25,0xE0,0xFF,0xFF,0xFF,0xE8,0x43,0xFE,0xFF,0xFF,0x48,0x8B,0x54,0x24,0x28,0x64,0x48,0x2B,0x14,0x25,0x28,0x0,0x0,0x0,0x75,0x6,0x48,0x83,0xC4,0x30,0x5B,0xC3,0xE8,0x8,0xFE,0xFF,0xFF,0x48,0x83,0xEC,0x8,0x48,0x83,0xC4,0x8,0xC3,0x0,0x0,0x0,0x1,0x0,0x2,0x0,0x68,0x65,0x6C,0x6C,0x6F,0x20,0x25,0x73,0x20,0x25,0x64,0xA,0x0,0x67,0x6F,0x62,0x6C,0x69,0x6E,0x0,0x1,0x1B,0x3,0x3B,0x20,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0xC4,0xFD,0xFF,0xFF,0x6C,0x0,0x0,0x0,0x14,0xFE,0xFF,0xFF,0x94,0x0,0x0,0x0,0x74,0xFE,0xFF,0xFF,0x3C,0x0,0x0,0x0,0x14,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x7A,0x52,0x0,0x1,0x78,0x10,0x1,0x1B,0xC,0x7,0x8,0x90,0x1,0x7,0x10,0x14,0x0,0x0,0x0,0x1C,0x0,0x0,0x0,0x30,0xFE,0xFF,0xFF,0x22,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x14,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x7A,0x52,0x0,0x1,0x78,0x10,0x1,0x1B,0xC,0x7,0x8,0x90,0x1,0x0,0x0,0x24,0x0,0x0,0x0,0x1C,0x0,0x0,0x0,0x50,0xFD,0xFF,0xFF,0x50,0x0,0x0,0x0,0x0,0xE,0x10,0x46,0xE,0x18,0x4A,0xF,0xB,0x77,0x8,0x80,0x0,0x3F,0x1A,0x3B,0x2A,0x33,0x24,0x22,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x44,0x0,0x0,0x0,0x78,0xFD,0xFF,0xFF,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2A,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x60,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x54,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8B,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xC,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB8,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0xD,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xE8,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x19,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x90,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x1B,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1A,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x98,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x1C,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF5,0xFE,0xFF,0x6F,0x0,0x0,0x0,0x0,0x40,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x58,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x68,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0xA,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xDC,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x15,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xA0,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x60,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x14,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x17,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x58,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x98,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xC0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1E,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xFB,0xFF,0xFF,0x6F,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0xFE,0xFF,0xFF,0x6F,0x0,0x0,0x0,0x0,0x48,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0xFF,0xFF,0xFF,0x6F,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF0,0xFF,0xFF,0x6F,0x0,0x0,0x0,0x0,0x34,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0xF9,0xFF,0xFF,0x6F,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xA0,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xE6,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0xF6,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x6,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x16,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x1,0x0,0x3,0x0,0x20,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB,0x0,0x0,0x0,0x2,0x0,0xE,0x0,0xB0,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xD,0x0,0x0,0x0,0x2,0x0,0xE,0x0,0xE0,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x2,0x0,0xE,0x0,0x20,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x36,0x0,0x0,0x0,0x1,0x0,0x1A,0x0,0x10,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x42,0x0,0x0,0x0,0x1,0x0,0x16,0x0,0x98,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x69,0x0,0x0,0x0,0x2,0x0,0xE,0x0,0x60,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x75,0x0,0x0,0x0,0x1,0x0,0x15,0x0,0x90,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x94,0x0,0x0,0x0,0x1,0x0,0x12,0x0,0xB4,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xA2,0x0,0x0,0x0,0x1,0x0,0x17,0x0,0xA0,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xAB,0x0,0x0,0x0,0x0,0x0,0x11,0x0,0xC,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xBE,0x0,0x0,0x0,0x1,0x0,0x18,0x0,0xA0,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xD4,0x0,0x0,0x0,0x12,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF1,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x50,0x1,0x0,0x0,0x20,0x0,0x19,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xD,0x1,0x0,0x0,0x10,0x0,0x19,0x0,0x10,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x14,0x1,0x0,0x0,0x12,0x2,0xF,0x0,0xE8,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1A,0x1,0x0,0x0,0x12,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2D,0x1,0x0,0x0,0x12,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x48,0x1,0x0,0x0,0x12,0x0,0xE,0x0,0x70,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4E,0x1,0x0,0x0,0x10,0x0,0x19,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x5B,0x1,0x0,0x0,0x12,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x74,0x1,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x83,0x1,0x0,0x0,0x11,0x2,0x19,0x0,0x8,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x90,0x1,0x0,0x0,0x11,0x0,0x10,0x0,0xF4,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x9F,0x1,0x0,0x0,0x10,0x0,0x1A,0x0,0x18,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x54,0x1,0x0,0x0,0x12,0x0,0xE,0x0,0x80,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x22,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xA4,0x1,0x0,0x0,0x16,0x0,0x13,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xAC,0x1,0x0,0x0,0x10,0x0,0x1A,0x0,0x10,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB8,0x1,0x0,0x0,0x12,0x0,0xE,0x0,0x30,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x44,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xBD,0x1,0x0,0x0,0x12,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xD6,0x1,0x0,0x0,0x16,0x0,0x14,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xDE,0x1,0x0,0x0,0x11,0x2,0x19,0x0,0x10,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xEA,0x1,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x2,0x0,0x0,0x22,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1F,0x2,0x0,0x0,0x12,0x2,0xB,0x0,0xB8,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x5F,0x5F,0x61,0x62,0x69,0x5F,0x74,0x61,0x67,0x0,0x64,0x65,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,0x5F,0x74,0x6D,0x5F,0x63,0x6C,0x6F,0x6E,0x65,0x73,0x0,0x5F,0x5F,0x64,0x6F,0x5F,0x67,0x6C,0x6F,0x62,0x61,0x6C,0x5F,0x64,0x74,0x6F,0x72,0x73,0x5F,0x61,0x75,0x78,0x0,0x63,0x6F,0x6D,0x70,0x6C,0x65,0x74,0x65,0x64,0x2E,0x30,0x0,0x5F,0x5F,0x64,0x6F,0x5F,0x67,0x6C,0x6F,0x62,0x61,0x6C,0x5F,0x64,0x74,0x6F,0x72,0x73,0x5F,0x61,0x75,0x78,0x5F,0x66,0x69,0x6E,0x69,0x5F,0x61,0x72,0x72,0x61,0x79,0x5F,0x65,0x6E,0x74,0x72,0x79,0x0,0x66,0x72,0x61,0x6D,0x65,0x5F,0x64,0x75,0x6D,0x6D,0x79,0x0,0x5F,0x5F,0x66,0x72,0x61,0x6D,0x65,0x5F,0x64,0x75,0x6D,0x6D,0x79,0x5F,0x69,0x6E,0x69,0x74,0x5F,0x61,0x72,0x72,0x61,0x79,0x5F,0x65,0x6E,0x74,0x72,0x79,0x0,0x5F,0x5F,0x46,0x52,0x41,0x4D,0x45,0x5F,0x45,0x4E,0x44,0x5F,0x5F,0x0,0x5F,0x44,0x59,0x4E,0x41,0x4D,0x49,0x43,0x0,0x5F,0x5F,0x47,0x4E,0x55,0x5F,0x45,0x48,0x5F,0x46,0x52,0x41,0x4D,0x45,0x5F,0x48,0x44,0x52,0x0,0x5F,0x47,0x4C,0x4F,0x42,0x41,0x4C,0x5F,0x4F,0x46,0x46,0x53,0x45,0x54,0x5F,0x54,0x41,0x42,0x4C,0x45,0x5F,0x0,0x5F,0x5F,0x6C,0x69,0x62,0x63,0x5F,0x73,0x74,0x61,0x72,0x74,0x5F,0x6D,0x61,0x69,0x6E,0x40,0x47,0x4C,0x49,0x42,0x43,0x5F,0x32,0x2E,0x33,0x34,0x0,0x5F,0x49,0x54,0x4D,0x5F,0x64,0x65,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,0x54,0x4D,0x43,0x6C,0x6F,0x6E,0x65,0x54,0x61,0x62,0x6C,0x65,0x0,0x5F,0x65,0x64,0x61,0x74,0x61,0x0,0x5F,0x66,0x69,0x6E,0x69,0x0,0x73,0x74,0x72,0x6C,0x65,0x6E,0x40,0x47,0x4C,0x49,0x42,0x43,0x5F,0x32,0x2E,0x32,0x2E,0x35,0x0,0x5F,0x5F,0x73,0x74,0x61,0x63,0x6B,0x5F,0x63,0x68,0x6B,0x5F,0x66,0x61,0x69,0x6C,0x40,0x47,0x4C,0x49,0x42,0x43,0x5F,0x32,0x2E,0x34,0x0,0x67,0x72,0x65,0x65,0x74,0x0,0x5F,0x5F,0x64,0x61,0x74,0x61,0x5F,0x73,0x74,0x61,0x72,0x74,0x0,0x5F,0x5F,0x6D,0x65,0x6D,0x63,0x70,0x79,0x5F,0x63,0x68,0x6B,0x40,0x47,0x4C,0x49,0x42,0x43,0x5F,0x32,0x2E,0x33,0x2E,0x34,0x0,0x5F,0x5F,0x67,0x6D,0x6F,0x6E,0x5F,0x73,0x74,0x61,0x72,0x74,0x5F,0x5F,0x0,0x5F,0x5F,0x64,0x73,0x6F,0x5F,0x68,0x61,0x6E,0x64,0x6C,0x65,0x0,0x5F,0x49,0x4F,0x5F,0x73,0x74,0x64,0x69,0x6E,0x5F,0x75,0x73,0x65,0x64,0x0,0x5F,0x65,0x6E,0x64,0x0,0x63,0x6F,0x75,0x6E,0x74,0x65,0x72,0x0,0x5F,0x5F,0x62,0x73,0x73,0x5F,0x73,0x74,0x61,0x72,0x74,0x0,0x6D,0x61,0x69,0x6E,0x0,0x5F,0x5F,0x70,0x72,0x69,0x6E,0x74,0x66,0x5F,0x63,0x68,0x6B,0x40,0x47,0x4C,0x49,0x42,0x43,0x5F,0x32,0x2E,0x33,0x2E,0x34,0x0,0x73,0x63,0x72,0x61,0x74,0x63,0x68,0x0,0x5F,0x5F,0x54,0x4D,0x43,0x5F,0x45,0x4E,0x44,0x5F,0x5F,0x0,0x5F,0x49,0x54,0x4D,0x5F,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,0x54,0x4D,0x43,0x6C,0x6F,0x6E,0x65,0x54,0x61,0x62,0x6C,0x65,0x0,0x5F,0x5F,0x63,0x78,0x61,0x5F,0x66,0x69,0x6E,0x61,0x6C,0x69,0x7A,0x65,0x40,0x47,0x4C,0x49,0x42,0x43,0x5F,0x32,0x2E,0x32,0x2E,0x35,0x0,0x5F,0x69,0x6E,0x69,0x74,0x0,0x0,0x2E,0x73,0x79,0x6D,0x74,0x61,0x62,0x0,0x2E,0x73,0x74,0x72,0x74,0x61,0x62,0x0,0x2E,0x73,0x68,0x73,0x74,0x72,0x74,0x61,0x62,0x0,0x2E,0x69,0x6E,0x74,0x65,0x72,0x70,0x0,0x2E,0x6E,0x6F,0x74,0x65,0x2E,0x67,0x6E,0x75,0x2E,0x70,0x72,0x6F,0x70,0x65,0x72,0x74,0x79,0x0,0x2E,0x6E,0x6F,0x74,0x65,0x2E,0x41,0x42,0x49,0x2D,0x74,0x61,0x67,0x0,0x2E,0x67,0x6E,0x75,0x2E,0x68,0x61,0x73,0x68,0x0,0x2E,0x64,0x79,0x6E,0x73,0x79,0x6D,0x0,0x2E,0x64,0x79,0x6E,0x73,0x74,0x72,0x0,0x2E,0x67,0x6E,0x75,0x2E,0x76,0x65,0x72,0x73,0x69,0x6F,0x6E,0x0,0x2E,0x67,0x6E,0x75,0x2E,0x76,0x65,0x72,0x73,0x69,0x6F,0x6E,0x5F,0x72,0x0,0x2E,0x72,0x65,0x6C,0x61,0x2E,0x64,0x79,0x6E,0x0,0x2E,0x72,0x65,0x6C,0x61,0x2E,0x70,0x6C,0x74,0x0,0x2E,0x69,0x6E,0x69,0x74,0x0,0x2E,0x70,0x6C,0x74,0x2E,0x67,0x6F,0x74,0x0,0x2E,0x74,0x65,0x78,0x74,0x0,0x2E,0x66,0x69,0x6E,0x69,0x0,0x2E,0x72,0x6F,0x64,0x61,0x74,0x61,0x0,0x2E,0x65,0x68,0x5F,0x66,0x72,0x61,0x6D,0x65,0x5F,0x68,0x64,0x72,0x0,0x2E,0x65,0x68,0x5F,0x66,0x72,0x61,0x6D,0x65,0x0,0x2E,0x74,0x64,0x61,0x74,0x61,0x0,0x2E,0x74,0x62,0x73,0x73,0x0,0x2E,0x69,0x6E,0x69,0x74,0x5F,0x61,0x72,0x72,0x61,0x79,0x0,0x2E,0x66,0x69,0x6E,0x69,0x5F,0x61,0x72,0x72,0x61,0x79,0x0,0x2E,0x64,0x79,0x6E,0x61,0x6D,0x69,0x63,0x0,0x2E,0x64,0x61,0x74,0x61,0x0,0x2E,0x62,0x73,0x73,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1B,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xE0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0xE0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x1C,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x23,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x36,0x0,0x0,0x0,0x7,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x44,0x0,0x0,0x0,0xF6,0xFF,0xFF,0x6F,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x40,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x40,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x24,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4E,0x0,0x0,0x0,0xB,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x68,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x68,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0xF0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x56,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x58,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x58,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0xDC,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x5E,0x0,0x0,0x0,0xFF,0xFF,0xFF,0x6F,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x34,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x34,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x14,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x6B,0x0,0x0,0x0,0xFE,0xFF,0xFF,0x6F,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x48,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x48,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x50,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x7A,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x98,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x98,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0xC0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x84,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x42,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x58,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x58,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x60,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x5,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8E,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB8,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0xB8,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x17,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x89,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xD0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0xD0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x50,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x94,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x9D,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x30,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0x30,0x7,0x0,0x0,0x0,0x0,0x0,0x0,0xB8,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xA3,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xE8,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0xE8,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xA9,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF4,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0xF4,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xB1,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xC,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0xC,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0x24,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xBF,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x30,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0x30,0x9,0x0,0x0,0x0,0x0,0x0,0x0,0x88,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xC9,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x3,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x80,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x80,0xD,0x0,0x0,0x0,0x0,0x0,0x0,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xD0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x3,0x4,0x0,0x0,0x0,0x0,0x0,0x0,0x90,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x84,0xD,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xD6,0x0,0x0,0x0,0xE,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x90,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x90,0xD,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xE2,0x0,0x0,0x0,0xF,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x98,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0x98,0xD,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xEE,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xA0,0x1D,0x0,0x0,0x0,0x0,0x0,0x0,0xA0,0xD,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x6,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x98,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xA0,0x1F,0x0,0x0,0x0,0x0,0x0,0x0,0xA0,0xF,0x0,0x0,0x0,0x0,0x0,0x0,0x60,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xF7,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xFD,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x20,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x10,0x10,0x0,0x0,0x0,0x0,0x0,0x0,0x78,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x1C,0x0,0x0,0x0,0xD,0x0,0x0,0x0,0x8,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x18,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x9,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x88,0x13,0x0,0x0,0x0,0x0,0x0,0x0,0x25,0x2,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x11,0x0,0x0,0x0,0x3,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0xAD,0x15,0x0,0x0,0x0,0x0,0x0,0x0,0x2,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x1,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0,0x0]
@@ -0,0 +1,103 @@
+//! Translation between virtual addresses and file offsets, using the `PT_LOAD` segments of a binary.
+//!
+//! Every `PT_LOAD` program header maps `p_filesz` bytes at file offset `p_offset` to the
+//! virtual address `p_vaddr`; the remaining `p_memsz - p_filesz` bytes (e.g., `.bss`) are zero
+//! filled and have no file offset. Segments may each have a different offset-to-address delta.
+
+if_alloc! {
+    use alloc::vec::Vec;
+    use elf::program_header::{ProgramHeader, PT_LOAD};
+
+    #[derive(Debug, Copy, Clone, PartialEq, Default)]
+    /// The file backed portion of a `PT_LOAD` segment
+    struct LoadSegment {
+        vaddr: u64,
+        offset: u64,
+        filesz: u64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    /// A map of the virtual address ranges of a binary's file image, built from its `PT_LOAD` segments
+    pub struct AddressMap {
+        segments: Vec<LoadSegment>,
+    }
+
+    impl AddressMap {
+        /// Builds the address map from the `PT_LOAD` headers in `program_headers`
+        pub fn new(program_headers: &[ProgramHeader]) -> Self {
+            let mut segments: Vec<LoadSegment> = program_headers.iter()
+                .filter(|phdr| phdr.p_type == PT_LOAD && phdr.p_filesz != 0)
+                .map(|phdr| LoadSegment { vaddr: phdr.p_vaddr, offset: phdr.p_offset, filesz: phdr.p_filesz })
+                .collect();
+            segments.sort_by_key(|segment| segment.vaddr);
+            AddressMap { segments }
+        }
+        /// Whether the binary has no file backed `PT_LOAD` segments, e.g., a relocatable object
+        pub fn is_empty(&self) -> bool {
+            self.segments.is_empty()
+        }
+        /// Returns the file offset of the virtual address `vaddr`, or `None` if it isn't backed by the file
+        pub fn vaddr_to_offset(&self, vaddr: u64) -> Option<usize> {
+            // segments are sorted by address, so the candidate is the last one starting at or before vaddr
+            let index = match self.segments.binary_search_by_key(&vaddr, |segment| segment.vaddr) {
+                Ok(index) => index,
+                Err(0) => return None,
+                Err(index) => index - 1,
+            };
+            let segment = &self.segments[index];
+            let delta = vaddr - segment.vaddr;
+            if delta < segment.filesz {
+                segment.offset.checked_add(delta).map(|offset| offset as usize)
+            } else {
+                None
+            }
+        }
+        /// Returns the virtual address the file offset `offset` is loaded at, or `None` if it isn't loaded
+        pub fn offset_to_vaddr(&self, offset: u64) -> Option<u64> {
+            self.segments.iter()
+                .find(|segment| segment.offset <= offset && offset - segment.offset < segment.filesz)
+                .and_then(|segment| segment.vaddr.checked_add(offset - segment.offset))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use elf::program_header::PT_DYNAMIC;
+
+        fn load(vaddr: u64, offset: u64, filesz: u64, memsz: u64) -> ProgramHeader {
+            ProgramHeader {
+                p_type: PT_LOAD,
+                p_vaddr: vaddr,
+                p_offset: offset,
+                p_filesz: filesz,
+                p_memsz: memsz,
+                ..ProgramHeader::new()
+            }
+        }
+
+        #[test]
+        fn translate_multiple_segments() {
+            let phdrs = [
+                load(0x20_0e10, 0xe10, 0x230, 0x240),
+                load(0x0, 0x0, 0x8c4, 0x8c4),
+                ProgramHeader { p_type: PT_DYNAMIC, p_vaddr: 0x20_0e20, p_offset: 0xe20, ..ProgramHeader::new() },
+            ];
+            let map = AddressMap::new(&phdrs);
+            assert_eq!(map.vaddr_to_offset(0x4a0), Some(0x4a0));
+            assert_eq!(map.vaddr_to_offset(0x20_0e20), Some(0xe20));
+            // in the zero filled part of the second segment
+            assert_eq!(map.vaddr_to_offset(0x20_1045), None);
+            // between the segments
+            assert_eq!(map.vaddr_to_offset(0x10_0000), None);
+            assert_eq!(map.offset_to_vaddr(0xe20), Some(0x20_0e20));
+            assert_eq!(map.offset_to_vaddr(0x100), Some(0x100));
+            assert_eq!(map.offset_to_vaddr(0x2000), None);
+            assert!(AddressMap::new(&[]).vaddr_to_offset(0).is_none());
+            // hostile headers whose translations overflow
+            let map = AddressMap::new(&[load(0x1000, u64::max_value() - 0x10, 0x100, 0x100), load(u64::max_value() - 0x10, 0x0, 0x100, 0x100)]);
+            assert_eq!(map.vaddr_to_offset(0x1080), None);
+            assert_eq!(map.offset_to_vaddr(0x80), None);
+        }
+    }
+}
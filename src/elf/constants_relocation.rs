@@ -794,6 +794,846 @@ pub const R_MIPS_COPY: u32 = 126;
 pub const R_MIPS_JUMP_SLOT: u32 = 127;
 pub const R_MIPS_NUM: u32 = 128;
 
+///////////////////
+// RISC-V
+///////////////////
+pub const R_RISCV_NONE: u32 = 0;
+pub const R_RISCV_32: u32 = 1;
+pub const R_RISCV_64: u32 = 2;
+pub const R_RISCV_RELATIVE: u32 = 3;
+pub const R_RISCV_COPY: u32 = 4;
+pub const R_RISCV_JUMP_SLOT: u32 = 5;
+pub const R_RISCV_TLS_DTPMOD32: u32 = 6;
+pub const R_RISCV_TLS_DTPMOD64: u32 = 7;
+pub const R_RISCV_TLS_DTPREL32: u32 = 8;
+pub const R_RISCV_TLS_DTPREL64: u32 = 9;
+pub const R_RISCV_TLS_TPREL32: u32 = 10;
+pub const R_RISCV_TLS_TPREL64: u32 = 11;
+/// TLS descriptor
+pub const R_RISCV_TLSDESC: u32 = 12;
+/// PC relative 13 bit conditional branch
+pub const R_RISCV_BRANCH: u32 = 16;
+/// PC relative 21 bit jump
+pub const R_RISCV_JAL: u32 = 17;
+/// PC relative call of an auipc and jalr pair
+pub const R_RISCV_CALL: u32 = 18;
+/// PC relative call of an auipc and jalr pair, through the PLT
+pub const R_RISCV_CALL_PLT: u32 = 19;
+/// High 20 bits of the PC relative offset to the GOT entry, paired with R_RISCV_PCREL_LO12_*
+pub const R_RISCV_GOT_HI20: u32 = 20;
+/// High 20 bits of the PC relative offset to the TLS IE GOT entry, paired with R_RISCV_PCREL_LO12_*
+pub const R_RISCV_TLS_GOT_HI20: u32 = 21;
+/// High 20 bits of the PC relative offset to the TLS GD GOT entry, paired with R_RISCV_PCREL_LO12_*
+pub const R_RISCV_TLS_GD_HI20: u32 = 22;
+/// High 20 bits of the PC relative offset, of auipc
+pub const R_RISCV_PCREL_HI20: u32 = 23;
+/// Low 12 bits of the PC relative offset of the R_RISCV_*_HI20 relocation at the symbol, in an I-type instruction
+pub const R_RISCV_PCREL_LO12_I: u32 = 24;
+/// Low 12 bits of the PC relative offset of the R_RISCV_*_HI20 relocation at the symbol, in an S-type instruction
+pub const R_RISCV_PCREL_LO12_S: u32 = 25;
+/// High 20 bits of the absolute address, of lui
+pub const R_RISCV_HI20: u32 = 26;
+/// Low 12 bits of the absolute address in an I-type instruction, paired with R_RISCV_HI20
+pub const R_RISCV_LO12_I: u32 = 27;
+/// Low 12 bits of the absolute address in an S-type instruction, paired with R_RISCV_HI20
+pub const R_RISCV_LO12_S: u32 = 28;
+/// High 20 bits of the TLS LE offset
+pub const R_RISCV_TPREL_HI20: u32 = 29;
+/// Low 12 bits of the TLS LE offset in an I-type instruction
+pub const R_RISCV_TPREL_LO12_I: u32 = 30;
+/// Low 12 bits of the TLS LE offset in an S-type instruction
+pub const R_RISCV_TPREL_LO12_S: u32 = 31;
+/// Marks the thread pointer add of TLS LE code
+pub const R_RISCV_TPREL_ADD: u32 = 32;
+pub const R_RISCV_ADD8: u32 = 33;
+pub const R_RISCV_ADD16: u32 = 34;
+pub const R_RISCV_ADD32: u32 = 35;
+pub const R_RISCV_ADD64: u32 = 36;
+pub const R_RISCV_SUB8: u32 = 37;
+pub const R_RISCV_SUB16: u32 = 38;
+pub const R_RISCV_SUB32: u32 = 39;
+pub const R_RISCV_SUB64: u32 = 40;
+pub const R_RISCV_GNU_VTINHERIT: u32 = 41;
+pub const R_RISCV_GNU_VTENTRY: u32 = 42;
+/// Alignment padding, removed by relaxation
+pub const R_RISCV_ALIGN: u32 = 43;
+pub const R_RISCV_RVC_BRANCH: u32 = 44;
+pub const R_RISCV_RVC_JUMP: u32 = 45;
+pub const R_RISCV_RVC_LUI: u32 = 46;
+pub const R_RISCV_GPREL_I: u32 = 47;
+pub const R_RISCV_GPREL_S: u32 = 48;
+pub const R_RISCV_TPREL_I: u32 = 49;
+pub const R_RISCV_TPREL_S: u32 = 50;
+/// The preceding relocation's instruction may be relaxed
+pub const R_RISCV_RELAX: u32 = 51;
+pub const R_RISCV_SUB6: u32 = 52;
+pub const R_RISCV_SET6: u32 = 53;
+pub const R_RISCV_SET8: u32 = 54;
+pub const R_RISCV_SET16: u32 = 55;
+pub const R_RISCV_SET32: u32 = 56;
+pub const R_RISCV_32_PCREL: u32 = 57;
+pub const R_RISCV_IRELATIVE: u32 = 58;
+/// 32 bit PC relative offset to the PLT entry
+pub const R_RISCV_PLT32: u32 = 59;
+/// ULEB128 set, paired with R_RISCV_SUB_ULEB128
+pub const R_RISCV_SET_ULEB128: u32 = 60;
+/// ULEB128 subtraction, paired with R_RISCV_SET_ULEB128
+pub const R_RISCV_SUB_ULEB128: u32 = 61;
+/// High 20 bits of the PC relative offset to the TLS descriptor
+pub const R_RISCV_TLSDESC_HI20: u32 = 62;
+/// Low 12 bits of the TLS descriptor load, paired with R_RISCV_TLSDESC_HI20
+pub const R_RISCV_TLSDESC_LOAD_LO12: u32 = 63;
+/// Low 12 bits of the TLS descriptor add, paired with R_RISCV_TLSDESC_HI20
+pub const R_RISCV_TLSDESC_ADD_LO12: u32 = 64;
+/// TLS descriptor call, paired with R_RISCV_TLSDESC_HI20
+pub const R_RISCV_TLSDESC_CALL: u32 = 65;
+
+///////////////////
+// PowerPC64
+///////////////////
+pub const R_PPC64_NONE: u32 = 0;
+/// 32bit absolute address
+pub const R_PPC64_ADDR32: u32 = 1;
+/// 26bit address, word aligned
+pub const R_PPC64_ADDR24: u32 = 2;
+/// 16bit absolute address
+pub const R_PPC64_ADDR16: u32 = 3;
+/// lower 16bits of address
+pub const R_PPC64_ADDR16_LO: u32 = 4;
+/// high 16bits of address
+pub const R_PPC64_ADDR16_HI: u32 = 5;
+/// adjusted high 16bits
+pub const R_PPC64_ADDR16_HA: u32 = 6;
+/// 16bit address, word aligned
+pub const R_PPC64_ADDR14: u32 = 7;
+pub const R_PPC64_ADDR14_BRTAKEN: u32 = 8;
+pub const R_PPC64_ADDR14_BRNTAKEN: u32 = 9;
+/// PC-rel. 26 bit, word aligned
+pub const R_PPC64_REL24: u32 = 10;
+/// PC relative 16 bit
+pub const R_PPC64_REL14: u32 = 11;
+pub const R_PPC64_REL14_BRTAKEN: u32 = 12;
+pub const R_PPC64_REL14_BRNTAKEN: u32 = 13;
+pub const R_PPC64_GOT16: u32 = 14;
+pub const R_PPC64_GOT16_LO: u32 = 15;
+pub const R_PPC64_GOT16_HI: u32 = 16;
+pub const R_PPC64_GOT16_HA: u32 = 17;
+pub const R_PPC64_COPY: u32 = 19;
+pub const R_PPC64_GLOB_DAT: u32 = 20;
+pub const R_PPC64_JMP_SLOT: u32 = 21;
+pub const R_PPC64_RELATIVE: u32 = 22;
+pub const R_PPC64_UADDR32: u32 = 24;
+pub const R_PPC64_UADDR16: u32 = 25;
+pub const R_PPC64_REL32: u32 = 26;
+pub const R_PPC64_PLT32: u32 = 27;
+pub const R_PPC64_PLTREL32: u32 = 28;
+pub const R_PPC64_PLT16_LO: u32 = 29;
+pub const R_PPC64_PLT16_HI: u32 = 30;
+pub const R_PPC64_PLT16_HA: u32 = 31;
+pub const R_PPC64_SECTOFF: u32 = 33;
+pub const R_PPC64_SECTOFF_LO: u32 = 34;
+pub const R_PPC64_SECTOFF_HI: u32 = 35;
+pub const R_PPC64_SECTOFF_HA: u32 = 36;
+/// word30 (S + A - P) >> 2
+pub const R_PPC64_ADDR30: u32 = 37;
+/// doubleword64 S + A
+pub const R_PPC64_ADDR64: u32 = 38;
+/// half16 #higher(S + A)
+pub const R_PPC64_ADDR16_HIGHER: u32 = 39;
+/// half16 #highera(S + A)
+pub const R_PPC64_ADDR16_HIGHERA: u32 = 40;
+/// half16 #highest(S + A)
+pub const R_PPC64_ADDR16_HIGHEST: u32 = 41;
+/// half16 #highesta(S + A)
+pub const R_PPC64_ADDR16_HIGHESTA: u32 = 42;
+/// doubleword64 S + A
+pub const R_PPC64_UADDR64: u32 = 43;
+/// doubleword64 S + A - P
+pub const R_PPC64_REL64: u32 = 44;
+/// doubleword64 L + A
+pub const R_PPC64_PLT64: u32 = 45;
+/// doubleword64 L + A - P
+pub const R_PPC64_PLTREL64: u32 = 46;
+/// half16* S + A - .TOC
+pub const R_PPC64_TOC16: u32 = 47;
+/// half16 #lo(S + A - .TOC.)
+pub const R_PPC64_TOC16_LO: u32 = 48;
+/// half16 #hi(S + A - .TOC.)
+pub const R_PPC64_TOC16_HI: u32 = 49;
+/// half16 #ha(S + A - .TOC.)
+pub const R_PPC64_TOC16_HA: u32 = 50;
+/// doubleword64 .TOC
+pub const R_PPC64_TOC: u32 = 51;
+/// half16* M + A
+pub const R_PPC64_PLTGOT16: u32 = 52;
+/// half16 #lo(M + A)
+pub const R_PPC64_PLTGOT16_LO: u32 = 53;
+/// half16 #hi(M + A)
+pub const R_PPC64_PLTGOT16_HI: u32 = 54;
+/// half16 #ha(M + A)
+pub const R_PPC64_PLTGOT16_HA: u32 = 55;
+/// half16ds* (S + A) >> 2
+pub const R_PPC64_ADDR16_DS: u32 = 56;
+/// half16ds #lo(S + A) >> 2
+pub const R_PPC64_ADDR16_LO_DS: u32 = 57;
+/// half16ds* (G + A) >> 2
+pub const R_PPC64_GOT16_DS: u32 = 58;
+/// half16ds #lo(G + A) >> 2
+pub const R_PPC64_GOT16_LO_DS: u32 = 59;
+/// half16ds #lo(L + A) >> 2
+pub const R_PPC64_PLT16_LO_DS: u32 = 60;
+/// half16ds* (R + A) >> 2
+pub const R_PPC64_SECTOFF_DS: u32 = 61;
+/// half16ds #lo(R + A) >> 2
+pub const R_PPC64_SECTOFF_LO_DS: u32 = 62;
+/// half16ds* (S + A - .TOC.) >> 2
+pub const R_PPC64_TOC16_DS: u32 = 63;
+/// half16ds #lo(S + A - .TOC.) >> 2
+pub const R_PPC64_TOC16_LO_DS: u32 = 64;
+/// half16ds* (M + A) >> 2
+pub const R_PPC64_PLTGOT16_DS: u32 = 65;
+/// half16ds #lo(M + A) >> 2
+pub const R_PPC64_PLTGOT16_LO_DS: u32 = 66;
+/// none (sym+add)@tls
+pub const R_PPC64_TLS: u32 = 67;
+/// doubleword64 (sym+add)@dtpmod
+pub const R_PPC64_DTPMOD64: u32 = 68;
+/// half16* (sym+add)@tprel
+pub const R_PPC64_TPREL16: u32 = 69;
+/// half16 (sym+add)@tprel@l
+pub const R_PPC64_TPREL16_LO: u32 = 70;
+/// half16 (sym+add)@tprel@h
+pub const R_PPC64_TPREL16_HI: u32 = 71;
+/// half16 (sym+add)@tprel@ha
+pub const R_PPC64_TPREL16_HA: u32 = 72;
+/// doubleword64 (sym+add)@tprel
+pub const R_PPC64_TPREL64: u32 = 73;
+/// half16* (sym+add)@dtprel
+pub const R_PPC64_DTPREL16: u32 = 74;
+/// half16 (sym+add)@dtprel@l
+pub const R_PPC64_DTPREL16_LO: u32 = 75;
+/// half16 (sym+add)@dtprel@h
+pub const R_PPC64_DTPREL16_HI: u32 = 76;
+/// half16 (sym+add)@dtprel@ha
+pub const R_PPC64_DTPREL16_HA: u32 = 77;
+/// doubleword64 (sym+add)@dtprel
+pub const R_PPC64_DTPREL64: u32 = 78;
+/// half16* (sym+add)@got@tlsgd
+pub const R_PPC64_GOT_TLSGD16: u32 = 79;
+/// half16 (sym+add)@got@tlsgd@l
+pub const R_PPC64_GOT_TLSGD16_LO: u32 = 80;
+/// half16 (sym+add)@got@tlsgd@h
+pub const R_PPC64_GOT_TLSGD16_HI: u32 = 81;
+/// half16 (sym+add)@got@tlsgd@ha
+pub const R_PPC64_GOT_TLSGD16_HA: u32 = 82;
+/// half16* (sym+add)@got@tlsld
+pub const R_PPC64_GOT_TLSLD16: u32 = 83;
+/// half16 (sym+add)@got@tlsld@l
+pub const R_PPC64_GOT_TLSLD16_LO: u32 = 84;
+/// half16 (sym+add)@got@tlsld@h
+pub const R_PPC64_GOT_TLSLD16_HI: u32 = 85;
+/// half16 (sym+add)@got@tlsld@ha
+pub const R_PPC64_GOT_TLSLD16_HA: u32 = 86;
+/// half16ds* (sym+add)@got@tprel
+pub const R_PPC64_GOT_TPREL16_DS: u32 = 87;
+/// half16ds (sym+add)@got@tprel@l
+pub const R_PPC64_GOT_TPREL16_LO_DS: u32 = 88;
+/// half16 (sym+add)@got@tprel@h
+pub const R_PPC64_GOT_TPREL16_HI: u32 = 89;
+/// half16 (sym+add)@got@tprel@ha
+pub const R_PPC64_GOT_TPREL16_HA: u32 = 90;
+/// half16ds* (sym+add)@got@dtprel
+pub const R_PPC64_GOT_DTPREL16_DS: u32 = 91;
+/// half16ds (sym+add)@got@dtprel@l
+pub const R_PPC64_GOT_DTPREL16_LO_DS: u32 = 92;
+/// half16 (sym+add)@got@dtprel@h
+pub const R_PPC64_GOT_DTPREL16_HI: u32 = 93;
+/// half16 (sym+add)@got@dtprel@ha
+pub const R_PPC64_GOT_DTPREL16_HA: u32 = 94;
+/// half16ds* (sym+add)@tprel
+pub const R_PPC64_TPREL16_DS: u32 = 95;
+/// half16ds (sym+add)@tprel@l
+pub const R_PPC64_TPREL16_LO_DS: u32 = 96;
+/// half16 (sym+add)@tprel@higher
+pub const R_PPC64_TPREL16_HIGHER: u32 = 97;
+/// half16 (sym+add)@tprel@highera
+pub const R_PPC64_TPREL16_HIGHERA: u32 = 98;
+/// half16 (sym+add)@tprel@highest
+pub const R_PPC64_TPREL16_HIGHEST: u32 = 99;
+/// half16 (sym+add)@tprel@highesta
+pub const R_PPC64_TPREL16_HIGHESTA: u32 = 100;
+/// half16ds* (sym+add)@dtprel
+pub const R_PPC64_DTPREL16_DS: u32 = 101;
+/// half16ds (sym+add)@dtprel@l
+pub const R_PPC64_DTPREL16_LO_DS: u32 = 102;
+/// half16 (sym+add)@dtprel@higher
+pub const R_PPC64_DTPREL16_HIGHER: u32 = 103;
+/// half16 (sym+add)@dtprel@highera
+pub const R_PPC64_DTPREL16_HIGHERA: u32 = 104;
+/// half16 (sym+add)@dtprel@highest
+pub const R_PPC64_DTPREL16_HIGHEST: u32 = 105;
+/// half16 (sym+add)@dtprel@highesta
+pub const R_PPC64_DTPREL16_HIGHESTA: u32 = 106;
+/// none (sym+add)@tlsgd
+pub const R_PPC64_TLSGD: u32 = 107;
+/// none (sym+add)@tlsld
+pub const R_PPC64_TLSLD: u32 = 108;
+pub const R_PPC64_TOCSAVE: u32 = 109;
+pub const R_PPC64_ADDR16_HIGH: u32 = 110;
+pub const R_PPC64_ADDR16_HIGHA: u32 = 111;
+pub const R_PPC64_TPREL16_HIGH: u32 = 112;
+pub const R_PPC64_TPREL16_HIGHA: u32 = 113;
+pub const R_PPC64_DTPREL16_HIGH: u32 = 114;
+pub const R_PPC64_DTPREL16_HIGHA: u32 = 115;
+/// PC relative 26 bit call which doesn't restore the TOC pointer
+pub const R_PPC64_REL24_NOTOC: u32 = 116;
+/// doubleword64 S + A, with the local entry point of S
+pub const R_PPC64_ADDR64_LOCAL: u32 = 117;
+/// Marks the global entry point code setting up the TOC pointer
+pub const R_PPC64_ENTRY: u32 = 118;
+/// Marks an inline PLT call sequence
+pub const R_PPC64_PLTSEQ: u32 = 119;
+/// Marks the call of an inline PLT call sequence
+pub const R_PPC64_PLTCALL: u32 = 120;
+/// Marks an inline PLT call sequence which doesn't use the TOC pointer
+pub const R_PPC64_PLTSEQ_NOTOC: u32 = 121;
+/// Marks the call of an inline PLT call sequence which doesn't use the TOC pointer
+pub const R_PPC64_PLTCALL_NOTOC: u32 = 122;
+/// Marks an optimizable PC relative GOT load
+pub const R_PPC64_PCREL_OPT: u32 = 123;
+/// PC relative 26 bit call which doesn't restore the TOC pointer, without Power10 instructions
+pub const R_PPC64_REL24_P9NOTOC: u32 = 124;
+/// prefixed 34 bit S + A
+pub const R_PPC64_D34: u32 = 128;
+/// prefixed 34 bit #lo34(S + A)
+pub const R_PPC64_D34_LO: u32 = 129;
+/// prefixed 34 bit #hi30(S + A)
+pub const R_PPC64_D34_HI30: u32 = 130;
+/// prefixed 34 bit #ha30(S + A)
+pub const R_PPC64_D34_HA30: u32 = 131;
+/// prefixed 34 bit S + A - P
+pub const R_PPC64_PCREL34: u32 = 132;
+/// prefixed 34 bit G + A - P
+pub const R_PPC64_GOT_PCREL34: u32 = 133;
+/// prefixed 34 bit L + A - P
+pub const R_PPC64_PLT_PCREL34: u32 = 134;
+/// prefixed 34 bit L + A - P, without a TOC pointer
+pub const R_PPC64_PLT_PCREL34_NOTOC: u32 = 135;
+/// half16 #high(S + A - P)
+pub const R_PPC64_REL16_HIGH: u32 = 240;
+/// half16 #higha(S + A - P)
+pub const R_PPC64_REL16_HIGHA: u32 = 241;
+/// half16 #higher(S + A - P)
+pub const R_PPC64_REL16_HIGHER: u32 = 242;
+/// half16 #highera(S + A - P)
+pub const R_PPC64_REL16_HIGHERA: u32 = 243;
+/// half16 #highest(S + A - P)
+pub const R_PPC64_REL16_HIGHEST: u32 = 244;
+/// half16 #highesta(S + A - P)
+pub const R_PPC64_REL16_HIGHESTA: u32 = 245;
+/// split16 #ha(S + A - P), of addpcis
+pub const R_PPC64_REL16DX_HA: u32 = 246;
+pub const R_PPC64_JMP_IREL: u32 = 247;
+pub const R_PPC64_IRELATIVE: u32 = 248;
+/// half16 (sym+add-.)
+pub const R_PPC64_REL16: u32 = 249;
+/// half16 (sym+add-.)@l
+pub const R_PPC64_REL16_LO: u32 = 250;
+/// half16 (sym+add-.)@h
+pub const R_PPC64_REL16_HI: u32 = 251;
+/// half16 (sym+add-.)@ha
+pub const R_PPC64_REL16_HA: u32 = 252;
+
+///////////////////
+// s390 / s390x
+///////////////////
+/// No reloc
+pub const R_390_NONE: u32 = 0;
+/// Direct 8 bit
+pub const R_390_8: u32 = 1;
+/// Direct 12 bit
+pub const R_390_12: u32 = 2;
+/// Direct 16 bit
+pub const R_390_16: u32 = 3;
+/// Direct 32 bit
+pub const R_390_32: u32 = 4;
+/// PC relative 32 bit
+pub const R_390_PC32: u32 = 5;
+/// 12 bit GOT offset
+pub const R_390_GOT12: u32 = 6;
+/// 32 bit GOT offset
+pub const R_390_GOT32: u32 = 7;
+/// 32 bit PC relative PLT address
+pub const R_390_PLT32: u32 = 8;
+/// Copy symbol at runtime
+pub const R_390_COPY: u32 = 9;
+/// Create GOT entry
+pub const R_390_GLOB_DAT: u32 = 10;
+/// Create PLT entry
+pub const R_390_JMP_SLOT: u32 = 11;
+/// Adjust by program base
+pub const R_390_RELATIVE: u32 = 12;
+/// 32 bit offset to GOT
+pub const R_390_GOTOFF32: u32 = 13;
+/// 32 bit PC relative offset to GOT
+pub const R_390_GOTPC: u32 = 14;
+/// 16 bit GOT offset
+pub const R_390_GOT16: u32 = 15;
+/// PC relative 16 bit
+pub const R_390_PC16: u32 = 16;
+/// PC relative 16 bit shifted by 1
+pub const R_390_PC16DBL: u32 = 17;
+/// 16 bit PC rel. PLT shifted by 1
+pub const R_390_PLT16DBL: u32 = 18;
+/// PC relative 32 bit shifted by 1
+pub const R_390_PC32DBL: u32 = 19;
+/// 32 bit PC rel. PLT shifted by 1
+pub const R_390_PLT32DBL: u32 = 20;
+/// 32 bit PC rel. GOT shifted by 1
+pub const R_390_GOTPCDBL: u32 = 21;
+/// Direct 64 bit
+pub const R_390_64: u32 = 22;
+/// PC relative 64 bit
+pub const R_390_PC64: u32 = 23;
+/// 64 bit GOT offset
+pub const R_390_GOT64: u32 = 24;
+/// 64 bit PC relative PLT address
+pub const R_390_PLT64: u32 = 25;
+/// 32 bit PC rel. to GOT entry >> 1
+pub const R_390_GOTENT: u32 = 26;
+/// 16 bit offset to GOT
+pub const R_390_GOTOFF16: u32 = 27;
+/// 64 bit offset to GOT
+pub const R_390_GOTOFF64: u32 = 28;
+/// 12 bit offset to jump slot
+pub const R_390_GOTPLT12: u32 = 29;
+/// 16 bit offset to jump slot
+pub const R_390_GOTPLT16: u32 = 30;
+/// 32 bit offset to jump slot
+pub const R_390_GOTPLT32: u32 = 31;
+/// 64 bit offset to jump slot
+pub const R_390_GOTPLT64: u32 = 32;
+/// 32 bit rel. offset to jump slot
+pub const R_390_GOTPLTENT: u32 = 33;
+/// 16 bit offset from GOT to PLT
+pub const R_390_PLTOFF16: u32 = 34;
+/// 32 bit offset from GOT to PLT
+pub const R_390_PLTOFF32: u32 = 35;
+/// 16 bit offset from GOT to PLT
+pub const R_390_PLTOFF64: u32 = 36;
+/// Tag for load insn in TLS code
+pub const R_390_TLS_LOAD: u32 = 37;
+/// Tag for function call in general dynamic TLS code
+pub const R_390_TLS_GDCALL: u32 = 38;
+/// Tag for function call in local dynamic TLS code
+pub const R_390_TLS_LDCALL: u32 = 39;
+/// Direct 32 bit for general dynamic thread local data
+pub const R_390_TLS_GD32: u32 = 40;
+/// Direct 64 bit for general dynamic thread local data
+pub const R_390_TLS_GD64: u32 = 41;
+/// 12 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE12: u32 = 42;
+/// 32 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE32: u32 = 43;
+/// 64 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE64: u32 = 44;
+/// Direct 32 bit for local dynamic thread local data in LE code
+pub const R_390_TLS_LDM32: u32 = 45;
+/// Direct 64 bit for local dynamic thread local data in LE code
+pub const R_390_TLS_LDM64: u32 = 46;
+/// 32 bit address of GOT entry for negated static TLS block offset
+pub const R_390_TLS_IE32: u32 = 47;
+/// 64 bit address of GOT entry for negated static TLS block offset
+pub const R_390_TLS_IE64: u32 = 48;
+/// 32 bit rel. offset to GOT entry for negated static TLS block offset
+pub const R_390_TLS_IEENT: u32 = 49;
+/// 32 bit negated offset relative to static TLS block
+pub const R_390_TLS_LE32: u32 = 50;
+/// 64 bit negated offset relative to static TLS block
+pub const R_390_TLS_LE64: u32 = 51;
+/// 32 bit offset relative to TLS block
+pub const R_390_TLS_LDO32: u32 = 52;
+/// 64 bit offset relative to TLS block
+pub const R_390_TLS_LDO64: u32 = 53;
+/// ID of module containing symbol
+pub const R_390_TLS_DTPMOD: u32 = 54;
+/// Offset in TLS block
+pub const R_390_TLS_DTPOFF: u32 = 55;
+/// Negated offset in static TLS block
+pub const R_390_TLS_TPOFF: u32 = 56;
+/// Direct 20 bit
+pub const R_390_20: u32 = 57;
+/// 20 bit GOT offset
+pub const R_390_GOT20: u32 = 58;
+/// 20 bit offset to jump slot
+pub const R_390_GOTPLT20: u32 = 59;
+/// 20 bit GOT offset for static TLS block offset
+pub const R_390_TLS_GOTIE20: u32 = 60;
+/// STT_GNU_IFUNC relocation
+pub const R_390_IRELATIVE: u32 = 61;
+pub const R_390_NUM: u32 = 62;
+
+///////////////////
+// SPARC
+///////////////////
+/// No reloc
+pub const R_SPARC_NONE: u32 = 0;
+/// Direct 8 bit
+pub const R_SPARC_8: u32 = 1;
+/// Direct 16 bit
+pub const R_SPARC_16: u32 = 2;
+/// Direct 32 bit
+pub const R_SPARC_32: u32 = 3;
+/// PC relative 8 bit
+pub const R_SPARC_DISP8: u32 = 4;
+/// PC relative 16 bit
+pub const R_SPARC_DISP16: u32 = 5;
+/// PC relative 32 bit
+pub const R_SPARC_DISP32: u32 = 6;
+/// PC relative 30 bit shifted
+pub const R_SPARC_WDISP30: u32 = 7;
+/// PC relative 22 bit shifted
+pub const R_SPARC_WDISP22: u32 = 8;
+/// High 22 bit
+pub const R_SPARC_HI22: u32 = 9;
+/// Direct 22 bit
+pub const R_SPARC_22: u32 = 10;
+/// Direct 13 bit
+pub const R_SPARC_13: u32 = 11;
+/// Truncated 10 bit
+pub const R_SPARC_LO10: u32 = 12;
+/// Truncated 10 bit GOT entry
+pub const R_SPARC_GOT10: u32 = 13;
+/// 13 bit GOT entry
+pub const R_SPARC_GOT13: u32 = 14;
+/// 22 bit GOT entry shifted
+pub const R_SPARC_GOT22: u32 = 15;
+/// PC relative 10 bit truncated
+pub const R_SPARC_PC10: u32 = 16;
+/// PC relative 22 bit shifted
+pub const R_SPARC_PC22: u32 = 17;
+/// 30 bit PC relative PLT address
+pub const R_SPARC_WPLT30: u32 = 18;
+/// Copy symbol at runtime
+pub const R_SPARC_COPY: u32 = 19;
+/// Create GOT entry
+pub const R_SPARC_GLOB_DAT: u32 = 20;
+/// Create PLT entry
+pub const R_SPARC_JMP_SLOT: u32 = 21;
+/// Adjust by program base
+pub const R_SPARC_RELATIVE: u32 = 22;
+/// Direct 32 bit unaligned
+pub const R_SPARC_UA32: u32 = 23;
+/// Direct 32 bit ref to PLT entry
+pub const R_SPARC_PLT32: u32 = 24;
+/// High 22 bit PLT entry
+pub const R_SPARC_HIPLT22: u32 = 25;
+/// Truncated 10 bit PLT entry
+pub const R_SPARC_LOPLT10: u32 = 26;
+/// PC rel 32 bit ref to PLT entry
+pub const R_SPARC_PCPLT32: u32 = 27;
+/// PC rel high 22 bit PLT entry
+pub const R_SPARC_PCPLT22: u32 = 28;
+/// PC rel trunc 10 bit PLT entry
+pub const R_SPARC_PCPLT10: u32 = 29;
+/// Direct 10 bit
+pub const R_SPARC_10: u32 = 30;
+/// Direct 11 bit
+pub const R_SPARC_11: u32 = 31;
+/// Direct 64 bit
+pub const R_SPARC_64: u32 = 32;
+/// 10bit with secondary 13bit addend
+pub const R_SPARC_OLO10: u32 = 33;
+/// Top 22 bits of direct 64 bit
+pub const R_SPARC_HH22: u32 = 34;
+/// High middle 10 bits of
+pub const R_SPARC_HM10: u32 = 35;
+/// Low middle 22 bits of
+pub const R_SPARC_LM22: u32 = 36;
+/// Top 22 bits of pc rel 64 bit
+pub const R_SPARC_PC_HH22: u32 = 37;
+/// High middle 10 bit of
+pub const R_SPARC_PC_HM10: u32 = 38;
+/// Low miggle 22 bits of
+pub const R_SPARC_PC_LM22: u32 = 39;
+/// PC relative 16 bit shifted
+pub const R_SPARC_WDISP16: u32 = 40;
+/// PC relative 19 bit shifted
+pub const R_SPARC_WDISP19: u32 = 41;
+/// was part of v9 ABI but was removed
+pub const R_SPARC_GLOB_JMP: u32 = 42;
+/// Direct 7 bit
+pub const R_SPARC_7: u32 = 43;
+/// Direct 5 bit
+pub const R_SPARC_5: u32 = 44;
+/// Direct 6 bit
+pub const R_SPARC_6: u32 = 45;
+/// PC relative 64 bit
+pub const R_SPARC_DISP64: u32 = 46;
+/// Direct 64 bit ref to PLT entry
+pub const R_SPARC_PLT64: u32 = 47;
+/// High 22 bit complemented
+pub const R_SPARC_HIX22: u32 = 48;
+/// Truncated 11 bit complemented
+pub const R_SPARC_LOX10: u32 = 49;
+/// Direct high 12 of 44 bit
+pub const R_SPARC_H44: u32 = 50;
+/// Direct mid 22 of 44 bit
+pub const R_SPARC_M44: u32 = 51;
+/// Direct low 10 of 44 bit
+pub const R_SPARC_L44: u32 = 52;
+/// Global register usage
+pub const R_SPARC_REGISTER: u32 = 53;
+/// Direct 64 bit unaligned
+pub const R_SPARC_UA64: u32 = 54;
+/// Direct 16 bit unaligned
+pub const R_SPARC_UA16: u32 = 55;
+pub const R_SPARC_TLS_GD_HI22: u32 = 56;
+pub const R_SPARC_TLS_GD_LO10: u32 = 57;
+pub const R_SPARC_TLS_GD_ADD: u32 = 58;
+pub const R_SPARC_TLS_GD_CALL: u32 = 59;
+pub const R_SPARC_TLS_LDM_HI22: u32 = 60;
+pub const R_SPARC_TLS_LDM_LO10: u32 = 61;
+pub const R_SPARC_TLS_LDM_ADD: u32 = 62;
+pub const R_SPARC_TLS_LDM_CALL: u32 = 63;
+pub const R_SPARC_TLS_LDO_HIX22: u32 = 64;
+pub const R_SPARC_TLS_LDO_LOX10: u32 = 65;
+pub const R_SPARC_TLS_LDO_ADD: u32 = 66;
+pub const R_SPARC_TLS_IE_HI22: u32 = 67;
+pub const R_SPARC_TLS_IE_LO10: u32 = 68;
+pub const R_SPARC_TLS_IE_LD: u32 = 69;
+pub const R_SPARC_TLS_IE_LDX: u32 = 70;
+pub const R_SPARC_TLS_IE_ADD: u32 = 71;
+pub const R_SPARC_TLS_LE_HIX22: u32 = 72;
+pub const R_SPARC_TLS_LE_LOX10: u32 = 73;
+pub const R_SPARC_TLS_DTPMOD32: u32 = 74;
+pub const R_SPARC_TLS_DTPMOD64: u32 = 75;
+pub const R_SPARC_TLS_DTPOFF32: u32 = 76;
+pub const R_SPARC_TLS_DTPOFF64: u32 = 77;
+pub const R_SPARC_TLS_TPOFF32: u32 = 78;
+pub const R_SPARC_TLS_TPOFF64: u32 = 79;
+pub const R_SPARC_GOTDATA_HIX22: u32 = 80;
+pub const R_SPARC_GOTDATA_LOX10: u32 = 81;
+pub const R_SPARC_GOTDATA_OP_HIX22: u32 = 82;
+pub const R_SPARC_GOTDATA_OP_LOX10: u32 = 83;
+pub const R_SPARC_GOTDATA_OP: u32 = 84;
+pub const R_SPARC_H34: u32 = 85;
+pub const R_SPARC_SIZE32: u32 = 86;
+pub const R_SPARC_SIZE64: u32 = 87;
+pub const R_SPARC_WDISP10: u32 = 88;
+pub const R_SPARC_JMP_IREL: u32 = 248;
+pub const R_SPARC_IRELATIVE: u32 = 249;
+pub const R_SPARC_GNU_VTINHERIT: u32 = 250;
+pub const R_SPARC_GNU_VTENTRY: u32 = 251;
+pub const R_SPARC_REV32: u32 = 252;
+pub const R_SPARC_NUM: u32 = 253;
+
+///////////////////
+// LoongArch
+///////////////////
+pub const R_LARCH_NONE: u32 = 0;
+pub const R_LARCH_32: u32 = 1;
+pub const R_LARCH_64: u32 = 2;
+pub const R_LARCH_RELATIVE: u32 = 3;
+pub const R_LARCH_COPY: u32 = 4;
+pub const R_LARCH_JUMP_SLOT: u32 = 5;
+pub const R_LARCH_TLS_DTPMOD32: u32 = 6;
+pub const R_LARCH_TLS_DTPMOD64: u32 = 7;
+pub const R_LARCH_TLS_DTPREL32: u32 = 8;
+pub const R_LARCH_TLS_DTPREL64: u32 = 9;
+pub const R_LARCH_TLS_TPREL32: u32 = 10;
+pub const R_LARCH_TLS_TPREL64: u32 = 11;
+pub const R_LARCH_IRELATIVE: u32 = 12;
+/// TLS descriptor, 32 bit
+pub const R_LARCH_TLS_DESC32: u32 = 13;
+/// TLS descriptor, 64 bit
+pub const R_LARCH_TLS_DESC64: u32 = 14;
+pub const R_LARCH_MARK_LA: u32 = 20;
+pub const R_LARCH_MARK_PCREL: u32 = 21;
+pub const R_LARCH_SOP_PUSH_PCREL: u32 = 22;
+pub const R_LARCH_SOP_PUSH_ABSOLUTE: u32 = 23;
+pub const R_LARCH_SOP_PUSH_DUP: u32 = 24;
+pub const R_LARCH_SOP_PUSH_GPREL: u32 = 25;
+pub const R_LARCH_SOP_PUSH_TLS_TPREL: u32 = 26;
+pub const R_LARCH_SOP_PUSH_TLS_GOT: u32 = 27;
+pub const R_LARCH_SOP_PUSH_TLS_GD: u32 = 28;
+pub const R_LARCH_SOP_PUSH_PLT_PCREL: u32 = 29;
+pub const R_LARCH_SOP_ASSERT: u32 = 30;
+pub const R_LARCH_SOP_NOT: u32 = 31;
+pub const R_LARCH_SOP_SUB: u32 = 32;
+pub const R_LARCH_SOP_SL: u32 = 33;
+pub const R_LARCH_SOP_SR: u32 = 34;
+pub const R_LARCH_SOP_ADD: u32 = 35;
+pub const R_LARCH_SOP_AND: u32 = 36;
+pub const R_LARCH_SOP_IF_ELSE: u32 = 37;
+pub const R_LARCH_SOP_POP_32_S_10_5: u32 = 38;
+pub const R_LARCH_SOP_POP_32_U_10_12: u32 = 39;
+pub const R_LARCH_SOP_POP_32_S_10_12: u32 = 40;
+pub const R_LARCH_SOP_POP_32_S_10_16: u32 = 41;
+pub const R_LARCH_SOP_POP_32_S_10_16_S2: u32 = 42;
+pub const R_LARCH_SOP_POP_32_S_5_20: u32 = 43;
+pub const R_LARCH_SOP_POP_32_S_0_5_10_16_S2: u32 = 44;
+pub const R_LARCH_SOP_POP_32_S_0_10_10_16_S2: u32 = 45;
+pub const R_LARCH_SOP_POP_32_U: u32 = 46;
+pub const R_LARCH_ADD8: u32 = 47;
+pub const R_LARCH_ADD16: u32 = 48;
+pub const R_LARCH_ADD24: u32 = 49;
+pub const R_LARCH_ADD32: u32 = 50;
+pub const R_LARCH_ADD64: u32 = 51;
+pub const R_LARCH_SUB8: u32 = 52;
+pub const R_LARCH_SUB16: u32 = 53;
+pub const R_LARCH_SUB24: u32 = 54;
+pub const R_LARCH_SUB32: u32 = 55;
+pub const R_LARCH_SUB64: u32 = 56;
+pub const R_LARCH_GNU_VTINHERIT: u32 = 57;
+pub const R_LARCH_GNU_VTENTRY: u32 = 58;
+/// 18 bit PC relative branch shifted by 2
+pub const R_LARCH_B16: u32 = 64;
+/// 23 bit PC relative branch shifted by 2
+pub const R_LARCH_B21: u32 = 65;
+/// 28 bit PC relative branch shifted by 2
+pub const R_LARCH_B26: u32 = 66;
+/// Bits 12..31 of the absolute address
+pub const R_LARCH_ABS_HI20: u32 = 67;
+/// Bits 0..11 of the absolute address
+pub const R_LARCH_ABS_LO12: u32 = 68;
+/// Bits 32..51 of the absolute address
+pub const R_LARCH_ABS64_LO20: u32 = 69;
+/// Bits 52..63 of the absolute address
+pub const R_LARCH_ABS64_HI12: u32 = 70;
+/// Bits 12..31 of the PC relative page offset, of pcalau12i
+pub const R_LARCH_PCALA_HI20: u32 = 71;
+/// Bits 0..11 of the address, paired with R_LARCH_PCALA_HI20
+pub const R_LARCH_PCALA_LO12: u32 = 72;
+/// Bits 32..51 of the PC relative page offset
+pub const R_LARCH_PCALA64_LO20: u32 = 73;
+/// Bits 52..63 of the PC relative page offset
+pub const R_LARCH_PCALA64_HI12: u32 = 74;
+/// Bits 12..31 of the PC relative page offset of the GOT entry
+pub const R_LARCH_GOT_PC_HI20: u32 = 75;
+/// Bits 0..11 of the address of the GOT entry
+pub const R_LARCH_GOT_PC_LO12: u32 = 76;
+/// Bits 32..51 of the PC relative page offset of the GOT entry
+pub const R_LARCH_GOT64_PC_LO20: u32 = 77;
+/// Bits 52..63 of the PC relative page offset of the GOT entry
+pub const R_LARCH_GOT64_PC_HI12: u32 = 78;
+/// Bits 12..31 of the absolute address of the GOT entry
+pub const R_LARCH_GOT_HI20: u32 = 79;
+/// Bits 0..11 of the absolute address of the GOT entry
+pub const R_LARCH_GOT_LO12: u32 = 80;
+/// Bits 32..51 of the absolute address of the GOT entry
+pub const R_LARCH_GOT64_LO20: u32 = 81;
+/// Bits 52..63 of the absolute address of the GOT entry
+pub const R_LARCH_GOT64_HI12: u32 = 82;
+/// Bits 12..31 of the TLS LE offset
+pub const R_LARCH_TLS_LE_HI20: u32 = 83;
+/// Bits 0..11 of the TLS LE offset
+pub const R_LARCH_TLS_LE_LO12: u32 = 84;
+/// Bits 32..51 of the TLS LE offset
+pub const R_LARCH_TLS_LE64_LO20: u32 = 85;
+/// Bits 52..63 of the TLS LE offset
+pub const R_LARCH_TLS_LE64_HI12: u32 = 86;
+/// Bits 12..31 of the PC relative page offset of the TLS IE GOT entry
+pub const R_LARCH_TLS_IE_PC_HI20: u32 = 87;
+/// Bits 0..11 of the address of the TLS IE GOT entry
+pub const R_LARCH_TLS_IE_PC_LO12: u32 = 88;
+/// Bits 32..51 of the PC relative page offset of the TLS IE GOT entry
+pub const R_LARCH_TLS_IE64_PC_LO20: u32 = 89;
+/// Bits 52..63 of the PC relative page offset of the TLS IE GOT entry
+pub const R_LARCH_TLS_IE64_PC_HI12: u32 = 90;
+/// Bits 12..31 of the absolute address of the TLS IE GOT entry
+pub const R_LARCH_TLS_IE_HI20: u32 = 91;
+/// Bits 0..11 of the absolute address of the TLS IE GOT entry
+pub const R_LARCH_TLS_IE_LO12: u32 = 92;
+/// Bits 32..51 of the absolute address of the TLS IE GOT entry
+pub const R_LARCH_TLS_IE64_LO20: u32 = 93;
+/// Bits 52..63 of the absolute address of the TLS IE GOT entry
+pub const R_LARCH_TLS_IE64_HI12: u32 = 94;
+/// Bits 12..31 of the PC relative page offset of the TLS LD GOT entry
+pub const R_LARCH_TLS_LD_PC_HI20: u32 = 95;
+/// Bits 12..31 of the absolute address of the TLS LD GOT entry
+pub const R_LARCH_TLS_LD_HI20: u32 = 96;
+/// Bits 12..31 of the PC relative page offset of the TLS GD GOT entry
+pub const R_LARCH_TLS_GD_PC_HI20: u32 = 97;
+/// Bits 12..31 of the absolute address of the TLS GD GOT entry
+pub const R_LARCH_TLS_GD_HI20: u32 = 98;
+/// PC relative 32 bit
+pub const R_LARCH_32_PCREL: u32 = 99;
+/// The instruction may be relaxed
+pub const R_LARCH_RELAX: u32 = 100;
+/// Alignment padding, removed by relaxation
+pub const R_LARCH_ALIGN: u32 = 102;
+/// 22 bit PC relative offset shifted by 2, of pcaddi
+pub const R_LARCH_PCREL20_S2: u32 = 103;
+/// 6 bit addition
+pub const R_LARCH_ADD6: u32 = 105;
+/// 6 bit subtraction
+pub const R_LARCH_SUB6: u32 = 106;
+/// ULEB128 addition
+pub const R_LARCH_ADD_ULEB128: u32 = 107;
+/// ULEB128 subtraction
+pub const R_LARCH_SUB_ULEB128: u32 = 108;
+/// PC relative 64 bit
+pub const R_LARCH_64_PCREL: u32 = 109;
+/// 38 bit PC relative call shifted by 2, of pcaddu18i and jirl
+pub const R_LARCH_CALL36: u32 = 110;
+
+/// Returns the `R_*_RELATIVE` relocation type of `machine`, which is implied by the entries of `DT_RELR`
+#[inline]
+pub fn r_relative(machine: u16) -> Option<u32> {
+    use elf::header::*;
+    match machine {
+        EM_386 => Some(R_386_RELATIVE),
+        EM_X86_64 => Some(R_X86_64_RELATIVE),
+        EM_ARM => Some(R_ARM_RELATIVE),
+        EM_AARCH64 => Some(R_AARCH64_RELATIVE),
+        EM_OPENRISC => Some(R_OR1K_RELATIVE),
+        EM_RISCV => Some(R_RISCV_RELATIVE),
+        EM_PPC64 => Some(R_PPC64_RELATIVE),
+        EM_S390 => Some(R_390_RELATIVE),
+        EM_SPARC | EM_SPARC32PLUS | EM_SPARCV9 => Some(R_SPARC_RELATIVE),
+        EM_LOONGARCH => Some(R_LARCH_RELATIVE),
+        _ => None,
+    }
+}
+
+/// Whether `typ` is a RISC-V `auipc` relocation, whose PC relative offset is split with the
+/// `R_RISCV_PCREL_LO12_*` (or `R_RISCV_TLSDESC_*_LO12`) relocations whose symbol is its address
+#[inline]
+pub fn is_riscv_pcrel_hi20(typ: u32) -> bool {
+    match typ {
+        R_RISCV_PCREL_HI20 | R_RISCV_GOT_HI20 | R_RISCV_TLS_GOT_HI20 | R_RISCV_TLS_GD_HI20 | R_RISCV_TLSDESC_HI20 => true,
+        _ => false,
+    }
+}
+
+/// Whether `typ` is a RISC-V relocation taking the low 12 bits of the offset computed by the
+/// `R_RISCV_*_HI20` relocation at its symbol, rather than of its own symbol
+#[inline]
+pub fn is_riscv_pcrel_lo12(typ: u32) -> bool {
+    match typ {
+        R_RISCV_PCREL_LO12_I | R_RISCV_PCREL_LO12_S | R_RISCV_TLSDESC_LOAD_LO12 | R_RISCV_TLSDESC_ADD_LO12 => true,
+        _ => false,
+    }
+}
+
+/// Returns the upper 20 bits of `value` for a RISC-V `lui`/`auipc`, rounded so that adding the
+/// sign extended `riscv_lo12(value)` gives back `value`
+#[inline]
+pub fn riscv_hi20(value: i64) -> i64 {
+    value.wrapping_add(0x800) >> 12
+}
+
+/// Returns the signed lower 12 bits of `value` for a RISC-V I-type or S-type immediate, see `riscv_hi20`
+#[inline]
+pub fn riscv_lo12(value: i64) -> i64 {
+    value.wrapping_sub(riscv_hi20(value) << 12)
+}
+
+/// The offset of the PowerPC64 TOC pointer (`.TOC.`, in `r2`) from the start of the `.got`, so that
+/// a signed 16 bit offset reaches 64KiB of the TOC
+pub const PPC64_TOC_BIAS: u64 = 0x8000;
+
 #[inline]
 pub fn r_to_str(typ: u32, machine: u16) -> &'static str {
     use elf::header::*;
@@ -1243,6 +2083,488 @@ pub fn r_to_str(typ: u32, machine: u16) -> &'static str {
         R_MIPS_COPY => "R_MIPS_COPY",
         R_MIPS_JUMP_SLOT => "R_MIPS_JUMP_SLOT",
         _ => "R_UNKNOWN_MIPS",
-        }} _ => "R_UNKNOWN"
+        }},
+        // riscv
+        EM_RISCV => { match typ {
+        R_RISCV_NONE => "R_RISCV_NONE",
+        R_RISCV_32 => "R_RISCV_32",
+        R_RISCV_64 => "R_RISCV_64",
+        R_RISCV_RELATIVE => "R_RISCV_RELATIVE",
+        R_RISCV_COPY => "R_RISCV_COPY",
+        R_RISCV_JUMP_SLOT => "R_RISCV_JUMP_SLOT",
+        R_RISCV_TLS_DTPMOD32 => "R_RISCV_TLS_DTPMOD32",
+        R_RISCV_TLS_DTPMOD64 => "R_RISCV_TLS_DTPMOD64",
+        R_RISCV_TLS_DTPREL32 => "R_RISCV_TLS_DTPREL32",
+        R_RISCV_TLS_DTPREL64 => "R_RISCV_TLS_DTPREL64",
+        R_RISCV_TLS_TPREL32 => "R_RISCV_TLS_TPREL32",
+        R_RISCV_TLS_TPREL64 => "R_RISCV_TLS_TPREL64",
+        R_RISCV_TLSDESC => "R_RISCV_TLSDESC",
+        R_RISCV_BRANCH => "R_RISCV_BRANCH",
+        R_RISCV_JAL => "R_RISCV_JAL",
+        R_RISCV_CALL => "R_RISCV_CALL",
+        R_RISCV_CALL_PLT => "R_RISCV_CALL_PLT",
+        R_RISCV_GOT_HI20 => "R_RISCV_GOT_HI20",
+        R_RISCV_TLS_GOT_HI20 => "R_RISCV_TLS_GOT_HI20",
+        R_RISCV_TLS_GD_HI20 => "R_RISCV_TLS_GD_HI20",
+        R_RISCV_PCREL_HI20 => "R_RISCV_PCREL_HI20",
+        R_RISCV_PCREL_LO12_I => "R_RISCV_PCREL_LO12_I",
+        R_RISCV_PCREL_LO12_S => "R_RISCV_PCREL_LO12_S",
+        R_RISCV_HI20 => "R_RISCV_HI20",
+        R_RISCV_LO12_I => "R_RISCV_LO12_I",
+        R_RISCV_LO12_S => "R_RISCV_LO12_S",
+        R_RISCV_TPREL_HI20 => "R_RISCV_TPREL_HI20",
+        R_RISCV_TPREL_LO12_I => "R_RISCV_TPREL_LO12_I",
+        R_RISCV_TPREL_LO12_S => "R_RISCV_TPREL_LO12_S",
+        R_RISCV_TPREL_ADD => "R_RISCV_TPREL_ADD",
+        R_RISCV_ADD8 => "R_RISCV_ADD8",
+        R_RISCV_ADD16 => "R_RISCV_ADD16",
+        R_RISCV_ADD32 => "R_RISCV_ADD32",
+        R_RISCV_ADD64 => "R_RISCV_ADD64",
+        R_RISCV_SUB8 => "R_RISCV_SUB8",
+        R_RISCV_SUB16 => "R_RISCV_SUB16",
+        R_RISCV_SUB32 => "R_RISCV_SUB32",
+        R_RISCV_SUB64 => "R_RISCV_SUB64",
+        R_RISCV_GNU_VTINHERIT => "R_RISCV_GNU_VTINHERIT",
+        R_RISCV_GNU_VTENTRY => "R_RISCV_GNU_VTENTRY",
+        R_RISCV_ALIGN => "R_RISCV_ALIGN",
+        R_RISCV_RVC_BRANCH => "R_RISCV_RVC_BRANCH",
+        R_RISCV_RVC_JUMP => "R_RISCV_RVC_JUMP",
+        R_RISCV_RVC_LUI => "R_RISCV_RVC_LUI",
+        R_RISCV_GPREL_I => "R_RISCV_GPREL_I",
+        R_RISCV_GPREL_S => "R_RISCV_GPREL_S",
+        R_RISCV_TPREL_I => "R_RISCV_TPREL_I",
+        R_RISCV_TPREL_S => "R_RISCV_TPREL_S",
+        R_RISCV_RELAX => "R_RISCV_RELAX",
+        R_RISCV_SUB6 => "R_RISCV_SUB6",
+        R_RISCV_SET6 => "R_RISCV_SET6",
+        R_RISCV_SET8 => "R_RISCV_SET8",
+        R_RISCV_SET16 => "R_RISCV_SET16",
+        R_RISCV_SET32 => "R_RISCV_SET32",
+        R_RISCV_32_PCREL => "R_RISCV_32_PCREL",
+        R_RISCV_IRELATIVE => "R_RISCV_IRELATIVE",
+        R_RISCV_PLT32 => "R_RISCV_PLT32",
+        R_RISCV_SET_ULEB128 => "R_RISCV_SET_ULEB128",
+        R_RISCV_SUB_ULEB128 => "R_RISCV_SUB_ULEB128",
+        R_RISCV_TLSDESC_HI20 => "R_RISCV_TLSDESC_HI20",
+        R_RISCV_TLSDESC_LOAD_LO12 => "R_RISCV_TLSDESC_LOAD_LO12",
+        R_RISCV_TLSDESC_ADD_LO12 => "R_RISCV_TLSDESC_ADD_LO12",
+        R_RISCV_TLSDESC_CALL => "R_RISCV_TLSDESC_CALL",
+        _ => "R_UNKNOWN_RISCV",
+        }},
+        // ppc64
+        EM_PPC64 => { match typ {
+        R_PPC64_NONE => "R_PPC64_NONE",
+        R_PPC64_ADDR32 => "R_PPC64_ADDR32",
+        R_PPC64_ADDR24 => "R_PPC64_ADDR24",
+        R_PPC64_ADDR16 => "R_PPC64_ADDR16",
+        R_PPC64_ADDR16_LO => "R_PPC64_ADDR16_LO",
+        R_PPC64_ADDR16_HI => "R_PPC64_ADDR16_HI",
+        R_PPC64_ADDR16_HA => "R_PPC64_ADDR16_HA",
+        R_PPC64_ADDR14 => "R_PPC64_ADDR14",
+        R_PPC64_ADDR14_BRTAKEN => "R_PPC64_ADDR14_BRTAKEN",
+        R_PPC64_ADDR14_BRNTAKEN => "R_PPC64_ADDR14_BRNTAKEN",
+        R_PPC64_REL24 => "R_PPC64_REL24",
+        R_PPC64_REL14 => "R_PPC64_REL14",
+        R_PPC64_REL14_BRTAKEN => "R_PPC64_REL14_BRTAKEN",
+        R_PPC64_REL14_BRNTAKEN => "R_PPC64_REL14_BRNTAKEN",
+        R_PPC64_GOT16 => "R_PPC64_GOT16",
+        R_PPC64_GOT16_LO => "R_PPC64_GOT16_LO",
+        R_PPC64_GOT16_HI => "R_PPC64_GOT16_HI",
+        R_PPC64_GOT16_HA => "R_PPC64_GOT16_HA",
+        R_PPC64_COPY => "R_PPC64_COPY",
+        R_PPC64_GLOB_DAT => "R_PPC64_GLOB_DAT",
+        R_PPC64_JMP_SLOT => "R_PPC64_JMP_SLOT",
+        R_PPC64_RELATIVE => "R_PPC64_RELATIVE",
+        R_PPC64_UADDR32 => "R_PPC64_UADDR32",
+        R_PPC64_UADDR16 => "R_PPC64_UADDR16",
+        R_PPC64_REL32 => "R_PPC64_REL32",
+        R_PPC64_PLT32 => "R_PPC64_PLT32",
+        R_PPC64_PLTREL32 => "R_PPC64_PLTREL32",
+        R_PPC64_PLT16_LO => "R_PPC64_PLT16_LO",
+        R_PPC64_PLT16_HI => "R_PPC64_PLT16_HI",
+        R_PPC64_PLT16_HA => "R_PPC64_PLT16_HA",
+        R_PPC64_SECTOFF => "R_PPC64_SECTOFF",
+        R_PPC64_SECTOFF_LO => "R_PPC64_SECTOFF_LO",
+        R_PPC64_SECTOFF_HI => "R_PPC64_SECTOFF_HI",
+        R_PPC64_SECTOFF_HA => "R_PPC64_SECTOFF_HA",
+        R_PPC64_ADDR30 => "R_PPC64_ADDR30",
+        R_PPC64_ADDR64 => "R_PPC64_ADDR64",
+        R_PPC64_ADDR16_HIGHER => "R_PPC64_ADDR16_HIGHER",
+        R_PPC64_ADDR16_HIGHERA => "R_PPC64_ADDR16_HIGHERA",
+        R_PPC64_ADDR16_HIGHEST => "R_PPC64_ADDR16_HIGHEST",
+        R_PPC64_ADDR16_HIGHESTA => "R_PPC64_ADDR16_HIGHESTA",
+        R_PPC64_UADDR64 => "R_PPC64_UADDR64",
+        R_PPC64_REL64 => "R_PPC64_REL64",
+        R_PPC64_PLT64 => "R_PPC64_PLT64",
+        R_PPC64_PLTREL64 => "R_PPC64_PLTREL64",
+        R_PPC64_TOC16 => "R_PPC64_TOC16",
+        R_PPC64_TOC16_LO => "R_PPC64_TOC16_LO",
+        R_PPC64_TOC16_HI => "R_PPC64_TOC16_HI",
+        R_PPC64_TOC16_HA => "R_PPC64_TOC16_HA",
+        R_PPC64_TOC => "R_PPC64_TOC",
+        R_PPC64_PLTGOT16 => "R_PPC64_PLTGOT16",
+        R_PPC64_PLTGOT16_LO => "R_PPC64_PLTGOT16_LO",
+        R_PPC64_PLTGOT16_HI => "R_PPC64_PLTGOT16_HI",
+        R_PPC64_PLTGOT16_HA => "R_PPC64_PLTGOT16_HA",
+        R_PPC64_ADDR16_DS => "R_PPC64_ADDR16_DS",
+        R_PPC64_ADDR16_LO_DS => "R_PPC64_ADDR16_LO_DS",
+        R_PPC64_GOT16_DS => "R_PPC64_GOT16_DS",
+        R_PPC64_GOT16_LO_DS => "R_PPC64_GOT16_LO_DS",
+        R_PPC64_PLT16_LO_DS => "R_PPC64_PLT16_LO_DS",
+        R_PPC64_SECTOFF_DS => "R_PPC64_SECTOFF_DS",
+        R_PPC64_SECTOFF_LO_DS => "R_PPC64_SECTOFF_LO_DS",
+        R_PPC64_TOC16_DS => "R_PPC64_TOC16_DS",
+        R_PPC64_TOC16_LO_DS => "R_PPC64_TOC16_LO_DS",
+        R_PPC64_PLTGOT16_DS => "R_PPC64_PLTGOT16_DS",
+        R_PPC64_PLTGOT16_LO_DS => "R_PPC64_PLTGOT16_LO_DS",
+        R_PPC64_TLS => "R_PPC64_TLS",
+        R_PPC64_DTPMOD64 => "R_PPC64_DTPMOD64",
+        R_PPC64_TPREL16 => "R_PPC64_TPREL16",
+        R_PPC64_TPREL16_LO => "R_PPC64_TPREL16_LO",
+        R_PPC64_TPREL16_HI => "R_PPC64_TPREL16_HI",
+        R_PPC64_TPREL16_HA => "R_PPC64_TPREL16_HA",
+        R_PPC64_TPREL64 => "R_PPC64_TPREL64",
+        R_PPC64_DTPREL16 => "R_PPC64_DTPREL16",
+        R_PPC64_DTPREL16_LO => "R_PPC64_DTPREL16_LO",
+        R_PPC64_DTPREL16_HI => "R_PPC64_DTPREL16_HI",
+        R_PPC64_DTPREL16_HA => "R_PPC64_DTPREL16_HA",
+        R_PPC64_DTPREL64 => "R_PPC64_DTPREL64",
+        R_PPC64_GOT_TLSGD16 => "R_PPC64_GOT_TLSGD16",
+        R_PPC64_GOT_TLSGD16_LO => "R_PPC64_GOT_TLSGD16_LO",
+        R_PPC64_GOT_TLSGD16_HI => "R_PPC64_GOT_TLSGD16_HI",
+        R_PPC64_GOT_TLSGD16_HA => "R_PPC64_GOT_TLSGD16_HA",
+        R_PPC64_GOT_TLSLD16 => "R_PPC64_GOT_TLSLD16",
+        R_PPC64_GOT_TLSLD16_LO => "R_PPC64_GOT_TLSLD16_LO",
+        R_PPC64_GOT_TLSLD16_HI => "R_PPC64_GOT_TLSLD16_HI",
+        R_PPC64_GOT_TLSLD16_HA => "R_PPC64_GOT_TLSLD16_HA",
+        R_PPC64_GOT_TPREL16_DS => "R_PPC64_GOT_TPREL16_DS",
+        R_PPC64_GOT_TPREL16_LO_DS => "R_PPC64_GOT_TPREL16_LO_DS",
+        R_PPC64_GOT_TPREL16_HI => "R_PPC64_GOT_TPREL16_HI",
+        R_PPC64_GOT_TPREL16_HA => "R_PPC64_GOT_TPREL16_HA",
+        R_PPC64_GOT_DTPREL16_DS => "R_PPC64_GOT_DTPREL16_DS",
+        R_PPC64_GOT_DTPREL16_LO_DS => "R_PPC64_GOT_DTPREL16_LO_DS",
+        R_PPC64_GOT_DTPREL16_HI => "R_PPC64_GOT_DTPREL16_HI",
+        R_PPC64_GOT_DTPREL16_HA => "R_PPC64_GOT_DTPREL16_HA",
+        R_PPC64_TPREL16_DS => "R_PPC64_TPREL16_DS",
+        R_PPC64_TPREL16_LO_DS => "R_PPC64_TPREL16_LO_DS",
+        R_PPC64_TPREL16_HIGHER => "R_PPC64_TPREL16_HIGHER",
+        R_PPC64_TPREL16_HIGHERA => "R_PPC64_TPREL16_HIGHERA",
+        R_PPC64_TPREL16_HIGHEST => "R_PPC64_TPREL16_HIGHEST",
+        R_PPC64_TPREL16_HIGHESTA => "R_PPC64_TPREL16_HIGHESTA",
+        R_PPC64_DTPREL16_DS => "R_PPC64_DTPREL16_DS",
+        R_PPC64_DTPREL16_LO_DS => "R_PPC64_DTPREL16_LO_DS",
+        R_PPC64_DTPREL16_HIGHER => "R_PPC64_DTPREL16_HIGHER",
+        R_PPC64_DTPREL16_HIGHERA => "R_PPC64_DTPREL16_HIGHERA",
+        R_PPC64_DTPREL16_HIGHEST => "R_PPC64_DTPREL16_HIGHEST",
+        R_PPC64_DTPREL16_HIGHESTA => "R_PPC64_DTPREL16_HIGHESTA",
+        R_PPC64_TLSGD => "R_PPC64_TLSGD",
+        R_PPC64_TLSLD => "R_PPC64_TLSLD",
+        R_PPC64_TOCSAVE => "R_PPC64_TOCSAVE",
+        R_PPC64_ADDR16_HIGH => "R_PPC64_ADDR16_HIGH",
+        R_PPC64_ADDR16_HIGHA => "R_PPC64_ADDR16_HIGHA",
+        R_PPC64_TPREL16_HIGH => "R_PPC64_TPREL16_HIGH",
+        R_PPC64_TPREL16_HIGHA => "R_PPC64_TPREL16_HIGHA",
+        R_PPC64_DTPREL16_HIGH => "R_PPC64_DTPREL16_HIGH",
+        R_PPC64_DTPREL16_HIGHA => "R_PPC64_DTPREL16_HIGHA",
+        R_PPC64_REL24_NOTOC => "R_PPC64_REL24_NOTOC",
+        R_PPC64_ADDR64_LOCAL => "R_PPC64_ADDR64_LOCAL",
+        R_PPC64_ENTRY => "R_PPC64_ENTRY",
+        R_PPC64_PLTSEQ => "R_PPC64_PLTSEQ",
+        R_PPC64_PLTCALL => "R_PPC64_PLTCALL",
+        R_PPC64_PLTSEQ_NOTOC => "R_PPC64_PLTSEQ_NOTOC",
+        R_PPC64_PLTCALL_NOTOC => "R_PPC64_PLTCALL_NOTOC",
+        R_PPC64_PCREL_OPT => "R_PPC64_PCREL_OPT",
+        R_PPC64_REL24_P9NOTOC => "R_PPC64_REL24_P9NOTOC",
+        R_PPC64_D34 => "R_PPC64_D34",
+        R_PPC64_D34_LO => "R_PPC64_D34_LO",
+        R_PPC64_D34_HI30 => "R_PPC64_D34_HI30",
+        R_PPC64_D34_HA30 => "R_PPC64_D34_HA30",
+        R_PPC64_PCREL34 => "R_PPC64_PCREL34",
+        R_PPC64_GOT_PCREL34 => "R_PPC64_GOT_PCREL34",
+        R_PPC64_PLT_PCREL34 => "R_PPC64_PLT_PCREL34",
+        R_PPC64_PLT_PCREL34_NOTOC => "R_PPC64_PLT_PCREL34_NOTOC",
+        R_PPC64_REL16_HIGH => "R_PPC64_REL16_HIGH",
+        R_PPC64_REL16_HIGHA => "R_PPC64_REL16_HIGHA",
+        R_PPC64_REL16_HIGHER => "R_PPC64_REL16_HIGHER",
+        R_PPC64_REL16_HIGHERA => "R_PPC64_REL16_HIGHERA",
+        R_PPC64_REL16_HIGHEST => "R_PPC64_REL16_HIGHEST",
+        R_PPC64_REL16_HIGHESTA => "R_PPC64_REL16_HIGHESTA",
+        R_PPC64_REL16DX_HA => "R_PPC64_REL16DX_HA",
+        R_PPC64_JMP_IREL => "R_PPC64_JMP_IREL",
+        R_PPC64_IRELATIVE => "R_PPC64_IRELATIVE",
+        R_PPC64_REL16 => "R_PPC64_REL16",
+        R_PPC64_REL16_LO => "R_PPC64_REL16_LO",
+        R_PPC64_REL16_HI => "R_PPC64_REL16_HI",
+        R_PPC64_REL16_HA => "R_PPC64_REL16_HA",
+        _ => "R_UNKNOWN_PPC64",
+        }},
+        // s390
+        EM_S390 => { match typ {
+        R_390_NONE => "R_390_NONE",
+        R_390_8 => "R_390_8",
+        R_390_12 => "R_390_12",
+        R_390_16 => "R_390_16",
+        R_390_32 => "R_390_32",
+        R_390_PC32 => "R_390_PC32",
+        R_390_GOT12 => "R_390_GOT12",
+        R_390_GOT32 => "R_390_GOT32",
+        R_390_PLT32 => "R_390_PLT32",
+        R_390_COPY => "R_390_COPY",
+        R_390_GLOB_DAT => "R_390_GLOB_DAT",
+        R_390_JMP_SLOT => "R_390_JMP_SLOT",
+        R_390_RELATIVE => "R_390_RELATIVE",
+        R_390_GOTOFF32 => "R_390_GOTOFF32",
+        R_390_GOTPC => "R_390_GOTPC",
+        R_390_GOT16 => "R_390_GOT16",
+        R_390_PC16 => "R_390_PC16",
+        R_390_PC16DBL => "R_390_PC16DBL",
+        R_390_PLT16DBL => "R_390_PLT16DBL",
+        R_390_PC32DBL => "R_390_PC32DBL",
+        R_390_PLT32DBL => "R_390_PLT32DBL",
+        R_390_GOTPCDBL => "R_390_GOTPCDBL",
+        R_390_64 => "R_390_64",
+        R_390_PC64 => "R_390_PC64",
+        R_390_GOT64 => "R_390_GOT64",
+        R_390_PLT64 => "R_390_PLT64",
+        R_390_GOTENT => "R_390_GOTENT",
+        R_390_GOTOFF16 => "R_390_GOTOFF16",
+        R_390_GOTOFF64 => "R_390_GOTOFF64",
+        R_390_GOTPLT12 => "R_390_GOTPLT12",
+        R_390_GOTPLT16 => "R_390_GOTPLT16",
+        R_390_GOTPLT32 => "R_390_GOTPLT32",
+        R_390_GOTPLT64 => "R_390_GOTPLT64",
+        R_390_GOTPLTENT => "R_390_GOTPLTENT",
+        R_390_PLTOFF16 => "R_390_PLTOFF16",
+        R_390_PLTOFF32 => "R_390_PLTOFF32",
+        R_390_PLTOFF64 => "R_390_PLTOFF64",
+        R_390_TLS_LOAD => "R_390_TLS_LOAD",
+        R_390_TLS_GDCALL => "R_390_TLS_GDCALL",
+        R_390_TLS_LDCALL => "R_390_TLS_LDCALL",
+        R_390_TLS_GD32 => "R_390_TLS_GD32",
+        R_390_TLS_GD64 => "R_390_TLS_GD64",
+        R_390_TLS_GOTIE12 => "R_390_TLS_GOTIE12",
+        R_390_TLS_GOTIE32 => "R_390_TLS_GOTIE32",
+        R_390_TLS_GOTIE64 => "R_390_TLS_GOTIE64",
+        R_390_TLS_LDM32 => "R_390_TLS_LDM32",
+        R_390_TLS_LDM64 => "R_390_TLS_LDM64",
+        R_390_TLS_IE32 => "R_390_TLS_IE32",
+        R_390_TLS_IE64 => "R_390_TLS_IE64",
+        R_390_TLS_IEENT => "R_390_TLS_IEENT",
+        R_390_TLS_LE32 => "R_390_TLS_LE32",
+        R_390_TLS_LE64 => "R_390_TLS_LE64",
+        R_390_TLS_LDO32 => "R_390_TLS_LDO32",
+        R_390_TLS_LDO64 => "R_390_TLS_LDO64",
+        R_390_TLS_DTPMOD => "R_390_TLS_DTPMOD",
+        R_390_TLS_DTPOFF => "R_390_TLS_DTPOFF",
+        R_390_TLS_TPOFF => "R_390_TLS_TPOFF",
+        R_390_20 => "R_390_20",
+        R_390_GOT20 => "R_390_GOT20",
+        R_390_GOTPLT20 => "R_390_GOTPLT20",
+        R_390_TLS_GOTIE20 => "R_390_TLS_GOTIE20",
+        R_390_IRELATIVE => "R_390_IRELATIVE",
+        _ => "R_UNKNOWN_390",
+        }},
+        // sparc
+        EM_SPARC | EM_SPARC32PLUS | EM_SPARCV9 => { match typ {
+        R_SPARC_NONE => "R_SPARC_NONE",
+        R_SPARC_8 => "R_SPARC_8",
+        R_SPARC_16 => "R_SPARC_16",
+        R_SPARC_32 => "R_SPARC_32",
+        R_SPARC_DISP8 => "R_SPARC_DISP8",
+        R_SPARC_DISP16 => "R_SPARC_DISP16",
+        R_SPARC_DISP32 => "R_SPARC_DISP32",
+        R_SPARC_WDISP30 => "R_SPARC_WDISP30",
+        R_SPARC_WDISP22 => "R_SPARC_WDISP22",
+        R_SPARC_HI22 => "R_SPARC_HI22",
+        R_SPARC_22 => "R_SPARC_22",
+        R_SPARC_13 => "R_SPARC_13",
+        R_SPARC_LO10 => "R_SPARC_LO10",
+        R_SPARC_GOT10 => "R_SPARC_GOT10",
+        R_SPARC_GOT13 => "R_SPARC_GOT13",
+        R_SPARC_GOT22 => "R_SPARC_GOT22",
+        R_SPARC_PC10 => "R_SPARC_PC10",
+        R_SPARC_PC22 => "R_SPARC_PC22",
+        R_SPARC_WPLT30 => "R_SPARC_WPLT30",
+        R_SPARC_COPY => "R_SPARC_COPY",
+        R_SPARC_GLOB_DAT => "R_SPARC_GLOB_DAT",
+        R_SPARC_JMP_SLOT => "R_SPARC_JMP_SLOT",
+        R_SPARC_RELATIVE => "R_SPARC_RELATIVE",
+        R_SPARC_UA32 => "R_SPARC_UA32",
+        R_SPARC_PLT32 => "R_SPARC_PLT32",
+        R_SPARC_HIPLT22 => "R_SPARC_HIPLT22",
+        R_SPARC_LOPLT10 => "R_SPARC_LOPLT10",
+        R_SPARC_PCPLT32 => "R_SPARC_PCPLT32",
+        R_SPARC_PCPLT22 => "R_SPARC_PCPLT22",
+        R_SPARC_PCPLT10 => "R_SPARC_PCPLT10",
+        R_SPARC_10 => "R_SPARC_10",
+        R_SPARC_11 => "R_SPARC_11",
+        R_SPARC_64 => "R_SPARC_64",
+        R_SPARC_OLO10 => "R_SPARC_OLO10",
+        R_SPARC_HH22 => "R_SPARC_HH22",
+        R_SPARC_HM10 => "R_SPARC_HM10",
+        R_SPARC_LM22 => "R_SPARC_LM22",
+        R_SPARC_PC_HH22 => "R_SPARC_PC_HH22",
+        R_SPARC_PC_HM10 => "R_SPARC_PC_HM10",
+        R_SPARC_PC_LM22 => "R_SPARC_PC_LM22",
+        R_SPARC_WDISP16 => "R_SPARC_WDISP16",
+        R_SPARC_WDISP19 => "R_SPARC_WDISP19",
+        R_SPARC_GLOB_JMP => "R_SPARC_GLOB_JMP",
+        R_SPARC_7 => "R_SPARC_7",
+        R_SPARC_5 => "R_SPARC_5",
+        R_SPARC_6 => "R_SPARC_6",
+        R_SPARC_DISP64 => "R_SPARC_DISP64",
+        R_SPARC_PLT64 => "R_SPARC_PLT64",
+        R_SPARC_HIX22 => "R_SPARC_HIX22",
+        R_SPARC_LOX10 => "R_SPARC_LOX10",
+        R_SPARC_H44 => "R_SPARC_H44",
+        R_SPARC_M44 => "R_SPARC_M44",
+        R_SPARC_L44 => "R_SPARC_L44",
+        R_SPARC_REGISTER => "R_SPARC_REGISTER",
+        R_SPARC_UA64 => "R_SPARC_UA64",
+        R_SPARC_UA16 => "R_SPARC_UA16",
+        R_SPARC_TLS_GD_HI22 => "R_SPARC_TLS_GD_HI22",
+        R_SPARC_TLS_GD_LO10 => "R_SPARC_TLS_GD_LO10",
+        R_SPARC_TLS_GD_ADD => "R_SPARC_TLS_GD_ADD",
+        R_SPARC_TLS_GD_CALL => "R_SPARC_TLS_GD_CALL",
+        R_SPARC_TLS_LDM_HI22 => "R_SPARC_TLS_LDM_HI22",
+        R_SPARC_TLS_LDM_LO10 => "R_SPARC_TLS_LDM_LO10",
+        R_SPARC_TLS_LDM_ADD => "R_SPARC_TLS_LDM_ADD",
+        R_SPARC_TLS_LDM_CALL => "R_SPARC_TLS_LDM_CALL",
+        R_SPARC_TLS_LDO_HIX22 => "R_SPARC_TLS_LDO_HIX22",
+        R_SPARC_TLS_LDO_LOX10 => "R_SPARC_TLS_LDO_LOX10",
+        R_SPARC_TLS_LDO_ADD => "R_SPARC_TLS_LDO_ADD",
+        R_SPARC_TLS_IE_HI22 => "R_SPARC_TLS_IE_HI22",
+        R_SPARC_TLS_IE_LO10 => "R_SPARC_TLS_IE_LO10",
+        R_SPARC_TLS_IE_LD => "R_SPARC_TLS_IE_LD",
+        R_SPARC_TLS_IE_LDX => "R_SPARC_TLS_IE_LDX",
+        R_SPARC_TLS_IE_ADD => "R_SPARC_TLS_IE_ADD",
+        R_SPARC_TLS_LE_HIX22 => "R_SPARC_TLS_LE_HIX22",
+        R_SPARC_TLS_LE_LOX10 => "R_SPARC_TLS_LE_LOX10",
+        R_SPARC_TLS_DTPMOD32 => "R_SPARC_TLS_DTPMOD32",
+        R_SPARC_TLS_DTPMOD64 => "R_SPARC_TLS_DTPMOD64",
+        R_SPARC_TLS_DTPOFF32 => "R_SPARC_TLS_DTPOFF32",
+        R_SPARC_TLS_DTPOFF64 => "R_SPARC_TLS_DTPOFF64",
+        R_SPARC_TLS_TPOFF32 => "R_SPARC_TLS_TPOFF32",
+        R_SPARC_TLS_TPOFF64 => "R_SPARC_TLS_TPOFF64",
+        R_SPARC_GOTDATA_HIX22 => "R_SPARC_GOTDATA_HIX22",
+        R_SPARC_GOTDATA_LOX10 => "R_SPARC_GOTDATA_LOX10",
+        R_SPARC_GOTDATA_OP_HIX22 => "R_SPARC_GOTDATA_OP_HIX22",
+        R_SPARC_GOTDATA_OP_LOX10 => "R_SPARC_GOTDATA_OP_LOX10",
+        R_SPARC_GOTDATA_OP => "R_SPARC_GOTDATA_OP",
+        R_SPARC_H34 => "R_SPARC_H34",
+        R_SPARC_SIZE32 => "R_SPARC_SIZE32",
+        R_SPARC_SIZE64 => "R_SPARC_SIZE64",
+        R_SPARC_WDISP10 => "R_SPARC_WDISP10",
+        R_SPARC_JMP_IREL => "R_SPARC_JMP_IREL",
+        R_SPARC_IRELATIVE => "R_SPARC_IRELATIVE",
+        R_SPARC_GNU_VTINHERIT => "R_SPARC_GNU_VTINHERIT",
+        R_SPARC_GNU_VTENTRY => "R_SPARC_GNU_VTENTRY",
+        R_SPARC_REV32 => "R_SPARC_REV32",
+        _ => "R_UNKNOWN_SPARC",
+        }},
+        // loongarch
+        EM_LOONGARCH => { match typ {
+        R_LARCH_NONE => "R_LARCH_NONE",
+        R_LARCH_32 => "R_LARCH_32",
+        R_LARCH_64 => "R_LARCH_64",
+        R_LARCH_RELATIVE => "R_LARCH_RELATIVE",
+        R_LARCH_COPY => "R_LARCH_COPY",
+        R_LARCH_JUMP_SLOT => "R_LARCH_JUMP_SLOT",
+        R_LARCH_TLS_DTPMOD32 => "R_LARCH_TLS_DTPMOD32",
+        R_LARCH_TLS_DTPMOD64 => "R_LARCH_TLS_DTPMOD64",
+        R_LARCH_TLS_DTPREL32 => "R_LARCH_TLS_DTPREL32",
+        R_LARCH_TLS_DTPREL64 => "R_LARCH_TLS_DTPREL64",
+        R_LARCH_TLS_TPREL32 => "R_LARCH_TLS_TPREL32",
+        R_LARCH_TLS_TPREL64 => "R_LARCH_TLS_TPREL64",
+        R_LARCH_IRELATIVE => "R_LARCH_IRELATIVE",
+        R_LARCH_TLS_DESC32 => "R_LARCH_TLS_DESC32",
+        R_LARCH_TLS_DESC64 => "R_LARCH_TLS_DESC64",
+        R_LARCH_MARK_LA => "R_LARCH_MARK_LA",
+        R_LARCH_MARK_PCREL => "R_LARCH_MARK_PCREL",
+        R_LARCH_SOP_PUSH_PCREL => "R_LARCH_SOP_PUSH_PCREL",
+        R_LARCH_SOP_PUSH_ABSOLUTE => "R_LARCH_SOP_PUSH_ABSOLUTE",
+        R_LARCH_SOP_PUSH_DUP => "R_LARCH_SOP_PUSH_DUP",
+        R_LARCH_SOP_PUSH_GPREL => "R_LARCH_SOP_PUSH_GPREL",
+        R_LARCH_SOP_PUSH_TLS_TPREL => "R_LARCH_SOP_PUSH_TLS_TPREL",
+        R_LARCH_SOP_PUSH_TLS_GOT => "R_LARCH_SOP_PUSH_TLS_GOT",
+        R_LARCH_SOP_PUSH_TLS_GD => "R_LARCH_SOP_PUSH_TLS_GD",
+        R_LARCH_SOP_PUSH_PLT_PCREL => "R_LARCH_SOP_PUSH_PLT_PCREL",
+        R_LARCH_SOP_ASSERT => "R_LARCH_SOP_ASSERT",
+        R_LARCH_SOP_NOT => "R_LARCH_SOP_NOT",
+        R_LARCH_SOP_SUB => "R_LARCH_SOP_SUB",
+        R_LARCH_SOP_SL => "R_LARCH_SOP_SL",
+        R_LARCH_SOP_SR => "R_LARCH_SOP_SR",
+        R_LARCH_SOP_ADD => "R_LARCH_SOP_ADD",
+        R_LARCH_SOP_AND => "R_LARCH_SOP_AND",
+        R_LARCH_SOP_IF_ELSE => "R_LARCH_SOP_IF_ELSE",
+        R_LARCH_SOP_POP_32_S_10_5 => "R_LARCH_SOP_POP_32_S_10_5",
+        R_LARCH_SOP_POP_32_U_10_12 => "R_LARCH_SOP_POP_32_U_10_12",
+        R_LARCH_SOP_POP_32_S_10_12 => "R_LARCH_SOP_POP_32_S_10_12",
+        R_LARCH_SOP_POP_32_S_10_16 => "R_LARCH_SOP_POP_32_S_10_16",
+        R_LARCH_SOP_POP_32_S_10_16_S2 => "R_LARCH_SOP_POP_32_S_10_16_S2",
+        R_LARCH_SOP_POP_32_S_5_20 => "R_LARCH_SOP_POP_32_S_5_20",
+        R_LARCH_SOP_POP_32_S_0_5_10_16_S2 => "R_LARCH_SOP_POP_32_S_0_5_10_16_S2",
+        R_LARCH_SOP_POP_32_S_0_10_10_16_S2 => "R_LARCH_SOP_POP_32_S_0_10_10_16_S2",
+        R_LARCH_SOP_POP_32_U => "R_LARCH_SOP_POP_32_U",
+        R_LARCH_ADD8 => "R_LARCH_ADD8",
+        R_LARCH_ADD16 => "R_LARCH_ADD16",
+        R_LARCH_ADD24 => "R_LARCH_ADD24",
+        R_LARCH_ADD32 => "R_LARCH_ADD32",
+        R_LARCH_ADD64 => "R_LARCH_ADD64",
+        R_LARCH_SUB8 => "R_LARCH_SUB8",
+        R_LARCH_SUB16 => "R_LARCH_SUB16",
+        R_LARCH_SUB24 => "R_LARCH_SUB24",
+        R_LARCH_SUB32 => "R_LARCH_SUB32",
+        R_LARCH_SUB64 => "R_LARCH_SUB64",
+        R_LARCH_GNU_VTINHERIT => "R_LARCH_GNU_VTINHERIT",
+        R_LARCH_GNU_VTENTRY => "R_LARCH_GNU_VTENTRY",
+        R_LARCH_B16 => "R_LARCH_B16",
+        R_LARCH_B21 => "R_LARCH_B21",
+        R_LARCH_B26 => "R_LARCH_B26",
+        R_LARCH_ABS_HI20 => "R_LARCH_ABS_HI20",
+        R_LARCH_ABS_LO12 => "R_LARCH_ABS_LO12",
+        R_LARCH_ABS64_LO20 => "R_LARCH_ABS64_LO20",
+        R_LARCH_ABS64_HI12 => "R_LARCH_ABS64_HI12",
+        R_LARCH_PCALA_HI20 => "R_LARCH_PCALA_HI20",
+        R_LARCH_PCALA_LO12 => "R_LARCH_PCALA_LO12",
+        R_LARCH_PCALA64_LO20 => "R_LARCH_PCALA64_LO20",
+        R_LARCH_PCALA64_HI12 => "R_LARCH_PCALA64_HI12",
+        R_LARCH_GOT_PC_HI20 => "R_LARCH_GOT_PC_HI20",
+        R_LARCH_GOT_PC_LO12 => "R_LARCH_GOT_PC_LO12",
+        R_LARCH_GOT64_PC_LO20 => "R_LARCH_GOT64_PC_LO20",
+        R_LARCH_GOT64_PC_HI12 => "R_LARCH_GOT64_PC_HI12",
+        R_LARCH_GOT_HI20 => "R_LARCH_GOT_HI20",
+        R_LARCH_GOT_LO12 => "R_LARCH_GOT_LO12",
+        R_LARCH_GOT64_LO20 => "R_LARCH_GOT64_LO20",
+        R_LARCH_GOT64_HI12 => "R_LARCH_GOT64_HI12",
+        R_LARCH_TLS_LE_HI20 => "R_LARCH_TLS_LE_HI20",
+        R_LARCH_TLS_LE_LO12 => "R_LARCH_TLS_LE_LO12",
+        R_LARCH_TLS_LE64_LO20 => "R_LARCH_TLS_LE64_LO20",
+        R_LARCH_TLS_LE64_HI12 => "R_LARCH_TLS_LE64_HI12",
+        R_LARCH_TLS_IE_PC_HI20 => "R_LARCH_TLS_IE_PC_HI20",
+        R_LARCH_TLS_IE_PC_LO12 => "R_LARCH_TLS_IE_PC_LO12",
+        R_LARCH_TLS_IE64_PC_LO20 => "R_LARCH_TLS_IE64_PC_LO20",
+        R_LARCH_TLS_IE64_PC_HI12 => "R_LARCH_TLS_IE64_PC_HI12",
+        R_LARCH_TLS_IE_HI20 => "R_LARCH_TLS_IE_HI20",
+        R_LARCH_TLS_IE_LO12 => "R_LARCH_TLS_IE_LO12",
+        R_LARCH_TLS_IE64_LO20 => "R_LARCH_TLS_IE64_LO20",
+        R_LARCH_TLS_IE64_HI12 => "R_LARCH_TLS_IE64_HI12",
+        R_LARCH_TLS_LD_PC_HI20 => "R_LARCH_TLS_LD_PC_HI20",
+        R_LARCH_TLS_LD_HI20 => "R_LARCH_TLS_LD_HI20",
+        R_LARCH_TLS_GD_PC_HI20 => "R_LARCH_TLS_GD_PC_HI20",
+        R_LARCH_TLS_GD_HI20 => "R_LARCH_TLS_GD_HI20",
+        R_LARCH_32_PCREL => "R_LARCH_32_PCREL",
+        R_LARCH_RELAX => "R_LARCH_RELAX",
+        R_LARCH_ALIGN => "R_LARCH_ALIGN",
+        R_LARCH_PCREL20_S2 => "R_LARCH_PCREL20_S2",
+        R_LARCH_ADD6 => "R_LARCH_ADD6",
+        R_LARCH_SUB6 => "R_LARCH_SUB6",
+        R_LARCH_ADD_ULEB128 => "R_LARCH_ADD_ULEB128",
+        R_LARCH_SUB_ULEB128 => "R_LARCH_SUB_ULEB128",
+        R_LARCH_64_PCREL => "R_LARCH_64_PCREL",
+        R_LARCH_CALL36 => "R_LARCH_CALL36",
+        _ => "R_UNKNOWN_LARCH",
+        }},
+        _ => "R_UNKNOWN"
     }
 }
@@ -0,0 +1,676 @@
+//! Decoders for the notes found in the `PT_NOTE` segment of an `ET_CORE` file.
+//!
+//! A Linux core file describes the crashed process with a sequence of notes named `CORE`:
+//! one `NT_PRSTATUS` per thread (followed by that thread's floating point registers), and a
+//! single `NT_PRPSINFO`, `NT_SIGINFO`, `NT_AUXV` and `NT_FILE` note after the first thread.
+//!
+//! The layouts of `prstatus` and `prpsinfo` depend on the word size of the dumped process, and
+//! the general purpose register set embedded in `prstatus` depends on its machine.
+
+use elf::header::{EM_386, EM_AARCH64, EM_ARM, EM_X86_64};
+
+/// End of vector
+pub const AT_NULL: u64 = 0;
+/// Entry should be ignored
+pub const AT_IGNORE: u64 = 1;
+/// File descriptor of program
+pub const AT_EXECFD: u64 = 2;
+/// Program headers for program
+pub const AT_PHDR: u64 = 3;
+/// Size of program header entry
+pub const AT_PHENT: u64 = 4;
+/// Number of program headers
+pub const AT_PHNUM: u64 = 5;
+/// System page size
+pub const AT_PAGESZ: u64 = 6;
+/// Base address of interpreter
+pub const AT_BASE: u64 = 7;
+/// Flags
+pub const AT_FLAGS: u64 = 8;
+/// Entry point of program
+pub const AT_ENTRY: u64 = 9;
+/// Program is not ELF
+pub const AT_NOTELF: u64 = 10;
+/// Real uid
+pub const AT_UID: u64 = 11;
+/// Effective uid
+pub const AT_EUID: u64 = 12;
+/// Real gid
+pub const AT_GID: u64 = 13;
+/// Effective gid
+pub const AT_EGID: u64 = 14;
+/// String identifying CPU for optimizations
+pub const AT_PLATFORM: u64 = 15;
+/// Arch dependent hints at CPU capabilities
+pub const AT_HWCAP: u64 = 16;
+/// Frequency at which times() increments
+pub const AT_CLKTCK: u64 = 17;
+/// Secure mode boolean
+pub const AT_SECURE: u64 = 23;
+/// String identifying real platform, may differ from `AT_PLATFORM`
+pub const AT_BASE_PLATFORM: u64 = 24;
+/// Address of 16 random bytes
+pub const AT_RANDOM: u64 = 25;
+/// Extension of `AT_HWCAP`
+pub const AT_HWCAP2: u64 = 26;
+/// Filename of program
+pub const AT_EXECFN: u64 = 31;
+/// Entry point of the vsyscall page
+pub const AT_SYSINFO: u64 = 32;
+/// Address of the vDSO
+pub const AT_SYSINFO_EHDR: u64 = 33;
+
+/// Returns the name of the auxiliary vector entry type `a_type`
+pub fn at_to_str(a_type: u64) -> &'static str {
+    match a_type {
+        AT_NULL => "AT_NULL",
+        AT_IGNORE => "AT_IGNORE",
+        AT_EXECFD => "AT_EXECFD",
+        AT_PHDR => "AT_PHDR",
+        AT_PHENT => "AT_PHENT",
+        AT_PHNUM => "AT_PHNUM",
+        AT_PAGESZ => "AT_PAGESZ",
+        AT_BASE => "AT_BASE",
+        AT_FLAGS => "AT_FLAGS",
+        AT_ENTRY => "AT_ENTRY",
+        AT_NOTELF => "AT_NOTELF",
+        AT_UID => "AT_UID",
+        AT_EUID => "AT_EUID",
+        AT_GID => "AT_GID",
+        AT_EGID => "AT_EGID",
+        AT_PLATFORM => "AT_PLATFORM",
+        AT_HWCAP => "AT_HWCAP",
+        AT_CLKTCK => "AT_CLKTCK",
+        AT_SECURE => "AT_SECURE",
+        AT_BASE_PLATFORM => "AT_BASE_PLATFORM",
+        AT_RANDOM => "AT_RANDOM",
+        AT_HWCAP2 => "AT_HWCAP2",
+        AT_EXECFN => "AT_EXECFN",
+        AT_SYSINFO => "AT_SYSINFO",
+        AT_SYSINFO_EHDR => "AT_SYSINFO_EHDR",
+        _ => "AT_UNKNOWN",
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "alloc", derive(Pread, Pwrite, SizeWith))]
+/// The x86_64 general purpose registers (`struct user_regs_struct`)
+pub struct X86_64Registers {
+    pub r15: u64,
+    pub r14: u64,
+    pub r13: u64,
+    pub r12: u64,
+    pub rbp: u64,
+    pub rbx: u64,
+    pub r11: u64,
+    pub r10: u64,
+    pub r9: u64,
+    pub r8: u64,
+    pub rax: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    pub orig_rax: u64,
+    pub rip: u64,
+    pub cs: u64,
+    pub eflags: u64,
+    pub rsp: u64,
+    pub ss: u64,
+    pub fs_base: u64,
+    pub gs_base: u64,
+    pub ds: u64,
+    pub es: u64,
+    pub fs: u64,
+    pub gs: u64,
+}
+
+pub const SIZEOF_X86_64_REGISTERS: usize = 27 * 8;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "alloc", derive(Pread, Pwrite, SizeWith))]
+/// The i386 general purpose registers (`struct user_regs_struct`)
+pub struct I386Registers {
+    pub ebx: u32,
+    pub ecx: u32,
+    pub edx: u32,
+    pub esi: u32,
+    pub edi: u32,
+    pub ebp: u32,
+    pub eax: u32,
+    pub xds: u32,
+    pub xes: u32,
+    pub xfs: u32,
+    pub xgs: u32,
+    pub orig_eax: u32,
+    pub eip: u32,
+    pub xcs: u32,
+    pub eflags: u32,
+    pub esp: u32,
+    pub xss: u32,
+}
+
+pub const SIZEOF_I386_REGISTERS: usize = 17 * 4;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "alloc", derive(Pread, Pwrite, SizeWith))]
+/// The AArch64 general purpose registers (`struct user_pt_regs`)
+pub struct AArch64Registers {
+    /// `x0` through `x30`, where `x29` is the frame pointer and `x30` the link register
+    pub regs: [u64; 31],
+    pub sp: u64,
+    pub pc: u64,
+    pub pstate: u64,
+}
+
+pub const SIZEOF_AARCH64_REGISTERS: usize = 34 * 8;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "alloc", derive(Pread, Pwrite, SizeWith))]
+/// The 32-bit ARM general purpose registers (`struct user_regs`)
+pub struct ArmRegisters {
+    /// `r0` through `r15`, where `r13` is the stack pointer, `r14` the link register and `r15` the pc
+    pub regs: [u32; 16],
+    pub cpsr: u32,
+    pub orig_r0: u32,
+}
+
+pub const SIZEOF_ARM_REGISTERS: usize = 18 * 4;
+
+use plain;
+unsafe impl plain::Plain for X86_64Registers {}
+unsafe impl plain::Plain for I386Registers {}
+unsafe impl plain::Plain for AArch64Registers {}
+unsafe impl plain::Plain for ArmRegisters {}
+
+if_alloc! {
+    use error;
+    use scroll::Pread;
+    use container::{Container, Ctx};
+    use alloc::vec::Vec;
+    use elf::note::{self, Note};
+
+    /// Reads a C `long` of the dumped process
+    fn gread_long(bytes: &[u8], offset: &mut usize, ctx: Ctx) -> error::Result<u64> {
+        Ok(match ctx.container {
+            Container::Little => bytes.gread_with::<u32>(offset, ctx.le)? as u64,
+            Container::Big => bytes.gread_with::<u64>(offset, ctx.le)?,
+        })
+    }
+
+    /// Returns the NUL padded string stored in the fixed size `bytes`, without its padding
+    fn fixed_str(bytes: &[u8]) -> &[u8] {
+        let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        &bytes[..len]
+    }
+
+    /// Reads the NUL terminated string at `offset`, without its terminator
+    fn gread_cstr<'a>(bytes: &'a [u8], offset: &mut usize) -> error::Result<&'a [u8]> {
+        let rest = bytes.get(*offset..).unwrap_or(&[]);
+        let len = rest.iter().position(|&b| b == 0)
+            .ok_or_else(|| error::Error::Malformed(format!("Unterminated string at offset {:#x} of core note", *offset)))?;
+        *offset += len + 1;
+        Ok(&rest[..len])
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    /// The general purpose registers of a thread, as saved in its `NT_PRSTATUS` note
+    pub enum Registers {
+        X86_64(X86_64Registers),
+        I386(I386Registers),
+        AArch64(AArch64Registers),
+        Arm(ArmRegisters),
+    }
+
+    impl Registers {
+        /// Parses the register set of a `machine` from `bytes`, or returns `None` if the machine is unsupported
+        pub fn parse(bytes: &[u8], machine: u16, ctx: Ctx) -> error::Result<Option<Self>> {
+            let (expected, registers) = match machine {
+                EM_X86_64 => (SIZEOF_X86_64_REGISTERS, Registers::X86_64(bytes.pread_with(0, ctx.le)?)),
+                EM_386 => (SIZEOF_I386_REGISTERS, Registers::I386(bytes.pread_with(0, ctx.le)?)),
+                EM_AARCH64 => (SIZEOF_AARCH64_REGISTERS, Registers::AArch64(bytes.pread_with(0, ctx.le)?)),
+                EM_ARM => (SIZEOF_ARM_REGISTERS, Registers::Arm(bytes.pread_with(0, ctx.le)?)),
+                _ => return Ok(None),
+            };
+            if bytes.len() != expected {
+                return Err(error::Error::Malformed(format!("Register set for machine {} is {} bytes, expected {}", machine, bytes.len(), expected)));
+            }
+            Ok(Some(registers))
+        }
+        /// The program counter
+        pub fn pc(&self) -> u64 {
+            match *self {
+                Registers::X86_64(ref regs) => regs.rip,
+                Registers::I386(ref regs) => regs.eip as u64,
+                Registers::AArch64(ref regs) => regs.pc,
+                Registers::Arm(ref regs) => regs.regs[15] as u64,
+            }
+        }
+        /// The stack pointer
+        pub fn sp(&self) -> u64 {
+            match *self {
+                Registers::X86_64(ref regs) => regs.rsp,
+                Registers::I386(ref regs) => regs.esp as u64,
+                Registers::AArch64(ref regs) => regs.sp,
+                Registers::Arm(ref regs) => regs.regs[13] as u64,
+            }
+        }
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Default)]
+    /// A `struct timeval`
+    pub struct TimeVal {
+        pub tv_sec: u64,
+        pub tv_usec: u64,
+    }
+
+    impl TimeVal {
+        fn gread(bytes: &[u8], offset: &mut usize, ctx: Ctx) -> error::Result<Self> {
+            let tv_sec = gread_long(bytes, offset, ctx)?;
+            let tv_usec = gread_long(bytes, offset, ctx)?;
+            Ok(TimeVal { tv_sec, tv_usec })
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    /// The status of a thread (`struct elf_prstatus`), from an `NT_PRSTATUS` note
+    pub struct PrStatus<'a> {
+        /// Signal number
+        pub si_signo: i32,
+        /// Extra code
+        pub si_code: i32,
+        /// Errno
+        pub si_errno: i32,
+        /// Current signal
+        pub pr_cursig: i16,
+        /// Set of pending signals
+        pub pr_sigpend: u64,
+        /// Set of held signals
+        pub pr_sighold: u64,
+        pub pr_pid: i32,
+        pub pr_ppid: i32,
+        pub pr_pgrp: i32,
+        pub pr_sid: i32,
+        /// User time
+        pub pr_utime: TimeVal,
+        /// System time
+        pub pr_stime: TimeVal,
+        /// Cumulative user time
+        pub pr_cutime: TimeVal,
+        /// Cumulative system time
+        pub pr_cstime: TimeVal,
+        /// The general purpose registers, if the machine is supported
+        pub pr_reg: Option<Registers>,
+        /// The raw bytes of the general purpose registers
+        pub pr_reg_bytes: &'a [u8],
+        /// True if math co-processor being used
+        pub pr_fpvalid: i32,
+    }
+
+    impl<'a> PrStatus<'a> {
+        /// Parses an `NT_PRSTATUS` note descriptor of a `machine` core file
+        pub fn parse(desc: &'a [u8], machine: u16, ctx: Ctx) -> error::Result<Self> {
+            let offset = &mut 0;
+            let si_signo = desc.gread_with(offset, ctx.le)?;
+            let si_code = desc.gread_with(offset, ctx.le)?;
+            let si_errno = desc.gread_with(offset, ctx.le)?;
+            let pr_cursig = desc.gread_with(offset, ctx.le)?;
+            // padding to the alignment of pr_sigpend
+            *offset += 2;
+            let pr_sigpend = gread_long(desc, offset, ctx)?;
+            let pr_sighold = gread_long(desc, offset, ctx)?;
+            let pr_pid = desc.gread_with(offset, ctx.le)?;
+            let pr_ppid = desc.gread_with(offset, ctx.le)?;
+            let pr_pgrp = desc.gread_with(offset, ctx.le)?;
+            let pr_sid = desc.gread_with(offset, ctx.le)?;
+            let pr_utime = TimeVal::gread(desc, offset, ctx)?;
+            let pr_stime = TimeVal::gread(desc, offset, ctx)?;
+            let pr_cutime = TimeVal::gread(desc, offset, ctx)?;
+            let pr_cstime = TimeVal::gread(desc, offset, ctx)?;
+            // the register set runs until pr_fpvalid, which is padded to the alignment of a long
+            let fpvalid_size = ctx.size();
+            if desc.len() < *offset + fpvalid_size {
+                return Err(error::Error::Malformed(format!("NT_PRSTATUS note is too small: {} bytes", desc.len())));
+            }
+            let reg_end = desc.len() - fpvalid_size;
+            let pr_reg_bytes = &desc[*offset..reg_end];
+            let pr_reg = Registers::parse(pr_reg_bytes, machine, ctx)?;
+            let pr_fpvalid = desc.pread_with(reg_end, ctx.le)?;
+            Ok(PrStatus {
+                si_signo, si_code, si_errno, pr_cursig, pr_sigpend, pr_sighold,
+                pr_pid, pr_ppid, pr_pgrp, pr_sid,
+                pr_utime, pr_stime, pr_cutime, pr_cstime,
+                pr_reg, pr_reg_bytes, pr_fpvalid,
+            })
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    /// Information about the dumped process (`struct elf_prpsinfo`), from an `NT_PRPSINFO` note
+    pub struct PrPsInfo<'a> {
+        /// Numeric process state
+        pub pr_state: i8,
+        /// Char for `pr_state`
+        pub pr_sname: u8,
+        /// Zombie
+        pub pr_zomb: i8,
+        /// Nice value
+        pub pr_nice: i8,
+        /// Flags
+        pub pr_flag: u64,
+        pub pr_uid: u32,
+        pub pr_gid: u32,
+        pub pr_pid: i32,
+        pub pr_ppid: i32,
+        pub pr_pgrp: i32,
+        pub pr_sid: i32,
+        /// Filename of the executable, as raw bytes since the kernel doesn't guarantee an encoding
+        pub pr_fname: &'a [u8],
+        /// Initial part of the argument list, as raw bytes
+        pub pr_psargs: &'a [u8],
+    }
+
+    impl<'a> PrPsInfo<'a> {
+        /// Parses an `NT_PRPSINFO` note descriptor
+        pub fn parse(desc: &'a [u8], ctx: Ctx) -> error::Result<Self> {
+            let offset = &mut 0;
+            let pr_state = desc.gread_with(offset, ctx.le)?;
+            let pr_sname = desc.gread_with(offset, ctx.le)?;
+            let pr_zomb = desc.gread_with(offset, ctx.le)?;
+            let pr_nice = desc.gread_with(offset, ctx.le)?;
+            // padding to the alignment of a long
+            *offset = ctx.size();
+            let pr_flag = gread_long(desc, offset, ctx)?;
+            // 32-bit ABIs use 16-bit uids and gids
+            let (pr_uid, pr_gid) = match ctx.container {
+                Container::Little => (desc.gread_with::<u16>(offset, ctx.le)? as u32, desc.gread_with::<u16>(offset, ctx.le)? as u32),
+                Container::Big => (desc.gread_with(offset, ctx.le)?, desc.gread_with(offset, ctx.le)?),
+            };
+            let pr_pid = desc.gread_with(offset, ctx.le)?;
+            let pr_ppid = desc.gread_with(offset, ctx.le)?;
+            let pr_pgrp = desc.gread_with(offset, ctx.le)?;
+            let pr_sid = desc.gread_with(offset, ctx.le)?;
+            let pr_fname = fixed_str(desc.gread_with(offset, 16)?);
+            let pr_psargs = fixed_str(desc.gread_with(offset, 80)?);
+            Ok(PrPsInfo {
+                pr_state, pr_sname, pr_zomb, pr_nice, pr_flag, pr_uid, pr_gid,
+                pr_pid, pr_ppid, pr_pgrp, pr_sid, pr_fname, pr_psargs,
+            })
+        }
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    /// The signal that caused the dump (`siginfo_t`), from an `NT_SIGINFO` note
+    pub struct SigInfo {
+        /// Signal number
+        pub si_signo: i32,
+        /// Errno
+        pub si_errno: i32,
+        /// Signal code
+        pub si_code: i32,
+        /// The faulting address, for kernel generated `SIGILL`, `SIGTRAP`, `SIGBUS`, `SIGFPE` and `SIGSEGV`
+        pub si_addr: Option<u64>,
+    }
+
+    impl SigInfo {
+        /// Parses an `NT_SIGINFO` note descriptor
+        pub fn parse(desc: &[u8], ctx: Ctx) -> error::Result<Self> {
+            let offset = &mut 0;
+            let si_signo = desc.gread_with(offset, ctx.le)?;
+            let si_errno = desc.gread_with(offset, ctx.le)?;
+            let si_code = desc.gread_with(offset, ctx.le)?;
+            // the union is aligned to a long
+            if ctx.is_big() {
+                *offset = 16;
+            }
+            // a positive code means the kernel sent the signal
+            let si_addr = match si_signo {
+                4 | 5 | 7 | 8 | 11 if si_code > 0 => Some(gread_long(desc, offset, ctx)?),
+                _ => None,
+            };
+            Ok(SigInfo { si_signo, si_errno, si_code, si_addr })
+        }
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    /// An entry of the auxiliary vector, from an `NT_AUXV` note
+    pub struct AuxvEntry {
+        /// The entry type, e.g., `AT_ENTRY`
+        pub a_type: u64,
+        pub a_val: u64,
+    }
+
+    /// Parses the auxiliary vector in an `NT_AUXV` note descriptor, up to its `AT_NULL` terminator
+    pub fn parse_auxv(desc: &[u8], ctx: Ctx) -> error::Result<Vec<AuxvEntry>> {
+        let offset = &mut 0;
+        let mut auxv = Vec::with_capacity(desc.len() / (ctx.size() * 2));
+        while *offset < desc.len() {
+            let a_type = gread_long(desc, offset, ctx)?;
+            let a_val = gread_long(desc, offset, ctx)?;
+            if a_type == AT_NULL {
+                break;
+            }
+            auxv.push(AuxvEntry { a_type, a_val });
+        }
+        Ok(auxv)
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    /// A file mapped into the dumped process, from an `NT_FILE` note
+    pub struct MappedFile<'a> {
+        /// Start address of the mapping
+        pub start: u64,
+        /// End address of the mapping
+        pub end: u64,
+        /// Offset of the mapping into the file, in bytes
+        pub file_offset: u64,
+        /// Path of the mapped file, as raw bytes since paths needn't be UTF-8
+        pub path: &'a [u8],
+    }
+
+    /// Parses the mapped file table in an `NT_FILE` note descriptor
+    pub fn parse_mapped_files<'a>(desc: &'a [u8], ctx: Ctx) -> error::Result<Vec<MappedFile<'a>>> {
+        let offset = &mut 0;
+        let count = gread_long(desc, offset, ctx)? as usize;
+        let page_size = gread_long(desc, offset, ctx)?;
+        if count > desc.len() / (ctx.size() * 3) {
+            return Err(error::Error::Malformed(format!("NT_FILE note has too many entries ({}) for its size ({})", count, desc.len())));
+        }
+        let mut files = Vec::with_capacity(count);
+        for _ in 0..count {
+            let start = gread_long(desc, offset, ctx)?;
+            let end = gread_long(desc, offset, ctx)?;
+            let file_offset = gread_long(desc, offset, ctx)?.wrapping_mul(page_size);
+            files.push(MappedFile { start, end, file_offset, path: &[] });
+        }
+        for file in &mut files {
+            file.path = gread_cstr(desc, offset)?;
+        }
+        Ok(files)
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    /// A thread of the dumped process
+    pub struct Thread<'a> {
+        /// The thread's status and general purpose registers
+        pub status: PrStatus<'a>,
+        /// The raw floating point registers (`NT_PRFPREG`), if present
+        pub fpregs: Option<&'a [u8]>,
+    }
+
+    impl<'a> Thread<'a> {
+        /// The thread id
+        pub fn tid(&self) -> i32 {
+            self.status.pr_pid
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    /// The process described by the notes of a core file
+    pub struct CoreDump<'a> {
+        /// Information about the process, if present
+        pub process: Option<PrPsInfo<'a>>,
+        /// The signal which caused the dump, if present
+        pub signal: Option<SigInfo>,
+        /// The threads, in dump order; the first one received the signal
+        pub threads: Vec<Thread<'a>>,
+        /// The files mapped into the process
+        pub mapped_files: Vec<MappedFile<'a>>,
+        /// The auxiliary vector
+        pub auxv: Vec<AuxvEntry>,
+    }
+
+    impl<'a> CoreDump<'a> {
+        /// Decodes the core notes in `notes` of a core file for `machine`; notes that aren't core notes are skipped
+        pub fn parse<I>(notes: I, machine: u16, ctx: Ctx) -> error::Result<Self>
+            where I: Iterator<Item = error::Result<Note<'a>>> {
+            let mut core = CoreDump::default();
+            for note in notes {
+                let note = note?;
+                if note.name != note::ELF_NOTE_CORE {
+                    continue;
+                }
+                match note.n_type {
+                    note::NT_PRSTATUS => {
+                        let status = PrStatus::parse(note.desc, machine, ctx)?;
+                        core.threads.push(Thread { status, fpregs: None });
+                    },
+                    note::NT_PRFPREG => {
+                        if let Some(thread) = core.threads.last_mut() {
+                            thread.fpregs = Some(note.desc);
+                        }
+                    },
+                    note::NT_PRPSINFO => core.process = Some(PrPsInfo::parse(note.desc, ctx)?),
+                    note::NT_SIGINFO => core.signal = Some(SigInfo::parse(note.desc, ctx)?),
+                    note::NT_AUXV => core.auxv = parse_auxv(note.desc, ctx)?,
+                    note::NT_FILE => core.mapped_files = parse_mapped_files(note.desc, ctx)?,
+                    _ => (),
+                }
+            }
+            Ok(core)
+        }
+        /// Returns the value of the auxiliary vector entry `a_type`, if present
+        pub fn auxv_value(&self, a_type: u64) -> Option<u64> {
+            self.auxv.iter().find(|entry| entry.a_type == a_type).map(|entry| entry.a_val)
+        }
+        /// Returns the mapped file containing the address `addr`, if any
+        pub fn mapped_file_for(&self, addr: u64) -> Option<&MappedFile<'a>> {
+            self.mapped_files.iter().find(|file| file.start <= addr && addr < file.end)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use scroll::{Pwrite, LE};
+        use core::mem::size_of;
+
+        const CTX64: Ctx = Ctx { container: Container::Big, le: LE };
+        const CTX32: Ctx = Ctx { container: Container::Little, le: LE };
+
+        #[test]
+        fn size_of_registers() {
+            assert_eq!(size_of::<X86_64Registers>(), SIZEOF_X86_64_REGISTERS);
+            assert_eq!(size_of::<I386Registers>(), SIZEOF_I386_REGISTERS);
+            assert_eq!(size_of::<AArch64Registers>(), SIZEOF_AARCH64_REGISTERS);
+            assert_eq!(size_of::<ArmRegisters>(), SIZEOF_ARM_REGISTERS);
+        }
+
+        #[test]
+        fn prstatus_x86_64() {
+            let mut desc = [0u8; 336];
+            desc.pwrite_with(11i32, 0, LE).unwrap();
+            desc.pwrite_with(11i16, 12, LE).unwrap();
+            desc.pwrite_with(1234i32, 32, LE).unwrap();
+            let regs = X86_64Registers { rip: 0x40_1000, rsp: 0x7fff_0000, ..Default::default() };
+            desc.pwrite_with(regs, 112, LE).unwrap();
+            desc.pwrite_with(1i32, 328, LE).unwrap();
+            let status = PrStatus::parse(&desc, EM_X86_64, CTX64).unwrap();
+            assert_eq!(status.si_signo, 11);
+            assert_eq!(status.pr_cursig, 11);
+            assert_eq!(status.pr_pid, 1234);
+            assert_eq!(status.pr_fpvalid, 1);
+            let regs = status.pr_reg.unwrap();
+            assert_eq!(regs.pc(), 0x40_1000);
+            assert_eq!(regs.sp(), 0x7fff_0000);
+            assert!(PrStatus::parse(&desc, EM_AARCH64, CTX64).is_err());
+        }
+
+        #[test]
+        fn prstatus_arm() {
+            let mut desc = [0u8; 148];
+            desc.pwrite_with(42i32, 24, LE).unwrap();
+            let mut regs = ArmRegisters::default();
+            regs.regs[15] = 0x8000;
+            desc.pwrite_with(regs, 72, LE).unwrap();
+            let status = PrStatus::parse(&desc, EM_ARM, CTX32).unwrap();
+            assert_eq!(status.pr_pid, 42);
+            assert_eq!(status.pr_reg.unwrap().pc(), 0x8000);
+        }
+
+        #[test]
+        fn prpsinfo() {
+            let mut desc = [0u8; 136];
+            desc[1] = b'R';
+            desc.pwrite_with(1000u32, 16, LE).unwrap();
+            desc.pwrite_with(77i32, 24, LE).unwrap();
+            desc[40..44].copy_from_slice(b"cat\0");
+            desc[56..68].copy_from_slice(b"cat /etc/foo");
+            let info = PrPsInfo::parse(&desc, CTX64).unwrap();
+            assert_eq!(info.pr_sname, b'R');
+            assert_eq!(info.pr_uid, 1000);
+            assert_eq!(info.pr_pid, 77);
+            assert_eq!(info.pr_fname, b"cat");
+            assert_eq!(info.pr_psargs, b"cat /etc/foo");
+            // comm is whatever the process was named, which needn't be UTF-8
+            desc[40..44].copy_from_slice(b"c\xffz\0");
+            assert_eq!(PrPsInfo::parse(&desc, CTX64).unwrap().pr_fname, b"c\xffz");
+        }
+
+        #[test]
+        fn core_dump_from_notes() {
+            let mut file = [0u8; 16 + 2 * 24 + 16];
+            let mut offset = 0;
+            for &word in &[2u64, 0x1000, 0x40_0000, 0x40_1000, 0, 0x60_0000, 0x60_2000, 2] {
+                file.gwrite_with(word, &mut offset, LE).unwrap();
+            }
+            file[offset..offset + 16].copy_from_slice(b"/bin/\xe9\0/bin/bb\0\0");
+            let mut auxv = [0u8; 48];
+            let mut offset = 0;
+            for &word in &[AT_PAGESZ, 4096, AT_ENTRY, 0x40_0100, AT_NULL, 0] {
+                auxv.gwrite_with(word, &mut offset, LE).unwrap();
+            }
+            let mut siginfo = [0u8; 128];
+            siginfo.pwrite_with(11i32, 0, LE).unwrap();
+            siginfo.pwrite_with(1i32, 8, LE).unwrap();
+            siginfo.pwrite_with(0xdeadu64, 16, LE).unwrap();
+            let mut prstatus = [0u8; 336];
+            prstatus.pwrite_with(7i32, 32, LE).unwrap();
+            let notes = vec![
+                Ok(Note { n_type: note::NT_PRSTATUS, name: "CORE", desc: &prstatus }),
+                Ok(Note { n_type: note::NT_SIGINFO, name: "CORE", desc: &siginfo }),
+                Ok(Note { n_type: note::NT_AUXV, name: "CORE", desc: &auxv }),
+                Ok(Note { n_type: note::NT_FILE, name: "CORE", desc: &file }),
+                Ok(Note { n_type: note::NT_PRFPREG, name: "CORE", desc: &auxv[..8] }),
+                Ok(Note { n_type: note::NT_X86_XSTATE, name: "LINUX", desc: &[] }),
+                Ok(Note { n_type: note::NT_PRSTATUS, name: "CORE", desc: &prstatus }),
+            ];
+            let core = CoreDump::parse(notes.into_iter(), EM_X86_64, CTX64).unwrap();
+            assert_eq!(core.threads.len(), 2);
+            assert_eq!(core.threads[0].tid(), 7);
+            assert!(core.threads[0].fpregs.is_some());
+            assert!(core.threads[1].fpregs.is_none());
+            assert_eq!(core.signal.unwrap().si_addr, Some(0xdead));
+            assert_eq!(core.auxv_value(AT_ENTRY), Some(0x40_0100));
+            assert_eq!(core.mapped_files.len(), 2);
+            assert_eq!(core.mapped_files[1].file_offset, 0x2000);
+            assert_eq!(core.mapped_files[0].path, b"/bin/\xe9");
+            assert_eq!(core.mapped_file_for(0x60_1000).unwrap().path, b"/bin/bb");
+            assert!(core.mapped_file_for(0x50_0000).is_none());
+        }
+    }
+}
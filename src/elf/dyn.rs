@@ -91,10 +91,30 @@ pub const DT_ENCODING: u64 = 32;
 pub const DT_PREINIT_ARRAY: u64 = 32;
 /// size in bytes of DT_PREINIT_ARRAY
 pub const DT_PREINIT_ARRAYSZ: u64 = 33;
+/// Total size of the RELR relative relocations
+pub const DT_RELRSZ: u64 = 35;
+/// Address of the RELR relative relocations
+pub const DT_RELR: u64 = 36;
+/// Size of one RELR relative relocation entry
+pub const DT_RELRENT: u64 = 37;
 /// Number used
-pub const DT_NUM: u64 = 34;
+pub const DT_NUM: u64 = 38;
 /// Start of OS-specific
 pub const DT_LOOS: u64 = 0x6000000d;
+/// Address of the Android packed relocations without addends ("APS2")
+pub const DT_ANDROID_REL: u64 = 0x6000000f;
+/// Total size of the Android packed relocations without addends
+pub const DT_ANDROID_RELSZ: u64 = 0x60000010;
+/// Address of the Android packed relocations with addends ("APS2")
+pub const DT_ANDROID_RELA: u64 = 0x60000011;
+/// Total size of the Android packed relocations with addends
+pub const DT_ANDROID_RELASZ: u64 = 0x60000012;
+/// Address of the Android RELR relative relocations, which predate `DT_RELR`
+pub const DT_ANDROID_RELR: u64 = 0x6fffe000;
+/// Total size of the Android RELR relative relocations
+pub const DT_ANDROID_RELRSZ: u64 = 0x6fffe001;
+/// Size of one Android RELR relative relocation entry
+pub const DT_ANDROID_RELRENT: u64 = 0x6fffe003;
 /// End of OS-specific
 pub const DT_HIOS: u64 = 0x6ffff000;
 /// Start of processor-specific
@@ -103,6 +123,10 @@ pub const DT_LOPROC: u64 = 0x70000000;
 pub const DT_HIPROC: u64 = 0x7fffffff;
 // Most used by any processor
 // pub const DT_PROCNUM: u64 = DT_MIPS_NUM;
+/// Shared object to load before self, i.e., an auxiliary filtee
+pub const DT_AUXILIARY: u64 = 0x7ffffffd;
+/// Shared object to get values from, i.e., a standard filtee
+pub const DT_FILTER: u64 = 0x7fffffff;
 
 /// DT_* entries which fall between DT_ADDRRNGHI & DT_ADDRRNGLO use the
 /// Dyn.d_un.d_ptr field of the Elf*_Dyn structure.
@@ -190,20 +214,33 @@ pub fn tag_to_str(tag: u64) -> &'static str {
         DT_FLAGS => "DT_FLAGS",
         DT_PREINIT_ARRAY => "DT_PREINIT_ARRAY",
         DT_PREINIT_ARRAYSZ => "DT_PREINIT_ARRAYSZ",
+        DT_RELRSZ => "DT_RELRSZ",
+        DT_RELR => "DT_RELR",
+        DT_RELRENT => "DT_RELRENT",
         DT_NUM => "DT_NUM",
         DT_LOOS => "DT_LOOS",
         DT_HIOS => "DT_HIOS",
+        DT_ANDROID_REL => "DT_ANDROID_REL",
+        DT_ANDROID_RELSZ => "DT_ANDROID_RELSZ",
+        DT_ANDROID_RELA => "DT_ANDROID_RELA",
+        DT_ANDROID_RELASZ => "DT_ANDROID_RELASZ",
+        DT_ANDROID_RELR => "DT_ANDROID_RELR",
+        DT_ANDROID_RELRSZ => "DT_ANDROID_RELRSZ",
+        DT_ANDROID_RELRENT => "DT_ANDROID_RELRENT",
         DT_LOPROC => "DT_LOPROC",
         DT_HIPROC => "DT_HIPROC",
         DT_VERSYM => "DT_VERSYM",
         DT_RELACOUNT => "DT_RELACOUNT",
         DT_RELCOUNT => "DT_RELCOUNT",
         DT_GNU_HASH => "DT_GNU_HASH",
+        DT_TLSDESC_PLT => "DT_TLSDESC_PLT",
+        DT_TLSDESC_GOT => "DT_TLSDESC_GOT",
         DT_VERDEF => "DT_VERDEF",
         DT_VERDEFNUM => "DT_VERDEFNUM",
         DT_VERNEED => "DT_VERNEED",
         DT_VERNEEDNUM => "DT_VERNEEDNUM",
         DT_FLAGS_1 => "DT_FLAGS_1",
+        DT_AUXILIARY => "DT_AUXILIARY",
         _ => "UNKNOWN_TAG",
     }
 }
@@ -270,6 +307,131 @@ pub const DF_1_SYMINTPOSE: u64 = 0x00800000;
 pub const DF_1_GLOBAUDIT: u64 = 0x01000000;
 /// Singleton dyn are used.
 pub const DF_1_SINGLETON: u64 = 0x02000000;
+/// Object has no dynamic sections, it's a stub.
+pub const DF_1_STUB: u64 = 0x04000000;
+/// Object is a position independent executable.
+pub const DF_1_PIE: u64 = 0x08000000;
+/// Object is a kernel module.
+pub const DF_1_KMOD: u64 = 0x10000000;
+/// Object is a weak standard filter.
+pub const DF_1_WEAKFILTER: u64 = 0x20000000;
+/// Object has no common symbols.
+pub const DF_1_NOCOMMON: u64 = 0x40000000;
+
+/// Converts a `DF_*` flag to its string representation.
+pub fn df_to_str(flag: u64) -> &'static str {
+    match flag {
+        DF_ORIGIN => "DF_ORIGIN",
+        DF_SYMBOLIC => "DF_SYMBOLIC",
+        DF_TEXTREL => "DF_TEXTREL",
+        DF_BIND_NOW => "DF_BIND_NOW",
+        DF_STATIC_TLS => "DF_STATIC_TLS",
+        _ => "DF_UNKNOWN",
+    }
+}
+
+/// Converts a `DF_1_*` flag to its string representation.
+pub fn df_1_to_str(flag: u64) -> &'static str {
+    match flag {
+        DF_1_NOW => "DF_1_NOW",
+        DF_1_GLOBAL => "DF_1_GLOBAL",
+        DF_1_GROUP => "DF_1_GROUP",
+        DF_1_NODELETE => "DF_1_NODELETE",
+        DF_1_LOADFLTR => "DF_1_LOADFLTR",
+        DF_1_INITFIRST => "DF_1_INITFIRST",
+        DF_1_NOOPEN => "DF_1_NOOPEN",
+        DF_1_ORIGIN => "DF_1_ORIGIN",
+        DF_1_DIRECT => "DF_1_DIRECT",
+        DF_1_TRANS => "DF_1_TRANS",
+        DF_1_INTERPOSE => "DF_1_INTERPOSE",
+        DF_1_NODEFLIB => "DF_1_NODEFLIB",
+        DF_1_NODUMP => "DF_1_NODUMP",
+        DF_1_CONFALT => "DF_1_CONFALT",
+        DF_1_ENDFILTEE => "DF_1_ENDFILTEE",
+        DF_1_DISPRELDNE => "DF_1_DISPRELDNE",
+        DF_1_DISPRELPND => "DF_1_DISPRELPND",
+        DF_1_NODIRECT => "DF_1_NODIRECT",
+        DF_1_IGNMULDEF => "DF_1_IGNMULDEF",
+        DF_1_NOKSYMS => "DF_1_NOKSYMS",
+        DF_1_NOHDR => "DF_1_NOHDR",
+        DF_1_EDITED => "DF_1_EDITED",
+        DF_1_NORELOC => "DF_1_NORELOC",
+        DF_1_SYMINTPOSE => "DF_1_SYMINTPOSE",
+        DF_1_GLOBAUDIT => "DF_1_GLOBAUDIT",
+        DF_1_SINGLETON => "DF_1_SINGLETON",
+        DF_1_STUB => "DF_1_STUB",
+        DF_1_PIE => "DF_1_PIE",
+        DF_1_KMOD => "DF_1_KMOD",
+        DF_1_WEAKFILTER => "DF_1_WEAKFILTER",
+        DF_1_NOCOMMON => "DF_1_NOCOMMON",
+        _ => "DF_1_UNKNOWN",
+    }
+}
+
+/// Writes the names of the bits of `flags`, separated by spaces, and the unknown bits in hex
+fn fmt_flags(f: &mut ::core::fmt::Formatter, flags: u64, to_str: fn(u64) -> &'static str, unknown: &str) -> ::core::fmt::Result {
+    let mut separator = "";
+    for bit in 0..64 {
+        let flag = 1u64 << bit;
+        if flags & flag == 0 {
+            continue;
+        }
+        let name = to_str(flag);
+        if name == unknown {
+            write!(f, "{}{:#x}", separator, flag)?;
+        } else {
+            write!(f, "{}{}", separator, name)?;
+        }
+        separator = " ";
+    }
+    Ok(())
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// The `DF_*` bits of `DT_FLAGS`, displayed as their names
+pub struct DynFlags(pub u64);
+
+impl DynFlags {
+    /// Whether all the bits of `flags` are set
+    pub fn contains(&self, flags: u64) -> bool {
+        self.0 & flags == flags
+    }
+    /// Whether the object must be bound at load time, i.e., `DF_BIND_NOW`
+    pub fn bind_now(&self) -> bool {
+        self.contains(DF_BIND_NOW)
+    }
+}
+
+impl ::core::fmt::Display for DynFlags {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        fmt_flags(f, self.0, df_to_str, "DF_UNKNOWN")
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// The `DF_1_*` bits of `DT_FLAGS_1`, displayed as their names
+pub struct DynFlags1(pub u64);
+
+impl DynFlags1 {
+    /// Whether all the bits of `flags` are set
+    pub fn contains(&self, flags: u64) -> bool {
+        self.0 & flags == flags
+    }
+    /// Whether the object must be bound at load time, i.e., `DF_1_NOW`
+    pub fn now(&self) -> bool {
+        self.contains(DF_1_NOW)
+    }
+    /// Whether the object is a position independent executable, i.e., `DF_1_PIE`
+    pub fn pie(&self) -> bool {
+        self.contains(DF_1_PIE)
+    }
+}
+
+impl ::core::fmt::Display for DynFlags1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        fmt_flags(f, self.0, df_1_to_str, "DF_1_UNKNOWN")
+    }
+}
 
 if_alloc! {
     use core::fmt;
@@ -279,6 +441,18 @@ if_alloc! {
     use strtab::Strtab;
     use self::dyn32::{DynamicInfo};
     use alloc::vec::Vec;
+    use elf::address_map::AddressMap;
+
+    /// Whether the value of the dynamic entry `tag` is a virtual address (`d_ptr`)
+    pub fn is_address_tag(tag: u64) -> bool {
+        match tag {
+            DT_PLTGOT | DT_HASH | DT_STRTAB | DT_SYMTAB | DT_RELA | DT_INIT | DT_FINI | DT_REL |
+            DT_JMPREL | DT_INIT_ARRAY | DT_FINI_ARRAY | DT_PREINIT_ARRAY | DT_GNU_HASH |
+            DT_VERSYM | DT_VERDEF | DT_VERNEED | DT_RELR | DT_ANDROID_RELR | DT_ANDROID_REL |
+            DT_ANDROID_RELA | DT_TLSDESC_PLT | DT_TLSDESC_GOT => true,
+            _ => false,
+        }
+    }
 
     #[derive(Default, PartialEq, Clone)]
     pub struct Dyn {
@@ -361,8 +535,8 @@ if_alloc! {
 
     impl Dynamic {
         #[cfg(feature = "endian_fd")]
-        /// Returns a vector of dynamic entries from the underlying byte `bytes`, with `endianness`, using the provided `phdrs`
-        pub fn parse(bytes: &[u8], phdrs: &[::elf::program_header::ProgramHeader], bias: usize, ctx: Ctx) -> ::error::Result<Option<Self>> {
+        /// Reads the entries of the `PT_DYNAMIC` segment in `phdrs`, up to and including `DT_NULL`
+        fn parse_dyns(bytes: &[u8], phdrs: &[::elf::program_header::ProgramHeader], ctx: Ctx) -> ::error::Result<Option<Vec<Dyn>>> {
             use scroll::ctx::SizeWith;
             use scroll::Pread;
             use elf::program_header;
@@ -379,31 +553,93 @@ if_alloc! {
                         dyns.push(dyn);
                         if tag == DT_NULL { break }
                     }
+                    return Ok(Some(dyns));
+                }
+            }
+            Ok(None)
+        }
+
+        #[cfg(feature = "endian_fd")]
+        /// Returns a vector of dynamic entries from the underlying byte `bytes`, with `endianness`, using the provided `phdrs`
+        pub fn parse(bytes: &[u8], phdrs: &[::elf::program_header::ProgramHeader], bias: usize, ctx: Ctx) -> ::error::Result<Option<Self>> {
+            match Self::parse_dyns(bytes, phdrs, ctx)? {
+                Some(dyns) => {
                     let mut info = DynamicInfo::default();
                     for dyn in &dyns {
                         let dyn: dyn32::Dyn = dyn.clone().into();
                         info.update(bias, &dyn);
                     }
                     let count = dyns.len();
-                    return Ok(Some(Dynamic { dyns: dyns, info: info, count: count }));
-                }
+                    Ok(Some(Dynamic { dyns: dyns, info: info, count: count }))
+                },
+                None => Ok(None),
+            }
+        }
+
+        #[cfg(feature = "endian_fd")]
+        /// Like `parse`, but the addresses in the resulting `DynamicInfo` are translated to file offsets with
+        /// `address_map`, instead of by subtracting a single bias; entries whose address isn't backed by the
+        /// file are skipped
+        pub fn parse_with_address_map(bytes: &[u8], phdrs: &[::elf::program_header::ProgramHeader], address_map: &AddressMap, ctx: Ctx) -> ::error::Result<Option<Self>> {
+            match Self::parse_dyns(bytes, phdrs, ctx)? {
+                Some(dyns) => {
+                    let mut info = DynamicInfo::default();
+                    for dyn in &dyns {
+                        let mut dyn = dyn.clone();
+                        if is_address_tag(dyn.d_tag) {
+                            match address_map.vaddr_to_offset(dyn.d_val) {
+                                Some(offset) => dyn.d_val = offset as u64,
+                                None => continue,
+                            }
+                        }
+                        let dyn: dyn32::Dyn = dyn.into();
+                        info.update(0, &dyn);
+                    }
+                    let count = dyns.len();
+                    Ok(Some(Dynamic { dyns: dyns, info: info, count: count }))
+                },
+                None => Ok(None),
             }
-            Ok(None)
         }
 
         pub fn get_libraries<'a>(&self, strtab: &Strtab<'a>) -> Vec<&'a str> {
-            let count = self.info.needed_count;
-            let mut needed = Vec::with_capacity(count);
+            self.get_strings(DT_NEEDED, strtab)
+        }
+
+        /// Returns the `DT_RPATH` entries, each a colon separated list of directories
+        pub fn get_rpaths<'a>(&self, strtab: &Strtab<'a>) -> Vec<&'a str> {
+            self.get_strings(DT_RPATH, strtab)
+        }
+
+        /// Returns the `DT_RUNPATH` entries, each a colon separated list of directories
+        pub fn get_runpaths<'a>(&self, strtab: &Strtab<'a>) -> Vec<&'a str> {
+            self.get_strings(DT_RUNPATH, strtab)
+        }
+
+        /// Returns the strings of the entries with the tag `tag` whose value is an offset into the dynamic
+        /// string table `strtab`, e.g., `DT_NEEDED`, `DT_AUXILIARY` or `DT_FILTER`, in order
+        pub fn get_strings<'a>(&self, tag: u64, strtab: &Strtab<'a>) -> Vec<&'a str> {
+            let mut strings = Vec::new();
             for dyn in &self.dyns {
-                if dyn.d_tag as u64 == DT_NEEDED {
+                if dyn.d_tag == tag {
                     match strtab.get(dyn.d_val as usize) {
-                        Some(Ok(lib)) => needed.push(lib),
+                        Some(Ok(string)) => strings.push(string),
                         // FIXME: warn! here
                         _ => (),
                     }
                 }
             }
-            needed
+            strings
+        }
+
+        /// Returns the `DT_FLAGS` flags
+        pub fn flags(&self) -> DynFlags {
+            DynFlags(self.info.flags as u64)
+        }
+
+        /// Returns the `DT_FLAGS_1` flags
+        pub fn flags_1(&self) -> DynFlags1 {
+            DynFlags1(self.info.flags_1 as u64)
         }
     }
 }
@@ -468,7 +704,7 @@ macro_rules! elf_dyn_std_impl {
                     let gnu_hash = if let Some(addr) = self.gnu_hash { addr } else { 0 };
                     let hash = if let Some(addr) = self.hash { addr } else { 0 };
                     let pltgot = if let Some(addr) = self.pltgot { addr } else { 0 };
-                    write!(f, "rela: 0x{:x} relasz: {} relaent: {} relacount: {} gnu_hash: 0x{:x} hash: 0x{:x} strtab: 0x{:x} strsz: {} symtab: 0x{:x} syment: {} pltgot: 0x{:x} pltrelsz: {} pltrel: {} jmprel: 0x{:x} verneed: 0x{:x} verneednum: {} versym: 0x{:x} init: 0x{:x} fini: 0x{:x} needed_count: {}",
+                    write!(f, "rela: 0x{:x} relasz: {} relaent: {} relacount: {} gnu_hash: 0x{:x} hash: 0x{:x} strtab: 0x{:x} strsz: {} symtab: 0x{:x} syment: {} pltgot: 0x{:x} pltrelsz: {} pltrel: {} jmprel: 0x{:x} verneed: 0x{:x} verneednum: {} versym: 0x{:x} verdef: 0x{:x} verdefnum: {} init: 0x{:x} fini: 0x{:x} needed_count: {}",
                            self.rela,
                            self.relasz,
                            self.relaent,
@@ -486,6 +722,8 @@ macro_rules! elf_dyn_std_impl {
                            self.verneed,
                            self.verneednum,
                            self.versym,
+                           self.verdef,
+                           self.verdefnum,
                            self.init,
                            self.fini,
                            self.needed_count,
@@ -559,6 +797,13 @@ macro_rules! elf_dyn_std_impl {
             pub relsz: usize,
             pub relent: $size,
             pub relcount: usize,
+            pub relr: usize,
+            pub relrsz: usize,
+            pub relrent: $size,
+            pub android_rel: usize,
+            pub android_relsz: usize,
+            pub android_rela: usize,
+            pub android_relasz: usize,
             pub gnu_hash: Option<$size>,
             pub hash: Option<$size>,
             pub strtab: usize,
@@ -572,16 +817,24 @@ macro_rules! elf_dyn_std_impl {
             pub verneed: $size,
             pub verneednum: $size,
             pub versym: $size,
+            pub verdef: $size,
+            pub verdefnum: $size,
             pub init: $size,
             pub fini: $size,
             pub init_array: $size,
             pub init_arraysz: usize,
             pub fini_array: $size,
             pub fini_arraysz: usize,
+            pub preinit_array: $size,
+            pub preinit_arraysz: usize,
+            pub tlsdesc_plt: $size,
+            pub tlsdesc_got: $size,
             pub needed_count: usize,
             pub flags: $size,
             pub flags_1: $size,
             pub soname: usize,
+            pub rpath: usize,
+            pub runpath: usize,
             pub textrel: bool,
         }
 
@@ -597,6 +850,13 @@ macro_rules! elf_dyn_std_impl {
                     DT_RELSZ => self.relsz = dyn.d_val as usize,
                     DT_RELENT => self.relent = dyn.d_val as _,
                     DT_RELCOUNT => self.relcount = dyn.d_val as usize,
+                    DT_RELR | DT_ANDROID_RELR => self.relr = dyn.d_val.wrapping_add(bias as _) as usize, // .relr.dyn
+                    DT_RELRSZ | DT_ANDROID_RELRSZ => self.relrsz = dyn.d_val as usize,
+                    DT_RELRENT | DT_ANDROID_RELRENT => self.relrent = dyn.d_val as _,
+                    DT_ANDROID_REL => self.android_rel = dyn.d_val.wrapping_add(bias as _) as usize,
+                    DT_ANDROID_RELSZ => self.android_relsz = dyn.d_val as usize,
+                    DT_ANDROID_RELA => self.android_rela = dyn.d_val.wrapping_add(bias as _) as usize,
+                    DT_ANDROID_RELASZ => self.android_relasz = dyn.d_val as usize,
                     DT_GNU_HASH => self.gnu_hash = Some(dyn.d_val.wrapping_add(bias as _)),
                     DT_HASH => self.hash = Some(dyn.d_val.wrapping_add(bias as _)) as _,
                     DT_STRTAB => self.strtab = dyn.d_val.wrapping_add(bias as _) as usize,
@@ -610,16 +870,24 @@ macro_rules! elf_dyn_std_impl {
                     DT_VERNEED => self.verneed = dyn.d_val.wrapping_add(bias as _) as _,
                     DT_VERNEEDNUM => self.verneednum = dyn.d_val as _,
                     DT_VERSYM => self.versym = dyn.d_val.wrapping_add(bias as _) as _,
+                    DT_VERDEF => self.verdef = dyn.d_val.wrapping_add(bias as _) as _,
+                    DT_VERDEFNUM => self.verdefnum = dyn.d_val as _,
                     DT_INIT => self.init = dyn.d_val.wrapping_add(bias as _) as _,
                     DT_FINI => self.fini = dyn.d_val.wrapping_add(bias as _) as _,
                     DT_INIT_ARRAY => self.init_array = dyn.d_val.wrapping_add(bias as _) as _,
                     DT_INIT_ARRAYSZ => self.init_arraysz = dyn.d_val as _,
                     DT_FINI_ARRAY => self.fini_array = dyn.d_val.wrapping_add(bias as _) as _,
                     DT_FINI_ARRAYSZ => self.fini_arraysz = dyn.d_val as _,
+                    DT_PREINIT_ARRAY => self.preinit_array = dyn.d_val.wrapping_add(bias as _) as _,
+                    DT_PREINIT_ARRAYSZ => self.preinit_arraysz = dyn.d_val as _,
+                    DT_TLSDESC_PLT => self.tlsdesc_plt = dyn.d_val.wrapping_add(bias as _) as _,
+                    DT_TLSDESC_GOT => self.tlsdesc_got = dyn.d_val.wrapping_add(bias as _) as _,
                     DT_NEEDED => self.needed_count += 1,
                     DT_FLAGS => self.flags = dyn.d_val as _,
                     DT_FLAGS_1 => self.flags_1 = dyn.d_val as _,
                     DT_SONAME => self.soname = dyn.d_val as _,
+                    DT_RPATH => self.rpath = dyn.d_val as _,
+                    DT_RUNPATH => self.runpath = dyn.d_val as _,
                     DT_TEXTREL => self.textrel = true,
                     _ => (),
                 }
@@ -657,3 +925,44 @@ pub mod dyn64 {
 
     elf_dyn_std_impl!(u64, ::elf64::program_header::ProgramHeader);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_names() {
+        let flags = DynFlags(DF_BIND_NOW | DF_STATIC_TLS);
+        assert!(flags.bind_now());
+        assert!(flags.contains(DF_BIND_NOW | DF_STATIC_TLS));
+        assert!(!flags.contains(DF_BIND_NOW | DF_TEXTREL));
+        assert_eq!(flags.to_string(), "DF_BIND_NOW DF_STATIC_TLS");
+        let flags_1 = DynFlags1(DF_1_NOW | DF_1_PIE | 0x8000_0000);
+        assert!(flags_1.now() && flags_1.pie());
+        assert_eq!(flags_1.to_string(), "DF_1_NOW DF_1_PIE 0x80000000");
+        assert_eq!(DynFlags1::default().to_string(), "");
+        assert_eq!(df_1_to_str(DF_1_NODELETE), "DF_1_NODELETE");
+        assert_eq!(tag_to_str(DT_AUXILIARY), "DT_AUXILIARY");
+    }
+
+    #[cfg(feature = "endian_fd")]
+    #[test]
+    fn parse_with_bias_or_address_map() {
+        use container::{Container, Ctx};
+        use elf::address_map::AddressMap;
+        let hello: Vec<u8> = include!("../../etc/hello.rs");
+        let elf = ::elf::Elf::parse(&hello).unwrap();
+        let ctx = Ctx::new(Container::Big, ::scroll::LE);
+        // the first PT_LOAD of the fixture maps offset 0 at address 0, so the two agree on what it holds
+        let biased = Dynamic::parse(&hello, &elf.program_headers, 0, ctx).unwrap().unwrap();
+        let address_map = AddressMap::new(&elf.program_headers);
+        let mapped = Dynamic::parse_with_address_map(&hello, &elf.program_headers, &address_map, ctx).unwrap().unwrap();
+        assert_eq!(biased.dyns, mapped.dyns);
+        assert_eq!(biased.info.strtab, mapped.info.strtab);
+        assert_eq!(biased.info.gnu_hash, mapped.info.gnu_hash);
+        assert_eq!(mapped.info.needed_count, 1);
+        // the bias is added to every address
+        let biased = Dynamic::parse(&hello, &elf.program_headers, 0x10, ctx).unwrap().unwrap();
+        assert_eq!(biased.info.strtab, mapped.info.strtab + 0x10);
+    }
+}
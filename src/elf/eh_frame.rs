@@ -0,0 +1,530 @@
+//! The call frame information of `.eh_frame` and its lookup table `.eh_frame_hdr`, used to unwind stacks.
+//!
+//! `.eh_frame` is a sequence of Common Information Entries (CIEs) and Frame Description Entries
+//! (FDEs). An FDE describes the unwind rules for a range of program counters; the parts shared by
+//! several FDEs, e.g., the encoding of their pointers, are in the CIE it refers to. The
+//! `.eh_frame_hdr` section (`PT_GNU_EH_FRAME`) contains a table of the FDEs sorted by their
+//! initial PC, which is binary searched to find the FDE of a PC.
+//!
+//! The pointers in both sections are encoded as described by a `DW_EH_PE_*` byte: the low nibble is
+//! the format of the value, and the high nibble what it is relative to.
+//!
+//! See: https://refspecs.linuxfoundation.org/LSB_5.0.0/LSB-Core-generic/LSB-Core-generic/ehframechpt.html
+
+/// The value is an address sized word
+pub const DW_EH_PE_ABSPTR: u8 = 0x00;
+/// The value is an unsigned LEB128
+pub const DW_EH_PE_ULEB128: u8 = 0x01;
+/// The value is an unsigned 16-bit word
+pub const DW_EH_PE_UDATA2: u8 = 0x02;
+/// The value is an unsigned 32-bit word
+pub const DW_EH_PE_UDATA4: u8 = 0x03;
+/// The value is an unsigned 64-bit word
+pub const DW_EH_PE_UDATA8: u8 = 0x04;
+/// The value is a signed LEB128
+pub const DW_EH_PE_SLEB128: u8 = 0x09;
+/// The value is a signed 16-bit word
+pub const DW_EH_PE_SDATA2: u8 = 0x0a;
+/// The value is a signed 32-bit word
+pub const DW_EH_PE_SDATA4: u8 = 0x0b;
+/// The value is a signed 64-bit word
+pub const DW_EH_PE_SDATA8: u8 = 0x0c;
+/// The value is relative to the address of the value itself
+pub const DW_EH_PE_PCREL: u8 = 0x10;
+/// The value is relative to the start of the text section
+pub const DW_EH_PE_TEXTREL: u8 = 0x20;
+/// The value is relative to the data base: the start of `.eh_frame_hdr` in it, the GOT in `.eh_frame`
+pub const DW_EH_PE_DATAREL: u8 = 0x30;
+/// The value is relative to the start of the function
+pub const DW_EH_PE_FUNCREL: u8 = 0x40;
+/// The value is an address sized word, aligned to the address size
+pub const DW_EH_PE_ALIGNED: u8 = 0x50;
+/// The value is the address of the actual value
+pub const DW_EH_PE_INDIRECT: u8 = 0x80;
+/// There is no value
+pub const DW_EH_PE_OMIT: u8 = 0xff;
+
+if_alloc! {
+    use scroll::{Pread, Uleb128, Sleb128};
+    use error;
+    use container::Ctx;
+
+    /// The base addresses of the relative pointer encodings
+    #[derive(Debug, Copy, Clone, PartialEq, Default)]
+    pub struct Bases {
+        /// The address of the first byte of the section being read, for `DW_EH_PE_PCREL`
+        pub section: u64,
+        /// The address of the text section, for `DW_EH_PE_TEXTREL`
+        pub text: u64,
+        /// The data base address, for `DW_EH_PE_DATAREL`
+        pub data: u64,
+    }
+
+    /// Reads a pointer encoded with `encoding` at `offset` of `bytes`, which is loaded at `bases.section`.
+    ///
+    /// `func` is the base address of `DW_EH_PE_FUNCREL`. A `DW_EH_PE_INDIRECT` pointer isn't
+    /// dereferenced: the address of the actual value is returned.
+    pub fn read_pointer(bytes: &[u8], offset: &mut usize, encoding: u8, bases: &Bases, func: u64, ctx: Ctx) -> error::Result<u64> {
+        if encoding == DW_EH_PE_OMIT {
+            return Err(error::Error::Malformed("Cannot read an omitted pointer".into()));
+        }
+        let le = ctx.le;
+        let address = bases.section.wrapping_add(*offset as u64);
+        let mut application = encoding & 0x70;
+        if application == DW_EH_PE_ALIGNED {
+            // the pointer is aligned in memory, so the padding depends on the address, not the offset
+            let size = ctx.size() as u64;
+            let padding = size.wrapping_sub(address & (size - 1)) & (size - 1);
+            *offset = offset.checked_add(padding as usize)
+                .ok_or_else(|| error::Error::Malformed(format!("Aligned pointer at {:#x} overflows", address)))?;
+            application = 0;
+        }
+        let value = match encoding & 0x0f {
+            DW_EH_PE_ABSPTR => if ctx.is_big() { bytes.gread_with::<u64>(offset, le)? } else { bytes.gread_with::<u32>(offset, le)? as u64 },
+            DW_EH_PE_ULEB128 => Uleb128::read(bytes, offset)?,
+            DW_EH_PE_UDATA2 => bytes.gread_with::<u16>(offset, le)? as u64,
+            DW_EH_PE_UDATA4 => bytes.gread_with::<u32>(offset, le)? as u64,
+            DW_EH_PE_UDATA8 => bytes.gread_with::<u64>(offset, le)?,
+            DW_EH_PE_SLEB128 => Sleb128::read(bytes, offset)? as u64,
+            DW_EH_PE_SDATA2 => bytes.gread_with::<i16>(offset, le)? as i64 as u64,
+            DW_EH_PE_SDATA4 => bytes.gread_with::<i32>(offset, le)? as i64 as u64,
+            DW_EH_PE_SDATA8 => bytes.gread_with::<i64>(offset, le)? as u64,
+            format => return Err(error::Error::Malformed(format!("Unknown pointer format {:#x} in encoding {:#x}", format, encoding))),
+        };
+        let base = match application {
+            0 => 0,
+            DW_EH_PE_PCREL => address,
+            DW_EH_PE_TEXTREL => bases.text,
+            DW_EH_PE_DATAREL => bases.data,
+            DW_EH_PE_FUNCREL => func,
+            _ => return Err(error::Error::Malformed(format!("Unknown pointer application in encoding {:#x}", encoding))),
+        };
+        let value = base.wrapping_add(value);
+        Ok(if ctx.is_big() { value } else { value & 0xffff_ffff })
+    }
+
+    /// The size of a pointer encoded with `encoding`, or `None` if it's variable
+    fn pointer_size(encoding: u8, ctx: Ctx) -> Option<usize> {
+        match encoding & 0x0f {
+            DW_EH_PE_ABSPTR => Some(ctx.size()),
+            DW_EH_PE_UDATA2 | DW_EH_PE_SDATA2 => Some(2),
+            DW_EH_PE_UDATA4 | DW_EH_PE_SDATA4 => Some(4),
+            DW_EH_PE_UDATA8 | DW_EH_PE_SDATA8 => Some(8),
+            _ => None,
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    /// A Common Information Entry
+    pub struct Cie<'a> {
+        /// The offset of the entry in `.eh_frame`
+        pub offset: usize,
+        pub version: u8,
+        /// The augmentation string, e.g., `zR` or `zPLR`, describing the augmentation data
+        pub augmentation: &'a str,
+        pub code_alignment_factor: u64,
+        pub data_alignment_factor: i64,
+        /// The DWARF number of the register holding the return address
+        pub return_address_register: u64,
+        /// The augmentation data, when the augmentation string starts with `z`
+        pub augmentation_data: &'a [u8],
+        /// The encoding of the PC range of the FDEs (`R`)
+        pub fde_encoding: u8,
+        /// The encoding of the LSDA pointer of the FDEs (`L`), `DW_EH_PE_OMIT` if they have none
+        pub lsda_encoding: u8,
+        /// The encoding of the personality routine pointer (`P`), `DW_EH_PE_OMIT` if there is none
+        pub personality_encoding: u8,
+        /// The personality routine, or the address of the pointer to it with `DW_EH_PE_INDIRECT`
+        pub personality: Option<u64>,
+        /// Whether the FDEs describe signal handler frames (`S`)
+        pub is_signal_frame: bool,
+        /// The call frame instructions defining the initial rules of the FDEs
+        pub initial_instructions: &'a [u8],
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    /// A Frame Description Entry
+    pub struct Fde<'a> {
+        /// The offset of the entry in `.eh_frame`
+        pub offset: usize,
+        /// The offset of the entry's CIE in `.eh_frame`
+        pub cie_offset: usize,
+        /// The address of the first instruction described
+        pub pc_begin: u64,
+        /// The number of bytes of instructions described
+        pub pc_range: u64,
+        pub augmentation_data: &'a [u8],
+        /// The language specific data area, e.g., the C++ exception tables
+        pub lsda: Option<u64>,
+        /// The call frame instructions
+        pub instructions: &'a [u8],
+    }
+
+    impl<'a> Fde<'a> {
+        /// The address after the last instruction described
+        pub fn pc_end(&self) -> u64 {
+            self.pc_begin.wrapping_add(self.pc_range)
+        }
+        /// Whether `pc` is described by this FDE
+        pub fn contains(&self, pc: u64) -> bool {
+            pc >= self.pc_begin && pc - self.pc_begin < self.pc_range
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    /// An entry of `.eh_frame`
+    pub enum Entry<'a> {
+        Cie(Cie<'a>),
+        Fde(Fde<'a>),
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    /// The `.eh_frame` section
+    pub struct EhFrame<'a> {
+        bytes: &'a [u8],
+        bases: Bases,
+        ctx: Ctx,
+    }
+
+    /// The bounds of an entry: the offsets of its CIE id field and of its end
+    struct Record {
+        id_offset: usize,
+        end: usize,
+    }
+
+    impl<'a> EhFrame<'a> {
+        /// Creates a reader of the `.eh_frame` contents `bytes`, whose address is `bases.section`
+        pub fn new(bytes: &'a [u8], bases: Bases, ctx: Ctx) -> Self {
+            EhFrame { bytes, bases, ctx }
+        }
+        /// The address of the section
+        pub fn address(&self) -> u64 {
+            self.bases.section
+        }
+        /// Reads the length of the entry at `offset`; `None` for the terminator or the end of the section
+        fn record(&self, offset: usize) -> error::Result<Option<Record>> {
+            if offset >= self.bytes.len() {
+                return Ok(None);
+            }
+            let mut id_offset = offset;
+            let length = match self.bytes.gread_with::<u32>(&mut id_offset, self.ctx.le)? {
+                0 => return Ok(None),
+                0xffff_ffff => self.bytes.gread_with::<u64>(&mut id_offset, self.ctx.le)? as usize,
+                length => length as usize,
+            };
+            let end = match id_offset.checked_add(length) {
+                Some(end) if end <= self.bytes.len() && length >= 4 => end,
+                _ => return Err(error::Error::Malformed(format!("Entry at {:#x} of .eh_frame has a bad length {:#x}", offset, length))),
+            };
+            Ok(Some(Record { id_offset, end }))
+        }
+        /// Parses the entry at `offset`, returning it with the offset of the next entry
+        fn parse_entry(&self, offset: usize) -> error::Result<Option<(Entry<'a>, usize)>> {
+            let record = match self.record(offset)? {
+                Some(record) => record,
+                None => return Ok(None),
+            };
+            let id = self.bytes.pread_with::<u32>(record.id_offset, self.ctx.le)? as usize;
+            let entry = if id == 0 {
+                Entry::Cie(self.parse_cie(offset, &record)?)
+            } else {
+                let cie_offset = record.id_offset.checked_sub(id)
+                    .ok_or_else(|| error::Error::Malformed(format!("FDE at {:#x} of .eh_frame has a bad CIE pointer {:#x}", offset, id)))?;
+                let cie = self.cie_at(cie_offset)?;
+                Entry::Fde(self.parse_fde(offset, &record, cie_offset, &cie)?)
+            };
+            Ok(Some((entry, record.end)))
+        }
+        fn parse_cie(&self, offset: usize, record: &Record) -> error::Result<Cie<'a>> {
+            let bytes = &self.bytes[..record.end];
+            let cursor = &mut (record.id_offset + 4);
+            let version: u8 = bytes.gread(cursor)?;
+            if version != 1 && version != 3 && version != 4 {
+                return Err(error::Error::Malformed(format!("CIE at {:#x} of .eh_frame has an unsupported version {}", offset, version)));
+            }
+            let augmentation: &str = bytes.gread(cursor)?;
+            if augmentation.contains("eh") {
+                // the old GCC exception table pointer
+                *cursor += self.ctx.size();
+            }
+            if version == 4 {
+                // the address and segment selector sizes
+                *cursor += 2;
+            }
+            let code_alignment_factor = Uleb128::read(bytes, cursor)?;
+            let data_alignment_factor = Sleb128::read(bytes, cursor)?;
+            let return_address_register = if version == 1 { bytes.gread::<u8>(cursor)? as u64 } else { Uleb128::read(bytes, cursor)? };
+            let mut cie = Cie {
+                offset,
+                version,
+                augmentation,
+                code_alignment_factor,
+                data_alignment_factor,
+                return_address_register,
+                augmentation_data: &[],
+                fde_encoding: DW_EH_PE_ABSPTR,
+                lsda_encoding: DW_EH_PE_OMIT,
+                personality_encoding: DW_EH_PE_OMIT,
+                personality: None,
+                is_signal_frame: false,
+                initial_instructions: &[],
+            };
+            if augmentation.starts_with('z') {
+                let size = Uleb128::read(bytes, cursor)? as usize;
+                let mut start = *cursor;
+                cie.augmentation_data = bytes.gread_with(cursor, size)?;
+                let data_offset = &mut start;
+                for c in augmentation.chars().skip(1) {
+                    match c {
+                        'L' => cie.lsda_encoding = bytes.gread(data_offset)?,
+                        'R' => cie.fde_encoding = bytes.gread(data_offset)?,
+                        'P' => {
+                            cie.personality_encoding = bytes.gread(data_offset)?;
+                            cie.personality = Some(read_pointer(bytes, data_offset, cie.personality_encoding, &self.bases, 0, self.ctx)?);
+                        },
+                        'S' => cie.is_signal_frame = true,
+                        // AArch64 branch target identification and memory tagging
+                        'B' | 'G' => (),
+                        _ => break,
+                    }
+                }
+            }
+            cie.initial_instructions = &bytes[*cursor..];
+            Ok(cie)
+        }
+        fn parse_fde(&self, offset: usize, record: &Record, cie_offset: usize, cie: &Cie<'a>) -> error::Result<Fde<'a>> {
+            let bytes = &self.bytes[..record.end];
+            let cursor = &mut (record.id_offset + 4);
+            let pc_begin = read_pointer(bytes, cursor, cie.fde_encoding, &self.bases, 0, self.ctx)?;
+            // the range is an unsigned size, not an address
+            let pc_range = read_pointer(bytes, cursor, cie.fde_encoding & 0x0f, &self.bases, 0, self.ctx)?;
+            let mut fde = Fde { offset, cie_offset, pc_begin, pc_range, augmentation_data: &[], lsda: None, instructions: &[] };
+            if cie.augmentation.starts_with('z') {
+                let size = Uleb128::read(bytes, cursor)? as usize;
+                let mut data_offset = *cursor;
+                fde.augmentation_data = bytes.gread_with(cursor, size)?;
+                if cie.lsda_encoding != DW_EH_PE_OMIT && size > 0 {
+                    fde.lsda = Some(read_pointer(bytes, &mut data_offset, cie.lsda_encoding, &self.bases, pc_begin, self.ctx)?);
+                }
+            }
+            fde.instructions = &bytes[*cursor..];
+            Ok(fde)
+        }
+        /// Parses the CIE at `offset`
+        pub fn cie_at(&self, offset: usize) -> error::Result<Cie<'a>> {
+            match self.record(offset)? {
+                Some(ref record) if self.bytes.pread_with::<u32>(record.id_offset, self.ctx.le)? == 0 => self.parse_cie(offset, record),
+                _ => Err(error::Error::Malformed(format!("No CIE at {:#x} of .eh_frame", offset))),
+            }
+        }
+        /// Parses the FDE at `offset`
+        pub fn fde_at(&self, offset: usize) -> error::Result<Fde<'a>> {
+            match self.parse_entry(offset)? {
+                Some((Entry::Fde(fde), _)) => Ok(fde),
+                _ => Err(error::Error::Malformed(format!("No FDE at {:#x} of .eh_frame", offset))),
+            }
+        }
+        /// Iterates over the CIEs and FDEs, up to the terminator or the end of the section
+        pub fn entries(&self) -> EntryIterator<'a> {
+            EntryIterator { eh_frame: *self, offset: 0 }
+        }
+        /// Finds the FDE describing `pc` with a linear search; prefer `EhFrameHdr::find_fde`
+        pub fn find_fde(&self, pc: u64) -> error::Result<Option<Fde<'a>>> {
+            for entry in self.entries() {
+                if let Entry::Fde(fde) = entry? {
+                    if fde.contains(pc) {
+                        return Ok(Some(fde));
+                    }
+                }
+            }
+            Ok(None)
+        }
+    }
+
+    /// An iterator over the entries of `.eh_frame`
+    pub struct EntryIterator<'a> {
+        eh_frame: EhFrame<'a>,
+        offset: usize,
+    }
+
+    impl<'a> Iterator for EntryIterator<'a> {
+        type Item = error::Result<Entry<'a>>;
+        fn next(&mut self) -> Option<Self::Item> {
+            match self.eh_frame.parse_entry(self.offset) {
+                Ok(Some((entry, next))) => {
+                    self.offset = next;
+                    Some(Ok(entry))
+                },
+                Ok(None) => None,
+                Err(err) => {
+                    self.offset = self.eh_frame.bytes.len();
+                    Some(Err(err))
+                },
+            }
+        }
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    /// The `.eh_frame_hdr` section, with its table of FDEs sorted by initial PC
+    pub struct EhFrameHdr<'a> {
+        pub version: u8,
+        /// The address of `.eh_frame`
+        pub eh_frame_ptr: u64,
+        /// The number of entries in the table
+        pub fde_count: usize,
+        /// The encoding of the table entries
+        pub table_encoding: u8,
+        table: &'a [u8],
+        /// The offset of the table in the section
+        table_offset: usize,
+        address: u64,
+        ctx: Ctx,
+    }
+
+    impl<'a> EhFrameHdr<'a> {
+        /// Parses the `.eh_frame_hdr` contents `bytes`, which are loaded at `address`
+        pub fn parse(bytes: &'a [u8], address: u64, ctx: Ctx) -> error::Result<Self> {
+            let offset = &mut 0;
+            let version: u8 = bytes.gread(offset)?;
+            if version != 1 {
+                return Err(error::Error::Malformed(format!("Unsupported .eh_frame_hdr version {}", version)));
+            }
+            let eh_frame_ptr_encoding: u8 = bytes.gread(offset)?;
+            let fde_count_encoding: u8 = bytes.gread(offset)?;
+            let table_encoding: u8 = bytes.gread(offset)?;
+            let bases = Bases { section: address, text: 0, data: address };
+            let eh_frame_ptr = read_pointer(bytes, offset, eh_frame_ptr_encoding, &bases, 0, ctx)?;
+            let mut fde_count = 0;
+            let mut table: &[u8] = &[];
+            if fde_count_encoding != DW_EH_PE_OMIT && table_encoding != DW_EH_PE_OMIT {
+                fde_count = read_pointer(bytes, offset, fde_count_encoding, &bases, 0, ctx)? as usize;
+                let entry_size = pointer_size(table_encoding, ctx)
+                    .ok_or_else(|| error::Error::Malformed(format!("Unsupported .eh_frame_hdr table encoding {:#x}", table_encoding)))? * 2;
+                let size = fde_count.checked_mul(entry_size)
+                    .ok_or_else(|| error::Error::Malformed(format!("Too many .eh_frame_hdr table entries: {}", fde_count)))?;
+                table = offset.checked_add(size).and_then(|end| bytes.get(*offset..end))
+                    .ok_or_else(|| error::Error::Malformed(format!("The .eh_frame_hdr table of {} entries is truncated", fde_count)))?;
+            }
+            Ok(EhFrameHdr { version, eh_frame_ptr, fde_count, table_encoding, table, table_offset: *offset, address, ctx })
+        }
+        /// Returns the initial PC and the address of the FDE of the table entry at `index`
+        pub fn entry(&self, index: usize) -> error::Result<(u64, u64)> {
+            if index >= self.fde_count {
+                return Err(error::Error::Malformed(format!("No .eh_frame_hdr table entry {}", index)));
+            }
+            let size = pointer_size(self.table_encoding, self.ctx).unwrap();
+            let offset = &mut (index * 2 * size);
+            let bases = Bases { section: self.address.wrapping_add(self.table_offset as u64), text: 0, data: self.address };
+            let pc = read_pointer(self.table, offset, self.table_encoding, &bases, 0, self.ctx)?;
+            let fde = read_pointer(self.table, offset, self.table_encoding, &bases, 0, self.ctx)?;
+            Ok((pc, fde))
+        }
+        /// Returns the address of the FDE whose initial PC is the closest at or below `pc`
+        pub fn lookup(&self, pc: u64) -> error::Result<Option<u64>> {
+            let (mut low, mut high) = (0, self.fde_count);
+            while low < high {
+                let middle = low + (high - low) / 2;
+                if self.entry(middle)?.0 <= pc {
+                    low = middle + 1;
+                } else {
+                    high = middle;
+                }
+            }
+            if low == 0 {
+                Ok(None)
+            } else {
+                Ok(Some(self.entry(low - 1)?.1))
+            }
+        }
+        /// Finds the FDE of `eh_frame` describing `pc` with a binary search of the table
+        pub fn find_fde(&self, eh_frame: &EhFrame<'a>, pc: u64) -> error::Result<Option<Fde<'a>>> {
+            let address = match self.lookup(pc)? {
+                Some(address) => address,
+                None => return Ok(None),
+            };
+            let offset = address.checked_sub(eh_frame.address()).map(|offset| offset as usize)
+                .ok_or_else(|| error::Error::Malformed(format!("FDE address {:#x} is outside of .eh_frame", address)))?;
+            let fde = eh_frame.fde_at(offset)?;
+            Ok(if fde.contains(pc) { Some(fde) } else { None })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use scroll::{self, Pwrite};
+        use container::Container;
+
+        #[test]
+        fn parse_and_search() {
+            let ctx = Ctx::new(Container::Big, scroll::LE);
+            let mut eh_frame = [0u8; 52];
+            // a CIE for PC relative FDE pointers, with the x86_64 initial rules: the CFA is rsp + 8, and
+            // the return address is at CFA - 8
+            eh_frame.pwrite_with(20u32, 0, scroll::LE).unwrap();
+            eh_frame[8] = 1;
+            eh_frame[9..12].copy_from_slice(b"zR\0");
+            eh_frame[12..24].copy_from_slice(&[1, 0x78, 16, 1, DW_EH_PE_PCREL | DW_EH_PE_SDATA4, 0x0c, 0x07, 0x08, 0x90, 0x01, 0, 0]);
+            // an FDE for 0x1000..0x1020
+            eh_frame.pwrite_with(20u32, 24, scroll::LE).unwrap();
+            eh_frame.pwrite_with(28u32, 28, scroll::LE).unwrap();
+            eh_frame.pwrite_with(0x1000i32 - 0x2020, 32, scroll::LE).unwrap();
+            eh_frame.pwrite_with(0x20u32, 36, scroll::LE).unwrap();
+            let eh_frame = EhFrame::new(&eh_frame, Bases { section: 0x2000, text: 0, data: 0 }, ctx);
+
+            let entries: Vec<Entry> = eh_frame.entries().map(|entry| entry.unwrap()).collect();
+            assert_eq!(entries.len(), 2);
+            match entries[0] {
+                Entry::Cie(ref cie) => {
+                    assert_eq!(cie.augmentation, "zR");
+                    assert_eq!((cie.code_alignment_factor, cie.data_alignment_factor, cie.return_address_register), (1, -8, 16));
+                    assert_eq!(cie.fde_encoding, DW_EH_PE_PCREL | DW_EH_PE_SDATA4);
+                    assert_eq!(cie.initial_instructions, &[0x0c, 0x07, 0x08, 0x90, 0x01, 0, 0]);
+                },
+                _ => panic!("expected a CIE"),
+            }
+            match entries[1] {
+                Entry::Fde(ref fde) => {
+                    assert_eq!((fde.offset, fde.cie_offset, fde.pc_begin, fde.pc_end()), (24, 0, 0x1000, 0x1020));
+                    assert_eq!(fde.lsda, None);
+                },
+                _ => panic!("expected an FDE"),
+            }
+            assert_eq!(eh_frame.find_fde(0x101f).unwrap().map(|fde| fde.offset), Some(24));
+
+            let mut hdr = [0u8; 20];
+            hdr[0..4].copy_from_slice(&[1, DW_EH_PE_PCREL | DW_EH_PE_SDATA4, DW_EH_PE_UDATA4, DW_EH_PE_DATAREL | DW_EH_PE_SDATA4]);
+            hdr.pwrite_with(0x2000i32 - 0x1f04, 4, scroll::LE).unwrap();
+            hdr.pwrite_with(1u32, 8, scroll::LE).unwrap();
+            hdr.pwrite_with(0x1000i32 - 0x1f00, 12, scroll::LE).unwrap();
+            hdr.pwrite_with(0x2018i32 - 0x1f00, 16, scroll::LE).unwrap();
+            let hdr = EhFrameHdr::parse(&hdr, 0x1f00, ctx).unwrap();
+            assert_eq!((hdr.eh_frame_ptr, hdr.fde_count), (0x2000, 1));
+            assert_eq!(hdr.entry(0).unwrap(), (0x1000, 0x2018));
+            assert_eq!(hdr.find_fde(&eh_frame, 0x1010).unwrap().map(|fde| fde.pc_begin), Some(0x1000));
+            assert!(hdr.find_fde(&eh_frame, 0x1020).unwrap().is_none());
+            assert!(hdr.find_fde(&eh_frame, 0xfff).unwrap().is_none());
+        }
+
+        #[test]
+        fn read_aligned_pointer() {
+            let ctx = Ctx::new(Container::Big, scroll::LE);
+            let mut bytes = [0u8; 24];
+            bytes.pwrite_with(0x1122_3344_5566_7788u64, 4, scroll::LE).unwrap();
+            bytes.pwrite_with(0x99aa_bbcc_ddee_ff00u64, 12, scroll::LE).unwrap();
+            // the section is loaded at an address 4 past an 8 byte boundary, so the pointer at
+            // offset 1 is padded up to offset 4, not 8
+            let bases = Bases { section: 0x2004, text: 0, data: 0 };
+            let offset = &mut 1;
+            assert_eq!(read_pointer(&bytes, offset, DW_EH_PE_ALIGNED, &bases, 0, ctx).unwrap(), 0x1122_3344_5566_7788);
+            assert_eq!(*offset, 12);
+            // already aligned
+            assert_eq!(read_pointer(&bytes, offset, DW_EH_PE_ALIGNED, &bases, 0, ctx).unwrap(), 0x99aa_bbcc_ddee_ff00);
+            assert_eq!(*offset, 20);
+            assert!(read_pointer(&bytes, offset, DW_EH_PE_ALIGNED, &bases, 0, ctx).is_err());
+            // a table too large for the address space
+            let hdr = [1, DW_EH_PE_UDATA4, DW_EH_PE_UDATA8, DW_EH_PE_UDATA2, 0, 0x20, 0, 0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x3f];
+            assert!(EhFrameHdr::parse(&hdr, 0x1000, ctx).is_err());
+        }
+    }
+}
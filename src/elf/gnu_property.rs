@@ -0,0 +1,272 @@
+//! GNU program properties, stored as `NT_GNU_PROPERTY_TYPE_0` notes in `.note.gnu.property`.
+//!
+//! The descriptor of the note is an array of properties, each consisting of a `u32` type, a
+//! `u32` data size and the data, padded to 8 bytes in 64-bit objects and 4 bytes in 32-bit ones.
+//! Executables and shared objects mark the note with a `PT_GNU_PROPERTY` program header.
+//!
+//! Properties in the processor specific range carry the hardening features the object was
+//! built with, e.g., Intel CET (IBT and SHSTK) or AArch64 BTI and PAC.
+
+use elf::header::{EM_386, EM_AARCH64, EM_X86_64};
+
+/// Stack size
+pub const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+/// No copy relocation on protected data symbol
+pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+/// Start of the processor specific properties
+pub const GNU_PROPERTY_LOPROC: u32 = 0xc000_0000;
+/// End of the processor specific properties
+pub const GNU_PROPERTY_HIPROC: u32 = 0xdfff_ffff;
+/// Start of the application specific properties
+pub const GNU_PROPERTY_LOUSER: u32 = 0xe000_0000;
+/// End of the application specific properties
+pub const GNU_PROPERTY_HIUSER: u32 = 0xffff_ffff;
+
+/// The x86 features the object is compatible with; set only if all input objects are
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc000_0002;
+/// The x86 features used by the object
+pub const GNU_PROPERTY_X86_FEATURE_2_USED: u32 = 0xc001_0001;
+/// The x86 ISA level needed by the object
+pub const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 0xc000_8002;
+/// The x86 ISA level used by the object
+pub const GNU_PROPERTY_X86_ISA_1_USED: u32 = 0xc001_0002;
+/// The AArch64 features the object is compatible with; set only if all input objects are
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc000_0000;
+
+/// Compatible with Indirect Branch Tracking (`endbr` instructions)
+pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 1 << 0;
+/// Compatible with the Shadow Stack
+pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 1 << 1;
+/// Compatible with Linear Address Masking of 48-bit user addresses
+pub const GNU_PROPERTY_X86_FEATURE_1_LAM_U48: u32 = 1 << 2;
+/// Compatible with Linear Address Masking of 57-bit user addresses
+pub const GNU_PROPERTY_X86_FEATURE_1_LAM_U57: u32 = 1 << 3;
+
+/// Compatible with Branch Target Identification (`bti` instructions)
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 1 << 0;
+/// Compatible with Pointer Authentication of return addresses
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 1 << 1;
+/// Compatible with the Guarded Control Stack
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_GCS: u32 = 1 << 2;
+
+/// Returns the name of the property type `pr_type` of an object for `machine`
+pub fn pr_type_to_str(pr_type: u32, machine: u16) -> &'static str {
+    match pr_type {
+        GNU_PROPERTY_STACK_SIZE => "GNU_PROPERTY_STACK_SIZE",
+        GNU_PROPERTY_NO_COPY_ON_PROTECTED => "GNU_PROPERTY_NO_COPY_ON_PROTECTED",
+        _ => match machine {
+            EM_X86_64 | EM_386 => match pr_type {
+                GNU_PROPERTY_X86_FEATURE_1_AND => "GNU_PROPERTY_X86_FEATURE_1_AND",
+                GNU_PROPERTY_X86_FEATURE_2_USED => "GNU_PROPERTY_X86_FEATURE_2_USED",
+                GNU_PROPERTY_X86_ISA_1_NEEDED => "GNU_PROPERTY_X86_ISA_1_NEEDED",
+                GNU_PROPERTY_X86_ISA_1_USED => "GNU_PROPERTY_X86_ISA_1_USED",
+                _ => "GNU_PROPERTY_UNKNOWN",
+            },
+            EM_AARCH64 => match pr_type {
+                GNU_PROPERTY_AARCH64_FEATURE_1_AND => "GNU_PROPERTY_AARCH64_FEATURE_1_AND",
+                _ => "GNU_PROPERTY_UNKNOWN",
+            },
+            _ => "GNU_PROPERTY_UNKNOWN",
+        },
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// The `GNU_PROPERTY_X86_FEATURE_1_AND` feature bits
+pub struct X86Features(pub u32);
+
+impl X86Features {
+    /// Whether the object is compatible with Indirect Branch Tracking
+    pub fn ibt(&self) -> bool {
+        self.0 & GNU_PROPERTY_X86_FEATURE_1_IBT != 0
+    }
+    /// Whether the object is compatible with the Shadow Stack
+    pub fn shstk(&self) -> bool {
+        self.0 & GNU_PROPERTY_X86_FEATURE_1_SHSTK != 0
+    }
+    /// Whether the object is fully CET enabled, i.e., compatible with both IBT and SHSTK
+    pub fn cet(&self) -> bool {
+        self.ibt() && self.shstk()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// The `GNU_PROPERTY_AARCH64_FEATURE_1_AND` feature bits
+pub struct AArch64Features(pub u32);
+
+impl AArch64Features {
+    /// Whether the object is compatible with Branch Target Identification
+    pub fn bti(&self) -> bool {
+        self.0 & GNU_PROPERTY_AARCH64_FEATURE_1_BTI != 0
+    }
+    /// Whether the object is compatible with Pointer Authentication
+    pub fn pac(&self) -> bool {
+        self.0 & GNU_PROPERTY_AARCH64_FEATURE_1_PAC != 0
+    }
+    /// Whether the object is compatible with the Guarded Control Stack
+    pub fn gcs(&self) -> bool {
+        self.0 & GNU_PROPERTY_AARCH64_FEATURE_1_GCS != 0
+    }
+}
+
+if_alloc! {
+    use error;
+    use scroll::Pread;
+    use container::Ctx;
+    use alloc::vec::Vec;
+    use elf::note::{self, Note};
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    /// A single GNU program property
+    pub enum Property<'a> {
+        /// `GNU_PROPERTY_STACK_SIZE`
+        StackSize(u64),
+        /// `GNU_PROPERTY_NO_COPY_ON_PROTECTED`
+        NoCopyOnProtected,
+        /// `GNU_PROPERTY_X86_FEATURE_1_AND`
+        X86Feature1(X86Features),
+        /// `GNU_PROPERTY_X86_ISA_1_NEEDED`
+        X86IsaNeeded(u32),
+        /// `GNU_PROPERTY_X86_ISA_1_USED`
+        X86IsaUsed(u32),
+        /// `GNU_PROPERTY_AARCH64_FEATURE_1_AND`
+        AArch64Feature1(AArch64Features),
+        /// Any other property, with its raw data
+        Other { pr_type: u32, data: &'a [u8] },
+    }
+
+    fn u32_data(pr_type: u32, data: &[u8], ctx: Ctx) -> error::Result<u32> {
+        if data.len() != 4 {
+            return Err(error::Error::Malformed(format!("GNU property {:#x} has data size {}, expected 4", pr_type, data.len())));
+        }
+        Ok(data.pread_with(0, ctx.le)?)
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    /// The properties of an `NT_GNU_PROPERTY_TYPE_0` note
+    pub struct GnuProperties<'a> {
+        pub properties: Vec<Property<'a>>,
+    }
+
+    impl<'a> GnuProperties<'a> {
+        /// Parses the property array in the descriptor of an `NT_GNU_PROPERTY_TYPE_0` note of an object for `machine`
+        pub fn parse(desc: &'a [u8], machine: u16, ctx: Ctx) -> error::Result<Self> {
+            let align = ctx.size();
+            let mut properties = Vec::new();
+            let mut offset = 0;
+            while offset < desc.len() {
+                let pr_type: u32 = desc.gread_with(&mut offset, ctx.le)?;
+                let pr_datasz: u32 = desc.gread_with(&mut offset, ctx.le)?;
+                let data: &'a [u8] = desc.gread_with(&mut offset, pr_datasz as usize)?;
+                offset = (offset + align - 1) & !(align - 1);
+                let is_x86 = machine == EM_X86_64 || machine == EM_386;
+                let property = match pr_type {
+                    GNU_PROPERTY_STACK_SIZE => Property::StackSize(match data.len() {
+                        4 => data.pread_with::<u32>(0, ctx.le)? as u64,
+                        _ => data.pread_with::<u64>(0, ctx.le)?,
+                    }),
+                    GNU_PROPERTY_NO_COPY_ON_PROTECTED => Property::NoCopyOnProtected,
+                    GNU_PROPERTY_X86_FEATURE_1_AND if is_x86 => Property::X86Feature1(X86Features(u32_data(pr_type, data, ctx)?)),
+                    GNU_PROPERTY_X86_ISA_1_NEEDED if is_x86 => Property::X86IsaNeeded(u32_data(pr_type, data, ctx)?),
+                    GNU_PROPERTY_X86_ISA_1_USED if is_x86 => Property::X86IsaUsed(u32_data(pr_type, data, ctx)?),
+                    GNU_PROPERTY_AARCH64_FEATURE_1_AND if machine == EM_AARCH64 => Property::AArch64Feature1(AArch64Features(u32_data(pr_type, data, ctx)?)),
+                    _ => Property::Other { pr_type, data },
+                };
+                properties.push(property);
+            }
+            Ok(GnuProperties { properties })
+        }
+        /// Parses the first `NT_GNU_PROPERTY_TYPE_0` note in `notes`, if there is one
+        pub fn from_notes<I>(notes: I, machine: u16, ctx: Ctx) -> error::Result<Option<Self>>
+            where I: Iterator<Item = error::Result<Note<'a>>> {
+            for note in notes {
+                let note = note?;
+                if note.name == note::ELF_NOTE_GNU && note.n_type == note::NT_GNU_PROPERTY_TYPE_0 {
+                    return Self::parse(note.desc, machine, ctx).map(Some);
+                }
+            }
+            Ok(None)
+        }
+        /// The x86 feature bits, if present
+        pub fn x86_features(&self) -> Option<X86Features> {
+            self.properties.iter().filter_map(|property| match *property {
+                Property::X86Feature1(features) => Some(features),
+                _ => None,
+            }).next()
+        }
+        /// The AArch64 feature bits, if present
+        pub fn aarch64_features(&self) -> Option<AArch64Features> {
+            self.properties.iter().filter_map(|property| match *property {
+                Property::AArch64Feature1(features) => Some(features),
+                _ => None,
+            }).next()
+        }
+        /// Whether the object is marked as compatible with both IBT and SHSTK
+        pub fn is_cet_enabled(&self) -> bool {
+            match self.x86_features() {
+                Some(features) => features.cet(),
+                None => false,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use scroll::{Pwrite, LE};
+        use container::{Container, Ctx};
+        use elf::note::NoteDataIterator;
+
+        const CTX64: Ctx = Ctx { container: Container::Big, le: LE };
+
+        fn property_note(feature_type: u32, features: u32) -> [u8; 48] {
+            let mut bytes = [0u8; 48];
+            let mut offset = 0;
+            // namesz, descsz, type, "GNU\0"
+            for &word in &[4u32, 32, note::NT_GNU_PROPERTY_TYPE_0, 0x0055_4e47] {
+                bytes.gwrite_with(word, &mut offset, LE).unwrap();
+            }
+            for &word in &[feature_type, 4, features, 0, GNU_PROPERTY_X86_ISA_1_USED, 4, 1, 0] {
+                bytes.gwrite_with(word, &mut offset, LE).unwrap();
+            }
+            bytes
+        }
+
+        fn notes(bytes: &[u8]) -> NoteDataIterator {
+            NoteDataIterator { data: bytes, size: bytes.len(), offset: 0, ctx: (8, CTX64) }
+        }
+
+        #[test]
+        fn x86_cet() {
+            let bytes = property_note(GNU_PROPERTY_X86_FEATURE_1_AND, GNU_PROPERTY_X86_FEATURE_1_IBT | GNU_PROPERTY_X86_FEATURE_1_SHSTK);
+            let properties = GnuProperties::from_notes(notes(&bytes), EM_X86_64, CTX64).unwrap().unwrap();
+            assert_eq!(properties.properties.len(), 2);
+            assert_eq!(properties.properties[1], Property::X86IsaUsed(1));
+            assert!(properties.is_cet_enabled());
+            assert!(properties.aarch64_features().is_none());
+
+            let bytes = property_note(GNU_PROPERTY_X86_FEATURE_1_AND, GNU_PROPERTY_X86_FEATURE_1_IBT);
+            let properties = GnuProperties::from_notes(notes(&bytes), EM_X86_64, CTX64).unwrap().unwrap();
+            assert!(properties.x86_features().unwrap().ibt());
+            assert!(!properties.is_cet_enabled());
+        }
+
+        #[test]
+        fn aarch64_bti_pac() {
+            let bytes = property_note(GNU_PROPERTY_AARCH64_FEATURE_1_AND, GNU_PROPERTY_AARCH64_FEATURE_1_BTI | GNU_PROPERTY_AARCH64_FEATURE_1_PAC);
+            let properties = GnuProperties::from_notes(notes(&bytes), EM_AARCH64, CTX64).unwrap().unwrap();
+            let features = properties.aarch64_features().unwrap();
+            assert!(features.bti() && features.pac() && !features.gcs());
+            // processor specific properties are interpreted according to the machine
+            let properties = GnuProperties::from_notes(notes(&bytes), EM_X86_64, CTX64).unwrap().unwrap();
+            assert!(properties.aarch64_features().is_none());
+            assert!(properties.x86_features().is_none());
+        }
+
+        #[test]
+        fn truncated_property() {
+            let bytes = property_note(GNU_PROPERTY_X86_FEATURE_1_AND, 0);
+            assert!(GnuProperties::parse(&bytes[16..26], EM_X86_64, CTX64).is_err());
+        }
+    }
+}
@@ -0,0 +1,343 @@
+//! Safe, byte-slice based readers for the dynamic symbol hash tables of a parsed binary.
+//!
+//! Two formats exist:
+//!
+//!   1. The SysV hash table (`DT_HASH`): `nbucket`, `nchain`, followed by `nbucket` buckets
+//!      and `nchain` chain entries, all `u32`s. `nchain` is the number of dynamic symbols.
+//!   2. The GNU hash table (`DT_GNU_HASH`): a four word header (`nbuckets`, `symoffset`,
+//!      `bloom_size`, `bloom_shift`), a bloom filter of native word sized entries, `nbuckets`
+//!      buckets, and a hash value for every symbol starting at `symoffset`.
+//!
+//! Unlike the process-memory based `GnuHash` in `goblin::elf64::gnu_hash`, these readers respect
+//! the binary's endianness and bounds check every access.
+//!
+//! See: https://blogs.oracle.com/ali/entry/gnu_hash_elf_sections
+
+/// GNU hash function: takes a symbol name and returns its `DT_GNU_HASH` hash value
+pub fn gnu_hash(symbol: &[u8]) -> u32 {
+    let mut hash: u32 = 5381;
+    for b in symbol {
+        hash = hash.wrapping_mul(33).wrapping_add(*b as u32);
+    }
+    hash
+}
+
+/// SysV hash function: takes a symbol name and returns its `DT_HASH` hash value
+pub fn sysv_hash(symbol: &[u8]) -> u32 {
+    let mut hash: u32 = 0;
+    for b in symbol {
+        hash = (hash << 4).wrapping_add(*b as u32);
+        let high = hash & 0xf000_0000;
+        if high != 0 {
+            hash ^= high >> 24;
+        }
+        hash &= !high;
+    }
+    hash
+}
+
+if_alloc! {
+    use error;
+    use scroll::Pread;
+    use container::{Container, Ctx};
+    use strtab::Strtab;
+    use elf::sym::{Sym, Symtab};
+
+    const SIZEOF_WORD: usize = 4;
+
+    #[derive(Debug, Clone, Copy)]
+    /// A GNU-style hash table (`DT_GNU_HASH`, `.gnu.hash`)
+    pub struct GnuHash<'a> {
+        /// Number of hash buckets
+        pub nbuckets: u32,
+        /// Index of the first symbol reachable through the hash table
+        pub symoffset: u32,
+        /// Number of words in the bloom filter
+        pub bloom_size: u32,
+        /// Shift applied to the hash for the second bloom filter bit
+        pub bloom_shift: u32,
+        bloom: &'a [u8],
+        buckets: &'a [u8],
+        chains: &'a [u8],
+        ctx: Ctx,
+    }
+
+    impl<'a> GnuHash<'a> {
+        /// Parses a GNU hash table from `bytes` at `offset`
+        pub fn parse(bytes: &'a [u8], offset: usize, ctx: Ctx) -> error::Result<Self> {
+            let mut offset = offset;
+            let nbuckets: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            let symoffset: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            let bloom_size: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            let bloom_shift: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            if nbuckets == 0 {
+                return Err(error::Error::Malformed("GNU hash table has no buckets".into()));
+            }
+            // the shift applies to the 32-bit hash
+            if bloom_shift >= 32 {
+                return Err(error::Error::Malformed(format!("GNU hash bloom shift ({}) is not less than 32", bloom_shift)));
+            }
+            let bloom_len = (bloom_size as usize).checked_mul(ctx.size())
+                .ok_or_else(|| error::Error::Malformed(format!("GNU hash bloom filter size ({}) overflows", bloom_size)))?;
+            let bloom: &'a [u8] = bytes.gread_with(&mut offset, bloom_len)?;
+            let buckets: &'a [u8] = bytes.gread_with(&mut offset, nbuckets as usize * SIZEOF_WORD)?;
+            // the chain array has no explicit length; it runs until the end of the last chain
+            let chains: &'a [u8] = bytes.pread_with(offset, bytes.len() - offset)?;
+            Ok(GnuHash { nbuckets, symoffset, bloom_size, bloom_shift, bloom, buckets, chains, ctx })
+        }
+
+        #[inline]
+        fn bucket(&self, index: u32) -> error::Result<u32> {
+            Ok(self.buckets.pread_with(index as usize * SIZEOF_WORD, self.ctx.le)?)
+        }
+
+        #[inline]
+        fn chain(&self, symbol_index: u32) -> error::Result<u32> {
+            let index = symbol_index.checked_sub(self.symoffset)
+                .ok_or_else(|| error::Error::Malformed(format!("GNU hash bucket points to symbol {} below symoffset {}", symbol_index, self.symoffset)))?;
+            Ok(self.chains.pread_with(index as usize * SIZEOF_WORD, self.ctx.le)?)
+        }
+
+        /// Whether the bloom filter rules out that a symbol with hash `hash` is present
+        fn filtered(&self, hash: u32) -> error::Result<bool> {
+            if self.bloom_size == 0 {
+                return Ok(true);
+            }
+            let (word, bits) = match self.ctx.container {
+                Container::Little => {
+                    let index = (hash / 32) % self.bloom_size;
+                    (self.bloom.pread_with::<u32>(index as usize * 4, self.ctx.le)? as u64, 32)
+                },
+                Container::Big => {
+                    let index = (hash / 64) % self.bloom_size;
+                    (self.bloom.pread_with::<u64>(index as usize * 8, self.ctx.le)?, 64)
+                },
+            };
+            let mask = (1u64 << (hash % bits)) | (1u64 << ((hash >> self.bloom_shift) % bits));
+            Ok(word & mask != mask)
+        }
+
+        /// The number of symbols in the dynamic symbol table, computed from the longest chain
+        pub fn dynsym_count(&self) -> error::Result<usize> {
+            let mut max = 0;
+            for i in 0..self.nbuckets {
+                let bucket = self.bucket(i)?;
+                if bucket > max {
+                    max = bucket;
+                }
+            }
+            if max < self.symoffset {
+                return Ok(self.symoffset as usize);
+            }
+            // walk the chain of the highest bucket until its terminator
+            loop {
+                let hash = self.chain(max)?;
+                max += 1;
+                if hash & 1 == 1 {
+                    return Ok(max as usize);
+                }
+            }
+        }
+
+        /// Returns the index of the symbol named `name` in `dynsyms`, if it is present
+        pub fn find(&self, name: &str, dynsyms: &Symtab, dynstrtab: &Strtab) -> error::Result<Option<usize>> {
+            let hash = gnu_hash(name.as_bytes());
+            if self.filtered(hash)? {
+                return Ok(None);
+            }
+            let mut index = self.bucket(hash % self.nbuckets)?;
+            if index == 0 || index < self.symoffset {
+                return Ok(None);
+            }
+            loop {
+                let chain_hash = self.chain(index)?;
+                if hash | 1 == chain_hash | 1 {
+                    if let Some(sym) = dynsyms.get(index as usize) {
+                        if let Some(Ok(sym_name)) = dynstrtab.get(sym.st_name) {
+                            if sym_name == name {
+                                return Ok(Some(index as usize));
+                            }
+                        }
+                    }
+                }
+                if chain_hash & 1 == 1 {
+                    return Ok(None);
+                }
+                index += 1;
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    /// A SysV-style hash table (`DT_HASH`, `.hash`)
+    pub struct SysvHash<'a> {
+        /// Number of hash buckets
+        pub nbucket: u32,
+        /// Number of chain entries, which is also the number of dynamic symbols
+        pub nchain: u32,
+        buckets: &'a [u8],
+        chains: &'a [u8],
+        ctx: Ctx,
+    }
+
+    impl<'a> SysvHash<'a> {
+        /// Parses a SysV hash table from `bytes` at `offset`
+        pub fn parse(bytes: &'a [u8], offset: usize, ctx: Ctx) -> error::Result<Self> {
+            let mut offset = offset;
+            let nbucket: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            let nchain: u32 = bytes.gread_with(&mut offset, ctx.le)?;
+            if nbucket == 0 {
+                return Err(error::Error::Malformed("SysV hash table has no buckets".into()));
+            }
+            let buckets: &'a [u8] = bytes.gread_with(&mut offset, nbucket as usize * SIZEOF_WORD)?;
+            let chains: &'a [u8] = bytes.gread_with(&mut offset, nchain as usize * SIZEOF_WORD)?;
+            Ok(SysvHash { nbucket, nchain, buckets, chains, ctx })
+        }
+
+        /// The number of symbols in the dynamic symbol table
+        pub fn dynsym_count(&self) -> usize {
+            self.nchain as usize
+        }
+
+        /// Returns the index of the symbol named `name` in `dynsyms`, if it is present
+        pub fn find(&self, name: &str, dynsyms: &Symtab, dynstrtab: &Strtab) -> error::Result<Option<usize>> {
+            let hash = sysv_hash(name.as_bytes());
+            let bucket = hash % self.nbucket;
+            let mut index: u32 = self.buckets.pread_with(bucket as usize * SIZEOF_WORD, self.ctx.le)?;
+            // bound the walk by the chain length, in case the chains form a cycle
+            for _ in 0..self.nchain {
+                if index == 0 {
+                    break;
+                }
+                if let Some(sym) = dynsyms.get(index as usize) {
+                    if let Some(Ok(sym_name)) = dynstrtab.get(sym.st_name) {
+                        if sym_name == name {
+                            return Ok(Some(index as usize));
+                        }
+                    }
+                }
+                index = self.chains.pread_with(index as usize * SIZEOF_WORD, self.ctx.le)?;
+            }
+            Ok(None)
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    /// The dynamic symbol hash table of a binary; GNU hash tables are preferred when both are present
+    pub enum HashTable<'a> {
+        Gnu(GnuHash<'a>),
+        Sysv(SysvHash<'a>),
+    }
+
+    impl<'a> HashTable<'a> {
+        /// The number of symbols in the dynamic symbol table
+        pub fn dynsym_count(&self) -> error::Result<usize> {
+            match *self {
+                HashTable::Gnu(ref gnu) => gnu.dynsym_count(),
+                HashTable::Sysv(ref sysv) => Ok(sysv.dynsym_count()),
+            }
+        }
+        /// Returns the index of the symbol named `name` in `dynsyms`, if it is present
+        pub fn find(&self, name: &str, dynsyms: &Symtab, dynstrtab: &Strtab) -> error::Result<Option<usize>> {
+            match *self {
+                HashTable::Gnu(ref gnu) => gnu.find(name, dynsyms, dynstrtab),
+                HashTable::Sysv(ref sysv) => sysv.find(name, dynsyms, dynstrtab),
+            }
+        }
+        /// Returns the symbol named `name`, if it is present
+        pub fn lookup(&self, name: &str, dynsyms: &Symtab, dynstrtab: &Strtab) -> Option<Sym> {
+            match self.find(name, dynsyms, dynstrtab) {
+                Ok(Some(index)) => dynsyms.get(index),
+                _ => None,
+            }
+        }
+    }
+} // end if_alloc
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_functions() {
+        assert_eq!(gnu_hash(b""), 0x0000_1505);
+        assert_eq!(gnu_hash(b"printf"), 0x156b_2bb8);
+        assert_eq!(gnu_hash(b"exit"), 0x7c96_7e3f);
+        assert_eq!(sysv_hash(b""), 0);
+        assert_eq!(sysv_hash(b"printf"), 0x0779_05a6);
+        assert_eq!(sysv_hash(b"exit"), 0x0006_cf04);
+    }
+
+    #[cfg(feature = "endian_fd")]
+    mod tables {
+        use super::super::*;
+        use scroll::{Pwrite, LE};
+        use container::{Container, Ctx};
+        use strtab::Strtab;
+        use elf::sym::{self, Symtab};
+
+        const STRTAB: &'static [u8] = b"\0foo\0bar\0";
+
+        fn dynsyms(bytes: &mut [u8]) -> Symtab {
+            let mut offset = 0;
+            for &name in &[0, 1, 5] {
+                let sym = sym::sym64::Sym { st_name: name, st_info: 0x12, st_shndx: 1, st_value: 0x1000 + name as u64, ..Default::default() };
+                bytes.gwrite_with(sym, &mut offset, LE).unwrap();
+            }
+            Symtab::parse(bytes, 0, 3, Ctx::new(Container::Big, LE)).unwrap()
+        }
+
+        #[test]
+        fn gnu_hash_lookup() {
+            let mut sym_bytes = [0u8; 3 * sym::sym64::SIZEOF_SYM];
+            let dynsyms = dynsyms(&mut sym_bytes);
+            let strtab = Strtab::new(STRTAB, 0x0);
+            let mut table = [0u8; 16 + 8 + 4 + 8];
+            let mut offset = 0;
+            for &word in &[1u32, 1, 1, 6] {
+                table.gwrite_with(word, &mut offset, LE).unwrap();
+            }
+            table.gwrite_with(!0u64, &mut offset, LE).unwrap();
+            table.gwrite_with(1u32, &mut offset, LE).unwrap();
+            table.gwrite_with(gnu_hash(b"foo") & !1, &mut offset, LE).unwrap();
+            table.gwrite_with(gnu_hash(b"bar") | 1, &mut offset, LE).unwrap();
+            let gnu = GnuHash::parse(&table, 0, Ctx::new(Container::Big, LE)).unwrap();
+            assert_eq!(gnu.dynsym_count().unwrap(), 3);
+            assert_eq!(gnu.find("foo", &dynsyms, &strtab).unwrap(), Some(1));
+            assert_eq!(gnu.find("bar", &dynsyms, &strtab).unwrap(), Some(2));
+            assert_eq!(gnu.find("baz", &dynsyms, &strtab).unwrap(), None);
+            let sym = HashTable::Gnu(gnu).lookup("bar", &dynsyms, &strtab).unwrap();
+            assert_eq!(sym.st_value, 0x1005);
+        }
+
+        #[test]
+        fn gnu_hash_bloom_shift() {
+            let mut table = [0u8; 16 + 8 + 4 + 4];
+            let mut offset = 0;
+            for &word in &[1u32, 1, 1, 64] {
+                table.gwrite_with(word, &mut offset, LE).unwrap();
+            }
+            assert!(GnuHash::parse(&table, 0, Ctx::new(Container::Big, LE)).is_err());
+            table.pwrite_with(31u32, 12, LE).unwrap();
+            assert!(GnuHash::parse(&table, 0, Ctx::new(Container::Big, LE)).is_ok());
+        }
+
+        #[test]
+        fn sysv_hash_lookup() {
+            let mut sym_bytes = [0u8; 3 * sym::sym64::SIZEOF_SYM];
+            let dynsyms = dynsyms(&mut sym_bytes);
+            let strtab = Strtab::new(STRTAB, 0x0);
+            let mut table = [0u8; 24];
+            let mut offset = 0;
+            // nbucket, nchain, bucket[0], chain[0..3]
+            for &word in &[1u32, 3, 2, 0, 0, 1] {
+                table.gwrite_with(word, &mut offset, LE).unwrap();
+            }
+            let sysv = SysvHash::parse(&table, 0, Ctx::new(Container::Big, LE)).unwrap();
+            assert_eq!(sysv.dynsym_count(), 3);
+            assert_eq!(sysv.find("foo", &dynsyms, &strtab).unwrap(), Some(1));
+            assert_eq!(sysv.find("bar", &dynsyms, &strtab).unwrap(), Some(2));
+            assert_eq!(sysv.find("baz", &dynsyms, &strtab).unwrap(), None);
+        }
+    }
+}
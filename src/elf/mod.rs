@@ -52,6 +52,14 @@ pub mod dyn;
 #[macro_use]
 pub mod reloc;
 pub mod note;
+pub mod symver;
+pub mod hash;
+pub mod coredump;
+pub mod gnu_property;
+pub mod address_map;
+pub mod section_group;
+pub mod eh_frame;
+pub mod tls;
 
 macro_rules! if_sylvan {
     ($($i:item)*) => ($(
@@ -60,12 +68,21 @@ macro_rules! if_sylvan {
     )*)
 }
 
+if_sylvan! {
+    pub mod writer;
+}
+
+pub mod debug_file;
+
 if_sylvan! {
     use scroll::{self, ctx, Pread, Endian};
     use strtab::Strtab;
     use error;
     use container::{Container, Ctx};
     use alloc::vec::Vec;
+    use core::cmp;
+    #[cfg(feature = "compression")]
+    use alloc::borrow::Cow;
 
     pub type Header = header::Header;
     pub type ProgramHeader = program_header::ProgramHeader;
@@ -99,6 +116,10 @@ if_sylvan! {
         /// This is what the dynamic linker uses to dynamically load and link your binary,
         /// or find imported symbols for binaries which dynamically link against your library
         pub dynsyms: Symtab<'a>,
+        /// The dynamic symbol hash table, if any; `DT_GNU_HASH` is preferred over `DT_HASH`
+        pub dynsym_hash: Option<hash::HashTable<'a>>,
+        /// The extended section indices of the symbols in `syms` (`SHT_SYMTAB_SHNDX`), if any
+        pub symtab_shndx: Vec<u32>,
         /// The debugging symbol table
         pub syms: Symtab<'a>,
         /// The string table for the symbol table
@@ -109,23 +130,38 @@ if_sylvan! {
         pub dynrelas: Vec<Reloc>,
         /// The dynamic relocation entries without an addend
         pub dynrels: Vec<Reloc>,
+        /// The relative relocations packed in `DT_RELR`, expanded to one entry per relocated word.
+        /// The addends are implicit, and the type is the machine's `R_*_RELATIVE`, or 0 if it's unknown
+        pub dynrelrs: Vec<Reloc>,
+        /// The dynamic relocation entries packed in Android's `DT_ANDROID_REL` or `DT_ANDROID_RELA` format;
+        /// the latter have an addend
+        pub android_relocs: Vec<Reloc>,
         /// The plt relocation entries (procedure linkage table). For 32-bit binaries these are usually Rel (no addend)
         pub pltrelocs: Vec<Reloc>,
         /// Section relocations by section index (only present if this is a relocatable object file)
         pub shdr_relocs: Vec<(ShdrIdx, Vec<Reloc>)>,
+        /// Symbol versioning information from `.gnu.version`, `.gnu.version_d` and `.gnu.version_r`
+        pub symbol_versions: symver::SymbolVersions<'a>,
         /// The binary's soname, if it has one
         pub soname: Option<&'a str>,
         /// The binary's program interpreter (e.g., dynamic linker), if it has one
         pub interpreter: Option<&'a str>,
         /// A list of this binary's dynamic libraries it uses, if there are any
         pub libraries: Vec<&'a str>,
+        /// The `DT_RPATH` library search paths, each a colon separated list of directories
+        pub rpaths: Vec<&'a str>,
+        /// The `DT_RUNPATH` library search paths, each a colon separated list of directories
+        pub runpaths: Vec<&'a str>,
         pub is_64: bool,
         /// Whether this is a shared object or not
         pub is_lib: bool,
         /// The binaries entry point address, if it has one
         pub entry: u64,
-        /// The bias used to overflow virtual memory addresses into physical byte offsets into the binary
+        /// The bias used to overflow virtual memory addresses of the first `PT_LOAD` segment into
+        /// byte offsets into the binary; see `vaddr_to_offset` for a translation of every segment
         pub bias: u64,
+        /// The virtual address to file offset map of the `PT_LOAD` segments
+        pub address_map: address_map::AddressMap,
         /// Whether the binary is little endian or not
         pub little_endian: bool,
         ctx: Ctx,
@@ -197,9 +233,379 @@ if_sylvan! {
                 })
             }
         }
+        /// Parses the GNU program properties (`NT_GNU_PROPERTY_TYPE_0`) of this binary, e.g., to check
+        /// whether it was built with CET or BTI enabled.
+        ///
+        /// The note is located through the `PT_GNU_PROPERTY` program header, falling back to the
+        /// `.note.gnu.property` section for binaries without one, e.g., relocatable objects
+        pub fn gnu_properties(&self, data: &'a [u8]) -> error::Result<Option<gnu_property::GnuProperties<'a>>> {
+            let machine = self.header.e_machine;
+            for phdr in &self.program_headers {
+                if phdr.p_type == program_header::PT_GNU_PROPERTY {
+                    let offset = phdr.p_offset as usize;
+                    let notes = note::NoteDataIterator {
+                        data,
+                        offset,
+                        size: offset + phdr.p_filesz as usize,
+                        ctx: (phdr.p_align as usize, self.ctx)
+                    };
+                    return gnu_property::GnuProperties::from_notes(notes, machine, self.ctx);
+                }
+            }
+            match self.iter_note_sections(data, Some(".note.gnu.property")) {
+                Some(notes) => gnu_property::GnuProperties::from_notes(notes, machine, self.ctx),
+                None => Ok(None),
+            }
+        }
+        /// Decodes the notes of a core file, listing its threads, mapped files and auxiliary vector;
+        /// returns `None` if this binary isn't an `ET_CORE` file
+        pub fn core_dump(&self, data: &'a [u8]) -> error::Result<Option<coredump::CoreDump<'a>>> {
+            if self.header.e_type != header::ET_CORE {
+                return Ok(None);
+            }
+            match self.iter_note_headers(data) {
+                Some(notes) => coredump::CoreDump::parse(notes, self.header.e_machine, self.ctx).map(Some),
+                None => Ok(Some(coredump::CoreDump::default())),
+            }
+        }
+        /// Summarizes the thread-local storage of this binary: its `PT_TLS` template, its TLS variables
+        /// and the dynamic relocations referencing TLS
+        pub fn tls(&self) -> tls::Tls<'a> {
+            let template = self.program_headers.iter().find(|phdr| phdr.p_type == program_header::PT_TLS).map(|phdr| tls::TlsTemplate {
+                offset: phdr.p_offset,
+                vaddr: phdr.p_vaddr,
+                filesz: phdr.p_filesz,
+                memsz: phdr.p_memsz,
+                align: phdr.p_align,
+            });
+            let (syms, strtab) = if self.syms.is_empty() { (&self.dynsyms, &self.dynstrtab) } else { (&self.syms, &self.strtab) };
+            let mut symbols = Vec::new();
+            for (index, sym) in syms.iter().enumerate() {
+                // only undefined symbols are skipped, so an unresolved SHN_XINDEX is as good as the real index here
+                if sym.st_type() != sym::STT_TLS || sym.st_shndx == section_header::SHN_UNDEF as usize {
+                    continue;
+                }
+                match strtab.get(sym.st_name) {
+                    Some(Ok(name)) if !name.is_empty() => symbols.push(tls::TlsSymbol { name, offset: sym.st_value, size: sym.st_size, index }),
+                    _ => (),
+                }
+            }
+            symbols.sort_by_key(|sym| sym.offset);
+            let machine = self.header.e_machine;
+            let mut relocations = Vec::new();
+            for reloc in self.dynrelas.iter().chain(&self.dynrels).chain(&self.android_relocs).chain(&self.pltrelocs) {
+                if let Some(kind) = tls::tls_relocation_kind(machine, reloc.r_type) {
+                    let symbol = if reloc.r_sym == 0 {
+                        None
+                    } else {
+                        self.dynsyms.get(reloc.r_sym).and_then(|sym| self.dynstrtab.get(sym.st_name)).and_then(|name| name.ok())
+                    };
+                    relocations.push(tls::TlsRelocation { kind, reloc: *reloc, symbol });
+                }
+            }
+            relocations.sort_by_key(|relocation| relocation.reloc.r_offset);
+            tls::Tls { template, symbols, relocations }
+        }
+        /// Returns the build ID of this binary, i.e., the contents of its `NT_GNU_BUILD_ID` note, if it has one
+        pub fn build_id(&self, data: &'a [u8]) -> Option<&'a [u8]> {
+            let notes = self.iter_note_headers(data).into_iter().chain(self.iter_note_sections(data, None));
+            for iter in notes {
+                for note in iter {
+                    match note {
+                        Ok(ref note) if note.n_type == note::NT_GNU_BUILD_ID && note.name == note::ELF_NOTE_GNU => return Some(note.desc),
+                        Ok(_) => (),
+                        Err(_) => break,
+                    }
+                }
+            }
+            None
+        }
+        /// Returns the name and the CRC-32 of this binary's split debug file, from its `.gnu_debuglink` section
+        pub fn debuglink(&self, data: &'a [u8]) -> error::Result<Option<(&'a str, u32)>> {
+            match self.section_by_name(debug_file::GNU_DEBUGLINK) {
+                Some(shdr) => {
+                    let bytes: &[u8] = data.pread_with(shdr.sh_offset as usize, shdr.sh_size as usize)?;
+                    debug_file::parse_debuglink(bytes, self.ctx.le).map(Some)
+                },
+                None => Ok(None),
+            }
+        }
+        /// Returns the PowerPC64 TOC pointer of this module, the `S` of `R_PPC64_TOC` and the `.TOC.` of the
+        /// `R_PPC64_TOC16*` relocations: the value of the `.TOC.` symbol, or else the `.got` biased by
+        /// `PPC64_TOC_BIAS`
+        pub fn ppc64_toc_base(&self) -> Option<u64> {
+            for &(syms, strtab) in &[(&self.syms, &self.strtab), (&self.dynsyms, &self.dynstrtab)] {
+                let toc = syms.iter().find(|sym| sym.st_shndx != section_header::SHN_UNDEF as usize && strtab.get(sym.st_name).map_or(false, |name| name.ok() == Some(".TOC.")));
+                if let Some(toc) = toc {
+                    return Some(toc.st_value);
+                }
+            }
+            self.section_by_name(".got").map(|shdr| shdr.sh_addr + reloc::PPC64_TOC_BIAS)
+        }
+        /// Returns the section header named `name`, if any
+        fn section_by_name(&self, name: &str) -> Option<&SectionHeader> {
+            self.section_headers.iter().find(|shdr| {
+                match self.shdr_strtab.get(shdr.sh_name) {
+                    Some(Ok(shdr_name)) => shdr_name == name,
+                    _ => false,
+                }
+            })
+        }
+        /// Returns the `.eh_frame_hdr` unwind table, located through the `PT_GNU_EH_FRAME` program
+        /// header or the section header
+        pub fn eh_frame_hdr(&self, data: &'a [u8]) -> error::Result<Option<eh_frame::EhFrameHdr<'a>>> {
+            let (offset, size, address) = match self.program_headers.iter().find(|phdr| phdr.p_type == program_header::PT_GNU_EH_FRAME) {
+                Some(phdr) => (phdr.p_offset, phdr.p_filesz, phdr.p_vaddr),
+                None => match self.section_by_name(".eh_frame_hdr") {
+                    Some(shdr) => (shdr.sh_offset, shdr.sh_size, shdr.sh_addr),
+                    None => return Ok(None),
+                },
+            };
+            let bytes: &[u8] = data.pread_with(offset as usize, size as usize)?;
+            eh_frame::EhFrameHdr::parse(bytes, address, self.ctx).map(Some)
+        }
+        /// Returns the `.eh_frame` call frame information, located through the section header or,
+        /// in a binary without section headers, through `.eh_frame_hdr`
+        pub fn eh_frame(&self, data: &'a [u8]) -> error::Result<Option<eh_frame::EhFrame<'a>>> {
+            let bases = |address| eh_frame::Bases {
+                section: address,
+                text: self.section_by_name(".text").map_or(0, |shdr| shdr.sh_addr),
+                data: self.section_by_name(".got").map_or(0, |shdr| shdr.sh_addr),
+            };
+            if let Some(shdr) = self.section_by_name(".eh_frame") {
+                let bytes: &[u8] = data.pread_with(shdr.sh_offset as usize, shdr.sh_size as usize)?;
+                return Ok(Some(eh_frame::EhFrame::new(bytes, bases(shdr.sh_addr), self.ctx)));
+            }
+            if let Some(hdr) = self.eh_frame_hdr(data)? {
+                // the section ends with a terminator, so it may extend up to the end of the file
+                if let Some(bytes) = self.vaddr_to_offset(hdr.eh_frame_ptr).and_then(|offset| data.get(offset..)) {
+                    return Ok(Some(eh_frame::EhFrame::new(bytes, bases(hdr.eh_frame_ptr), self.ctx)));
+                }
+            }
+            Ok(None)
+        }
+        /// Parses the section groups (`SHT_GROUP`) of this binary, e.g., the COMDAT groups of a relocatable object,
+        /// with their signature symbol names and member sections
+        pub fn section_groups(&self, data: &'a [u8]) -> error::Result<Vec<section_group::SectionGroup<'a>>> {
+            let mut groups = vec![];
+            for (index, shdr) in self.section_headers.iter().enumerate() {
+                if shdr.sh_type != section_header::SHT_GROUP {
+                    continue;
+                }
+                shdr.check_size(data.len())?;
+                let endianness = if self.little_endian { scroll::LE } else { scroll::BE };
+                let (flags, sections) = section_group::parse_group(data, shdr.sh_offset as usize, shdr.sh_size as usize, endianness)?;
+                let signature = self.group_signature(data, shdr)?;
+                groups.push(section_group::SectionGroup { index, signature, flags, sections });
+            }
+            Ok(groups)
+        }
+        /// Returns the name of the signature symbol of the group section `shdr`, i.e., symbol `sh_info` of the symbol table `sh_link`
+        fn group_signature(&self, data: &'a [u8], shdr: &SectionHeader) -> error::Result<&'a str> {
+            let malformed = || error::Error::Malformed(format!("Section group {} has an invalid signature symbol {} in section {}",
+                shdr.sh_name, shdr.sh_info, shdr.sh_link));
+            let symtab = self.section_headers.get(shdr.sh_link as usize).ok_or_else(malformed)?;
+            let strtab = self.section_headers.get(symtab.sh_link as usize).ok_or_else(malformed)?;
+            let entsize = if symtab.sh_entsize == 0 { Sym::size(self.ctx.container) as u64 } else { symtab.sh_entsize };
+            if shdr.sh_info as u64 >= symtab.sh_size / entsize {
+                return Err(malformed());
+            }
+            let sym: Sym = data.pread_with((symtab.sh_offset + shdr.sh_info as u64 * entsize) as usize, self.ctx)?;
+            // a section symbol has no name of its own, the group is named after the section
+            if sym.st_type() == sym::STT_SECTION && sym.st_name == 0 {
+                let shndx = if sym.st_shndx == section_header::SHN_XINDEX as usize && symtab.sh_type == section_header::SHT_SYMTAB {
+                    self.symtab_shndx.get(shdr.sh_info as usize).map(|&shndx| shndx as usize)
+                } else {
+                    Some(sym.st_shndx)
+                };
+                let section = shndx.and_then(|shndx| self.section_headers.get(shndx)).ok_or_else(malformed)?;
+                return match self.shdr_strtab.get(section.sh_name) {
+                    Some(name) => name,
+                    None => Err(malformed()),
+                };
+            }
+            strtab.check_size(data.len())?;
+            let strtab = Strtab::parse(data, strtab.sh_offset as usize, strtab.sh_size as usize, 0x0)?;
+            match strtab.get(sym.st_name) {
+                Some(name) => name,
+                None => Err(malformed()),
+            }
+        }
+        /// Applies the relocations of the section at `index` of this relocatable object to `data`, the
+        /// section's contents, where each section is loaded at its address in `section_addresses`.
+        ///
+        /// Undefined symbols are resolved by name with `resolve`; unresolved weak symbols are 0
+        pub fn relocate_section<F>(&self, index: usize, data: &mut [u8], section_addresses: &[u64], mut resolve: F) -> Result<(), reloc::RelocationError>
+            where F: FnMut(&str) -> Option<u64>
+        {
+            let address = match section_addresses.get(index) {
+                Some(&address) => address,
+                None => return Err(reloc::RelocationError::InvalidSection { shndx: index }),
+            };
+            let relocator = reloc::Relocator::new(self.header.e_machine, self.ctx.le);
+            for &(reloc_idx, ref relocs) in &self.shdr_relocs {
+                if self.section_headers[reloc_idx].sh_info as usize != index {
+                    continue;
+                }
+                for reloc in relocs {
+                    let symbol = self.relocation_symbol(reloc.r_sym, section_addresses, &mut resolve)?;
+                    relocator.apply(data, address, reloc, symbol)?;
+                }
+            }
+            Ok(())
+        }
+        /// Returns the address of the symbol `r_sym` of a relocation (`S`)
+        fn relocation_symbol<F>(&self, r_sym: usize, section_addresses: &[u64], resolve: &mut F) -> Result<u64, reloc::RelocationError>
+            where F: FnMut(&str) -> Option<u64>
+        {
+            if r_sym == 0 {
+                return Ok(0);
+            }
+            let sym = match self.syms.get(r_sym) {
+                Some(sym) => sym,
+                None => return Err(reloc::RelocationError::InvalidSymbol { r_sym }),
+            };
+            if let Some(shndx) = self.symbol_section_index(r_sym) {
+                return match section_addresses.get(shndx) {
+                    Some(&address) => Ok(address.wrapping_add(sym.st_value)),
+                    None => Err(reloc::RelocationError::InvalidSection { shndx }),
+                };
+            }
+            if sym.st_shndx as u32 == section_header::SHN_ABS {
+                return Ok(sym.st_value);
+            }
+            let address = match self.strtab.get(sym.st_name) {
+                Some(Ok(name)) => resolve(name),
+                _ => None,
+            };
+            match address {
+                Some(address) => Ok(address),
+                None if sym.st_bind() == sym::STB_WEAK => Ok(0),
+                None => Err(reloc::RelocationError::UndefinedSymbol { r_sym }),
+            }
+        }
         pub fn is_object_file(&self) -> bool {
             self.header.e_type == header::ET_REL
         }
+        /// Returns the contents of the section described by `shdr`, decompressing them if needed.
+        ///
+        /// Handles both `SHF_COMPRESSED` sections and the legacy GNU `.zdebug*` sections, whose
+        /// contents start with the magic `ZLIB` followed by the big endian 64-bit uncompressed size
+        #[cfg(feature = "compression")]
+        pub fn section_data(&self, bytes: &'a [u8], shdr: &SectionHeader) -> error::Result<Cow<'a, [u8]>> {
+            let data = shdr.data(bytes, self.ctx)?;
+            let is_zdebug = match self.shdr_strtab.get(shdr.sh_name) {
+                Some(Ok(name)) => name.starts_with(".zdebug"),
+                _ => false,
+            };
+            if !is_zdebug || shdr.is_compressed() || !data.starts_with(b"ZLIB") {
+                return Ok(data);
+            }
+            let size: u64 = data.pread_with(4, scroll::BE)?;
+            let decompressed = ::inflate::zlib_decompress(&data[12..], size as usize)?;
+            if decompressed.len() as u64 != size {
+                return Err(error::Error::Malformed(format!("Section {} decompressed to {} bytes, but its header says {}",
+                    shdr.sh_name, decompressed.len(), size)));
+            }
+            Ok(Cow::Owned(decompressed))
+        }
+        /// Looks up the dynamic symbol named `name` via the binary's hash table.
+        ///
+        /// Falls back to a linear scan of `dynsyms` if the binary has no hash table
+        pub fn lookup_dynsym(&self, name: &str) -> Option<Sym> {
+            if let Some(ref table) = self.dynsym_hash {
+                return table.lookup(name, &self.dynsyms, &self.dynstrtab);
+            }
+            self.dynsyms.iter().find(|sym| {
+                match self.dynstrtab.get(sym.st_name) {
+                    Some(Ok(sym_name)) => sym_name == name,
+                    _ => false,
+                }
+            })
+        }
+        /// Returns the index of the section the symbol at `index` in `syms` is defined in, resolving
+        /// `SHN_XINDEX` through the `SHT_SYMTAB_SHNDX` section.
+        ///
+        /// Returns `None` for undefined symbols, and symbols with a reserved index such as `SHN_ABS` or `SHN_COMMON`
+        pub fn symbol_section_index(&self, index: usize) -> Option<usize> {
+            self.syms.get(index).and_then(|sym| match sym.st_shndx as u32 {
+                section_header::SHN_XINDEX => self.symtab_shndx.get(index).map(|&shndx| shndx as usize),
+                section_header::SHN_UNDEF => None,
+                shndx if shndx >= section_header::SHN_LORESERVE => None,
+                shndx => Some(shndx as usize),
+            })
+        }
+        /// Returns the file offset of the virtual address `vaddr`, or `None` if it isn't backed by the file
+        pub fn vaddr_to_offset(&self, vaddr: u64) -> Option<usize> {
+            self.address_map.vaddr_to_offset(vaddr)
+        }
+        /// Returns the virtual address the file offset `offset` is loaded at, or `None` if it isn't loaded
+        pub fn offset_to_vaddr(&self, offset: u64) -> Option<u64> {
+            self.address_map.offset_to_vaddr(offset)
+        }
+        /// Returns the addresses of the initialization functions in `DT_INIT_ARRAY`, in the order they're called
+        pub fn init_array(&self, data: &[u8]) -> error::Result<Vec<u64>> {
+            self.dynamic_array(data, dyn::DT_INIT_ARRAY, dyn::DT_INIT_ARRAYSZ)
+        }
+        /// Returns the addresses of the termination functions in `DT_FINI_ARRAY`; they're called in reverse order
+        pub fn fini_array(&self, data: &[u8]) -> error::Result<Vec<u64>> {
+            self.dynamic_array(data, dyn::DT_FINI_ARRAY, dyn::DT_FINI_ARRAYSZ)
+        }
+        /// Returns the addresses of the pre-initialization functions in `DT_PREINIT_ARRAY`, which an executable
+        /// calls before the initialization functions of all the objects
+        pub fn preinit_array(&self, data: &[u8]) -> error::Result<Vec<u64>> {
+            self.dynamic_array(data, dyn::DT_PREINIT_ARRAY, dyn::DT_PREINIT_ARRAYSZ)
+        }
+        /// Reads the array of addresses at the `tag` entry with the size of the `size_tag` entry.
+        ///
+        /// The addresses of position independent objects are relocated by relative relocations, whose
+        /// addend (if any) replaces the address in the file, e.g., 0 for lld outputs
+        fn dynamic_array(&self, data: &[u8], tag: u64, size_tag: u64) -> error::Result<Vec<u64>> {
+            let dynamic = match self.dynamic {
+                Some(ref dynamic) => dynamic,
+                None => return Ok(Vec::new()),
+            };
+            let value = |tag| dynamic.dyns.iter().find(|dyn| dyn.d_tag == tag).map(|dyn| dyn.d_val);
+            let (vaddr, size) = match (value(tag), value(size_tag)) {
+                (Some(vaddr), Some(size)) => (vaddr, size),
+                _ => return Ok(Vec::new()),
+            };
+            let offset = self.vaddr_to_offset(vaddr).ok_or_else(|| {
+                error::Error::Malformed(format!("{} at {:#x} isn't backed by the file", dyn::tag_to_str(tag), vaddr))
+            })?;
+            // a trailing partial entry is ignored
+            let entry_size = self.ctx.size() as u64;
+            let count = size / entry_size;
+            let end = vaddr.checked_add(count * entry_size).ok_or_else(|| {
+                error::Error::Malformed(format!("{} at {:#x} of size {:#x} overflows the address space", dyn::tag_to_str(tag), vaddr, size))
+            })?;
+            let remaining = data.len().saturating_sub(offset) as u64 / entry_size;
+            let mut addresses = Vec::with_capacity(cmp::min(count, remaining) as usize);
+            let mut offset = offset;
+            for _ in 0..count {
+                let address = if self.is_64 { data.gread_with::<u64>(&mut offset, self.ctx.le)? } else { data.gread_with::<u32>(&mut offset, self.ctx.le)? as u64 };
+                addresses.push(address);
+            }
+            let r_relative = reloc::r_relative(self.header.e_machine);
+            for reloc in self.dynrelas.iter().chain(self.android_relocs.iter()) {
+                if let Some(addend) = reloc.r_addend {
+                    if Some(reloc.r_type) == r_relative && reloc.r_offset >= vaddr && reloc.r_offset < end {
+                        addresses[((reloc.r_offset - vaddr) / entry_size) as usize] = addend as u64;
+                    }
+                }
+            }
+            Ok(addresses)
+        }
+        /// Returns the allocated section containing the virtual address `vaddr`, if any
+        pub fn section_for_vaddr(&self, vaddr: u64) -> Option<&SectionHeader> {
+            self.section_headers.iter().find(|shdr| {
+                shdr.is_alloc() && shdr.sh_addr <= vaddr && vaddr - shdr.sh_addr < shdr.sh_size
+            })
+        }
+        /// Returns the version of the dynamic symbol at `index` in `dynsyms`, or `None` if it is unversioned
+        pub fn symbol_version(&self, index: usize) -> Option<symver::SymbolVersion<'a>> {
+            self.symbol_versions.get(index)
+        }
         /// Parses the contents of the byte stream in `bytes`, and maybe returns a unified binary
         pub fn parse(bytes: &'a [u8]) -> error::Result<Self> {
             let header = bytes.pread::<Header>(0)?;
@@ -217,20 +623,40 @@ if_sylvan! {
             let container = if is_64 { Container::Big } else { Container::Little };
             let ctx = Ctx::new(container, endianness);
 
-            let program_headers = ProgramHeader::parse(bytes, header.e_phoff as usize, header.e_phnum as usize, ctx)?;
+            // with extended numbering, the real counts and indices are stored in the first section header
+            let mut shnum = header.e_shnum as usize;
+            let mut phnum = header.e_phnum as usize;
+            let mut shstrndx = header.e_shstrndx as usize;
+            if header.e_shoff != 0 && (shnum == 0 || header.e_phnum == program_header::PN_XNUM || shstrndx == section_header::SHN_XINDEX as usize) {
+                let shdr0: SectionHeader = bytes.pread_with(header.e_shoff as usize, ctx)?;
+                if shnum == 0 {
+                    shnum = shdr0.sh_size as usize;
+                }
+                if header.e_phnum == program_header::PN_XNUM {
+                    phnum = shdr0.sh_info as usize;
+                }
+                if shstrndx == section_header::SHN_XINDEX as usize {
+                    shstrndx = shdr0.sh_link as usize;
+                }
+                // unlike e_shnum and e_phnum, the extended counts can be large enough to exhaust memory
+                let fits = |offset: u64, count: usize, entsize: usize| {
+                    count.checked_mul(entsize).map_or(false, |size| size as u64 <= (bytes.len() as u64).saturating_sub(offset))
+                };
+                if !fits(header.e_shoff, shnum, SectionHeader::size(&ctx)) {
+                    return Err(error::Error::Malformed(format!("{} section headers at {:#x} don't fit in the file", shnum, header.e_shoff)));
+                }
+                if !fits(header.e_phoff, phnum, ProgramHeader::size(&ctx)) {
+                    return Err(error::Error::Malformed(format!("{} program headers at {:#x} don't fit in the file", phnum, header.e_phoff)));
+                }
+            }
+
+            let program_headers = ProgramHeader::parse(bytes, header.e_phoff as usize, phnum, ctx)?;
 
             let mut bias: usize = 0;
             for ph in &program_headers {
                 if ph.p_type == program_header::PT_LOAD {
-                    // NB this _only_ works on the first load address, and the GOT values (usually at base + 2000) will be incorrect binary offsets...
-                    // this is an overflow hack that allows us to use virtual memory addresses
-                    // as though they're in the file by generating a fake load bias which is then
-                    // used to overflow the values in the dynamic array, and in a few other places
-                    // (see Dyn::DynamicInfo), to generate actual file offsets; you may have to
-                    // marinate a bit on why this works. i am unsure whether it works in every
-                    // conceivable case. i learned this trick from reading too much dynamic linker
-                    // C code (a whole other class of C code) and having to deal with broken older
-                    // kernels on VMs. enjoi
+                    // NB this only translates addresses in the first PT_LOAD segment; the parser itself uses
+                    // the address map, which handles every segment
                     bias = match container {
                         Container::Little => (::core::u32::MAX - (ph.p_vaddr as u32)).wrapping_add(1) as usize,
                         Container::Big    => (::core::u64::MAX - ph.p_vaddr).wrapping_add(1) as usize,
@@ -249,7 +675,7 @@ if_sylvan! {
                 }
             }
 
-            let section_headers = SectionHeader::parse(bytes, header.e_shoff as usize, header.e_shnum as usize, ctx)?;
+            let section_headers = SectionHeader::parse(bytes, header.e_shoff as usize, shnum, ctx)?;
 
             let get_strtab = |section_headers: &[SectionHeader], section_idx: usize| {
                 if section_idx >= section_headers.len() {
@@ -262,28 +688,48 @@ if_sylvan! {
                 }
             };
 
-            let strtab_idx = header.e_shstrndx as usize;
-            let shdr_strtab = get_strtab(&section_headers, strtab_idx)?;
+            let shdr_strtab = get_strtab(&section_headers, shstrndx)?;
 
             let mut syms = Symtab::default();
             let mut strtab = Strtab::default();
-            for shdr in &section_headers {
+            let mut symtab_idx = None;
+            for (idx, shdr) in section_headers.iter().enumerate() {
                 if shdr.sh_type as u32 == section_header::SHT_SYMTAB {
                     let size = shdr.sh_entsize;
                     let count = if size == 0 { 0 } else { shdr.sh_size / size };
                     syms = Symtab::parse(bytes, shdr.sh_offset as usize, count as usize, ctx)?;
                     strtab = get_strtab(&section_headers, shdr.sh_link as usize)?;
+                    symtab_idx = Some(idx);
+                }
+            }
+
+            let mut symtab_shndx = vec![];
+            for shdr in &section_headers {
+                if shdr.sh_type == section_header::SHT_SYMTAB_SHNDX && Some(shdr.sh_link as usize) == symtab_idx {
+                    shdr.check_size(bytes.len())?;
+                    let count = shdr.sh_size as usize / 4;
+                    let mut offset = shdr.sh_offset as usize;
+                    symtab_shndx = Vec::with_capacity(count);
+                    for _ in 0..count {
+                        symtab_shndx.push(bytes.gread_with::<u32>(&mut offset, endianness)?);
+                    }
                 }
             }
 
             let mut soname = None;
             let mut libraries = vec![];
+            let mut rpaths = vec![];
+            let mut runpaths = vec![];
             let mut dynsyms = Symtab::default();
+            let mut dynsym_hash = None;
             let mut dynrelas = vec![];
             let mut dynrels = vec![];
+            let mut dynrelrs = vec![];
+            let mut android_relocs = vec![];
             let mut pltrelocs = vec![];
             let mut dynstrtab = Strtab::default();
-            let dynamic = Dynamic::parse(bytes, &program_headers, bias, ctx)?;
+            let address_map = address_map::AddressMap::new(&program_headers);
+            let dynamic = Dynamic::parse_with_address_map(bytes, &program_headers, &address_map, ctx)?;
             if let Some(ref dynamic) = dynamic {
                 let dyn_info = &dynamic.info;
                 dynstrtab = Strtab::parse(bytes,
@@ -298,15 +744,84 @@ if_sylvan! {
                 if dyn_info.needed_count > 0 {
                     libraries = dynamic.get_libraries(&dynstrtab);
                 }
-                let num_syms = if dyn_info.syment == 0 { 0 } else { if dyn_info.strtab <= dyn_info.symtab { 0 } else { (dyn_info.strtab - dyn_info.symtab) / dyn_info.syment }};
+                rpaths = dynamic.get_rpaths(&dynstrtab);
+                runpaths = dynamic.get_runpaths(&dynstrtab);
+                // a malformed hash table is dropped rather than failing the parse, since the symbols can still be counted without it
+                if let Some(gnu_hash) = dyn_info.gnu_hash {
+                    dynsym_hash = hash::GnuHash::parse(bytes, gnu_hash as usize, ctx).ok().map(hash::HashTable::Gnu);
+                } else if let Some(sysv_hash) = dyn_info.hash {
+                    dynsym_hash = hash::SysvHash::parse(bytes, sysv_hash as usize, ctx).ok().map(hash::HashTable::Sysv);
+                }
+                let hash_count = match dynsym_hash {
+                    Some(ref table) => table.dynsym_count().ok(),
+                    None => None,
+                };
+                if hash_count.is_none() {
+                    dynsym_hash = None;
+                }
+                // the hash table knows the exact number of dynamic symbols; otherwise use the `.dynsym` section header,
+                // or guess from the distance to the strtab
+                let num_syms = match hash_count {
+                    Some(count) => count,
+                    None => match section_headers.iter().find(|shdr| shdr.sh_type == section_header::SHT_DYNSYM && shdr.sh_entsize != 0) {
+                        Some(shdr) => (shdr.sh_size / shdr.sh_entsize) as usize,
+                        None => if dyn_info.syment == 0 { 0 } else { if dyn_info.strtab <= dyn_info.symtab { 0 } else { (dyn_info.strtab - dyn_info.symtab) / dyn_info.syment }},
+                    },
+                };
                 dynsyms = Symtab::parse(bytes, dyn_info.symtab, num_syms, ctx)?;
                 // parse the dynamic relocations
                 dynrelas = Reloc::parse(bytes, dyn_info.rela, dyn_info.relasz, true, ctx)?;
                 dynrels = Reloc::parse(bytes, dyn_info.rel, dyn_info.relsz, false, ctx)?;
+                if dyn_info.relr != 0 {
+                    let r_type = reloc::r_relative(header.e_machine).unwrap_or(0);
+                    dynrelrs = Reloc::parse_relr(bytes, dyn_info.relr, dyn_info.relrsz, r_type, ctx)?;
+                }
+                if dyn_info.android_rela != 0 {
+                    android_relocs = Reloc::parse_android(bytes, dyn_info.android_rela, dyn_info.android_relasz, true, ctx)?;
+                } else if dyn_info.android_rel != 0 {
+                    android_relocs = Reloc::parse_android(bytes, dyn_info.android_rel, dyn_info.android_relsz, false, ctx)?;
+                }
                 let is_rela = dyn_info.pltrel as u64 == dyn::DT_RELA;
                 pltrelocs = Reloc::parse(bytes, dyn_info.jmprel, dyn_info.pltrelsz, is_rela, ctx)?;
             }
 
+            let mut symbol_versions = symver::SymbolVersions::default();
+            for shdr in &section_headers {
+                match shdr.sh_type {
+                    section_header::SHT_GNU_VERSYM => {
+                        shdr.check_size(bytes.len())?;
+                        let count = shdr.sh_size as usize / symver::SIZEOF_VERSYM;
+                        symbol_versions.versym = symver::parse_versym(bytes, shdr.sh_offset as usize, count, endianness)?;
+                    },
+                    section_header::SHT_GNU_VERDEF => {
+                        shdr.check_size(bytes.len())?;
+                        let strtab = get_strtab(&section_headers, shdr.sh_link as usize)?;
+                        symbol_versions.verdef = symver::parse_verdef(bytes, shdr.sh_offset as usize, shdr.sh_info as usize, &strtab, endianness)?;
+                    },
+                    section_header::SHT_GNU_VERNEED => {
+                        shdr.check_size(bytes.len())?;
+                        let strtab = get_strtab(&section_headers, shdr.sh_link as usize)?;
+                        symbol_versions.verneed = symver::parse_verneed(bytes, shdr.sh_offset as usize, shdr.sh_info as usize, &strtab, endianness)?;
+                    },
+                    _ => (),
+                }
+            }
+            // the section headers were stripped, so fall back to the dynamic array
+            if symbol_versions.is_empty() {
+                if let Some(ref dynamic) = dynamic {
+                    let dyn_info = &dynamic.info;
+                    if dyn_info.versym != 0 {
+                        symbol_versions.versym = symver::parse_versym(bytes, dyn_info.versym as usize, dynsyms.len(), endianness)?;
+                    }
+                    if dyn_info.verdef != 0 {
+                        symbol_versions.verdef = symver::parse_verdef(bytes, dyn_info.verdef as usize, dyn_info.verdefnum as usize, &dynstrtab, endianness)?;
+                    }
+                    if dyn_info.verneed != 0 {
+                        symbol_versions.verneed = symver::parse_verneed(bytes, dyn_info.verneed as usize, dyn_info.verneednum as usize, &dynstrtab, endianness)?;
+                    }
+                }
+            }
+
             // iterate through shdrs again iff we're an ET_REL
             let shdr_relocs = {
                 let mut relocs = vec![];
@@ -334,19 +849,27 @@ if_sylvan! {
                 dynamic: dynamic,
                 dynsyms: dynsyms,
                 dynstrtab: dynstrtab,
+                dynsym_hash,
+                symtab_shndx,
                 syms: syms,
                 strtab: strtab,
                 dynrelas: dynrelas,
                 dynrels: dynrels,
+                dynrelrs,
+                android_relocs,
                 pltrelocs: pltrelocs,
                 shdr_relocs: shdr_relocs,
+                symbol_versions,
                 soname: soname,
                 interpreter: interpreter,
                 libraries: libraries,
+                rpaths,
+                runpaths,
                 is_64: is_64,
                 is_lib: is_lib,
                 entry: entry as u64,
                 bias: bias as u64,
+                address_map,
                 little_endian: is_lsb,
                 ctx,
             })
@@ -430,4 +953,152 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn parse_comdat_groups() {
+        // an object with the COMDAT groups of an inline function and two template instances, see etc/comdat.cpp
+        let bytes: Vec<u8> = include!("../../etc/comdat.rs");
+        let elf = Elf::parse(&bytes).unwrap();
+        let groups = elf.section_groups(&bytes).unwrap();
+        let signatures: Vec<&str> = groups.iter().map(|group| group.signature).collect();
+        assert_eq!(signatures, vec!["_Z6answerv", "_Z5twiceIiET_S0_", "_Z5twiceIlET_S0_"]);
+        for group in &groups {
+            assert!(group.is_comdat());
+            assert_eq!(elf.section_headers[group.index].sh_type, section_header::SHT_GROUP);
+            let members: Vec<&str> = group.sections.iter().map(|&index| &elf.shdr_strtab[elf.section_headers[index].sh_name]).collect();
+            assert_eq!(members, vec![format!(".text.{}", group.signature)]);
+            assert!(elf.section_headers[group.sections[0]].sh_flags & section_header::SHF_GROUP as u64 != 0);
+        }
+    }
+
+    #[test]
+    fn thread_local_storage() {
+        // `counter` in .tdata and `scratch` in .tbss, see etc/hello.c
+        let bytes: Vec<u8> = include!("../../etc/hello.rs");
+        let elf = Elf::parse(&bytes).unwrap();
+        let tls = elf.tls();
+        let template = tls.template.unwrap();
+        assert_eq!((template.vaddr, template.filesz, template.memsz, template.align), (0x1d80, 4, 0x20, 0x10));
+        assert_eq!(template.bss_size(), 0x1c);
+        assert_eq!(template.image(&bytes), Some(&[42, 0, 0, 0][..]));
+        let symbols: Vec<(&str, u64, u64)> = tls.symbols.iter().map(|sym| (sym.name, sym.offset, sym.size)).collect();
+        assert_eq!(symbols, vec![("counter", 0, 4), ("scratch", 0x10, 0x10)]);
+        assert_eq!(tls.symbol_at(0x1f).map(|sym| sym.name), Some("scratch"));
+        assert!(tls.symbol_at(0x8).is_none());
+        // the executable accesses its variables at fixed offsets from the thread pointer, without relocations
+        assert!(tls.relocations.is_empty());
+        assert_eq!(template.tp_offset(header::EM_X86_64, 0), Some(-0x20));
+        assert_eq!(template.tp_offset(header::EM_X86_64, 0x10), Some(-0x10));
+    }
+
+    #[test]
+    fn unwind_tables() {
+        // hello.c is built without unwind tables, so only _start and the PLTs have FDEs, see etc/hello.c
+        let bytes: Vec<u8> = include!("../../etc/hello.rs");
+        let elf = Elf::parse(&bytes).unwrap();
+        let hdr = elf.eh_frame_hdr(&bytes).unwrap().unwrap();
+        let eh_frame = elf.eh_frame(&bytes).unwrap().unwrap();
+        assert_eq!(hdr.eh_frame_ptr, eh_frame.address());
+        assert_eq!(Some(hdr.eh_frame_ptr), elf.section_by_name(".eh_frame").map(|shdr| shdr.sh_addr));
+        let fdes: Vec<eh_frame::Fde> = eh_frame.entries().filter_map(|entry| match entry.unwrap() {
+            eh_frame::Entry::Fde(fde) => Some(fde),
+            eh_frame::Entry::Cie(_) => None,
+        }).collect();
+        assert_eq!(fdes.len(), hdr.fde_count);
+        // the table is sorted by initial PC and points at each FDE
+        let table: Vec<(u64, u64)> = (0..hdr.fde_count).map(|index| hdr.entry(index).unwrap()).collect();
+        assert_eq!(table, vec![(0x6d0, 0x978), (0x720, 0x9a0), (0x780, 0x948)]);
+        for fde in &fdes {
+            assert_eq!(hdr.find_fde(&eh_frame, fde.pc_end() - 1).unwrap().map(|found| found.offset), Some(fde.offset));
+        }
+        let start = eh_frame.find_fde(0x790).unwrap().unwrap();
+        assert_eq!((start.pc_begin, start.pc_end()), (0x780, 0x7a2));
+        assert_eq!(hdr.find_fde(&eh_frame, 0x790).unwrap().map(|fde| fde.offset), Some(start.offset));
+        // main, between the .plt.got and _start FDEs
+        assert!(hdr.find_fde(&eh_frame, 0x740).unwrap().is_none());
+        assert!(eh_frame.find_fde(0x740).unwrap().is_none());
+    }
+
+    #[test]
+    fn init_and_fini_arrays() {
+        // a PIE whose arrays are relocated by relative relocations, see etc/hello.c
+        let bytes: Vec<u8> = include!("../../etc/hello.rs");
+        let mut elf = Elf::parse(&bytes).unwrap();
+        // frame_dummy and __do_global_dtors_aux
+        assert_eq!(elf.init_array(&bytes).unwrap(), vec![0x860]);
+        assert_eq!(elf.fini_array(&bytes).unwrap(), vec![0x820]);
+        assert!(elf.preinit_array(&bytes).unwrap().is_empty());
+        let set = |elf: &mut Elf, tag, value| {
+            let dynamic = elf.dynamic.as_mut().unwrap();
+            dynamic.dyns.iter_mut().find(|dyn| dyn.d_tag == tag).unwrap().d_val = value;
+        };
+        // a partial entry, which the relative relocation at 0x1d90 still points into
+        set(&mut elf, dyn::DT_INIT_ARRAYSZ, 4);
+        assert!(elf.init_array(&bytes).unwrap().is_empty());
+        // sizes past the end of the file and the address space
+        set(&mut elf, dyn::DT_INIT_ARRAYSZ, u64::max_value() & !7);
+        assert!(elf.init_array(&bytes).is_err());
+        set(&mut elf, dyn::DT_FINI_ARRAYSZ, 0x1_0000);
+        assert!(elf.fini_array(&bytes).is_err());
+    }
+
+    #[test]
+    fn parse_extended_numbering() {
+        use scroll::Pwrite;
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let mut bytes = vec![0u8; 0x100 + 6 * 64];
+        let mut header = Header::new(ctx);
+        header.e_type = header::ET_REL;
+        header.e_shoff = 0x100;
+        header.e_shnum = 0;
+        header.e_shstrndx = section_header::SHN_XINDEX as u16;
+        bytes.pwrite_with(header, 0, scroll::LE).unwrap();
+        // symbols: null, a section symbol for the section given by SHT_SYMTAB_SHNDX, and one in section 2
+        let sym_xindex = Sym { st_info: sym::STT_SECTION, st_shndx: section_header::SHN_XINDEX as usize, ..Sym::default() };
+        let sym_direct = Sym { st_name: 3, st_shndx: 2, ..Sym::default() };
+        bytes.pwrite_with(sym_xindex, 0x40 + 24, ctx).unwrap();
+        bytes.pwrite_with(sym_direct, 0x40 + 48, ctx).unwrap();
+        bytes.pwrite(&b"\0x\0y\0"[..], 0x88).unwrap();
+        for (i, shndx) in [0u32, 3, 0].iter().enumerate() {
+            bytes.pwrite_with(*shndx, 0xa0 + i * 4, scroll::LE).unwrap();
+        }
+        bytes.pwrite(&b"\0.symtab\0.strtab\0.symtab_shndx\0.shstrtab\0"[..], 0xb0).unwrap();
+        // a group whose signature is the section symbol
+        bytes.pwrite_with(section_group::GRP_COMDAT, 0xf0, scroll::LE).unwrap();
+        bytes.pwrite_with(2u32, 0xf4, scroll::LE).unwrap();
+        let shdrs = [
+            // the real section count and section header string table index
+            SectionHeader { sh_size: 6, sh_link: 4, ..SectionHeader::new() },
+            SectionHeader { sh_name: 1, sh_type: section_header::SHT_SYMTAB, sh_offset: 0x40, sh_size: 72, sh_entsize: 24, sh_link: 2, ..SectionHeader::new() },
+            SectionHeader { sh_name: 9, sh_type: section_header::SHT_STRTAB, sh_offset: 0x88, sh_size: 5, ..SectionHeader::new() },
+            SectionHeader { sh_name: 17, sh_type: section_header::SHT_SYMTAB_SHNDX, sh_offset: 0xa0, sh_size: 12, sh_entsize: 4, sh_link: 1, ..SectionHeader::new() },
+            SectionHeader { sh_name: 31, sh_type: section_header::SHT_STRTAB, sh_offset: 0xb0, sh_size: 41, ..SectionHeader::new() },
+            SectionHeader { sh_type: section_header::SHT_GROUP, sh_offset: 0xf0, sh_size: 8, sh_entsize: 4, sh_link: 1, sh_info: 1, ..SectionHeader::new() },
+        ];
+        for (i, shdr) in shdrs.iter().enumerate() {
+            bytes.pwrite_with(shdr.clone(), 0x100 + i * 64, ctx).unwrap();
+        }
+        let elf = Elf::parse(&bytes).unwrap();
+        assert_eq!(elf.section_headers.len(), 6);
+        assert_eq!(&elf.shdr_strtab[elf.section_headers[3].sh_name], ".symtab_shndx");
+        assert_eq!(elf.syms.len(), 3);
+        assert_eq!(elf.symtab_shndx, vec![0, 3, 0]);
+        assert_eq!(elf.symbol_section_index(0), None);
+        assert_eq!(elf.symbol_section_index(1), Some(3));
+        assert_eq!(elf.symbol_section_index(2), Some(2));
+        assert_eq!(elf.symbol_section_index(3), None);
+        let groups = elf.section_groups(&bytes).unwrap();
+        assert_eq!(groups[0].signature, ".symtab_shndx");
+        assert_eq!(groups[0].sections, vec![2]);
+
+        // counts which can't fit in the file
+        bytes.pwrite_with(0x1_0000_0000u64, 0x100 + 32, scroll::LE).unwrap();
+        assert!(Elf::parse(&bytes).is_err());
+        bytes.pwrite_with(6u64, 0x100 + 32, scroll::LE).unwrap();
+        header.e_phoff = 0x100;
+        header.e_phnum = program_header::PN_XNUM;
+        bytes.pwrite_with(header, 0, scroll::LE).unwrap();
+        bytes.pwrite_with(0xffff_ffffu32, 0x100 + 44, scroll::LE).unwrap();
+        assert!(Elf::parse(&bytes).is_err());
+    }
 }
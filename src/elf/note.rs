@@ -31,6 +31,44 @@ pub const NT_GNU_BUILD_ID: u32 = 3;
 // Version note generated by GNU gold containing a version string.
 pub const NT_GNU_GOLD_VERSION: u32 = 4;
 
+// Program property.  The descriptor is an array of properties, see `elf::gnu_property`.
+pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+
+// Defined note types for core files, with the note name "CORE" (or "LINUX" for the
+// Linux specific register sets). They overlap with the GNU note types above.
+
+/// Contains a copy of prstatus struct
+pub const NT_PRSTATUS: u32 = 1;
+/// Contains a copy of fpregset struct
+pub const NT_PRFPREG: u32 = 2;
+/// Old name for `NT_PRFPREG`
+pub const NT_FPREGSET: u32 = NT_PRFPREG;
+/// Contains a copy of prpsinfo struct
+pub const NT_PRPSINFO: u32 = 3;
+/// Contains a copy of task structure
+pub const NT_TASKSTRUCT: u32 = 4;
+/// Contains copy of auxv array
+pub const NT_AUXV: u32 = 6;
+/// Contains copy of siginfo_t, size might increase
+pub const NT_SIGINFO: u32 = 0x5349_4749;
+/// Contains information about mapped files
+pub const NT_FILE: u32 = 0x4649_4c45;
+/// Contains copy of user_fxsr_struct
+pub const NT_PRXFPREG: u32 = 0x46e6_2b7f;
+/// x86 extended state using xsave
+pub const NT_X86_XSTATE: u32 = 0x202;
+/// ARM VFP/NEON registers
+pub const NT_ARM_VFP: u32 = 0x400;
+/// ARM TLS register
+pub const NT_ARM_TLS: u32 = 0x401;
+
+/// Note name of the core file notes
+pub const ELF_NOTE_CORE: &'static str = "CORE";
+/// Note name of the Linux specific core file notes
+pub const ELF_NOTE_LINUX: &'static str = "LINUX";
+/// Note name of the GNU notes
+pub const ELF_NOTE_GNU: &'static str = "GNU";
+
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "alloc", derive(Pread, Pwrite, IOread, IOwrite, SizeWith))]
 #[repr(C)]
@@ -153,12 +191,33 @@ if_alloc! {
     }
 
     impl<'a> Note<'a> {
+        /// Whether this is a core file note, i.e., its name is `CORE` or `LINUX`
+        pub fn is_core(&self) -> bool {
+            self.name == ELF_NOTE_CORE || self.name == ELF_NOTE_LINUX
+        }
         pub fn type_to_str(&self) -> &'static str {
+            if self.is_core() {
+                return match self.n_type {
+                    NT_PRSTATUS => "NT_PRSTATUS",
+                    NT_PRFPREG => "NT_PRFPREG",
+                    NT_PRPSINFO => "NT_PRPSINFO",
+                    NT_TASKSTRUCT => "NT_TASKSTRUCT",
+                    NT_AUXV => "NT_AUXV",
+                    NT_SIGINFO => "NT_SIGINFO",
+                    NT_FILE => "NT_FILE",
+                    NT_PRXFPREG => "NT_PRXFPREG",
+                    NT_X86_XSTATE => "NT_X86_XSTATE",
+                    NT_ARM_VFP => "NT_ARM_VFP",
+                    NT_ARM_TLS => "NT_ARM_TLS",
+                    _ => "NT_UNKNOWN"
+                };
+            }
             match self.n_type {
                 NT_GNU_ABI_TAG => "NT_GNU_ABI_TAG",
                 NT_GNU_HWCAP => "NT_GNU_HWCAP",
                 NT_GNU_BUILD_ID => "NT_GNU_BUILD_ID",
                 NT_GNU_GOLD_VERSION => "NT_GNU_GOLD_VERSION",
+                NT_GNU_PROPERTY_TYPE_0 => "NT_GNU_PROPERTY_TYPE_0",
                 _ => "NT_UNKNOWN"
             }
         }
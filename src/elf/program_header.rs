@@ -24,6 +24,8 @@ pub const PT_GNU_EH_FRAME: u32 = 0x6474e550;
 pub const PT_GNU_STACK: u32 = 0x6474e551;
 /// Read-only after relocation
 pub const PT_GNU_RELRO: u32 = 0x6474e552;
+/// GNU property notes for linker and run-time loaders
+pub const PT_GNU_PROPERTY: u32 = 0x6474e553;
 /// Sun Specific segment
 pub const PT_LOSUNW: u32 = 0x6ffffffa;
 /// Sun Specific segment
@@ -50,6 +52,10 @@ pub const PF_W: u32 = 1 << 1;
 /// Segment is readable
 pub const PF_R: u32 = 1 << 2;
 
+/// Special value for `e_phnum`: the actual number of program headers is in the `sh_info` field of
+/// the section header at index 0
+pub const PN_XNUM: u16 = 0xffff;
+
 pub fn pt_to_str(pt: u32) -> &'static str {
     match pt {
         PT_NULL => "PT_NULL",
@@ -65,6 +71,7 @@ pub fn pt_to_str(pt: u32) -> &'static str {
         PT_GNU_EH_FRAME => "PT_GNU_EH_FRAME",
         PT_GNU_STACK => "PT_GNU_STACK",
         PT_GNU_RELRO => "PT_GNU_RELRO",
+        PT_GNU_PROPERTY => "PT_GNU_PROPERTY",
         PT_SUNWBSS => "PT_SUNWBSS",
         PT_SUNWSTACK => "PT_SUNWSTACK",
         PT_HIOS => "PT_HIOS",
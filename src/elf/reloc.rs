@@ -43,6 +43,9 @@
 //! the value used in this relocation is the program address returned by the function,
 //! which takes no arguments, at the address of the result of the corresponding
 //! `R_X86_64_RELATIVE` relocation.
+//!
+//! `Relocator` performs these computations for the common x86_64 and AArch64 relocations of
+//! relocatable objects, see also `Elf::relocate_section`.
 
 include!("constants_relocation.rs");
 
@@ -249,6 +252,17 @@ pub mod reloc64 {
     elf_rela_std_impl!(u64, i64);
 }
 
+/// The magic of Android packed relocations
+pub const ANDROID_PACKED_MAGIC: &'static [u8; 4] = b"APS2";
+/// All relocations of the group have the same `r_info`
+pub const RELOCATION_GROUPED_BY_INFO_FLAG: i64 = 1;
+/// All relocations of the group have the same offset delta
+pub const RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG: i64 = 2;
+/// All relocations of the group have the same addend delta
+pub const RELOCATION_GROUPED_BY_ADDEND_FLAG: i64 = 4;
+/// The relocations of the group have addends
+pub const RELOCATION_GROUP_HAS_ADDEND_FLAG: i64 = 8;
+
 //////////////////////////////
 // Generic Reloc
 /////////////////////////////
@@ -290,6 +304,112 @@ if_alloc! {
             }
             Ok(relocs)
         }
+        /// Expands the `size` bytes of RELR relative relocations at `offset` (`DT_RELR`, `SHT_RELR`) into one
+        /// relocation of type `r_type` per relocated word; the addends are implicit, i.e., stored in the relocated words
+        #[cfg(feature = "endian_fd")]
+        pub fn parse_relr(bytes: &[u8], offset: usize, size: usize, r_type: u32, ctx: Ctx) -> ::error::Result<Vec<Reloc>> {
+            use scroll::Pread;
+            // bounds the table, and so the capacity, by the file before trusting DT_RELRSZ
+            let bytes: &[u8] = bytes.pread_with(offset, size)?;
+            let mut offset = 0;
+            let word = ctx.size() as u64;
+            let count = size / ctx.size();
+            let mut relocs = Vec::with_capacity(count);
+            let relative = |r_offset| Reloc { r_offset, r_addend: None, r_sym: 0, r_type };
+            let mut base = 0u64;
+            for _ in 0..count {
+                let entry = match ctx.container {
+                    Container::Little => bytes.gread_with::<u32>(&mut offset, ctx.le)? as u64,
+                    Container::Big => bytes.gread_with::<u64>(&mut offset, ctx.le)?,
+                };
+                if entry & 1 == 0 {
+                    // an address, which the following bitmaps are relative to
+                    relocs.push(relative(entry));
+                    base = entry.wrapping_add(word);
+                } else {
+                    // a bitmap, whose bit i + 1 relocates the i-th word after base
+                    let bits = word * 8 - 1;
+                    for i in 0..bits {
+                        if (entry >> (i + 1)) & 1 == 1 {
+                            relocs.push(relative(base.wrapping_add(i * word)));
+                        }
+                    }
+                    base = base.wrapping_add(bits * word);
+                }
+            }
+            Ok(relocs)
+        }
+        /// Decodes the `size` bytes of Android packed ("APS2") relocations at `offset` (`DT_ANDROID_REL`, `DT_ANDROID_RELA`)
+        #[cfg(feature = "endian_fd")]
+        pub fn parse_android(bytes: &[u8], offset: usize, size: usize, is_rela: bool, ctx: Ctx) -> ::error::Result<Vec<Reloc>> {
+            use scroll::{Pread, Sleb128};
+            use error::Error;
+            let bytes: &[u8] = bytes.pread_with(offset, size)?;
+            if size < 4 || &bytes[..4] != ANDROID_PACKED_MAGIC {
+                return Err(Error::Malformed(format!("Invalid Android packed relocations magic at {:#x}", offset)));
+            }
+            let mut offset = 4;
+            let count = Sleb128::read(bytes, &mut offset)?;
+            if count < 0 {
+                return Err(Error::Malformed(format!("Invalid Android packed relocation count: {}", count)));
+            }
+            let count = count as usize;
+            let mut r_offset = Sleb128::read(bytes, &mut offset)? as u64;
+            let mut r_info = 0u64;
+            let mut r_addend = 0i64;
+            // a group can encode any number of relocations in a few bytes, so don't trust the count for the capacity
+            let mut relocs = Vec::with_capacity(if count < size { count } else { size });
+            while relocs.len() < count {
+                let group_size = Sleb128::read(bytes, &mut offset)?;
+                let group_flags = Sleb128::read(bytes, &mut offset)?;
+                if group_size <= 0 || group_size as usize > count - relocs.len() {
+                    return Err(Error::Malformed(format!("Invalid Android packed relocation group size: {}", group_size)));
+                }
+                let grouped_by_info = group_flags & RELOCATION_GROUPED_BY_INFO_FLAG != 0;
+                let grouped_by_offset_delta = group_flags & RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG != 0;
+                let grouped_by_addend = group_flags & RELOCATION_GROUPED_BY_ADDEND_FLAG != 0;
+                let has_addend = group_flags & RELOCATION_GROUP_HAS_ADDEND_FLAG != 0;
+                let group_offset_delta = if grouped_by_offset_delta { Sleb128::read(bytes, &mut offset)? } else { 0 };
+                if grouped_by_info {
+                    r_info = Sleb128::read(bytes, &mut offset)? as u64;
+                }
+                // addends, grouped or per relocation, only exist in DT_ANDROID_RELA
+                if has_addend && !is_rela {
+                    return Err(Error::Malformed("Android packed relocations without addends have a group with addends".into()));
+                }
+                if has_addend && grouped_by_addend {
+                    r_addend = r_addend.wrapping_add(Sleb128::read(bytes, &mut offset)?);
+                } else if !has_addend {
+                    r_addend = 0;
+                }
+                for _ in 0..group_size {
+                    let delta = if grouped_by_offset_delta { group_offset_delta } else { Sleb128::read(bytes, &mut offset)? };
+                    r_offset = r_offset.wrapping_add(delta as u64);
+                    if !grouped_by_info {
+                        r_info = Sleb128::read(bytes, &mut offset)? as u64;
+                    }
+                    if has_addend && !grouped_by_addend {
+                        r_addend = r_addend.wrapping_add(Sleb128::read(bytes, &mut offset)?);
+                    }
+                    let reloc = match ctx.container {
+                        Container::Little => Reloc {
+                            r_offset: r_offset as u32 as u64,
+                            r_addend: if is_rela { Some(r_addend as i32 as i64) } else { None },
+                            r_sym: reloc32::r_sym(r_info as u32) as usize,
+                            r_type: reloc32::r_type(r_info as u32),
+                        },
+                        Container::Big => Reloc {
+                            r_offset,
+                            r_addend: if is_rela { Some(r_addend) } else { None },
+                            r_sym: reloc64::r_sym(r_info) as usize,
+                            r_type: reloc64::r_type(r_info),
+                        },
+                    };
+                    relocs.push(reloc);
+                }
+            }
+            Ok(relocs)
+        }
     }
 
     type RelocCtx = (bool, Ctx);
@@ -391,4 +511,389 @@ if_alloc! {
             }
         }
     }
+
+    /// Returns the RISC-V `R_RISCV_*_HI20` relocation of `relocs` at `label`, the value of the symbol of a
+    /// `R_RISCV_PCREL_LO12_*` relocation (see `is_riscv_pcrel_lo12`).
+    ///
+    /// The low relocation takes the low 12 bits of the PC relative offset computed for the `auipc` at
+    /// `label`, i.e., `S + A - P` of the returned relocation, and ignores its own addend
+    pub fn riscv_pcrel_hi20(relocs: &[Reloc], label: u64) -> Option<&Reloc> {
+        relocs.iter().find(|reloc| reloc.r_offset == label && is_riscv_pcrel_hi20(reloc.r_type))
+    }
+} // end if_alloc
+
+//////////////////////////////
+// Relocation engine
+/////////////////////////////
+if_alloc! {
+    use scroll::{Pread, Pwrite, Endian, LE};
+    use elf::header::{EM_X86_64, EM_AARCH64};
+
+    /// An error applying a relocation, see `Relocator`
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub enum RelocationError {
+        /// The relocation type isn't supported for the machine
+        Unsupported { machine: u16, r_type: u32 },
+        /// The relocated value doesn't fit in the relocated field
+        Overflow { r_type: u32, r_offset: u64, value: i64 },
+        /// The relocated value isn't aligned as the relocated instruction requires
+        Misaligned { r_type: u32, r_offset: u64, value: i64 },
+        /// The relocated field isn't contained in the section
+        OutOfBounds { r_type: u32, r_offset: u64 },
+        /// The relocation refers to a symbol which isn't in the symbol table
+        InvalidSymbol { r_sym: usize },
+        /// The relocation refers to an undefined symbol which couldn't be resolved
+        UndefinedSymbol { r_sym: usize },
+        /// The relocation refers to a section which wasn't given an address
+        InvalidSection { shndx: usize },
+    }
+
+    impl fmt::Display for RelocationError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match *self {
+                RelocationError::Unsupported { machine, r_type } => {
+                    write!(f, "Unsupported relocation type {} ({}) for machine {}", r_type, r_to_str(r_type, machine), machine)
+                },
+                RelocationError::Overflow { r_type, r_offset, value } => {
+                    write!(f, "Relocation type {} at {:#x} overflows with value {:#x}", r_type, r_offset, value)
+                },
+                RelocationError::Misaligned { r_type, r_offset, value } => {
+                    write!(f, "Relocation type {} at {:#x} has misaligned value {:#x}", r_type, r_offset, value)
+                },
+                RelocationError::OutOfBounds { r_type, r_offset } => {
+                    write!(f, "Relocation type {} at {:#x} is out of the section's bounds", r_type, r_offset)
+                },
+                RelocationError::InvalidSymbol { r_sym } => write!(f, "Relocation refers to invalid symbol {}", r_sym),
+                RelocationError::UndefinedSymbol { r_sym } => write!(f, "Relocation refers to undefined symbol {}", r_sym),
+                RelocationError::InvalidSection { shndx } => write!(f, "Relocation refers to section {} without an address", shndx),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl ::std::error::Error for RelocationError {
+        fn description(&self) -> &str {
+            "Relocation error"
+        }
+    }
+
+    /// The range a relocated value must be in to fit its field
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    enum Check {
+        /// The value is truncated
+        None,
+        /// A signed value
+        Signed,
+        /// An unsigned value
+        Unsigned,
+        /// Either a signed or an unsigned value
+        Either,
+    }
+
+    fn fits(value: i64, bits: u32, check: Check) -> bool {
+        if bits >= 64 {
+            return true;
+        }
+        let min = -(1i64 << (bits - 1));
+        match check {
+            Check::None => true,
+            Check::Signed => value >= min && value < 1i64 << (bits - 1),
+            Check::Unsigned => (value as u64) < 1u64 << bits,
+            Check::Either => value >= min && value < 1i64 << bits,
+        }
+    }
+
+    /// Computes `Page(address)`, the address of the 4KiB page containing `address`
+    #[inline]
+    fn page(address: u64) -> u64 {
+        address & !0xfff
+    }
+
+    /// Applies the relocations of relocatable objects (`ET_REL`) to the contents of their sections.
+    ///
+    /// Supports the common static relocation types of x86_64 and AArch64; relocations which need
+    /// a GOT, a PLT or TLS are reported as `RelocationError::Unsupported`
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    pub struct Relocator {
+        /// The machine of the relocations, i.e., `e_machine`
+        pub machine: u16,
+        /// The endianness of data; AArch64 instructions are always little endian
+        pub endian: Endian,
+    }
+
+    impl Relocator {
+        pub fn new(machine: u16, endian: Endian) -> Self {
+            Relocator { machine, endian }
+        }
+        /// Applies `reloc` to `data`, the contents of a section loaded at `address`, where `symbol` is the
+        /// address of the relocation's symbol (`S`)
+        pub fn apply(&self, data: &mut [u8], address: u64, reloc: &Reloc, symbol: u64) -> result::Result<(), RelocationError> {
+            match self.machine {
+                EM_X86_64 => self.apply_x86_64(data, address, reloc, symbol),
+                EM_AARCH64 => self.apply_aarch64(data, address, reloc, symbol),
+                machine => Err(RelocationError::Unsupported { machine, r_type: reloc.r_type }),
+            }
+        }
+        fn apply_x86_64(&self, data: &mut [u8], address: u64, reloc: &Reloc, symbol: u64) -> result::Result<(), RelocationError> {
+            let (size, pc_relative, check) = match reloc.r_type {
+                R_X86_64_NONE => return Ok(()),
+                R_X86_64_64 => (8, false, Check::None),
+                R_X86_64_PC64 => (8, true, Check::None),
+                R_X86_64_32 => (4, false, Check::Unsigned),
+                R_X86_64_32S => (4, false, Check::Signed),
+                // without a PLT, calls go straight to the symbol
+                R_X86_64_PC32 | R_X86_64_PLT32 => (4, true, Check::Signed),
+                R_X86_64_16 => (2, false, Check::Either),
+                R_X86_64_PC16 => (2, true, Check::Signed),
+                R_X86_64_8 => (1, false, Check::Either),
+                R_X86_64_PC8 => (1, true, Check::Signed),
+                r_type => return Err(RelocationError::Unsupported { machine: self.machine, r_type }),
+            };
+            let addend = match reloc.r_addend {
+                Some(addend) => addend,
+                None => self.read(data, reloc, size, self.endian)?,
+            };
+            let mut value = symbol.wrapping_add(addend as u64);
+            if pc_relative {
+                value = value.wrapping_sub(address.wrapping_add(reloc.r_offset));
+            }
+            self.write(data, reloc, size, value as i64, check, self.endian)
+        }
+        fn apply_aarch64(&self, data: &mut [u8], address: u64, reloc: &Reloc, symbol: u64) -> result::Result<(), RelocationError> {
+            let unsupported = RelocationError::Unsupported { machine: self.machine, r_type: reloc.r_type };
+            let p = address.wrapping_add(reloc.r_offset);
+            let addend = match reloc.r_addend {
+                Some(addend) => addend,
+                // the implicit addends of instructions are too awkward to be worth it, AArch64 objects use RELA
+                None => match reloc.r_type {
+                    R_AARCH64_ABS64 | R_AARCH64_PREL64 => self.read(data, reloc, 8, self.endian)?,
+                    R_AARCH64_ABS32 | R_AARCH64_PREL32 => self.read(data, reloc, 4, self.endian)?,
+                    R_AARCH64_ABS16 | R_AARCH64_PREL16 => self.read(data, reloc, 2, self.endian)?,
+                    R_AARCH64_NONE => 0,
+                    _ => return Err(unsupported),
+                },
+            };
+            let s_a = symbol.wrapping_add(addend as u64);
+            let prel = s_a.wrapping_sub(p) as i64;
+            // (mask of the immediate in the instruction, the value, the number of signed bits it must fit in, its alignment);
+            // the immediate is the value without its aligned low bits
+            let (mask, value, bits, align): (u32, i64, u32, i64) = match reloc.r_type {
+                R_AARCH64_NONE => return Ok(()),
+                R_AARCH64_ABS64 => return self.write(data, reloc, 8, s_a as i64, Check::None, self.endian),
+                R_AARCH64_ABS32 => return self.write(data, reloc, 4, s_a as i64, Check::Either, self.endian),
+                R_AARCH64_ABS16 => return self.write(data, reloc, 2, s_a as i64, Check::Either, self.endian),
+                R_AARCH64_PREL64 => return self.write(data, reloc, 8, prel, Check::None, self.endian),
+                R_AARCH64_PREL32 => return self.write(data, reloc, 4, prel, Check::Either, self.endian),
+                R_AARCH64_PREL16 => return self.write(data, reloc, 2, prel, Check::Either, self.endian),
+                R_AARCH64_CALL26 | R_AARCH64_JUMP26 => (0x03ff_ffff, prel, 28, 4),
+                R_AARCH64_CONDBR19 | R_AARCH64_LD_PREL_LO19 => (0x7ffff << 5, prel, 21, 4),
+                R_AARCH64_TSTBR14 => (0x3fff << 5, prel, 16, 4),
+                R_AARCH64_ADR_PREL_LO21 => return self.write_adr(data, reloc, prel, 21),
+                R_AARCH64_ADR_PREL_PG_HI21 => return self.write_adr(data, reloc, page(s_a).wrapping_sub(page(p)) as i64, 33),
+                R_AARCH64_ADR_PREL_PG_HI21_NC => return self.write_adr(data, reloc, page(s_a).wrapping_sub(page(p)) as i64, 64),
+                R_AARCH64_ADD_ABS_LO12_NC | R_AARCH64_LDST8_ABS_LO12_NC => (0xfff << 10, (s_a & 0xfff) as i64, 64, 1),
+                R_AARCH64_LDST16_ABS_LO12_NC => (0xfff << 10, (s_a & 0xfff) as i64, 64, 2),
+                R_AARCH64_LDST32_ABS_LO12_NC => (0xfff << 10, (s_a & 0xfff) as i64, 64, 4),
+                R_AARCH64_LDST64_ABS_LO12_NC => (0xfff << 10, (s_a & 0xfff) as i64, 64, 8),
+                R_AARCH64_LDST128_ABS_LO12_NC => (0xfff << 10, (s_a & 0xfff) as i64, 64, 16),
+                R_AARCH64_MOVW_UABS_G0 => return self.write_movw(data, reloc, s_a, 0, true),
+                R_AARCH64_MOVW_UABS_G0_NC => return self.write_movw(data, reloc, s_a, 0, false),
+                R_AARCH64_MOVW_UABS_G1 => return self.write_movw(data, reloc, s_a, 1, true),
+                R_AARCH64_MOVW_UABS_G1_NC => return self.write_movw(data, reloc, s_a, 1, false),
+                R_AARCH64_MOVW_UABS_G2 => return self.write_movw(data, reloc, s_a, 2, true),
+                R_AARCH64_MOVW_UABS_G2_NC => return self.write_movw(data, reloc, s_a, 2, false),
+                R_AARCH64_MOVW_UABS_G3 => return self.write_movw(data, reloc, s_a, 3, false),
+                _ => return Err(unsupported),
+            };
+            if value & (align - 1) != 0 {
+                return Err(RelocationError::Misaligned { r_type: reloc.r_type, r_offset: reloc.r_offset, value });
+            }
+            if !fits(value, bits, Check::Signed) {
+                return Err(RelocationError::Overflow { r_type: reloc.r_type, r_offset: reloc.r_offset, value });
+            }
+            let encoded = ((value >> align.trailing_zeros()) as u32) << mask.trailing_zeros();
+            let insn = self.read_insn(data, reloc)?;
+            self.write_insn(data, reloc, (insn & !mask) | (encoded & mask))
+        }
+        /// Writes the 21-bit immediate of an `adr` or `adrp` instruction, from `value` (`adrp` takes the page delta)
+        fn write_adr(&self, data: &mut [u8], reloc: &Reloc, value: i64, bits: u32) -> result::Result<(), RelocationError> {
+            if !fits(value, bits, Check::Signed) {
+                return Err(RelocationError::Overflow { r_type: reloc.r_type, r_offset: reloc.r_offset, value });
+            }
+            let imm = (if bits == 21 { value } else { value >> 12 }) as u32;
+            let mask = (0x3 << 29) | (0x7ffff << 5);
+            let insn = self.read_insn(data, reloc)?;
+            self.write_insn(data, reloc, (insn & !mask) | ((imm & 0x3) << 29) | (((imm >> 2) & 0x7ffff) << 5))
+        }
+        /// Writes the 16-bit immediate of a `movz` or `movk` instruction, from the 16 bits of `value` in `group`
+        fn write_movw(&self, data: &mut [u8], reloc: &Reloc, value: u64, group: u32, check: bool) -> result::Result<(), RelocationError> {
+            if check && !fits(value as i64, 16 * (group + 1), Check::Unsigned) {
+                return Err(RelocationError::Overflow { r_type: reloc.r_type, r_offset: reloc.r_offset, value: value as i64 });
+            }
+            let imm = ((value >> (16 * group)) & 0xffff) as u32;
+            let mask = 0xffff << 5;
+            let insn = self.read_insn(data, reloc)?;
+            self.write_insn(data, reloc, (insn & !mask) | (imm << 5))
+        }
+        /// Reads the implicit addend of `size` bytes of a `Rel`
+        fn read(&self, data: &[u8], reloc: &Reloc, size: usize, endian: Endian) -> result::Result<i64, RelocationError> {
+            let offset = reloc.r_offset as usize;
+            let out_of_bounds = RelocationError::OutOfBounds { r_type: reloc.r_type, r_offset: reloc.r_offset };
+            let value = match size {
+                1 => data.pread_with::<i8>(offset, endian).map(|value| value as i64),
+                2 => data.pread_with::<i16>(offset, endian).map(|value| value as i64),
+                4 => data.pread_with::<i32>(offset, endian).map(|value| value as i64),
+                _ => data.pread_with::<i64>(offset, endian),
+            };
+            value.map_err(|_| out_of_bounds)
+        }
+        /// Writes `value` into the `size` bytes relocated by `reloc`, if it fits
+        fn write(&self, data: &mut [u8], reloc: &Reloc, size: usize, value: i64, check: Check, endian: Endian) -> result::Result<(), RelocationError> {
+            if !fits(value, size as u32 * 8, check) {
+                return Err(RelocationError::Overflow { r_type: reloc.r_type, r_offset: reloc.r_offset, value });
+            }
+            let offset = reloc.r_offset as usize;
+            let written = match size {
+                1 => data.pwrite_with(value as u8, offset, endian),
+                2 => data.pwrite_with(value as u16, offset, endian),
+                4 => data.pwrite_with(value as u32, offset, endian),
+                _ => data.pwrite_with(value as u64, offset, endian),
+            };
+            match written {
+                Ok(_) => Ok(()),
+                Err(_) => Err(RelocationError::OutOfBounds { r_type: reloc.r_type, r_offset: reloc.r_offset }),
+            }
+        }
+        fn read_insn(&self, data: &[u8], reloc: &Reloc) -> result::Result<u32, RelocationError> {
+            data.pread_with::<u32>(reloc.r_offset as usize, LE)
+                .map_err(|_| RelocationError::OutOfBounds { r_type: reloc.r_type, r_offset: reloc.r_offset })
+        }
+        fn write_insn(&self, data: &mut [u8], reloc: &Reloc, insn: u32) -> result::Result<(), RelocationError> {
+            match data.pwrite_with(insn, reloc.r_offset as usize, LE) {
+                Ok(_) => Ok(()),
+                Err(_) => Err(RelocationError::OutOfBounds { r_type: reloc.r_type, r_offset: reloc.r_offset }),
+            }
+        }
+    }
 } // end if_alloc
+
+#[cfg(all(test, feature = "endian_fd"))]
+mod tests {
+    use super::*;
+    use container::{Container, Ctx};
+    use scroll::{self, Pwrite};
+
+    #[test]
+    fn parse_relr() {
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let mut bytes = [0u8; 24];
+        bytes.pwrite_with(0x1000u64, 0, scroll::LE).unwrap();
+        // relocates the first and third word after 0x1000
+        bytes.pwrite_with(1u64 | 1 << 1 | 1 << 3, 8, scroll::LE).unwrap();
+        bytes.pwrite_with(0x2000u64, 16, scroll::LE).unwrap();
+        let relocs = Reloc::parse_relr(&bytes, 0, bytes.len(), R_X86_64_RELATIVE, ctx).unwrap();
+        let offsets: Vec<u64> = relocs.iter().map(|reloc| reloc.r_offset).collect();
+        assert_eq!(offsets, vec![0x1000, 0x1008, 0x1018, 0x2000]);
+        assert!(relocs.iter().all(|reloc| reloc.r_type == R_X86_64_RELATIVE && reloc.r_addend.is_none()));
+        assert!(Reloc::parse_relr(&bytes, 8, bytes.len(), R_X86_64_RELATIVE, ctx).is_err());
+        assert!(Reloc::parse_relr(&bytes, 0, usize::max_value(), R_X86_64_RELATIVE, ctx).is_err());
+    }
+
+    #[test]
+    fn parse_android_packed() {
+        let ctx = Ctx::new(Container::Big, scroll::LE);
+        let bytes = [
+            b'A', b'P', b'S', b'2',
+            // 3 relocations, starting at 0x1000
+            0x03, 0x80, 0x20,
+            // 2 relocations every 8 bytes with r_info 8 and addend deltas 0x10 and 0x8
+            0x02, 0x0b, 0x08, 0x08, 0x10, 0x08,
+            // 1 relocation without an addend 0x10 bytes later, with r_info (1 << 32) | 7
+            0x01, 0x00, 0x10, 0x87, 0x80, 0x80, 0x80, 0x10,
+        ];
+        let relocs = Reloc::parse_android(&bytes, 0, bytes.len(), true, ctx).unwrap();
+        assert_eq!(relocs.len(), 3);
+        assert_eq!((relocs[0].r_offset, relocs[0].r_type, relocs[0].r_addend), (0x1008, R_X86_64_RELATIVE, Some(0x10)));
+        assert_eq!((relocs[1].r_offset, relocs[1].r_type, relocs[1].r_addend), (0x1010, R_X86_64_RELATIVE, Some(0x18)));
+        assert_eq!((relocs[2].r_offset, relocs[2].r_sym, relocs[2].r_type, relocs[2].r_addend), (0x1020, 1, R_X86_64_JUMP_SLOT, Some(0)));
+        assert!(Reloc::parse_android(&bytes[1..], 0, bytes.len() - 1, true, ctx).is_err());
+        // the first group has per relocation addends, which DT_ANDROID_REL can't have
+        assert!(Reloc::parse_android(&bytes, 0, bytes.len(), false, ctx).is_err());
+        // nor can it have a grouped addend
+        let bytes = [b'A', b'P', b'S', b'2', 0x01, 0x80, 0x20, 0x01, 0x0f, 0x08, 0x08, 0x10];
+        assert!(Reloc::parse_android(&bytes, 0, bytes.len(), false, ctx).is_err());
+        assert_eq!(Reloc::parse_android(&bytes, 0, bytes.len(), true, ctx).unwrap()[0].r_addend, Some(0x10));
+    }
+
+    #[test]
+    fn machine_relocations() {
+        use elf::header::{EM_RISCV, EM_PPC64, EM_S390, EM_SPARCV9, EM_LOONGARCH};
+        use elf::sym::ppc64_local_entry_offset;
+        assert_eq!(r_to_str(R_RISCV_PCREL_HI20, EM_RISCV), "R_RISCV_PCREL_HI20");
+        assert_eq!(r_to_str(R_PPC64_TOC16_HA, EM_PPC64), "R_PPC64_TOC16_HA");
+        assert_eq!(r_to_str(R_390_GLOB_DAT, EM_S390), "R_390_GLOB_DAT");
+        assert_eq!(r_to_str(R_SPARC_JMP_SLOT, EM_SPARCV9), "R_SPARC_JMP_SLOT");
+        assert_eq!(r_to_str(R_LARCH_PCALA_HI20, EM_LOONGARCH), "R_LARCH_PCALA_HI20");
+        assert_eq!(r_to_str(0xff, EM_RISCV), "R_UNKNOWN_RISCV");
+        assert_eq!(r_relative(EM_PPC64), Some(R_PPC64_RELATIVE));
+        // the low part is sign extended, so the high part rounds up
+        assert_eq!((riscv_hi20(0x1234_5678), riscv_lo12(0x1234_5678)), (0x12345, 0x678));
+        assert_eq!((riscv_hi20(0x1234_5878), riscv_lo12(0x1234_5878)), (0x12346, -0x788));
+        assert_eq!((riscv_hi20(-4), riscv_lo12(-4)), (0, -4));
+        // auipc a0, %pcrel_hi(sym) at 0x10; addi a0, a0, %pcrel_lo(.L0) at 0x14, where .L0 is 0x10
+        let relocs = [reloc(0x10, R_RISCV_PCREL_HI20, Some(8)), reloc(0x14, R_RISCV_PCREL_LO12_I, Some(0))];
+        assert!(is_riscv_pcrel_lo12(relocs[1].r_type));
+        assert_eq!(riscv_pcrel_hi20(&relocs, 0x10), Some(&relocs[0]));
+        assert_eq!(riscv_pcrel_hi20(&relocs, 0x14), None);
+        assert_eq!(ppc64_local_entry_offset(0), 0);
+        assert_eq!(ppc64_local_entry_offset(3 << 5), 8);
+        assert_eq!(ppc64_local_entry_offset(6 << 5), 64);
+    }
+
+    fn reloc(r_offset: u64, r_type: u32, r_addend: Option<i64>) -> Reloc {
+        Reloc { r_offset, r_addend, r_sym: 1, r_type }
+    }
+
+    #[test]
+    fn relocate_x86_64() {
+        use elf::header::EM_X86_64;
+        let relocator = Relocator::new(EM_X86_64, scroll::LE);
+        let mut data = [0u8; 16];
+        data[8] = 0x10;
+        relocator.apply(&mut data, 0x1000, &reloc(0, R_X86_64_PC32, Some(-4)), 0x2000).unwrap();
+        assert_eq!(data[..4], [0xfc, 0x0f, 0, 0]);
+        // the implicit addend of a Rel
+        relocator.apply(&mut data, 0x1000, &reloc(8, R_X86_64_64, None), 0x100).unwrap();
+        assert_eq!(data[8..], [0x10, 0x01, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(relocator.apply(&mut data, 0x1000, &reloc(0, R_X86_64_32, Some(0)), 0x1_0000_0000),
+                   Err(RelocationError::Overflow { r_type: R_X86_64_32, r_offset: 0, value: 0x1_0000_0000 }));
+        assert_eq!(relocator.apply(&mut data, 0x1000, &reloc(14, R_X86_64_32, Some(0)), 0),
+                   Err(RelocationError::OutOfBounds { r_type: R_X86_64_32, r_offset: 14 }));
+        assert_eq!(relocator.apply(&mut data, 0x1000, &reloc(0, R_X86_64_GOTPCREL, Some(0)), 0),
+                   Err(RelocationError::Unsupported { machine: EM_X86_64, r_type: R_X86_64_GOTPCREL }));
+    }
+
+    #[test]
+    fn relocate_aarch64() {
+        use elf::header::EM_AARCH64;
+        let relocator = Relocator::new(EM_AARCH64, scroll::LE);
+        let insns = |data: &[u8]| -> Vec<u32> { (0..data.len() / 4).map(|i| data.pread_with(i * 4, scroll::LE).unwrap()).collect() };
+        let mut data = [0u8; 20];
+        // bl; adrp x0; add x0, x0; ldr x1, [x0]; movz x0, lsl 16
+        for (i, insn) in [0x9400_0000u32, 0x9000_0000, 0x9100_0000, 0xf940_0001, 0xd2a0_0000].iter().enumerate() {
+            data.pwrite_with(*insn, i * 4, scroll::LE).unwrap();
+        }
+        relocator.apply(&mut data, 0x1000, &reloc(0, R_AARCH64_CALL26, Some(0)), 0x2000).unwrap();
+        relocator.apply(&mut data, 0x1000, &reloc(4, R_AARCH64_ADR_PREL_PG_HI21, Some(0)), 0x1234_5678).unwrap();
+        relocator.apply(&mut data, 0x1000, &reloc(8, R_AARCH64_ADD_ABS_LO12_NC, Some(0)), 0x1234_5678).unwrap();
+        relocator.apply(&mut data, 0x1000, &reloc(12, R_AARCH64_LDST64_ABS_LO12_NC, Some(0)), 0x1234_5678).unwrap();
+        relocator.apply(&mut data, 0x1000, &reloc(16, R_AARCH64_MOVW_UABS_G1_NC, Some(0)), 0x1234_5678).unwrap();
+        assert_eq!(insns(&data), vec![0x9400_0400, 0x9009_1a20, 0x9119_e000, 0xf943_3c01, 0xd2a2_4680]);
+        assert_eq!(relocator.apply(&mut data, 0x1000, &reloc(0, R_AARCH64_CALL26, Some(0)), 0x1000 + (1 << 27)),
+                   Err(RelocationError::Overflow { r_type: R_AARCH64_CALL26, r_offset: 0, value: 1 << 27 }));
+        assert_eq!(relocator.apply(&mut data, 0x1000, &reloc(12, R_AARCH64_LDST64_ABS_LO12_NC, Some(0)), 0x1234_5674),
+                   Err(RelocationError::Misaligned { r_type: R_AARCH64_LDST64_ABS_LO12_NC, r_offset: 12, value: 0x674 }));
+        assert_eq!(relocator.apply(&mut data, 0x1000, &reloc(16, R_AARCH64_MOVW_UABS_G1, Some(0)), 0x1_0000_0000),
+                   Err(RelocationError::Overflow { r_type: R_AARCH64_MOVW_UABS_G1, r_offset: 16, value: 0x1_0000_0000 }));
+    }
+}
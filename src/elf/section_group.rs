@@ -0,0 +1,80 @@
+//! Section groups (`SHT_GROUP`), mostly used for the COMDAT deduplication of C++ inline functions and templates.
+//!
+//! The contents of a group section are an array of 32-bit words: a flag word, followed by the
+//! indices of the member sections. The section's `sh_link` is the index of the symbol table
+//! containing the group's signature symbol, and `sh_info` is the index of that symbol.
+//!
+//! See: https://refspecs.linuxfoundation.org/elf/gabi4+/ch4.sheader.html#section_groups
+
+/// The group is a COMDAT group: the linker keeps only one of the groups with the same signature
+pub const GRP_COMDAT: u32 = 0x1;
+/// Bits reserved for operating system specific semantics
+pub const GRP_MASKOS: u32 = 0x0ff0_0000;
+/// Bits reserved for processor specific semantics
+pub const GRP_MASKPROC: u32 = 0xf000_0000;
+
+if_alloc! {
+    use alloc::vec::Vec;
+    use scroll::{Pread, Endian};
+    use error;
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    /// A section group, i.e., a set of sections which the linker keeps or discards as a unit
+    pub struct SectionGroup<'a> {
+        /// The index of the `SHT_GROUP` section describing this group
+        pub index: usize,
+        /// The name of the group's signature symbol, which identifies duplicate COMDAT groups
+        pub signature: &'a str,
+        /// The group's flags, see `GRP_*`
+        pub flags: u32,
+        /// The indices of the sections in this group
+        pub sections: Vec<usize>,
+    }
+
+    impl<'a> SectionGroup<'a> {
+        /// Whether this is a COMDAT group
+        pub fn is_comdat(&self) -> bool {
+            self.flags & GRP_COMDAT != 0
+        }
+        /// Whether the section at `index` is a member of this group
+        pub fn contains(&self, index: usize) -> bool {
+            self.sections.contains(&index)
+        }
+    }
+
+    /// Parses the flag word and the member section indices of the `size` bytes of group section contents at `offset`
+    pub fn parse_group(bytes: &[u8], mut offset: usize, size: usize, endian: Endian) -> error::Result<(u32, Vec<usize>)> {
+        if size < 4 {
+            return Err(error::Error::Malformed(format!("Section group at {:#x} is too small: {} bytes", offset, size)));
+        }
+        let flags = bytes.gread_with::<u32>(&mut offset, endian)?;
+        let count = size / 4 - 1;
+        let mut sections = Vec::with_capacity(::core::cmp::min(count, bytes.len().saturating_sub(offset) / 4));
+        for _ in 0..count {
+            sections.push(bytes.gread_with::<u32>(&mut offset, endian)? as usize);
+        }
+        Ok((flags, sections))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use scroll::{self, Pwrite};
+
+        #[test]
+        fn parse_comdat_group() {
+            let mut bytes = [0u8; 12];
+            bytes.pwrite_with(GRP_COMDAT, 0, scroll::BE).unwrap();
+            bytes.pwrite_with(5u32, 4, scroll::BE).unwrap();
+            bytes.pwrite_with(6u32, 8, scroll::BE).unwrap();
+            let (flags, sections) = parse_group(&bytes, 0, bytes.len(), scroll::BE).unwrap();
+            let group = SectionGroup { index: 3, signature: "_Z3foov", flags, sections };
+            assert!(group.is_comdat());
+            assert_eq!(group.sections, vec![5, 6]);
+            assert!(group.contains(6));
+            assert!(!group.contains(3));
+            assert!(parse_group(&bytes, 0, 2, scroll::BE).is_err());
+            assert!(parse_group(&bytes, 0, ::core::usize::MAX, scroll::BE).is_err());
+        }
+    }
+}
@@ -113,10 +113,18 @@ pub const SHT_PREINIT_ARRAY: u32 = 16;
 pub const SHT_GROUP: u32 = 17;
 /// Extended section indeces.
 pub const SHT_SYMTAB_SHNDX: u32 = 18;
+/// RELR relative relocations.
+pub const SHT_RELR: u32 = 19;
 /// Number of defined types.
-pub const SHT_NUM: u32 = 19;
+pub const SHT_NUM: u32 = 20;
 /// Start OS-specific.
 pub const SHT_LOOS: u32 = 0x60000000;
+/// Android packed relocations without addends.
+pub const SHT_ANDROID_REL: u32 = 0x60000001;
+/// Android packed relocations with addends.
+pub const SHT_ANDROID_RELA: u32 = 0x60000002;
+/// Android RELR relative relocations.
+pub const SHT_ANDROID_RELR: u32 = 0x6fffff00;
 /// Object attributes.
 pub const SHT_GNU_ATTRIBUTES: u32 = 0x6ffffff5;
 /// GNU-style hash table.
@@ -217,8 +225,12 @@ pub fn sht_to_str(sht: u32) -> &'static str {
         SHT_PREINIT_ARRAY => "SHT_PREINIT_ARRAY",
         SHT_GROUP => "SHT_GROUP",
         SHT_SYMTAB_SHNDX => "SHT_SYMTAB_SHNDX",
+        SHT_RELR => "SHT_RELR",
         SHT_NUM => "SHT_NUM",
         SHT_LOOS => "SHT_LOOS",
+        SHT_ANDROID_REL => "SHT_ANDROID_REL",
+        SHT_ANDROID_RELA => "SHT_ANDROID_RELA",
+        SHT_ANDROID_RELR => "SHT_ANDROID_RELR",
         SHT_GNU_ATTRIBUTES => "SHT_GNU_ATTRIBUTES",
         SHT_GNU_HASH => "SHT_GNU_HASH",
         SHT_GNU_LIBLIST => "SHT_GNU_LIBLIST",
@@ -371,6 +383,8 @@ if_alloc! {
 
     #[cfg(feature = "endian_fd")]
     use alloc::vec::Vec;
+    #[cfg(all(feature = "endian_fd", feature = "compression"))]
+    use alloc::borrow::Cow;
 
     #[derive(Default, PartialEq, Clone)]
     /// A unified SectionHeader - convertable to and from 32-bit and 64-bit variants
@@ -461,6 +475,37 @@ if_alloc! {
         pub fn is_alloc(&self) -> bool {
             self.sh_flags as u32 & SHF_ALLOC == SHF_ALLOC
         }
+        /// Whether this section's contents start with a compression header (`SHF_COMPRESSED`)
+        pub fn is_compressed(&self) -> bool {
+            self.sh_flags as u32 & SHF_COMPRESSED == SHF_COMPRESSED
+        }
+        /// Returns this section's contents in `bytes`, decompressing them if the section is `SHF_COMPRESSED`
+        ///
+        /// Returns a `Malformed` error if the compression type is unsupported, or if the decompressed
+        /// length does not match the header's `ch_size`
+        #[cfg(all(feature = "endian_fd", feature = "compression"))]
+        pub fn data<'a>(&self, bytes: &'a [u8], ctx: Ctx) -> error::Result<Cow<'a, [u8]>> {
+            use scroll::Pread;
+            use elf::compression_header::{CompressionHeader, ELFCOMPRESS_ZLIB};
+            if self.sh_type == SHT_NOBITS {
+                return Ok(Cow::Borrowed(&[]));
+            }
+            self.check_size(bytes.len())?;
+            let data = &bytes[self.file_range()];
+            if !self.is_compressed() {
+                return Ok(Cow::Borrowed(data));
+            }
+            let chdr: CompressionHeader = data.pread_with(0, ctx)?;
+            if chdr.ch_type != ELFCOMPRESS_ZLIB {
+                return Err(error::Error::Malformed(format!("Section {} has unsupported compression type {}", self.sh_name, chdr.ch_type)));
+            }
+            let decompressed = ::inflate::zlib_decompress(&data[CompressionHeader::size(&ctx)..], chdr.ch_size as usize)?;
+            if decompressed.len() as u64 != chdr.ch_size {
+                return Err(error::Error::Malformed(format!("Section {} decompressed to {} bytes, but its compression header says {}",
+                    self.sh_name, decompressed.len(), chdr.ch_size)));
+            }
+            Ok(Cow::Owned(decompressed))
+        }
     }
 
     impl fmt::Debug for SectionHeader {
@@ -545,3 +590,44 @@ if_alloc! {
         }
     }
 } // end if_alloc
+
+#[cfg(all(test, feature = "endian_fd", feature = "compression"))]
+mod tests {
+    use super::*;
+    use scroll::{Pwrite, LE};
+    use container::{Container, Ctx};
+    use elf::compression_header::{CompressionHeader, ELFCOMPRESS_ZLIB};
+
+    // zlib.compress(b"hello hello hello hello goblin\n", 9)
+    const HELLO: [u8; 23] = [
+        0x78, 0xda, 0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0xc8, 0xc0, 0x20, 0xd3, 0xf3, 0x93,
+        0x72, 0x32, 0xf3, 0xb8, 0x00, 0xb9, 0xb1, 0x0b, 0x56];
+
+    fn compressed_section(ch_size: u64) -> (Vec<u8>, SectionHeader) {
+        let ctx = Ctx::new(Container::Big, LE);
+        let mut bytes = vec![0u8; CompressionHeader::size(&ctx) + HELLO.len()];
+        let chdr = CompressionHeader { ch_type: ELFCOMPRESS_ZLIB, ch_size, ch_addralign: 1 };
+        let offset = bytes.pwrite_with(chdr, 0, ctx).unwrap();
+        bytes[offset..].copy_from_slice(&HELLO);
+        let shdr = SectionHeader {
+            sh_type: SHT_PROGBITS,
+            sh_flags: SHF_COMPRESSED as u64,
+            sh_size: bytes.len() as u64,
+            ..SectionHeader::new()
+        };
+        (bytes, shdr)
+    }
+
+    #[test]
+    fn decompress_section() {
+        let (bytes, shdr) = compressed_section(31);
+        let data = shdr.data(&bytes, Ctx::new(Container::Big, LE)).unwrap();
+        assert_eq!(&data[..], &b"hello hello hello hello goblin\n"[..]);
+    }
+
+    #[test]
+    fn decompressed_size_mismatch() {
+        let (bytes, shdr) = compressed_section(32);
+        assert!(shdr.data(&bytes, Ctx::new(Container::Big, LE)).is_err());
+    }
+}
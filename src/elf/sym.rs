@@ -46,6 +46,21 @@ pub const STT_LOPROC: u8 = 13;
 /// End of processor-specific.
 pub const STT_HIPROC: u8 = 15;
 
+/// === PowerPC64 st_other ===
+/// The first bit of the local entry offset of an ELFv2 function.
+pub const STO_PPC64_LOCAL_BIT: u8 = 5;
+/// The bits of the local entry offset of an ELFv2 function.
+pub const STO_PPC64_LOCAL_MASK: u8 = 7 << STO_PPC64_LOCAL_BIT;
+
+/// Get the offset of the local entry point of a PowerPC64 ELFv2 function from its global entry point.
+///
+/// The global entry point sets up the TOC pointer; calls from the same module, sharing the TOC,
+/// enter at `st_value` plus this offset.
+#[inline]
+pub fn ppc64_local_entry_offset(other: u8) -> u64 {
+    ((1u64 << ((other & STO_PPC64_LOCAL_MASK) >> STO_PPC64_LOCAL_BIT)) >> 2) << 2
+}
+
 /// Get the ST bind.
 ///
 /// This is the first four bits of the byte.
@@ -467,6 +482,12 @@ if_alloc! {
             self.count
         }
 
+        /// Whether the table has no symbols.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.count == 0
+        }
+
         /// Iterate over all symbols.
         #[inline]
         pub fn iter(&self) -> SymIterator<'a> {
@@ -0,0 +1,424 @@
+//! Symbol versioning, as implemented by the GNU toolchain.
+//!
+//! Three sections cooperate to describe symbol versions:
+//!
+//!   1. `.gnu.version` (`SHT_GNU_VERSYM`, `DT_VERSYM`): an array of `u16`s, parallel to the dynamic symbol table
+//!   2. `.gnu.version_d` (`SHT_GNU_VERDEF`, `DT_VERDEF`): the versions this object defines
+//!   3. `.gnu.version_r` (`SHT_GNU_VERNEED`, `DT_VERNEED`): the versions this object requires from its dependencies
+//!
+//! A versym entry is an index which is matched against `vd_ndx` of a version definition, or
+//! `vna_other` of a version requirement. Its top bit marks the symbol as hidden.
+//!
+//! See: https://refspecs.linuxfoundation.org/LSB_5.0.0/LSB-Core-generic/LSB-Core-generic/symversion.html
+
+/// Symbol is local, i.e., not available outside the object
+pub const VER_NDX_LOCAL: u16 = 0;
+/// Symbol is global and unversioned, i.e., the base definition
+pub const VER_NDX_GLOBAL: u16 = 1;
+/// Beginning of reserved entries
+pub const VER_NDX_LORESERVE: u16 = 0xff00;
+/// Symbol is to be eliminated
+pub const VER_NDX_ELIMINATE: u16 = 0xff01;
+
+/// Bit set in a versym entry when the symbol is hidden
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+/// Mask of the version index in a versym entry
+pub const VERSYM_VERSION: u16 = 0x7fff;
+
+/// Version definition of the file itself
+pub const VER_FLG_BASE: u16 = 0x1;
+/// Weak version identifier
+pub const VER_FLG_WEAK: u16 = 0x2;
+
+/// Current version of the version definition structure
+pub const VER_DEF_CURRENT: u16 = 1;
+/// Current version of the version requirement structure
+pub const VER_NEED_CURRENT: u16 = 1;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "alloc", derive(Pread, Pwrite, SizeWith))]
+/// An entry in the version symbol table; identical for 32 and 64-bit binaries
+pub struct Versym {
+    /// The version index, and possibly the `VERSYM_HIDDEN` bit
+    pub vs_val: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "alloc", derive(Pread, Pwrite, SizeWith))]
+/// A version definition; identical for 32 and 64-bit binaries
+pub struct Verdef {
+    /// Version revision, should be `VER_DEF_CURRENT`
+    pub vd_version: u16,
+    /// Version information flags
+    pub vd_flags: u16,
+    /// Version index, as referenced from versym entries
+    pub vd_ndx: u16,
+    /// Number of associated `Verdaux` entries
+    pub vd_cnt: u16,
+    /// ELF hash of the version name
+    pub vd_hash: u32,
+    /// Offset in bytes to the first `Verdaux` entry
+    pub vd_aux: u32,
+    /// Offset in bytes to the next `Verdef` entry
+    pub vd_next: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "alloc", derive(Pread, Pwrite, SizeWith))]
+/// Auxiliary version definition information: the names of the version and its predecessors
+pub struct Verdaux {
+    /// Version or dependency name (string tbl index)
+    pub vda_name: u32,
+    /// Offset in bytes to the next `Verdaux` entry
+    pub vda_next: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "alloc", derive(Pread, Pwrite, SizeWith))]
+/// A version requirement on a single dependency; identical for 32 and 64-bit binaries
+pub struct Verneed {
+    /// Version of the structure, should be `VER_NEED_CURRENT`
+    pub vn_version: u16,
+    /// Number of associated `Vernaux` entries
+    pub vn_cnt: u16,
+    /// Filename of the dependency (string tbl index)
+    pub vn_file: u32,
+    /// Offset in bytes to the first `Vernaux` entry
+    pub vn_aux: u32,
+    /// Offset in bytes to the next `Verneed` entry
+    pub vn_next: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "alloc", derive(Pread, Pwrite, SizeWith))]
+/// Auxiliary version requirement information: a single version needed from the dependency
+pub struct Vernaux {
+    /// ELF hash of the version name
+    pub vna_hash: u32,
+    /// Version information flags
+    pub vna_flags: u16,
+    /// Version index, as referenced from versym entries
+    pub vna_other: u16,
+    /// Version name (string tbl index)
+    pub vna_name: u32,
+    /// Offset in bytes to the next `Vernaux` entry
+    pub vna_next: u32,
+}
+
+pub const SIZEOF_VERSYM: usize = 2;
+pub const SIZEOF_VERDEF: usize = 20;
+pub const SIZEOF_VERDAUX: usize = 8;
+pub const SIZEOF_VERNEED: usize = 16;
+pub const SIZEOF_VERNAUX: usize = 16;
+
+use plain;
+unsafe impl plain::Plain for Versym {}
+unsafe impl plain::Plain for Verdef {}
+unsafe impl plain::Plain for Verdaux {}
+unsafe impl plain::Plain for Verneed {}
+unsafe impl plain::Plain for Vernaux {}
+
+impl Versym {
+    /// The version index of this entry, without the hidden bit
+    #[inline]
+    pub fn version(&self) -> u16 {
+        self.vs_val & VERSYM_VERSION
+    }
+    /// Whether this symbol is hidden, i.e., not the default version of the symbol
+    #[inline]
+    pub fn is_hidden(&self) -> bool {
+        self.vs_val & VERSYM_HIDDEN != 0
+    }
+    /// Whether this symbol is local to the object
+    #[inline]
+    pub fn is_local(&self) -> bool {
+        self.version() == VER_NDX_LOCAL
+    }
+    /// Whether this symbol is global and unversioned
+    #[inline]
+    pub fn is_global(&self) -> bool {
+        self.version() == VER_NDX_GLOBAL
+    }
+}
+
+if_alloc! {
+    use error;
+    use strtab::Strtab;
+    use alloc::vec::Vec;
+
+    #[derive(Debug, Clone, PartialEq)]
+    /// A parsed version definition, with its names resolved
+    pub struct VersionDefinition<'a> {
+        /// Version information flags, e.g., `VER_FLG_BASE`
+        pub flags: u16,
+        /// The version index, as referenced from versym entries
+        pub index: u16,
+        /// ELF hash of the version name
+        pub hash: u32,
+        /// The version name, followed by the names of the versions it inherits from
+        pub names: Vec<&'a str>,
+    }
+
+    impl<'a> VersionDefinition<'a> {
+        /// The name of this version, if it has one
+        pub fn name(&self) -> Option<&'a str> {
+            self.names.first().cloned()
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    /// A single version required from a dependency
+    pub struct VersionAux<'a> {
+        /// ELF hash of the version name
+        pub hash: u32,
+        /// Version information flags, e.g., `VER_FLG_WEAK`
+        pub flags: u16,
+        /// The version index, as referenced from versym entries
+        pub index: u16,
+        /// The version name, e.g., `GLIBC_2.28`
+        pub name: &'a str,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    /// The versions required from a single dependency
+    pub struct VersionNeed<'a> {
+        /// The dependency's file name, e.g., `libc.so.6`
+        pub file: &'a str,
+        /// The versions required from `file`
+        pub versions: Vec<VersionAux<'a>>,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    /// The resolved version of a symbol
+    pub struct SymbolVersion<'a> {
+        /// The version name, e.g., `GLIBC_2.2.5`
+        pub name: &'a str,
+        /// The file the version is required from, or `None` if it is defined by this object
+        pub file: Option<&'a str>,
+        /// The version index
+        pub index: u16,
+        /// Whether the symbol is hidden, i.e., not the default version
+        pub hidden: bool,
+    }
+
+    #[derive(Debug, Default)]
+    /// All symbol versioning information of a binary
+    pub struct SymbolVersions<'a> {
+        /// The version symbol table, parallel to the dynamic symbol table
+        pub versym: Vec<Versym>,
+        /// The version definitions
+        pub verdef: Vec<VersionDefinition<'a>>,
+        /// The version requirements
+        pub verneed: Vec<VersionNeed<'a>>,
+    }
+
+    fn get_str<'a>(strtab: &Strtab<'a>, idx: u32) -> error::Result<&'a str> {
+        match strtab.get(idx as usize) {
+            Some(name) => name,
+            None => Err(error::Error::Malformed(format!("Version string index {:#x} is out of bounds", idx))),
+        }
+    }
+
+    #[cfg(feature = "endian_fd")]
+    /// Bounds the untrusted entry count `count` by the number of `entsize` entries left in `bytes` after `offset`
+    fn capacity(bytes: &[u8], offset: usize, count: usize, entsize: usize) -> usize {
+        ::core::cmp::min(count, bytes.len().saturating_sub(offset) / entsize)
+    }
+
+    #[cfg(feature = "endian_fd")]
+    /// Parse `count` versym entries from `bytes` at `offset`
+    pub fn parse_versym(bytes: &[u8], mut offset: usize, count: usize, le: ::scroll::Endian) -> error::Result<Vec<Versym>> {
+        use scroll::Pread;
+        let mut versym = Vec::with_capacity(capacity(bytes, offset, count, SIZEOF_VERSYM));
+        for _ in 0..count {
+            versym.push(bytes.gread_with(&mut offset, le)?);
+        }
+        Ok(versym)
+    }
+
+    #[cfg(feature = "endian_fd")]
+    /// Parse `count` chained version definitions from `bytes` at `offset`, resolving names in `strtab`
+    pub fn parse_verdef<'a>(bytes: &[u8], offset: usize, count: usize, strtab: &Strtab<'a>, le: ::scroll::Endian) -> error::Result<Vec<VersionDefinition<'a>>> {
+        use scroll::Pread;
+        let mut verdefs = Vec::with_capacity(capacity(bytes, offset, count, SIZEOF_VERDEF));
+        let mut offset = offset;
+        for _ in 0..count {
+            let verdef: Verdef = bytes.pread_with(offset, le)?;
+            let mut aux_offset = offset.saturating_add(verdef.vd_aux as usize);
+            let mut names = Vec::with_capacity(capacity(bytes, aux_offset, verdef.vd_cnt as usize, SIZEOF_VERDAUX));
+            for _ in 0..verdef.vd_cnt {
+                let verdaux: Verdaux = bytes.pread_with(aux_offset, le)?;
+                names.push(get_str(strtab, verdaux.vda_name)?);
+                if verdaux.vda_next == 0 { break }
+                aux_offset = aux_offset.saturating_add(verdaux.vda_next as usize);
+            }
+            verdefs.push(VersionDefinition {
+                flags: verdef.vd_flags,
+                index: verdef.vd_ndx,
+                hash: verdef.vd_hash,
+                names,
+            });
+            if verdef.vd_next == 0 { break }
+            offset = offset.saturating_add(verdef.vd_next as usize);
+        }
+        Ok(verdefs)
+    }
+
+    #[cfg(feature = "endian_fd")]
+    /// Parse `count` chained version requirements from `bytes` at `offset`, resolving names in `strtab`
+    pub fn parse_verneed<'a>(bytes: &[u8], offset: usize, count: usize, strtab: &Strtab<'a>, le: ::scroll::Endian) -> error::Result<Vec<VersionNeed<'a>>> {
+        use scroll::Pread;
+        let mut verneeds = Vec::with_capacity(capacity(bytes, offset, count, SIZEOF_VERNEED));
+        let mut offset = offset;
+        for _ in 0..count {
+            let verneed: Verneed = bytes.pread_with(offset, le)?;
+            let mut aux_offset = offset.saturating_add(verneed.vn_aux as usize);
+            let mut versions = Vec::with_capacity(capacity(bytes, aux_offset, verneed.vn_cnt as usize, SIZEOF_VERNAUX));
+            for _ in 0..verneed.vn_cnt {
+                let vernaux: Vernaux = bytes.pread_with(aux_offset, le)?;
+                versions.push(VersionAux {
+                    hash: vernaux.vna_hash,
+                    flags: vernaux.vna_flags,
+                    index: vernaux.vna_other,
+                    name: get_str(strtab, vernaux.vna_name)?,
+                });
+                if vernaux.vna_next == 0 { break }
+                aux_offset = aux_offset.saturating_add(vernaux.vna_next as usize);
+            }
+            verneeds.push(VersionNeed {
+                file: get_str(strtab, verneed.vn_file)?,
+                versions,
+            });
+            if verneed.vn_next == 0 { break }
+            offset = offset.saturating_add(verneed.vn_next as usize);
+        }
+        Ok(verneeds)
+    }
+
+    impl<'a> SymbolVersions<'a> {
+        /// Whether this binary has no symbol versioning information at all
+        pub fn is_empty(&self) -> bool {
+            self.versym.is_empty() && self.verdef.is_empty() && self.verneed.is_empty()
+        }
+        /// Returns the version of the dynamic symbol at `index`, or `None` if it is local, global or unversioned
+        pub fn get(&self, index: usize) -> Option<SymbolVersion<'a>> {
+            if index >= self.versym.len() {
+                return None;
+            }
+            let versym = &self.versym[index];
+            let version = versym.version();
+            if version == VER_NDX_LOCAL || version == VER_NDX_GLOBAL {
+                return None;
+            }
+            let hidden = versym.is_hidden();
+            for verdef in &self.verdef {
+                if verdef.index == version {
+                    return verdef.name().map(|name| SymbolVersion { name, file: None, index: version, hidden });
+                }
+            }
+            for verneed in &self.verneed {
+                for aux in &verneed.versions {
+                    if aux.index == version {
+                        return Some(SymbolVersion { name: aux.name, file: Some(verneed.file), index: version, hidden });
+                    }
+                }
+            }
+            None
+        }
+    }
+} // end if_alloc
+
+#[cfg(all(test, feature = "endian_fd"))]
+mod tests {
+    use super::*;
+    use strtab::Strtab;
+
+    // "\0libc.so.6\0GLIBC_2.2.5\0GLIBC_2.28\0libfoo.so\0"
+    const STRTAB: &'static [u8] = b"\0libc.so.6\0GLIBC_2.2.5\0GLIBC_2.28\0libfoo.so\0";
+
+    // one Verneed for libc.so.6 with two Vernaux entries
+    const VERNEED: [u8; 48] = [
+        // vn_version, vn_cnt, vn_file, vn_aux, vn_next
+        0x01, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // vna_hash, vna_flags, vna_other, vna_name, vna_next
+        0x75, 0x1a, 0x69, 0x09, 0x00, 0x00, 0x03, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0xb8, 0x91, 0x96, 0x06, 0x00, 0x00, 0x02, 0x00, 0x17, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    // a base Verdef for libfoo.so, index 1
+    const VERDEF: [u8; 28] = [
+        // vd_version, vd_flags, vd_ndx, vd_cnt, vd_hash, vd_aux, vd_next
+        0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // vda_name, vda_next
+        0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn size_of() {
+        use std::mem::size_of;
+        assert_eq!(size_of::<Versym>(), SIZEOF_VERSYM);
+        assert_eq!(size_of::<Verdef>(), SIZEOF_VERDEF);
+        assert_eq!(size_of::<Verdaux>(), SIZEOF_VERDAUX);
+        assert_eq!(size_of::<Verneed>(), SIZEOF_VERNEED);
+        assert_eq!(size_of::<Vernaux>(), SIZEOF_VERNAUX);
+    }
+
+    #[test]
+    fn parse_verneed_chain() {
+        let strtab = Strtab::new(STRTAB, 0x0);
+        let verneed = parse_verneed(&VERNEED, 0, 1, &strtab, ::scroll::LE).unwrap();
+        assert_eq!(verneed.len(), 1);
+        assert_eq!(verneed[0].file, "libc.so.6");
+        assert_eq!(verneed[0].versions.len(), 2);
+        assert_eq!(verneed[0].versions[0].name, "GLIBC_2.2.5");
+        assert_eq!(verneed[0].versions[0].index, 3);
+        assert_eq!(verneed[0].versions[1].name, "GLIBC_2.28");
+        assert_eq!(verneed[0].versions[1].index, 2);
+    }
+
+    #[test]
+    fn resolve_symbol_versions() {
+        let strtab = Strtab::new(STRTAB, 0x0);
+        let versym_bytes = [0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x80];
+        let versions = SymbolVersions {
+            versym: parse_versym(&versym_bytes, 0, 4, ::scroll::LE).unwrap(),
+            verdef: parse_verdef(&VERDEF, 0, 1, &strtab, ::scroll::LE).unwrap(),
+            verneed: parse_verneed(&VERNEED, 0, 1, &strtab, ::scroll::LE).unwrap(),
+        };
+        assert_eq!(versions.verdef[0].name(), Some("libfoo.so"));
+        assert_eq!(versions.verdef[0].flags, VER_FLG_BASE);
+        assert!(versions.get(0).is_none());
+        assert!(versions.get(1).is_none());
+        let glibc_228 = versions.get(2).unwrap();
+        assert_eq!(glibc_228.name, "GLIBC_2.28");
+        assert_eq!(glibc_228.file, Some("libc.so.6"));
+        assert!(!glibc_228.hidden);
+        let hidden = versions.get(3).unwrap();
+        assert_eq!(hidden.name, "GLIBC_2.2.5");
+        assert!(hidden.hidden);
+        assert!(versions.get(4).is_none());
+    }
+
+    #[test]
+    fn untrusted_counts() {
+        let strtab = Strtab::new(STRTAB, 0x0);
+        // counts far beyond the section are bounded by its size, and fail when the chain runs out
+        assert!(parse_versym(&[0u8; 4], 0, ::core::usize::MAX, ::scroll::LE).is_err());
+        assert_eq!(parse_verneed(&VERNEED, 0, ::core::usize::MAX, &strtab, ::scroll::LE).unwrap().len(), 1);
+        let mut verdef = VERDEF;
+        // vd_cnt = 0xffff with a chained verdaux pointing past the end
+        verdef[6] = 0xff;
+        verdef[7] = 0xff;
+        verdef[24] = 0x08;
+        assert!(parse_verdef(&verdef, 0, ::core::usize::MAX, &strtab, ::scroll::LE).is_err());
+        // the hidden bit doesn't change the index
+        assert!(Versym { vs_val: VERSYM_HIDDEN | VER_NDX_GLOBAL }.is_global());
+        assert!(Versym { vs_val: VERSYM_HIDDEN | VER_NDX_GLOBAL }.is_hidden());
+        assert!(!Versym { vs_val: VERSYM_HIDDEN | 2 }.is_global());
+    }
+}
@@ -0,0 +1,195 @@
+//! Thread-local storage: the `PT_TLS` template, the `STT_TLS` symbols and the TLS relocations.
+//!
+//! Each module with thread-local variables has a `PT_TLS` segment, the template every thread's TLS
+//! block is initialized from: `p_filesz` bytes of `.tdata`, followed by `p_memsz - p_filesz`
+//! zeroed bytes of `.tbss`. The `st_value` of an `STT_TLS` symbol is its offset in the block.
+//!
+//! Code reaches a variable either dynamically, through the module ID and the offset in its block
+//! (`DTPMOD`/`DTPOFF` relocations, or a TLS descriptor), or statically, through its offset from the
+//! thread pointer (`TPOFF`), which depends on where the blocks are placed relative to the thread
+//! pointer: below it on x86 (variant II), and above the thread control block on AArch64 (variant I).
+//!
+//! See: https://www.akkadia.org/drepper/tls.pdf
+
+use align_up;
+use elf::header::{EM_AARCH64, EM_X86_64};
+use elf::reloc::{R_X86_64_DTPMOD64, R_X86_64_DTPOFF64, R_X86_64_TPOFF64, R_X86_64_TLSDESC};
+use elf::reloc::{R_AARCH64_TLS_DTPMOD, R_AARCH64_TLS_DTPREL, R_AARCH64_TLS_TPREL, R_AARCH64_TLSDESC};
+
+/// The size of the thread control block on AArch64, which the TLS blocks follow
+pub const AARCH64_TCB_SIZE: u64 = 16;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// What a TLS relocation resolves to
+pub enum TlsRelocationKind {
+    /// The ID of the module defining the symbol (`R_X86_64_DTPMOD64`, `R_AARCH64_TLS_DTPMOD`)
+    Module,
+    /// The offset of the symbol in its module's TLS block (`R_X86_64_DTPOFF64`, `R_AARCH64_TLS_DTPREL`)
+    DtpOffset,
+    /// The offset of the symbol from the thread pointer, in the static TLS (`R_X86_64_TPOFF64`, `R_AARCH64_TLS_TPREL`)
+    TpOffset,
+    /// A TLS descriptor, resolved by a function which returns the offset from the thread pointer
+    /// (`R_X86_64_TLSDESC`, `R_AARCH64_TLSDESC`)
+    Descriptor,
+}
+
+/// Returns the kind of the dynamic TLS relocation `r_type` of an object for `machine`, if it is one
+pub fn tls_relocation_kind(machine: u16, r_type: u32) -> Option<TlsRelocationKind> {
+    match (machine, r_type) {
+        (EM_X86_64, R_X86_64_DTPMOD64) | (EM_AARCH64, R_AARCH64_TLS_DTPMOD) => Some(TlsRelocationKind::Module),
+        (EM_X86_64, R_X86_64_DTPOFF64) | (EM_AARCH64, R_AARCH64_TLS_DTPREL) => Some(TlsRelocationKind::DtpOffset),
+        (EM_X86_64, R_X86_64_TPOFF64) | (EM_AARCH64, R_AARCH64_TLS_TPREL) => Some(TlsRelocationKind::TpOffset),
+        (EM_X86_64, R_X86_64_TLSDESC) | (EM_AARCH64, R_AARCH64_TLSDESC) => Some(TlsRelocationKind::Descriptor),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// The TLS initialization template, from the `PT_TLS` program header
+pub struct TlsTemplate {
+    /// The file offset of the initialized data (`.tdata`)
+    pub offset: u64,
+    /// The virtual address of the template
+    pub vaddr: u64,
+    /// The size of the initialized data
+    pub filesz: u64,
+    /// The size of the TLS block, including the zeroed data (`.tbss`)
+    pub memsz: u64,
+    /// The alignment of the TLS block
+    pub align: u64,
+}
+
+impl TlsTemplate {
+    /// The size of the zeroed data (`.tbss`) at the end of the block
+    pub fn bss_size(&self) -> u64 {
+        self.memsz.saturating_sub(self.filesz)
+    }
+    /// Returns the initialized data of the template in `data`, the bytes of the binary
+    pub fn image<'a>(&self, data: &'a [u8]) -> Option<&'a [u8]> {
+        let start = self.offset as usize;
+        start.checked_add(self.filesz as usize).and_then(|end| data.get(start..end))
+    }
+    /// Returns the offset from the thread pointer of `offset` in this block, when it is the first
+    /// block of the static TLS, i.e., that of the executable; `None` for machines other than
+    /// x86-64 and AArch64.
+    ///
+    /// The block is placed like glibc's `_dl_determine_tlsoffset` does, so that its address is congruent
+    /// to `vaddr` modulo its alignment. Also `None` if the alignment isn't a power of two or the offset
+    /// overflows.
+    pub fn tp_offset(&self, machine: u16, offset: u64) -> Option<i64> {
+        let align = if self.align == 0 { 1 } else { self.align };
+        // the distance from an aligned address to the next one congruent to vaddr
+        let first_byte = self.vaddr.wrapping_neg() & (align - 1);
+        match machine {
+            // variant II: the block ends at the thread pointer
+            EM_X86_64 => {
+                let block = align_up(self.memsz.saturating_sub(first_byte), align).and_then(|size| size.checked_add(first_byte));
+                match (to_i64(offset), block.and_then(to_i64)) {
+                    (Some(offset), Some(block)) => offset.checked_sub(block),
+                    _ => None,
+                }
+            },
+            // variant I: the block follows the thread control block, skipping an alignment unit if
+            // there isn't room for `first_byte` between them
+            EM_AARCH64 => {
+                let start = align_up(AARCH64_TCB_SIZE, align).and_then(|start| {
+                    if start - AARCH64_TCB_SIZE < first_byte { start.checked_add(align) } else { Some(start) }
+                });
+                start.and_then(|start| (start - first_byte).checked_add(offset)).and_then(to_i64)
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Converts `value` to an `i64`, if it fits
+fn to_i64(value: u64) -> Option<i64> {
+    let value = value as i64;
+    if value >= 0 { Some(value) } else { None }
+}
+
+if_alloc! {
+    use alloc::vec::Vec;
+    use elf::reloc::Reloc;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    /// A thread-local variable
+    pub struct TlsSymbol<'a> {
+        /// The symbol name
+        pub name: &'a str,
+        /// The offset of the variable in the TLS block; in relocatable objects, in its section instead
+        pub offset: u64,
+        /// The size of the variable
+        pub size: u64,
+        /// The index of the symbol in its symbol table
+        pub index: usize,
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    /// A dynamic relocation referencing thread-local storage
+    pub struct TlsRelocation<'a> {
+        /// What the relocation resolves to
+        pub kind: TlsRelocationKind,
+        /// The relocation
+        pub reloc: Reloc,
+        /// The name of the symbol it references, `None` for the module itself, e.g., a `DTPMOD` of
+        /// a local variable
+        pub symbol: Option<&'a str>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    /// The thread-local storage of a binary
+    pub struct Tls<'a> {
+        /// The TLS template, if the binary has a `PT_TLS` segment
+        pub template: Option<TlsTemplate>,
+        /// The defined `STT_TLS` symbols of `.symtab`, or of `.dynsym` if the binary is stripped, by offset
+        pub symbols: Vec<TlsSymbol<'a>>,
+        /// The dynamic relocations referencing TLS, by address
+        pub relocations: Vec<TlsRelocation<'a>>,
+    }
+
+    impl<'a> Tls<'a> {
+        /// Returns the symbol containing `offset` of the TLS block, if any
+        pub fn symbol_at(&self, offset: u64) -> Option<&TlsSymbol<'a>> {
+            self.symbols.iter().find(|sym| {
+                offset == sym.offset || (offset > sym.offset && offset - sym.offset < sym.size)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elf::header::EM_386;
+
+    #[test]
+    fn tls_layout() {
+        assert_eq!(tls_relocation_kind(EM_X86_64, R_X86_64_TPOFF64), Some(TlsRelocationKind::TpOffset));
+        assert_eq!(tls_relocation_kind(EM_AARCH64, R_AARCH64_TLSDESC), Some(TlsRelocationKind::Descriptor));
+        // R_AARCH64_TLS_DTPMOD is 1028, which isn't a TLS relocation on x86-64
+        assert_eq!(tls_relocation_kind(EM_X86_64, R_AARCH64_TLS_DTPMOD), None);
+        let template = TlsTemplate { offset: 0x1000, vaddr: 0x201000, filesz: 0x10, memsz: 0x1c, align: 8 };
+        assert_eq!(template.bss_size(), 0xc);
+        assert_eq!(template.tp_offset(EM_X86_64, 0), Some(-0x20));
+        assert_eq!(template.tp_offset(EM_X86_64, 0x18), Some(-0x8));
+        assert_eq!(template.tp_offset(EM_AARCH64, 0x18), Some(0x28));
+        assert_eq!(template.tp_offset(EM_386, 0), None);
+        let template = TlsTemplate { align: 64, ..template };
+        assert_eq!(template.tp_offset(EM_X86_64, 0), Some(-0x40));
+        assert_eq!(template.tp_offset(EM_AARCH64, 0), Some(0x40));
+        // a block 56 bytes into its alignment unit: 8 bytes of padding below it, which fit past the TCB
+        let unaligned = TlsTemplate { vaddr: 0x201038, ..template };
+        assert_eq!(unaligned.tp_offset(EM_X86_64, 0), Some(-0x48));
+        assert_eq!(unaligned.tp_offset(EM_AARCH64, 0), Some(0x38));
+        // 8 bytes into its alignment unit: 56 bytes of padding, which don't fit
+        let unaligned = TlsTemplate { vaddr: 0x201008, ..template };
+        assert_eq!(unaligned.tp_offset(EM_AARCH64, 0), Some(0x48));
+        assert_eq!(TlsTemplate { align: 24, ..template }.tp_offset(EM_X86_64, 0), None);
+        assert_eq!(TlsTemplate { memsz: u64::max_value(), ..template }.tp_offset(EM_X86_64, 0), None);
+        assert_eq!(template.tp_offset(EM_AARCH64, u64::max_value()), None);
+        let data = [0u8; 0x1008];
+        assert_eq!(template.image(&data), None);
+        assert_eq!(template.image(&[0u8; 0x1010]).map(|image| image.len()), Some(0x10));
+    }
+}
@@ -0,0 +1,778 @@
+//! Serializes a (modified) ELF binary back to bytes.
+//!
+//! The `Builder` starts from the original file and only moves what has to move: a modified
+//! section which still fits in its original place is rewritten there, while a larger or an added
+//! section is appended to the end of the file. Appended sections which are loaded at runtime
+//! (e.g., a grown `.dynstr` or `.dynamic`) are covered by a new `PT_LOAD` segment, placed above
+//! all existing segments, together with a copy of the program header table; the dynamic entries
+//! and the program headers referring to the moved sections are updated accordingly.
+//! An unmodified binary is written back byte for byte.
+//!
+//! ```rust,no_run
+//! use goblin::elf::Elf;
+//! use goblin::elf::writer::Builder;
+//! use std::fs::File;
+//! use std::io::Read;
+//!
+//! let mut bytes = Vec::new();
+//! File::open("/bin/ls").unwrap().read_to_end(&mut bytes).unwrap();
+//! let elf = Elf::parse(&bytes).unwrap();
+//! let mut builder = Builder::new(&elf, &bytes).unwrap();
+//! builder.set_interpreter("/opt/lib/ld-linux-x86-64.so.2").unwrap();
+//! builder.set_runpath("$ORIGIN/../lib").unwrap();
+//! builder.add_needed("libm.so.6").unwrap();
+//! let patched = builder.build().unwrap();
+//! ```
+
+use core::cmp;
+use alloc::vec::Vec;
+use scroll::{Pread, Pwrite};
+use scroll::ctx::IntoCtx;
+use error;
+use align_up;
+use container::Ctx;
+use elf::{Elf, Header, ProgramHeader, SectionHeader, Dyn, Sym};
+use elf::program_header;
+use elf::section_header;
+use elf::dyn;
+use elf::hash;
+
+/// The alignment of a new `PT_LOAD` segment, unless the existing segments require a larger one
+const MIN_SEGMENT_ALIGN: u64 = 0x1000;
+
+/// Rounds `value` up to the alignment `align`, which comes from the binary
+fn aligned(value: u64, align: u64) -> error::Result<u64> {
+    align_up(value, align).ok_or_else(|| error::Error::Malformed(format!("Cannot align {:#x} to {:#x}", value, align)))
+}
+
+#[derive(Debug)]
+/// A section of the binary being built
+struct Section<'a> {
+    /// The section header, as it will be written
+    header: SectionHeader,
+    /// The section header in the original binary, or `None` for an added section
+    original: Option<SectionHeader>,
+    /// The contents in the original binary
+    bytes: &'a [u8],
+    /// The new contents, if they were modified
+    data: Option<Vec<u8>>,
+}
+
+impl<'a> Section<'a> {
+    fn data(&self) -> &[u8] {
+        match self.data {
+            Some(ref data) => data,
+            None => self.bytes,
+        }
+    }
+    fn data_mut(&mut self) -> &mut Vec<u8> {
+        if self.data.is_none() {
+            self.data = Some(self.bytes.to_vec());
+        }
+        self.data.as_mut().unwrap()
+    }
+}
+
+#[derive(Debug)]
+/// Builds a modified copy of an ELF binary.
+///
+/// Sections are referred to by their index in the section header table; added sections are
+/// appended to it. The changes are only laid out and written by `build`.
+pub struct Builder<'a> {
+    bytes: &'a [u8],
+    ctx: Ctx,
+    header: Header,
+    program_headers: Vec<ProgramHeader>,
+    sections: Vec<Section<'a>>,
+    /// The index of the section header string table
+    shstrndx: usize,
+    /// The index of the `.dynamic` section and its entries, without the terminating `DT_NULL`s
+    dynamic: Option<(usize, Vec<Dyn>)>,
+    /// Whether the dynamic entries were modified
+    dynamic_modified: bool,
+}
+
+impl<'a> Builder<'a> {
+    /// Creates a builder for the binary `elf`, which was parsed from `bytes`
+    pub fn new(elf: &Elf<'a>, bytes: &'a [u8]) -> error::Result<Self> {
+        let ctx = elf.ctx;
+        let mut sections = Vec::with_capacity(elf.section_headers.len());
+        for shdr in &elf.section_headers {
+            let contents = if shdr.sh_type == section_header::SHT_NOBITS {
+                &[]
+            } else {
+                let range = shdr.file_range();
+                bytes.get(range.clone()).ok_or_else(|| error::Error::Malformed(
+                    format!("Section contents at {:#x}..{:#x} are out of bounds", range.start, range.end)))?
+            };
+            sections.push(Section { header: shdr.clone(), original: Some(shdr.clone()), bytes: contents, data: None });
+        }
+        let mut shstrndx = elf.header.e_shstrndx as usize;
+        if shstrndx == section_header::SHN_XINDEX as usize {
+            shstrndx = sections.first().map_or(0, |section| section.header.sh_link as usize);
+        }
+        let mut builder = Builder {
+            bytes,
+            ctx,
+            header: elf.header,
+            program_headers: elf.program_headers.clone(),
+            sections,
+            shstrndx,
+            dynamic: None,
+            dynamic_modified: false,
+        };
+        if let Some(index) = builder.sections.iter().position(|section| section.header.sh_type == section_header::SHT_DYNAMIC) {
+            let dyns = builder.parse_dynamic(index)?;
+            builder.dynamic = Some((index, dyns));
+        }
+        Ok(builder)
+    }
+
+    fn parse_dynamic(&self, index: usize) -> error::Result<Vec<Dyn>> {
+        let data = self.sections[index].data();
+        let mut offset = 0;
+        let mut dyns = Vec::new();
+        while offset < data.len() {
+            let dyn: Dyn = data.gread_with(&mut offset, self.ctx)?;
+            if dyn.d_tag == dyn::DT_NULL {
+                break;
+            }
+            dyns.push(dyn);
+        }
+        Ok(dyns)
+    }
+
+    fn section(&self, index: usize) -> error::Result<&Section<'a>> {
+        self.sections.get(index).ok_or_else(|| error::Error::Malformed(format!("Section index {} is out of bounds", index)))
+    }
+
+    fn string(&self, strtab: usize, offset: usize) -> Option<&str> {
+        self.sections.get(strtab).and_then(|section| section.data().pread::<&str>(offset).ok())
+    }
+
+    /// Returns the offset of `string` in the string table at index `strtab`, appending it if it isn't there yet
+    fn add_string(&mut self, strtab: usize, string: &str) -> error::Result<usize> {
+        if string.as_bytes().contains(&0) {
+            return Err(error::Error::Malformed(format!("String {:?} contains a NUL byte", string)));
+        }
+        if self.section(strtab)?.header.sh_type != section_header::SHT_STRTAB {
+            return Err(error::Error::Malformed(format!("Section {} is not a string table", strtab)));
+        }
+        let needle: Vec<u8> = string.bytes().chain(Some(0)).collect();
+        {
+            // reuse an existing string (or suffix of one)
+            let data = self.sections[strtab].data();
+            if let Some(offset) = data.windows(needle.len()).position(|window| window == &needle[..]) {
+                return Ok(offset);
+            }
+        }
+        let data = self.sections[strtab].data_mut();
+        let offset = data.len();
+        data.extend_from_slice(&needle);
+        Ok(offset)
+    }
+
+    /// Returns the index of the first section named `name`
+    pub fn section_by_name(&self, name: &str) -> Option<usize> {
+        (0..self.sections.len()).find(|&index| self.string(self.shstrndx, self.sections[index].header.sh_name) == Some(name))
+    }
+
+    /// Returns the current contents of the section at `index`
+    pub fn section_data(&self, index: usize) -> Option<&[u8]> {
+        self.sections.get(index).map(|section| section.data())
+    }
+
+    /// Replaces the contents of the section at `index` with `data`
+    pub fn set_section_data(&mut self, index: usize, data: Vec<u8>) -> error::Result<()> {
+        if self.section(index)?.header.sh_type == section_header::SHT_NOBITS {
+            return Err(error::Error::Malformed(format!("Section {} has no file contents", index)));
+        }
+        self.sections[index].data = Some(data);
+        let is_dynamic = self.dynamic.as_ref().map_or(false, |&(dynamic, _)| dynamic == index);
+        if is_dynamic {
+            let dyns = self.parse_dynamic(index)?;
+            self.dynamic = Some((index, dyns));
+        }
+        Ok(())
+    }
+
+    /// Appends a section named `name` with the contents `data` and returns its index.
+    ///
+    /// The size, offset and, for an allocated section, the address in `header` are assigned by `build`,
+    /// except that the size of an `SHT_NOBITS` section is kept.
+    pub fn add_section(&mut self, name: &str, mut header: SectionHeader, data: Vec<u8>) -> error::Result<usize> {
+        let shstrndx = self.shstrndx;
+        header.sh_name = self.add_string(shstrndx, name)?;
+        if header.sh_type != section_header::SHT_NOBITS {
+            header.sh_size = data.len() as u64;
+        }
+        self.sections.push(Section { header, original: None, bytes: &[], data: Some(data) });
+        Ok(self.sections.len() - 1)
+    }
+
+    /// Sets the program interpreter, i.e., the contents of `.interp`
+    pub fn set_interpreter(&mut self, interpreter: &str) -> error::Result<()> {
+        let index = self.section_by_name(".interp").ok_or_else(|| error::Error::Malformed("Binary has no .interp section".into()))?;
+        let mut data = interpreter.as_bytes().to_vec();
+        data.push(0);
+        self.set_section_data(index, data)
+    }
+
+    fn dynstr(&self) -> error::Result<usize> {
+        match self.dynamic {
+            Some((index, _)) => Ok(self.sections[index].header.sh_link as usize),
+            None => Err(error::Error::Malformed("Binary has no .dynamic section".into())),
+        }
+    }
+
+    fn dyns_mut(&mut self) -> &mut Vec<Dyn> {
+        self.dynamic_modified = true;
+        &mut self.dynamic.as_mut().unwrap().1
+    }
+
+    /// Sets the value of the first entry tagged with one of `tags` to the string `value`, or adds a `tags[0]` entry
+    fn set_dynamic_string(&mut self, tags: &[u64], value: &str) -> error::Result<()> {
+        let dynstr = self.dynstr()?;
+        let offset = self.add_string(dynstr, value)? as u64;
+        let dyns = self.dyns_mut();
+        match dyns.iter_mut().find(|dyn| tags.contains(&dyn.d_tag)) {
+            Some(dyn) => dyn.d_val = offset,
+            None => dyns.push(Dyn { d_tag: tags[0], d_val: offset }),
+        }
+        Ok(())
+    }
+
+    /// Sets the library search path: replaces the `DT_RUNPATH` or `DT_RPATH` entry, or adds a `DT_RUNPATH` entry
+    pub fn set_runpath(&mut self, runpath: &str) -> error::Result<()> {
+        self.set_dynamic_string(&[dyn::DT_RUNPATH, dyn::DT_RPATH], runpath)
+    }
+
+    /// Sets the shared object name (`DT_SONAME`)
+    pub fn set_soname(&mut self, soname: &str) -> error::Result<()> {
+        self.set_dynamic_string(&[dyn::DT_SONAME], soname)
+    }
+
+    fn find_needed(&self, library: &str) -> error::Result<Vec<usize>> {
+        let dynstr = self.dynstr()?;
+        let dyns = &self.dynamic.as_ref().unwrap().1;
+        Ok((0..dyns.len())
+            .filter(|&i| dyns[i].d_tag == dyn::DT_NEEDED && self.string(dynstr, dyns[i].d_val as usize) == Some(library))
+            .collect())
+    }
+
+    /// Adds a `DT_NEEDED` entry for `library` after the existing ones
+    pub fn add_needed(&mut self, library: &str) -> error::Result<()> {
+        let dynstr = self.dynstr()?;
+        let offset = self.add_string(dynstr, library)? as u64;
+        let dyns = self.dyns_mut();
+        let position = dyns.iter().rposition(|dyn| dyn.d_tag == dyn::DT_NEEDED).map_or(0, |i| i + 1);
+        dyns.insert(position, Dyn { d_tag: dyn::DT_NEEDED, d_val: offset });
+        Ok(())
+    }
+
+    /// Replaces the `DT_NEEDED` entries for `library` with `replacement`, returning whether there were any
+    pub fn replace_needed(&mut self, library: &str, replacement: &str) -> error::Result<bool> {
+        let indices = self.find_needed(library)?;
+        if indices.is_empty() {
+            return Ok(false);
+        }
+        let dynstr = self.dynstr()?;
+        let offset = self.add_string(dynstr, replacement)? as u64;
+        let dyns = self.dyns_mut();
+        for i in indices {
+            dyns[i].d_val = offset;
+        }
+        Ok(true)
+    }
+
+    /// Removes the `DT_NEEDED` entries for `library`, returning whether there were any
+    pub fn remove_needed(&mut self, library: &str) -> error::Result<bool> {
+        let indices = self.find_needed(library)?;
+        if indices.is_empty() {
+            return Ok(false);
+        }
+        let dyns = self.dyns_mut();
+        for i in indices.into_iter().rev() {
+            dyns.remove(i);
+        }
+        Ok(true)
+    }
+
+    /// Renames the symbols named `name` in `.symtab` and `.dynsym` to `new_name`, returning how many were renamed.
+    ///
+    /// The dynamic symbol hash tables are rebuilt for the new names.
+    pub fn rename_symbol(&mut self, name: &str, new_name: &str) -> error::Result<usize> {
+        let ctx = self.ctx;
+        let sym_size = Sym::size(ctx.container);
+        let mut renamed = 0;
+        for symtab in 0..self.sections.len() {
+            let sh_type = self.sections[symtab].header.sh_type;
+            if sh_type != section_header::SHT_SYMTAB && sh_type != section_header::SHT_DYNSYM {
+                continue;
+            }
+            let strtab = self.sections[symtab].header.sh_link as usize;
+            let mut matches = Vec::new();
+            {
+                let data = self.sections[symtab].data();
+                for offset in (0..data.len() / sym_size).map(|i| i * sym_size) {
+                    let sym: Sym = data.pread_with(offset, ctx)?;
+                    if sym.st_name != 0 && self.string(strtab, sym.st_name) == Some(name) {
+                        matches.push((offset, sym));
+                    }
+                }
+            }
+            if matches.is_empty() {
+                continue;
+            }
+            let new_offset = self.add_string(strtab, new_name)?;
+            {
+                let data = self.sections[symtab].data_mut();
+                for (offset, mut sym) in matches.iter().cloned() {
+                    sym.st_name = new_offset;
+                    data.pwrite_with(sym, offset, ctx)?;
+                }
+            }
+            renamed += matches.len();
+            if sh_type == section_header::SHT_DYNSYM {
+                self.rebuild_hash_tables(symtab)?;
+            }
+        }
+        Ok(renamed)
+    }
+
+    /// Returns the names of the symbols in the symbol table at `symtab`
+    fn symbol_names(&self, symtab: usize) -> error::Result<Vec<Vec<u8>>> {
+        let sym_size = Sym::size(self.ctx.container);
+        let strtab = self.sections[symtab].header.sh_link as usize;
+        let data = self.sections[symtab].data();
+        let mut names = Vec::with_capacity(data.len() / sym_size);
+        for i in 0..data.len() / sym_size {
+            let sym: Sym = data.pread_with(i * sym_size, self.ctx)?;
+            names.push(self.string(strtab, sym.st_name).unwrap_or("").as_bytes().to_vec());
+        }
+        Ok(names)
+    }
+
+    /// Rebuilds the `SHT_GNU_HASH` and `SHT_HASH` tables of the dynamic symbol table at `dynsym` in place.
+    ///
+    /// The GNU hash table is rebuilt with a single bucket and a bloom filter which matches everything,
+    /// so that it is never larger than the original, with the same symbol order.
+    fn rebuild_hash_tables(&mut self, dynsym: usize) -> error::Result<()> {
+        let le = self.ctx.le;
+        let word_size = self.ctx.size();
+        let names = self.symbol_names(dynsym)?;
+        let nsyms = names.len();
+        for index in 0..self.sections.len() {
+            let header = &self.sections[index].header;
+            if header.sh_link as usize != dynsym || (header.sh_type != section_header::SHT_GNU_HASH && header.sh_type != section_header::SHT_HASH) {
+                continue;
+            }
+            let is_gnu = header.sh_type == section_header::SHT_GNU_HASH;
+            let old = self.sections[index].data();
+            let mut data = vec![0u8; old.len()];
+            if is_gnu {
+                let symoffset = old.pread_with::<u32>(4, le)? as usize;
+                let bloom_shift = old.pread_with::<u32>(12, le)?;
+                if 16 + word_size + 4 + 4 * nsyms.saturating_sub(symoffset) > data.len() {
+                    return Err(error::Error::Malformed(format!("GNU hash table in section {} is too small", index)));
+                }
+                data.pwrite_with(1u32, 0, le)?;
+                data.pwrite_with(symoffset as u32, 4, le)?;
+                data.pwrite_with(1u32, 8, le)?;
+                data.pwrite_with(bloom_shift, 12, le)?;
+                for byte in &mut data[16..16 + word_size] {
+                    *byte = 0xff;
+                }
+                let buckets = 16 + word_size;
+                data.pwrite_with(if nsyms > symoffset { symoffset as u32 } else { 0 }, buckets, le)?;
+                for (i, name) in names.iter().enumerate().skip(symoffset) {
+                    let end = if i + 1 == nsyms { 1 } else { 0 };
+                    data.pwrite_with((hash::gnu_hash(name) & !1) | end, buckets + 4 + 4 * (i - symoffset), le)?;
+                }
+            } else {
+                let nbucket = old.pread_with::<u32>(0, le)? as usize;
+                if nbucket == 0 || 8 + 4 * (nbucket + nsyms) > data.len() {
+                    return Err(error::Error::Malformed(format!("SysV hash table in section {} is malformed", index)));
+                }
+                data.pwrite_with(nbucket as u32, 0, le)?;
+                data.pwrite_with(nsyms as u32, 4, le)?;
+                let buckets = 8;
+                let chains = buckets + 4 * nbucket;
+                // prepend each symbol to its bucket's chain, from the last to the first
+                for i in (1..nsyms).rev() {
+                    let bucket = buckets + 4 * (hash::sysv_hash(&names[i]) as usize % nbucket);
+                    let head: u32 = data.pread_with(bucket, le)?;
+                    data.pwrite_with(head, chains + 4 * i, le)?;
+                    data.pwrite_with(i as u32, bucket, le)?;
+                }
+            }
+            self.sections[index].data = Some(data);
+        }
+        Ok(())
+    }
+
+    /// The new contents of the section at `index`, if it was modified.
+    /// The `.dynamic` section is serialized separately, see `dynamic_size`
+    fn modified_data(&self, index: usize) -> Option<&[u8]> {
+        self.sections[index].data.as_ref().map(|data| &data[..])
+    }
+
+    /// The size of the `.dynamic` section, which keeps its original number of entries unless they don't fit
+    fn dynamic_size(&self) -> Option<(usize, u64)> {
+        self.dynamic.as_ref().map(|&(index, ref dyns)| {
+            let dyn_size = Dyn::size(self.ctx.container) as u64;
+            let capacity = self.sections[index].original.as_ref().map_or(0, |original| original.sh_size / dyn_size);
+            (index, cmp::max(dyns.len() as u64 + 1, capacity) * dyn_size)
+        })
+    }
+
+    /// Lays out the modified binary and returns its bytes
+    pub fn build(&self) -> error::Result<Vec<u8>> {
+        let ctx = self.ctx;
+        let mut sections: Vec<SectionHeader> = self.sections.iter().map(|section| section.header.clone()).collect();
+        let has_loads = self.program_headers.iter().any(|phdr| phdr.p_type == program_header::PT_LOAD);
+        let relocatable = self.header.e_type == super::header::ET_REL;
+
+        // the new size of every modified section; the dynamic entries are rewritten if they, or an address they refer to, changed
+        let mut sizes: Vec<Option<u64>> = (0..sections.len()).map(|i| self.modified_data(i).map(|data| data.len() as u64)).collect();
+        let dynamic_size = self.dynamic_size();
+        if let Some((index, size)) = dynamic_size {
+            sizes[index] = Some(size);
+        }
+        let mut in_place = Vec::new();
+        let mut loaded = Vec::new();
+        let mut appended = Vec::new();
+        for (index, section) in self.sections.iter().enumerate() {
+            let size = match sizes[index] {
+                Some(size) => size,
+                None => continue,
+            };
+            if section.original.is_some() && section.header.sh_type == section_header::SHT_NOBITS {
+                continue;
+            }
+            match section.original {
+                Some(ref original) if size <= original.sh_size => in_place.push(index),
+                _ => if section.header.is_alloc() && has_loads && !relocatable {
+                    loaded.push(index)
+                } else {
+                    appended.push(index)
+                },
+            }
+        }
+        let rewrite_dynamic = self.dynamic_modified || self.dynamic.as_ref().map_or(false, |&(index, _)| !in_place.contains(&index))
+            || loaded.iter().any(|&index| self.sections[index].original.is_some());
+        if !rewrite_dynamic {
+            if let Some((index, _)) = dynamic_size {
+                sizes[index] = None;
+                in_place.retain(|&i| i != index);
+            }
+        }
+        for &index in in_place.iter().chain(&loaded).chain(&appended) {
+            if sections[index].sh_type != section_header::SHT_NOBITS {
+                sections[index].sh_size = sizes[index].unwrap();
+            }
+        }
+
+        let mut program_headers = self.program_headers.clone();
+        let phentsize = ProgramHeader::size(&ctx) as u64;
+        let mut phoff = self.header.e_phoff;
+        let mut cursor = self.bytes.len() as u64;
+        if !loaded.is_empty() {
+            // the new segment starts past the end of the file and above all of the existing segments
+            let first = program_headers.iter().find(|phdr| phdr.p_type == program_header::PT_LOAD).unwrap().clone();
+            let last = program_headers.iter().rposition(|phdr| phdr.p_type == program_header::PT_LOAD).unwrap();
+            let delta = first.p_vaddr.wrapping_sub(first.p_offset);
+            let loads = program_headers.iter().filter(|phdr| phdr.p_type == program_header::PT_LOAD);
+            let align = loads.clone().map(|phdr| phdr.p_align).fold(MIN_SEGMENT_ALIGN, cmp::max);
+            let mut vaddr_end = 0;
+            for phdr in loads {
+                let end = phdr.p_vaddr.checked_add(phdr.p_memsz)
+                    .ok_or_else(|| error::Error::Malformed(format!("Segment at {:#x} of size {:#x} overflows the address space", phdr.p_vaddr, phdr.p_memsz)))?;
+                vaddr_end = cmp::max(vaddr_end, end);
+            }
+            let start = aligned(cmp::max(cursor, vaddr_end.wrapping_sub(delta)), align)?;
+            cursor = start + (program_headers.len() as u64 + 1) * phentsize;
+            phoff = start;
+            let mut flags = program_header::PF_R;
+            for &index in &loaded {
+                let shdr = &mut sections[index];
+                if shdr.sh_type == section_header::SHT_NOBITS {
+                    continue;
+                }
+                cursor = aligned(cursor, shdr.sh_addralign)?;
+                shdr.sh_offset = cursor;
+                shdr.sh_addr = cursor.wrapping_add(delta);
+                cursor += shdr.sh_size;
+            }
+            let filesz = cursor - start;
+            let mut memsz = filesz;
+            for &index in &loaded {
+                let shdr = &mut sections[index];
+                if shdr.sh_type == section_header::SHT_NOBITS {
+                    memsz = aligned(start + memsz, shdr.sh_addralign)? - start;
+                    shdr.sh_offset = start + memsz;
+                    shdr.sh_addr = shdr.sh_offset.wrapping_add(delta);
+                    memsz = memsz.checked_add(shdr.sh_size)
+                        .ok_or_else(|| error::Error::Malformed(format!("Section {} of size {:#x} overflows the address space", index, shdr.sh_size)))?;
+                }
+                if shdr.is_writable() {
+                    flags |= program_header::PF_W;
+                }
+                if shdr.is_executable() {
+                    flags |= program_header::PF_X;
+                }
+            }
+            program_headers.insert(last + 1, ProgramHeader {
+                p_type: program_header::PT_LOAD,
+                p_flags: flags,
+                p_offset: start,
+                p_vaddr: start.wrapping_add(delta),
+                p_paddr: start.wrapping_add(delta),
+                p_filesz: filesz,
+                p_memsz: memsz,
+                p_align: align,
+            });
+            let phsize = program_headers.len() as u64 * phentsize;
+            for phdr in program_headers.iter_mut().filter(|phdr| phdr.p_type == program_header::PT_PHDR) {
+                phdr.p_offset = phoff;
+                phdr.p_vaddr = phoff.wrapping_add(delta);
+                phdr.p_paddr = phdr.p_vaddr;
+                phdr.p_filesz = phsize;
+                phdr.p_memsz = phsize;
+            }
+        }
+        for &index in &appended {
+            let shdr = &mut sections[index];
+            cursor = aligned(cursor, shdr.sh_addralign)?;
+            shdr.sh_offset = cursor;
+            if shdr.sh_type != section_header::SHT_NOBITS {
+                cursor += shdr.sh_size;
+            }
+        }
+
+        // the non-loadable segments covering exactly one moved or resized section follow it
+        for index in in_place.iter().chain(&loaded) {
+            let original = match self.sections[*index].original {
+                Some(ref original) => original,
+                None => continue,
+            };
+            let shdr = &sections[*index];
+            for phdr in program_headers.iter_mut() {
+                if phdr.p_type != program_header::PT_LOAD && phdr.p_type != program_header::PT_PHDR
+                    && phdr.p_offset == original.sh_offset && phdr.p_filesz == original.sh_size {
+                    phdr.p_offset = shdr.sh_offset;
+                    phdr.p_vaddr = shdr.sh_addr;
+                    phdr.p_paddr = shdr.sh_addr;
+                    phdr.p_filesz = shdr.sh_size;
+                    phdr.p_memsz = shdr.sh_size;
+                }
+            }
+        }
+
+        // the dynamic entries referring to moved or resized sections follow them
+        let mut dyns = self.dynamic.as_ref().map_or_else(Vec::new, |dynamic| dynamic.1.clone());
+        for &index in &loaded {
+            if let Some(ref original) = self.sections[index].original {
+                for dyn in dyns.iter_mut().filter(|dyn| dyn::is_address_tag(dyn.d_tag) && dyn.d_val == original.sh_addr) {
+                    dyn.d_val = sections[index].sh_addr;
+                }
+            }
+        }
+        if let Some(&(index, _)) = self.dynamic.as_ref() {
+            let dynstr = self.sections[index].header.sh_link as usize;
+            if sizes.get(dynstr).map_or(false, |size| size.is_some()) {
+                for dyn in dyns.iter_mut().filter(|dyn| dyn.d_tag == dyn::DT_STRSZ) {
+                    dyn.d_val = sections[dynstr].sh_size;
+                }
+            }
+        }
+
+        // the section header table stays in place unless it grew
+        let shnum = sections.len();
+        let shentsize = SectionHeader::size(&ctx) as u64;
+        let original_shnum = self.sections.iter().filter(|section| section.original.is_some()).count();
+        let shoff = if shnum == 0 {
+            0
+        } else if self.header.e_shoff != 0 && shnum <= original_shnum {
+            self.header.e_shoff
+        } else {
+            cursor = aligned(cursor, ctx.size() as u64)?;
+            let shoff = cursor;
+            cursor += shnum as u64 * shentsize;
+            shoff
+        };
+
+        let mut header = self.header;
+        header.e_phoff = phoff;
+        header.e_shoff = shoff;
+        let phnum = program_headers.len();
+        header.e_phnum = if phnum >= program_header::PN_XNUM as usize { program_header::PN_XNUM } else { phnum as u16 };
+        header.e_shnum = if shnum >= section_header::SHN_LORESERVE as usize { 0 } else { shnum as u16 };
+        header.e_shstrndx = if self.shstrndx >= section_header::SHN_LORESERVE as usize { section_header::SHN_XINDEX as u16 } else { self.shstrndx as u16 };
+        if shnum > 0 {
+            let shdr0 = &mut sections[0];
+            if shnum >= section_header::SHN_LORESERVE as usize {
+                shdr0.sh_size = shnum as u64;
+            }
+            if header.e_shstrndx == section_header::SHN_XINDEX as u16 {
+                shdr0.sh_link = self.shstrndx as u32;
+            }
+            if header.e_phnum == program_header::PN_XNUM {
+                shdr0.sh_info = phnum as u32;
+            }
+        }
+
+        let mut bytes = self.bytes.to_vec();
+        let len = cmp::max(bytes.len() as u64, cursor) as usize;
+        bytes.resize(len, 0);
+        for &index in &in_place {
+            let original = self.sections[index].original.as_ref().unwrap();
+            let range = original.file_range();
+            for byte in &mut bytes[range] {
+                *byte = 0;
+            }
+        }
+        for &index in in_place.iter().chain(&loaded).chain(&appended) {
+            let shdr = &sections[index];
+            if shdr.sh_type == section_header::SHT_NOBITS {
+                continue;
+            }
+            let offset = shdr.sh_offset as usize;
+            if dynamic_size.map_or(false, |(dynamic, _)| dynamic == index) {
+                let mut offset = offset;
+                for dyn in dyns.iter().cloned().chain(Some(Dyn { d_tag: dyn::DT_NULL, d_val: 0 })) {
+                    bytes.gwrite_with(dyn, &mut offset, ctx)?;
+                }
+            } else if let Some(data) = self.modified_data(index) {
+                bytes[offset..offset + data.len()].copy_from_slice(data);
+            }
+        }
+        let mut offset = phoff as usize;
+        for phdr in program_headers {
+            bytes.gwrite_with(phdr, &mut offset, ctx)?;
+        }
+        if shnum > 0 {
+            let mut offset = shoff as usize;
+            for shdr in sections {
+                bytes.gwrite_with(shdr, &mut offset, ctx)?;
+            }
+            if shoff == self.header.e_shoff {
+                // clear the entries of removed sections
+                let end = shoff as usize + original_shnum * shentsize as usize;
+                for byte in &mut bytes[offset..end] {
+                    *byte = 0;
+                }
+            }
+        }
+        header.into_ctx(&mut bytes, ctx);
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_unmodified() {
+        let crt1: Vec<u8> = include!("../../etc/crt1.rs");
+        let crt132: Vec<u8> = include!("../../etc/crt132.rs");
+        for bytes in &[crt1, crt132] {
+            let elf = Elf::parse(bytes).unwrap();
+            let built = Builder::new(&elf, bytes).unwrap().build().unwrap();
+            assert_eq!(&built, bytes);
+        }
+    }
+
+    #[test]
+    fn rename_symbol_and_add_section() {
+        let crt1: Vec<u8> = include!("../../etc/crt1.rs");
+        let elf = Elf::parse(&crt1).unwrap();
+        let mut builder = Builder::new(&elf, &crt1).unwrap();
+        assert_eq!(builder.rename_symbol("_start", "_begin").unwrap(), 1);
+        let header = SectionHeader { sh_type: section_header::SHT_PROGBITS, sh_addralign: 1, ..SectionHeader::new() };
+        let index = builder.add_section(".comment.goblin", header, b"goblin\0".to_vec()).unwrap();
+        assert_eq!(builder.section_by_name(".comment.goblin"), Some(index));
+        let bytes = builder.build().unwrap();
+
+        let elf = Elf::parse(&bytes).unwrap();
+        assert_eq!(elf.section_headers.len(), index + 1);
+        assert!(elf.syms.iter().any(|sym| elf.strtab.get(sym.st_name).unwrap().unwrap() == "_begin"));
+        assert!(!elf.syms.iter().any(|sym| elf.strtab.get(sym.st_name).unwrap().unwrap() == "_start"));
+        let shdr = &elf.section_headers[index];
+        assert_eq!(elf.shdr_strtab.get(shdr.sh_name).unwrap().unwrap(), ".comment.goblin");
+        assert_eq!(&bytes[shdr.file_range()], b"goblin\0");
+        assert!(builder.set_interpreter("/lib/ld.so").is_err());
+        assert!(builder.add_needed("libc.so.6").is_err());
+        // an alignment which isn't a power of two
+        let mut builder = Builder::new(&elf, &bytes).unwrap();
+        let header = SectionHeader { sh_type: section_header::SHT_PROGBITS, sh_addralign: 12, ..SectionHeader::new() };
+        builder.add_section(".misaligned", header, vec![0; 4]).unwrap();
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn round_trip_dynamic() {
+        // a PIE with an interpreter, a RUNPATH and libc as its only dependency, see etc/hello.c
+        let hello: Vec<u8> = include!("../../etc/hello.rs");
+        let elf = Elf::parse(&hello).unwrap();
+        assert_eq!(Builder::new(&elf, &hello).unwrap().build().unwrap(), hello);
+
+        let mut builder = Builder::new(&elf, &hello).unwrap();
+        builder.set_interpreter("/opt/goblin/lib/ld-linux-x86-64.so.2").unwrap();
+        builder.set_runpath("$ORIGIN/../lib:/opt/goblin/lib").unwrap();
+        builder.add_needed("libm.so.6").unwrap();
+        builder.add_needed("libgoblin.so").unwrap();
+        assert!(builder.replace_needed("libc.so.6", "libc.so.7").unwrap());
+        let bytes = builder.build().unwrap();
+
+        let elf = Elf::parse(&bytes).unwrap();
+        assert_eq!(elf.interpreter, Some("/opt/goblin/lib/ld-linux-x86-64.so.2"));
+        assert_eq!(elf.runpaths, vec!["$ORIGIN/../lib:/opt/goblin/lib"]);
+        assert!(elf.rpaths.is_empty());
+        assert_eq!(elf.libraries, vec!["libc.so.7", "libm.so.6", "libgoblin.so"]);
+        // the dynamic symbols are still named through the moved string table
+        assert!(elf.dynsyms.iter().any(|sym| elf.dynstrtab.get(sym.st_name).and_then(|name| name.ok()) == Some("__printf_chk")));
+        let phdr = elf.program_headers.iter().find(|phdr| phdr.p_type == program_header::PT_PHDR).unwrap();
+        assert_eq!(phdr.p_offset, elf.header.e_phoff);
+        // and a builder on the output writes it back unchanged
+        assert_eq!(Builder::new(&elf, &bytes).unwrap().build().unwrap(), bytes);
+    }
+
+    #[test]
+    fn rename_dynamic_symbol() {
+        let hello: Vec<u8> = include!("../../etc/hello.rs");
+        let elf = Elf::parse(&hello).unwrap();
+        let ctx = elf.ctx;
+        let mut builder = Builder::new(&elf, &hello).unwrap();
+        let dynsym = builder.section_by_name(".dynsym").unwrap();
+        let nsyms = elf.dynsyms.len();
+        // hello only imports, so its GNU hash table is empty; grow it to hash every symbol but the null one
+        let gnu_hash = builder.section_by_name(".gnu.hash").unwrap();
+        let bloom_shift: u32 = builder.section_data(gnu_hash).unwrap().pread_with(12, ctx.le).unwrap();
+        let mut data = vec![0u8; 16 + 8 + 4 + 4 * (nsyms - 1)];
+        data.pwrite_with(1u32, 4, ctx.le).unwrap();
+        data.pwrite_with(bloom_shift, 12, ctx.le).unwrap();
+        builder.set_section_data(gnu_hash, data).unwrap();
+        // and give it a SysV hash table with 3 buckets
+        let header = SectionHeader { sh_type: section_header::SHT_HASH, sh_link: dynsym as u32, sh_entsize: 4, sh_addralign: 8, ..SectionHeader::new() };
+        let mut data = vec![0u8; 8 + 4 * (3 + nsyms)];
+        data.pwrite_with(3u32, 0, ctx.le).unwrap();
+        let sysv_hash = builder.add_section(".hash", header, data).unwrap();
+        assert!(builder.rename_symbol("__printf_chk", "__printf_goblin").unwrap() >= 1);
+        let bytes = builder.build().unwrap();
+
+        let elf = Elf::parse(&bytes).unwrap();
+        let gnu = hash::GnuHash::parse(&bytes, elf.section_headers[gnu_hash].sh_offset as usize, ctx).unwrap();
+        let sysv = hash::SysvHash::parse(&bytes, elf.section_headers[sysv_hash].sh_offset as usize, ctx).unwrap();
+        assert_eq!(sysv.dynsym_count(), nsyms);
+        for table in &[hash::HashTable::Gnu(gnu), hash::HashTable::Sysv(sysv)] {
+            assert_eq!(table.find("__printf_goblin", &elf.dynsyms, &elf.dynstrtab).unwrap(), Some(7));
+            assert_eq!(table.find("__printf_chk", &elf.dynsyms, &elf.dynstrtab).unwrap(), None);
+            assert_eq!(table.find("strlen", &elf.dynsyms, &elf.dynstrtab).unwrap(), Some(3));
+        }
+        // the loader finds the new GNU hash table through the dynamic section
+        assert!(elf.lookup_dynsym("__printf_goblin").is_some());
+        assert!(elf.lookup_dynsym("__printf_chk").is_none());
+    }
+}
@@ -0,0 +1,322 @@
+//! A checksec-style report of the exploit mitigations a binary was built with.
+//!
+//! The report is computed purely from the headers and tables the parsers already expose, so it
+//! reflects what the binary _declares_: e.g., a PE may set `IMAGE_DLLCHARACTERISTICS_GUARD_CF`
+//! without a populated load config directory.
+//!
+//! ```rust,no_run
+//! use goblin::{hardening, Object};
+//! use std::fs::File;
+//! use std::io::Read;
+//!
+//! let mut bytes = Vec::new();
+//! File::open("/bin/ls").unwrap().read_to_end(&mut bytes).unwrap();
+//! let object = Object::parse(&bytes).unwrap();
+//! let report = hardening::report(&object).unwrap();
+//! println!("nx: {:?}, pie: {:?}", report.nx(), report.pie());
+//! ```
+
+use alloc::vec::Vec;
+
+use error;
+use Object;
+use elf;
+use elf::dyn::DT_BIND_NOW;
+use elf::program_header::{PT_GNU_RELRO, PT_GNU_STACK, PF_X};
+use pe;
+use pe::characteristic::IMAGE_FILE_RELOCS_STRIPPED;
+use pe::optional_header::*;
+use mach;
+use mach::header::{MH_PIE, MH_ALLOW_STACK_EXECUTION, MH_NO_HEAP_EXECUTION};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// How much of the relocated data is made read-only after relocation
+pub enum Relro {
+    /// No `PT_GNU_RELRO` segment
+    None,
+    /// A `PT_GNU_RELRO` segment, but lazy binding leaves the PLT GOT writable
+    Partial,
+    /// A `PT_GNU_RELRO` segment and immediate binding (`BIND_NOW`)
+    Full,
+}
+
+/// Whether `name` is a stack protector symbol
+fn is_canary(name: &str) -> bool {
+    name == "__stack_chk_fail" || name == "__stack_chk_guard" || name == "__intel_security_cookie"
+}
+
+/// Whether `name` is a fortified libc function, e.g., `__memcpy_chk`
+fn is_fortified(name: &str) -> bool {
+    name.starts_with("__") && name.ends_with("_chk") && !is_canary(name)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The mitigations of an ELF binary
+pub struct ElfReport<'a> {
+    /// Read-only relocations
+    pub relro: Relro,
+    /// The stack is not executable: there is a `PT_GNU_STACK` segment without `PF_X`
+    pub nx: bool,
+    /// A position independent executable: `ET_DYN` with `DF_1_PIE` or a program interpreter
+    pub pie: bool,
+    /// Built with a stack protector, i.e., it references `__stack_chk_fail`
+    pub canary: bool,
+    /// The fortified (`_FORTIFY_SOURCE`) functions it references, e.g., `__memcpy_chk`
+    pub fortified: Vec<&'a str>,
+    /// The `DT_RPATH` entries
+    pub rpath: Vec<&'a str>,
+    /// The `DT_RUNPATH` entries
+    pub runpath: Vec<&'a str>,
+}
+
+impl<'a> ElfReport<'a> {
+    /// Computes the report of `elf`
+    pub fn new(elf: &elf::Elf<'a>) -> Self {
+        let has_relro = elf.program_headers.iter().any(|phdr| phdr.p_type == PT_GNU_RELRO);
+        let nx = elf.program_headers.iter().any(|phdr| phdr.p_type == PT_GNU_STACK && phdr.p_flags & PF_X == 0);
+        let mut bind_now = false;
+        let mut is_pie = false;
+        if let Some(ref dynamic) = elf.dynamic {
+            bind_now = dynamic.flags().bind_now() || dynamic.flags_1().now() || dynamic.dyns.iter().any(|dyn| dyn.d_tag == DT_BIND_NOW);
+            is_pie = dynamic.flags_1().pie();
+        }
+        let rpath = elf.rpaths.clone();
+        let runpath = elf.runpaths.clone();
+        let relro = match (has_relro, bind_now) {
+            (false, _) => Relro::None,
+            (true, false) => Relro::Partial,
+            (true, true) => Relro::Full,
+        };
+        let pie = elf.header.e_type == elf::header::ET_DYN && (is_pie || elf.interpreter.is_some());
+        let mut canary = false;
+        let mut fortified = Vec::new();
+        let names = elf.dynsyms.iter().filter_map(|sym| elf.dynstrtab.get(sym.st_name))
+            .chain(elf.syms.iter().filter_map(|sym| elf.strtab.get(sym.st_name)))
+            .filter_map(|name| name.ok());
+        for name in names {
+            if is_canary(name) {
+                canary = true;
+            } else if is_fortified(name) && !fortified.contains(&name) {
+                fortified.push(name);
+            }
+        }
+        ElfReport { relro, nx, pie, canary, fortified, rpath, runpath }
+    }
+    /// Whether it references any fortified functions
+    pub fn fortify(&self) -> bool {
+        !self.fortified.is_empty()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// The mitigations of a PE binary, from its `DllCharacteristics`
+pub struct PEReport {
+    /// Can be relocated at load time (`DYNAMIC_BASE`) and its relocations weren't stripped
+    pub aslr: bool,
+    /// Supports a 64-bit high entropy address space (`HIGH_ENTROPY_VA`)
+    pub high_entropy_va: bool,
+    /// Compatible with data execution prevention (`NX_COMPAT`)
+    pub dep: bool,
+    /// Supports Control Flow Guard (`GUARD_CF`)
+    pub cfg: bool,
+    /// Does not use structured exception handling (`NO_SEH`)
+    pub no_seh: bool,
+    /// Code integrity checks are enforced (`FORCE_INTEGRITY`)
+    pub force_integrity: bool,
+    /// Must execute in an AppContainer (`APPCONTAINER`)
+    pub appcontainer: bool,
+}
+
+impl PEReport {
+    /// Computes the report from the COFF `characteristics` and the optional header's `dll_characteristics`
+    pub fn from_characteristics(characteristics: u16, dll_characteristics: u16) -> Self {
+        let has = |flag| dll_characteristics & flag == flag;
+        let aslr = has(IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE) && characteristics & IMAGE_FILE_RELOCS_STRIPPED == 0;
+        PEReport {
+            aslr,
+            high_entropy_va: aslr && has(IMAGE_DLLCHARACTERISTICS_HIGH_ENTROPY_VA),
+            dep: has(IMAGE_DLLCHARACTERISTICS_NX_COMPAT),
+            cfg: has(IMAGE_DLLCHARACTERISTICS_GUARD_CF),
+            no_seh: has(IMAGE_DLLCHARACTERISTICS_NO_SEH),
+            force_integrity: has(IMAGE_DLLCHARACTERISTICS_FORCE_INTEGRITY),
+            appcontainer: has(IMAGE_DLLCHARACTERISTICS_APPCONTAINER),
+        }
+    }
+    /// Computes the report of `pe`
+    pub fn new(pe: &pe::PE) -> Self {
+        let dll_characteristics = match pe.header.optional_header {
+            Some(ref optional_header) => optional_header.windows_fields.dll_characteristics,
+            None => 0,
+        };
+        Self::from_characteristics(pe.header.coff_header.characteristics, dll_characteristics)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The mitigations of a single architecture Mach-o binary
+pub struct MachReport<'a> {
+    /// A position independent executable (`MH_PIE`)
+    pub pie: bool,
+    /// The stack is not executable, i.e., `MH_ALLOW_STACK_EXECUTION` is not set
+    pub nx_stack: bool,
+    /// The heap is not executable (`MH_NO_HEAP_EXECUTION`)
+    pub nx_heap: bool,
+    /// Has a `__RESTRICT` segment, which makes dyld ignore `DYLD_*` environment variables
+    pub restrict: bool,
+    /// Built with a stack protector, i.e., it references `___stack_chk_fail`
+    pub canary: bool,
+    /// The fortified functions it references, e.g., `___memcpy_chk`
+    pub fortified: Vec<&'a str>,
+}
+
+impl<'a> MachReport<'a> {
+    /// Computes the report of `macho`
+    pub fn new(macho: &mach::MachO<'a>) -> error::Result<Self> {
+        let flags = macho.header.flags;
+        let mut restrict = false;
+        for segment in macho.segments.iter() {
+            if segment.name()? == "__RESTRICT" {
+                restrict = true;
+            }
+        }
+        let mut canary = false;
+        let mut fortified = Vec::new();
+        for symbol in macho.symbols() {
+            let (name, _) = symbol?;
+            // C symbols carry a leading underscore
+            let name = if name.starts_with('_') { &name[1..] } else { name };
+            if is_canary(name) {
+                canary = true;
+            } else if is_fortified(name) && !fortified.contains(&name) {
+                fortified.push(name);
+            }
+        }
+        Ok(MachReport {
+            pie: flags & MH_PIE != 0,
+            nx_stack: flags & MH_ALLOW_STACK_EXECUTION == 0,
+            nx_heap: flags & MH_NO_HEAP_EXECUTION != 0,
+            restrict,
+            canary,
+            fortified,
+        })
+    }
+    /// Whether it references any fortified functions
+    pub fn fortify(&self) -> bool {
+        !self.fortified.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The hardening report of an `Object`
+pub enum Report<'a> {
+    Elf(ElfReport<'a>),
+    PE(PEReport),
+    /// One report per architecture; a single report for a regular Mach-o binary
+    Mach(Vec<MachReport<'a>>),
+    /// Archives and unknown objects have no mitigations to report
+    Unsupported,
+}
+
+impl<'a> Report<'a> {
+    /// Whether the stack (or data, for PE) is not executable; `None` if not applicable
+    pub fn nx(&self) -> Option<bool> {
+        match *self {
+            Report::Elf(ref report) => Some(report.nx),
+            Report::PE(ref report) => Some(report.dep),
+            Report::Mach(ref reports) => Some(reports.iter().all(|report| report.nx_stack)),
+            Report::Unsupported => None,
+        }
+    }
+    /// Whether the binary is position independent and can be randomized; `None` if not applicable
+    pub fn pie(&self) -> Option<bool> {
+        match *self {
+            Report::Elf(ref report) => Some(report.pie),
+            Report::PE(ref report) => Some(report.aslr),
+            Report::Mach(ref reports) => Some(reports.iter().all(|report| report.pie)),
+            Report::Unsupported => None,
+        }
+    }
+    /// Whether the binary was built with a stack protector; `None` if not applicable
+    pub fn canary(&self) -> Option<bool> {
+        match *self {
+            Report::Elf(ref report) => Some(report.canary),
+            Report::Mach(ref reports) => Some(reports.iter().all(|report| report.canary)),
+            Report::PE(_) | Report::Unsupported => None,
+        }
+    }
+}
+
+/// Computes the hardening report of `object`
+pub fn report<'a>(object: &Object<'a>) -> error::Result<Report<'a>> {
+    Ok(match *object {
+        Object::Elf(ref elf) => Report::Elf(ElfReport::new(elf)),
+        Object::PE(ref pe) => Report::PE(PEReport::new(pe)),
+        Object::Mach(mach::Mach::Binary(ref macho)) => Report::Mach(vec![MachReport::new(macho)?]),
+        Object::Mach(mach::Mach::Fat(ref multi)) => {
+            let mut reports = Vec::with_capacity(multi.narches);
+            for i in 0..multi.narches {
+                reports.push(MachReport::new(&multi.get(i)?)?);
+            }
+            Report::Mach(reports)
+        },
+        Object::Archive(_) | Object::Unknown(_) => Report::Unsupported,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elf_relocatable_object() {
+        let crt1: Vec<u8> = include!("../etc/crt1.rs");
+        let object = Object::parse(&crt1).unwrap();
+        match report(&object).unwrap() {
+            Report::Elf(report) => {
+                assert_eq!(report.relro, Relro::None);
+                assert!(!report.pie);
+                assert!(!report.fortify());
+                assert!(report.rpath.is_empty());
+            },
+            report => panic!("expected an ELF report, got {:?}", report),
+        }
+    }
+
+    #[test]
+    fn elf_hardened_executable() {
+        // a PIE built with -fstack-protector-all, -D_FORTIFY_SOURCE=2, -z relro -z now and a RUNPATH, see etc/hello.c
+        let hello: Vec<u8> = include!("../etc/hello.rs");
+        let object = Object::parse(&hello).unwrap();
+        match report(&object).unwrap() {
+            Report::Elf(report) => {
+                assert_eq!(report.relro, Relro::Full);
+                assert!(report.nx);
+                assert!(report.pie);
+                assert!(report.canary);
+                assert_eq!(report.fortified, vec!["__memcpy_chk", "__printf_chk"]);
+                assert!(report.rpath.is_empty());
+                assert_eq!(report.runpath, vec!["$ORIGIN/lib"]);
+            },
+            report => panic!("expected an ELF report, got {:?}", report),
+        }
+        let summary = report(&object).unwrap();
+        assert_eq!((summary.nx(), summary.pie(), summary.canary()), (Some(true), Some(true), Some(true)));
+    }
+
+    #[test]
+    fn pe_dll_characteristics() {
+        let report = PEReport::from_characteristics(0, 0x8160);
+        assert!(report.aslr && report.high_entropy_va && report.dep);
+        assert!(!report.cfg && !report.no_seh);
+        let report = PEReport::from_characteristics(IMAGE_FILE_RELOCS_STRIPPED, IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE | IMAGE_DLLCHARACTERISTICS_GUARD_CF);
+        assert!(!report.aslr && report.cfg);
+    }
+
+    #[test]
+    fn fortified_names() {
+        assert!(is_fortified("__memcpy_chk"));
+        assert!(is_fortified("__printf_chk"));
+        assert!(!is_fortified("__stack_chk_fail"));
+        assert!(!is_fortified("memcpy"));
+    }
+}
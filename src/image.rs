@@ -0,0 +1,691 @@
+//! A sparse virtual memory image of a binary, mapped the way a loader would map it.
+//!
+//! The image is built from the `PT_LOAD` segments of an ELF, the sections of a PE or the segments
+//! of a Mach-O: the file backed bytes are copied to their virtual addresses, and the remaining
+//! memory (e.g., `.bss`) is zero filled. Memory is tracked in pages of `PAGE_SIZE` bytes, each with
+//! the union of the permissions of the segments or sections overlapping it.
+//!
+//! An image can be moved to another load address with `Image::rebase`, which applies the ELF
+//! relative relocations, the PE base relocations or the Mach-O rebase opcodes.
+//!
+//! ```rust,no_run
+//! use goblin::{image::Image, Object};
+//! use std::fs::File;
+//! use std::io::Read;
+//!
+//! let mut bytes = Vec::new();
+//! File::open("/bin/ls").unwrap().read_to_end(&mut bytes).unwrap();
+//! let object = Object::parse(&bytes).unwrap();
+//! let mut image = Image::new(&object, &bytes).unwrap();
+//! image.rebase(0x5555_5555_4000).unwrap();
+//! let mut code = [0u8; 16];
+//! let entry = image.entry;
+//! assert!(image.read(entry, &mut code));
+//! ```
+
+use alloc::vec::Vec;
+use alloc::collections::btree_map::{self, BTreeMap};
+use scroll::{Pread, Pwrite, Endian, Uleb128};
+
+use error;
+use Object;
+use align_up;
+use elf;
+use elf::program_header::{PT_LOAD, PF_R, PF_W, PF_X};
+use pe;
+use pe::section_table::{IMAGE_SCN_MEM_READ, IMAGE_SCN_MEM_WRITE, IMAGE_SCN_MEM_EXECUTE};
+use pe::characteristic::IMAGE_FILE_RELOCS_STRIPPED;
+use pe::relocation::{RelocationData, BaseRelocation};
+use mach;
+use mach::bind_opcodes::*;
+use mach::load_command::CommandVariant;
+
+/// The size of a page of the image
+pub const PAGE_SIZE: u64 = 0x1000;
+
+/// The most memory an image can map, which bounds what a malformed binary can make us allocate
+pub const MAX_SIZE: u64 = 1 << 32;
+
+static ZERO_PAGE: [u8; PAGE_SIZE as usize] = [0; PAGE_SIZE as usize];
+
+/// Mach-O `VM_PROT_*` protection bits
+const VM_PROT_READ: u32 = 0x1;
+const VM_PROT_WRITE: u32 = 0x2;
+const VM_PROT_EXECUTE: u32 = 0x4;
+
+#[inline]
+fn page_floor(address: u64) -> u64 {
+    address & !(PAGE_SIZE - 1)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// The memory protection of a page
+pub struct Permissions {
+    pub read: bool,
+    pub write: bool,
+    pub execute: bool,
+}
+
+impl Permissions {
+    fn union(self, other: Permissions) -> Permissions {
+        Permissions {
+            read: self.read || other.read,
+            write: self.write || other.write,
+            execute: self.execute || other.execute,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A page of the image, with `PAGE_SIZE` bytes; a zero filled page has no data until it's written to
+struct Page {
+    data: Vec<u8>,
+    permissions: Permissions,
+}
+
+impl Page {
+    fn data(&self) -> &[u8] {
+        if self.data.is_empty() { &ZERO_PAGE } else { &self.data }
+    }
+    fn data_mut(&mut self) -> &mut [u8] {
+        if self.data.is_empty() {
+            self.data = vec![0; PAGE_SIZE as usize];
+        }
+        &mut self.data
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A word which must be adjusted when the image is rebased
+struct Fixup {
+    /// The address of the word, relative to the image's original base
+    offset: u64,
+    /// The size of the word, 4 or 8 bytes
+    size: usize,
+    /// The word is set to the load bias plus this addend, instead of having the delta added to it
+    addend: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default)]
+/// A sparse virtual memory image of a binary
+pub struct Image {
+    /// The address the image is loaded at: the lowest mapped address for an ELF, the image base for
+    /// a PE and the address of the `__TEXT` segment for a Mach-O
+    pub base: u64,
+    /// The address of the entry point, or 0 if there is none
+    pub entry: u64,
+    /// The endianness of the words patched by rebasing
+    pub endian: Endian,
+    pages: BTreeMap<u64, Page>,
+    /// The base the binary was linked at
+    original_base: u64,
+    /// Whether the binary can be loaded at another address
+    relocatable: bool,
+    fixups: Vec<Fixup>,
+    /// The PE base relocations, which aren't all whole words
+    base_relocations: Vec<BaseRelocation>,
+    /// The COFF machine of a PE, which the base relocation types depend on
+    machine: u16,
+}
+
+impl Image {
+    /// Maps the binary `object`, parsed from `bytes`, at its preferred address
+    pub fn new(object: &Object, bytes: &[u8]) -> error::Result<Self> {
+        match *object {
+            Object::Elf(ref elf) => Image::from_elf(elf, bytes),
+            Object::PE(ref pe) => Image::from_pe(pe, bytes),
+            Object::Mach(mach::Mach::Binary(ref macho)) => Image::from_mach(macho, bytes),
+            Object::Mach(mach::Mach::Fat(_)) => Err(error::Error::Malformed("Cannot map a fat Mach-O, map one of its architectures".to_string())),
+            _ => Err(error::Error::Malformed("Cannot map an object which isn't an ELF, PE or Mach-O binary".to_string())),
+        }
+    }
+    /// Maps the `PT_LOAD` segments of `elf`, parsed from `bytes`, at their virtual addresses
+    pub fn from_elf(elf: &elf::Elf, bytes: &[u8]) -> error::Result<Self> {
+        let mut image = Image {
+            endian: if elf.little_endian { Endian::Little } else { Endian::Big },
+            entry: elf.entry,
+            relocatable: elf.header.e_type == elf::header::ET_DYN,
+            ..Image::default()
+        };
+        let mut base = None;
+        for phdr in elf.program_headers.iter().filter(|phdr| phdr.p_type == PT_LOAD) {
+            let permissions = Permissions {
+                read: phdr.p_flags & PF_R != 0,
+                write: phdr.p_flags & PF_W != 0,
+                execute: phdr.p_flags & PF_X != 0,
+            };
+            let data = file_data(bytes, phdr.p_offset, phdr.p_filesz)?;
+            image.map(phdr.p_vaddr, phdr.p_memsz, data, permissions)?;
+            if base.is_none() {
+                base = Some(page_floor(phdr.p_vaddr));
+            }
+        }
+        image.base = base.unwrap_or(0);
+        image.original_base = image.base;
+        if let Some(r_type) = elf::reloc::r_relative(elf.header.e_machine) {
+            let size = if elf.is_64 { 8 } else { 4 };
+            let relocs = elf.dynrelas.iter().chain(elf.dynrels.iter()).chain(elf.dynrelrs.iter()).chain(elf.android_relocs.iter());
+            for reloc in relocs.filter(|reloc| reloc.r_type == r_type) {
+                image.fixups.push(Fixup { offset: reloc.r_offset.wrapping_sub(image.base), size, addend: reloc.r_addend });
+            }
+        }
+        Ok(image)
+    }
+    /// Maps the headers and sections of `pe`, parsed from `bytes`, at its image base, the way the
+    /// Windows loader aligns them with `file_alignment` and `section_alignment`
+    pub fn from_pe(pe: &pe::PE, bytes: &[u8]) -> error::Result<Self> {
+        let optional_header = match pe.header.optional_header {
+            Some(ref optional_header) => optional_header,
+            None => return Err(error::Error::Malformed("Cannot map a PE without an optional header".to_string())),
+        };
+        let windows_fields = &optional_header.windows_fields;
+        let file_alignment = windows_fields.file_alignment as u64;
+        let section_alignment = windows_fields.section_alignment as u64;
+        let base = pe.image_base as u64;
+        let rva_address = |rva: u32| base.checked_add(rva as u64)
+            .ok_or_else(|| error::Error::Malformed(format!("RVA {:#x} overflows the address space at image base {:#x}", rva, base)));
+        let mut image = Image {
+            base,
+            original_base: base,
+            endian: Endian::Little,
+            entry: if pe.entry == 0 { 0 } else { base.wrapping_add(pe.entry as u64) },
+            relocatable: pe.header.coff_header.characteristics & IMAGE_FILE_RELOCS_STRIPPED == 0,
+            machine: pe.header.coff_header.machine,
+            ..Image::default()
+        };
+        let readonly = Permissions { read: true, write: false, execute: false };
+        let size_of_headers = windows_fields.size_of_headers as u64;
+        image.map(base, size_of_headers, file_data(bytes, 0, size_of_headers)?, readonly)?;
+        for section in &pe.sections {
+            let virtual_size = if section.virtual_size == 0 { section.size_of_raw_data } else { section.virtual_size } as u64;
+            // the loader rounds the file offset down to a sector, and reads no more than the aligned sizes
+            let offset = if file_alignment >= 0x200 { section.pointer_to_raw_data as u64 & !0x1ff } else { section.pointer_to_raw_data as u64 };
+            let (raw_size, virtual_size) = match (align_up(section.size_of_raw_data as u64, file_alignment), align_up(virtual_size, section_alignment)) {
+                (Some(raw_size), Some(virtual_size)) => (raw_size, virtual_size),
+                _ => return Err(error::Error::Malformed(format!("File alignment {:#x} or section alignment {:#x} is not a power of two", file_alignment, section_alignment))),
+            };
+            let raw_size = ::core::cmp::min(raw_size, virtual_size);
+            let raw_size = ::core::cmp::min(raw_size, (bytes.len() as u64).saturating_sub(offset));
+            let permissions = Permissions {
+                read: section.characteristics & IMAGE_SCN_MEM_READ != 0,
+                write: section.characteristics & IMAGE_SCN_MEM_WRITE != 0,
+                execute: section.characteristics & IMAGE_SCN_MEM_EXECUTE != 0,
+            };
+            let data = file_data(bytes, offset, raw_size)?;
+            image.map(rva_address(section.virtual_address)?, virtual_size, data, permissions)?;
+        }
+        if let Some(ref directory) = *optional_header.data_directories.get_base_relocation_table() {
+            image.pe_fixups(directory.virtual_address as u64, directory.size as u64)?;
+        }
+        Ok(image)
+    }
+    /// Maps the segments of `macho`, parsed from `bytes`, at their virtual addresses
+    pub fn from_mach(macho: &mach::MachO, bytes: &[u8]) -> error::Result<Self> {
+        let mut image = Image {
+            endian: if macho.little_endian { Endian::Little } else { Endian::Big },
+            entry: macho.entry,
+            relocatable: macho.header.flags & mach::header::MH_PIE != 0 || macho.header.filetype == mach::header::MH_DYLIB,
+            ..Image::default()
+        };
+        let mut base = None;
+        for segment in macho.segments.iter() {
+            // e.g., __PAGEZERO, which only reserves the address space
+            if segment.initprot == 0 {
+                continue;
+            }
+            let permissions = Permissions {
+                read: segment.initprot & VM_PROT_READ != 0,
+                write: segment.initprot & VM_PROT_WRITE != 0,
+                execute: segment.initprot & VM_PROT_EXECUTE != 0,
+            };
+            let data = file_data(bytes, segment.fileoff, segment.filesize)?;
+            image.map(segment.vmaddr, segment.vmsize, data, permissions)?;
+            if segment.fileoff == 0 && segment.filesize != 0 {
+                base = Some(segment.vmaddr);
+            }
+        }
+        image.base = base.unwrap_or(0);
+        image.original_base = image.base;
+        let mut has_rebase_info = false;
+        for command in &macho.load_commands {
+            match command.command {
+                CommandVariant::DyldInfo(info) | CommandVariant::DyldInfoOnly(info) => {
+                    let opcodes = file_data(bytes, info.rebase_off as u64, info.rebase_size as u64)?;
+                    image.mach_fixups(macho, opcodes)?;
+                    has_rebase_info = true;
+                },
+                _ => (),
+            }
+        }
+        // without rebase opcodes, e.g., with chained fixups, we can't tell which pointers to slide
+        image.relocatable = image.relocatable && has_rebase_info;
+        Ok(image)
+    }
+    /// Copies `data` to `address`, and zero fills the rest of the `size` bytes there
+    fn map(&mut self, address: u64, size: u64, data: &[u8], permissions: Permissions) -> error::Result<()> {
+        let size = ::core::cmp::max(size, data.len() as u64);
+        let overflow = || error::Error::Malformed(format!("Mapping {:#x} bytes at {:#x} overflows the address space", size, address));
+        let end = address.checked_add(size).ok_or_else(overflow)?;
+        let mut page_address = page_floor(address);
+        // counting the pages which are already mapped too
+        let span = end - page_address;
+        let pages = span / PAGE_SIZE + (span & (PAGE_SIZE - 1) != 0) as u64;
+        if pages > (MAX_SIZE / PAGE_SIZE).saturating_sub(self.pages.len() as u64) {
+            return Err(error::Error::Malformed(format!("Mapping {:#x} bytes at {:#x} makes the image larger than {:#x} bytes", size, address, MAX_SIZE)));
+        }
+        while page_address < end {
+            let page_end = page_address.checked_add(PAGE_SIZE).ok_or_else(overflow)?;
+            let page = self.pages.entry(page_address).or_insert_with(Page::default);
+            page.permissions = page.permissions.union(permissions);
+            let start = ::core::cmp::max(page_address, address);
+            // data is no larger than size, so this can't overflow either
+            let stop = ::core::cmp::min(page_end, address + data.len() as u64);
+            if start < stop {
+                let source = &data[(start - address) as usize..(stop - address) as usize];
+                page.data_mut()[(start - page_address) as usize..(stop - page_address) as usize].copy_from_slice(source);
+            }
+            page_address = page_end;
+        }
+        Ok(())
+    }
+    /// Collects the PE base relocations of the `size` bytes of blocks at `rva`
+    fn pe_fixups(&mut self, rva: u64, size: u64) -> error::Result<()> {
+        // the table must be mapped, so it can't be larger than the image
+        if size > self.size() as u64 {
+            return Err(error::Error::Malformed(format!("Base relocation table at {:#x} of size {:#x} is larger than the image", rva, size)));
+        }
+        let mut blocks = vec![0u8; size as usize];
+        if !self.read(self.base.wrapping_add(rva), &mut blocks) {
+            return Err(error::Error::Malformed(format!("Base relocation table at {:#x} isn't mapped", rva)));
+        }
+        for relocation in RelocationData::new(&blocks).relocations()? {
+            let size = relocation.size(self.machine)
+                .ok_or_else(|| error::Error::Malformed(format!("Unsupported base relocation type {} at {:#x}", relocation.typ, relocation.rva)))?;
+            // checked upfront, so that rebasing can't fail halfway through
+            let address = self.base.wrapping_add(relocation.rva as u64);
+            match address.checked_add(size as u64 - 1) {
+                Some(last) if self.is_mapped_range(address, last) => (),
+                _ => return Err(error::Error::Malformed(format!("Base relocation at {:#x} isn't mapped", relocation.rva))),
+            }
+            self.base_relocations.push(relocation);
+        }
+        Ok(())
+    }
+    /// Collects the pointers to slide by interpreting the Mach-O rebase `opcodes`
+    fn mach_fixups(&mut self, macho: &mach::MachO, opcodes: &[u8]) -> error::Result<()> {
+        let pointer_size = if macho.is_64 { 8 } else { 4 };
+        // every fixup is a distinct word of the image, so there can't be more than it has words
+        let max_fixups = self.size() as u64 / 4;
+        let mut typ = 0;
+        let mut address = 0u64;
+        let mut offset = 0;
+        while offset < opcodes.len() {
+            let byte = opcodes.gread::<u8>(&mut offset)?;
+            let immediate = (byte & REBASE_IMMEDIATE_MASK) as u64;
+            let mut times = 0;
+            let mut skip = 0;
+            match byte & REBASE_OPCODE_MASK {
+                REBASE_OPCODE_DONE => break,
+                REBASE_OPCODE_SET_TYPE_IMM => typ = immediate as u8,
+                REBASE_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB => {
+                    let segment = match macho.segments.get(immediate as usize) {
+                        Some(segment) => segment,
+                        None => return Err(error::Error::Malformed(format!("Rebase opcodes refer to invalid segment {}", immediate))),
+                    };
+                    address = segment.vmaddr.wrapping_add(Uleb128::read(opcodes, &mut offset)?);
+                },
+                REBASE_OPCODE_ADD_ADDR_ULEB => address = address.wrapping_add(Uleb128::read(opcodes, &mut offset)?),
+                REBASE_OPCODE_ADD_ADDR_IMM_SCALED => address = address.wrapping_add(immediate * pointer_size),
+                REBASE_OPCODE_DO_REBASE_IMM_TIMES => times = immediate,
+                REBASE_OPCODE_DO_REBASE_ULEB_TIMES => times = Uleb128::read(opcodes, &mut offset)?,
+                REBASE_OPCODE_DO_REBASE_ADD_ADDR_ULEB => {
+                    times = 1;
+                    skip = Uleb128::read(opcodes, &mut offset)?;
+                },
+                REBASE_OPCODE_DO_REBASE_ULEB_TIMES_SKIPPING_ULEB => {
+                    times = Uleb128::read(opcodes, &mut offset)?;
+                    skip = Uleb128::read(opcodes, &mut offset)?;
+                },
+                opcode => return Err(error::Error::Malformed(format!("Unknown rebase opcode {:#x}", opcode))),
+            }
+            if times > max_fixups.saturating_sub(self.fixups.len() as u64) {
+                return Err(error::Error::Malformed(format!("Rebase opcodes slide more pointers than the image has: {} more at {:#x}", times, address)));
+            }
+            for _ in 0..times {
+                let size = match typ {
+                    REBASE_TYPE_POINTER => pointer_size as usize,
+                    REBASE_TYPE_TEXT_ABSOLUTE32 => 4,
+                    _ => return Err(error::Error::Malformed(format!("Unsupported rebase type {}", typ))),
+                };
+                self.fixups.push(Fixup { offset: address.wrapping_sub(self.base), size, addend: None });
+                address = address.wrapping_add(pointer_size).wrapping_add(skip);
+            }
+        }
+        Ok(())
+    }
+    /// Moves the image to `base`, applying the relocations which depend on the load address.
+    ///
+    /// `base` must be page aligned, and the binary must be relocatable, e.g., not an ELF `ET_EXEC`
+    pub fn rebase(&mut self, base: u64) -> error::Result<()> {
+        if base == self.base {
+            return Ok(());
+        }
+        if !self.relocatable {
+            return Err(error::Error::Malformed("Cannot rebase a binary which isn't relocatable".to_string()));
+        }
+        if base & (PAGE_SIZE - 1) != 0 {
+            return Err(error::Error::Malformed(format!("Cannot rebase to unaligned address {:#x}", base)));
+        }
+        let delta = base.wrapping_sub(self.base);
+        let pages = ::core::mem::replace(&mut self.pages, BTreeMap::new());
+        self.pages = pages.into_iter().map(|(address, page)| (address.wrapping_add(delta), page)).collect();
+        if self.entry != 0 {
+            self.entry = self.entry.wrapping_add(delta);
+        }
+        self.base = base;
+        let bias = base.wrapping_sub(self.original_base);
+        let fixups = ::core::mem::replace(&mut self.fixups, Vec::new());
+        let base_relocations = ::core::mem::replace(&mut self.base_relocations, Vec::new());
+        let result = self.apply_fixups(&fixups, &base_relocations, delta, bias);
+        self.fixups = fixups;
+        self.base_relocations = base_relocations;
+        result
+    }
+    fn apply_fixups(&mut self, fixups: &[Fixup], base_relocations: &[BaseRelocation], delta: u64, bias: u64) -> error::Result<()> {
+        let endian = self.endian;
+        for fixup in fixups {
+            let address = self.base.wrapping_add(fixup.offset);
+            let mut word = [0u8; 8];
+            let word = &mut word[..fixup.size];
+            if !self.read(address, word) {
+                return Err(error::Error::Malformed(format!("Relocated word at {:#x} isn't mapped", address)));
+            }
+            match (fixup.size, fixup.addend) {
+                (4, Some(addend)) => { word.pwrite_with(bias.wrapping_add(addend as u64) as u32, 0, endian)?; },
+                (4, None) => { let value: u32 = word.pread_with(0, endian)?; word.pwrite_with(value.wrapping_add(delta as u32), 0, endian)?; },
+                (_, Some(addend)) => { word.pwrite_with(bias.wrapping_add(addend as u64), 0, endian)?; },
+                (_, None) => { let value: u64 = word.pread_with(0, endian)?; word.pwrite_with(value.wrapping_add(delta), 0, endian)?; },
+            }
+            self.write(address, word);
+        }
+        for relocation in base_relocations {
+            let address = self.base.wrapping_add(relocation.rva as u64);
+            let mut word = [0u8; 8];
+            let word = &mut word[..relocation.size(self.machine).unwrap_or(0)];
+            if !self.read(address, word) {
+                return Err(error::Error::Malformed(format!("Relocated word at {:#x} isn't mapped", address)));
+            }
+            relocation.apply(word, 0, delta, self.machine)?;
+            self.write(address, word);
+        }
+        Ok(())
+    }
+    /// Reads `buf.len()` bytes at the virtual address `address` into `buf`; returns `false` if any of them isn't mapped
+    pub fn read(&self, address: u64, buf: &mut [u8]) -> bool {
+        let mut done = 0;
+        while done < buf.len() {
+            let current = address.wrapping_add(done as u64);
+            let page_address = page_floor(current);
+            let page = match self.pages.get(&page_address) {
+                Some(page) => page,
+                None => return false,
+            };
+            let start = (current - page_address) as usize;
+            let count = ::core::cmp::min(buf.len() - done, PAGE_SIZE as usize - start);
+            buf[done..done + count].copy_from_slice(&page.data()[start..start + count]);
+            done += count;
+        }
+        true
+    }
+    /// Writes `data` at the virtual address `address`, regardless of the pages' permissions;
+    /// returns `false`, without writing anything, if any of the bytes isn't mapped
+    pub fn write(&mut self, address: u64, data: &[u8]) -> bool {
+        if data.is_empty() {
+            return true;
+        }
+        match address.checked_add(data.len() as u64 - 1) {
+            Some(last) if self.is_mapped_range(address, last) => (),
+            _ => return false,
+        }
+        let mut done = 0;
+        while done < data.len() {
+            let current = address.wrapping_add(done as u64);
+            let page_address = page_floor(current);
+            let page = self.pages.get_mut(&page_address).unwrap();
+            let start = (current - page_address) as usize;
+            let count = ::core::cmp::min(data.len() - done, PAGE_SIZE as usize - start);
+            page.data_mut()[start..start + count].copy_from_slice(&data[done..done + count]);
+            done += count;
+        }
+        true
+    }
+    fn is_mapped_range(&self, first: u64, last: u64) -> bool {
+        let mut page_address = page_floor(first);
+        loop {
+            if !self.pages.contains_key(&page_address) {
+                return false;
+            }
+            if page_address >= page_floor(last) {
+                return true;
+            }
+            page_address += PAGE_SIZE;
+        }
+    }
+    /// Whether the virtual address `address` is mapped
+    pub fn is_mapped(&self, address: u64) -> bool {
+        self.pages.contains_key(&page_floor(address))
+    }
+    /// Returns the permissions of the page containing `address`, or `None` if it isn't mapped
+    pub fn permissions(&self, address: u64) -> Option<Permissions> {
+        self.pages.get(&page_floor(address)).map(|page| page.permissions)
+    }
+    /// Returns the mapped pages in address order, with their addresses, contents and permissions
+    pub fn pages<'a>(&'a self) -> Pages<'a> {
+        Pages { iter: self.pages.iter() }
+    }
+    /// The number of mapped bytes
+    pub fn size(&self) -> usize {
+        self.pages.len() * PAGE_SIZE as usize
+    }
+}
+
+/// An iterator over the mapped pages of an `Image`, in address order
+pub struct Pages<'a> {
+    iter: btree_map::Iter<'a, u64, Page>,
+}
+
+impl<'a> Iterator for Pages<'a> {
+    type Item = (u64, &'a [u8], Permissions);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(&address, page)| (address, page.data(), page.permissions))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Returns the `size` bytes at `offset` in `bytes`
+fn file_data(bytes: &[u8], offset: u64, size: u64) -> error::Result<&[u8]> {
+    if size == 0 {
+        return Ok(&[]);
+    }
+    let end = offset.checked_add(size);
+    match end {
+        Some(end) if end <= bytes.len() as u64 => Ok(&bytes[offset as usize..end as usize]),
+        _ => Err(error::Error::Malformed(format!("File data at {:#x} of size {:#x} is beyond the end of the file", offset, size))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::LE;
+
+    #[test]
+    fn map_read_write() {
+        let mut image = Image::default();
+        let rx = Permissions { read: true, write: false, execute: true };
+        let rw = Permissions { read: true, write: true, execute: false };
+        image.map(0x1ff8, 0x10, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], rx).unwrap();
+        // shares the page at 0x2000, and has a zero filled tail
+        image.map(0x2010, 0x2000, &[0xaa; 4], rw).unwrap();
+        assert_eq!(image.size(), 4 * PAGE_SIZE as usize);
+        assert_eq!(image.permissions(0x1000), Some(rx));
+        assert_eq!(image.permissions(0x2000), Some(Permissions { read: true, write: true, execute: true }));
+        assert_eq!(image.permissions(0x4000), Some(rw));
+        assert_eq!(image.permissions(0x5000), None);
+        let mut buf = [0u8; 4];
+        assert!(image.read(0x1ffe, &mut buf));
+        assert_eq!(buf, [7, 8, 9, 10]);
+        assert!(image.read(0x2012, &mut buf));
+        assert_eq!(buf, [0xaa, 0xaa, 0, 0]);
+        assert!(!image.read(0xffe, &mut buf));
+        assert!(image.write(0x1ffe, &[0xbb; 4]));
+        assert!(!image.write(0x4ffe, &[0xbb; 4]));
+        assert!(image.read(0x1ffc, &mut buf));
+        assert_eq!(buf, [5, 6, 0xbb, 0xbb]);
+        // the zero filled page is only allocated when it's written to
+        assert!(image.pages[&0x4000].data.is_empty());
+        assert!(image.write(0x4000, &[0xcc]));
+        assert!(image.read(0x3fff, &mut buf[..2]));
+        assert_eq!(buf[..2], [0, 0xcc]);
+        // wrapping around or beyond the end of the address space, or beyond the size limit
+        assert!(!image.write(!0, &[0; 2]));
+        assert!(image.map(!0 - 0x10, 0x20, &[], rw).is_err());
+        assert!(image.map(0x1_0000_0000, MAX_SIZE, &[], rw).is_err());
+    }
+
+    #[test]
+    fn rebase_fixups() {
+        let mut image = Image { relocatable: true, endian: Endian::Little, base: 0x1000, original_base: 0x1000, entry: 0x1010, ..Image::default() };
+        let mut data = [0u8; 16];
+        data.pwrite_with(0x1008u64, 0, LE).unwrap();
+        data.pwrite_with(0x1004u32, 8, LE).unwrap();
+        image.map(0x1000, 0x1000, &data, Permissions::default()).unwrap();
+        image.fixups.push(Fixup { offset: 0, size: 8, addend: None });
+        image.fixups.push(Fixup { offset: 8, size: 4, addend: None });
+        image.fixups.push(Fixup { offset: 12, size: 4, addend: Some(0x20) });
+        assert!(image.rebase(0x1234).is_err());
+        image.rebase(0x10000).unwrap();
+        image.rebase(0x20000).unwrap();
+        assert_eq!(image.entry, 0x20010);
+        assert!(!image.is_mapped(0x1000));
+        let mut buf = [0u8; 16];
+        assert!(image.read(0x20000, &mut buf));
+        assert_eq!(buf.pread_with::<u64>(0, LE).unwrap(), 0x20008);
+        assert_eq!(buf.pread_with::<u32>(8, LE).unwrap(), 0x20004);
+        // the load bias plus the addend
+        assert_eq!(buf.pread_with::<u32>(12, LE).unwrap(), 0x1f020);
+    }
+
+    #[test]
+    fn map_elf() {
+        // a PIE whose .init_array holds frame_dummy (0x860) through a relative relocation, see etc/hello.c
+        let bytes: Vec<u8> = include!("../etc/hello.rs");
+        let object = Object::parse(&bytes).unwrap();
+        let mut image = Image::new(&object, &bytes).unwrap();
+        assert_eq!((image.base, image.entry), (0, 0x780));
+        let pages: Vec<(u64, Permissions)> = image.pages().map(|(address, _, permissions)| (address, permissions)).collect();
+        let rx = Permissions { read: true, write: false, execute: true };
+        let rw = Permissions { read: true, write: true, execute: false };
+        assert_eq!(pages, vec![(0, rx), (0x1000, rw), (0x2000, rw)]);
+        let mut magic = [0u8; 4];
+        assert!(image.read(0, &mut magic));
+        assert_eq!(&magic, b"\x7fELF");
+        image.rebase(0x5555_5555_4000).unwrap();
+        assert_eq!(image.entry, 0x5555_5555_4780);
+        let mut init_array = [0u8; 8];
+        assert!(image.read(0x5555_5555_5d90, &mut init_array));
+        assert_eq!(init_array.pread_with::<u64>(0, LE).unwrap(), 0x5555_5555_4860);
+    }
+
+    #[test]
+    fn map_pe() {
+        // a DLL with a .data section of 0x10 bytes in the file and 0x1800 in memory, see etc/hello_dll.yaml
+        let bytes: Vec<u8> = include!("../etc/hello_dll.rs");
+        let object = Object::parse(&bytes).unwrap();
+        let mut image = Image::new(&object, &bytes).unwrap();
+        assert_eq!((image.base, image.entry), (0x1_4000_0000, 0x1_4000_1020));
+        assert_eq!(image.size(), 6 * PAGE_SIZE as usize);
+        assert_eq!(image.permissions(0x1_4000_1000), Some(Permissions { read: true, write: false, execute: true }));
+        assert_eq!(image.permissions(0x1_4000_4000), Some(Permissions { read: true, write: true, execute: false }));
+        let mut code = [0u8; 4];
+        assert!(image.read(0x1_4000_1000, &mut code));
+        assert_eq!(code, [0x8d, 0x04, 0x11, 0xc3]);
+        image.rebase(0x1_8000_0000).unwrap();
+        let mut data = [0u8; 0x18];
+        assert!(image.read(0x1_8000_3000, &mut data));
+        assert_eq!(data.pread_with::<u64>(0, LE).unwrap(), 0x1_8000_1000);
+        assert_eq!(data.pread_with::<u64>(8, LE).unwrap(), 0x1_8000_1020);
+        assert_eq!(data.pread_with::<u64>(0x10, LE).unwrap(), 0);
+        assert!(image.read(0x1_8000_47f8, &mut data[..8]));
+        // the loader rejects alignments which aren't powers of two
+        let mut object = object;
+        if let Object::PE(ref mut pe) = object {
+            pe.header.optional_header.as_mut().unwrap().windows_fields.file_alignment = 0x300;
+        }
+        assert!(Image::new(&object, &bytes).is_err());
+    }
+
+    #[test]
+    fn rebase_pe_halfwords() {
+        use pe::header::{COFF_MACHINE_X86, COFF_MACHINE_ARMNT};
+        use pe::relocation::{IMAGE_REL_BASED_HIGH, IMAGE_REL_BASED_LOW, IMAGE_REL_BASED_HIGHADJ, IMAGE_REL_BASED_HIGHLOW, IMAGE_REL_BASED_ARM_MOV32};
+        // a page with the halves of 0x401000 and the address itself, and a block relocating them at 0x800
+        let mut page = vec![0u8; 0x820];
+        page.pwrite_with(0x0040u16, 0, LE).unwrap();
+        page.pwrite_with(0x1000u16, 2, LE).unwrap();
+        page.pwrite_with(0x0040u16, 4, LE).unwrap();
+        page.pwrite_with(0x0040_1000u32, 8, LE).unwrap();
+        page.pwrite_with(0x1000u32, 0x800, LE).unwrap();
+        page.pwrite_with(20u32, 0x804, LE).unwrap();
+        let entries = [IMAGE_REL_BASED_HIGH << 12, IMAGE_REL_BASED_LOW << 12 | 2, IMAGE_REL_BASED_HIGHADJ << 12 | 4, 0x9000, IMAGE_REL_BASED_HIGHLOW << 12 | 8, 0];
+        for (i, entry) in entries.iter().enumerate() {
+            page.pwrite_with(*entry, 0x808 + i * 2, LE).unwrap();
+        }
+        let rw = Permissions { read: true, write: true, execute: false };
+        let mut image = Image { base: 0x40_0000, original_base: 0x40_0000, relocatable: true, machine: COFF_MACHINE_X86, ..Image::default() };
+        image.map(0x40_1000, PAGE_SIZE, &page, rw).unwrap();
+        image.pe_fixups(0x1800, 20).unwrap();
+        image.rebase(0x163_4000).unwrap();
+        let mut words = [0u8; 12];
+        assert!(image.read(0x163_5000, &mut words));
+        // HIGH and LOW add the halves of the delta 0x1234000, HIGHADJ rounds 0x40 << 16 - 0x7000 + delta
+        assert_eq!(words.pread_with::<u16>(0, LE).unwrap(), 0x0163);
+        assert_eq!(words.pread_with::<u16>(2, LE).unwrap(), 0x5000);
+        assert_eq!(words.pread_with::<u16>(4, LE).unwrap(), 0x0163);
+        assert_eq!(words.pread_with::<u32>(8, LE).unwrap(), 0x0163_5000);
+
+        // ARM_MOV32 is only valid for ARM images
+        page.pwrite_with(IMAGE_REL_BASED_ARM_MOV32 << 12 | 0x10, 0x808, LE).unwrap();
+        let mut image = Image { base: 0x40_0000, original_base: 0x40_0000, relocatable: true, machine: COFF_MACHINE_X86, ..Image::default() };
+        image.map(0x40_1000, PAGE_SIZE, &page, rw).unwrap();
+        assert!(image.pe_fixups(0x1800, 20).is_err());
+        image.machine = COFF_MACHINE_ARMNT;
+        assert!(image.pe_fixups(0x1800, 20).is_ok());
+        // a relocation straddling the end of the image is rejected before rebasing
+        page.pwrite_with(IMAGE_REL_BASED_HIGHLOW << 12 | 0xffe, 0x808, LE).unwrap();
+        let mut image = Image { base: 0x40_0000, original_base: 0x40_0000, relocatable: true, machine: COFF_MACHINE_X86, ..Image::default() };
+        image.map(0x40_1000, PAGE_SIZE, &page, rw).unwrap();
+        assert!(image.pe_fixups(0x1800, 20).is_err());
+    }
+
+    #[test]
+    fn map_mach() {
+        // a PIE with a pointer in __DATA slid by its rebase opcodes
+        let bytes: Vec<u8> = include!("../etc/deadbeef_mach64.rs");
+        let object = Object::parse(&bytes).unwrap();
+        let mut image = Image::new(&object, &bytes).unwrap();
+        assert_eq!((image.base, image.entry), (0x1_0000_0000, 0x1_0000_0f40));
+        // __PAGEZERO isn't mapped
+        assert!(!image.is_mapped(0));
+        assert_eq!(image.size(), 3 * PAGE_SIZE as usize);
+        let mut pointer = [0u8; 8];
+        assert!(image.read(0x1_0000_1010, &mut pointer));
+        let original = pointer.pread_with::<u64>(0, LE).unwrap();
+        assert!(original > image.base && original < image.base + PAGE_SIZE);
+        image.rebase(0x2_0000_0000).unwrap();
+        assert!(image.read(0x2_0000_1010, &mut pointer));
+        assert_eq!(pointer.pread_with::<u64>(0, LE).unwrap(), original + 0x1_0000_0000);
+    }
+}
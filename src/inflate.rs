@@ -0,0 +1,382 @@
+//! A small, allocation based DEFLATE (RFC 1951) and zlib (RFC 1950) decoder.
+//!
+//! This is only meant for decompressing the handful of compressed sections binaries carry
+//! (e.g., `SHF_COMPRESSED` or `.zdebug` debug info in ELF), and so favors simplicity and
+//! bounds checking over speed. Every malformed input is reported as `error::Error::Malformed`.
+
+use alloc::vec::Vec;
+use error;
+
+const MAX_BITS: usize = 15;
+/// The most a DEFLATE stream can expand: a 258 byte match per (at least) two bits
+const MAX_RATIO: usize = 1032;
+const MAX_LITLEN_CODES: usize = 288;
+const MAX_DIST_CODES: usize = 30;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31,
+    35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2,
+    3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193,
+    257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6,
+    7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+/// The order in which code length code lengths are stored in a dynamic block header
+const CLEN_ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+
+fn malformed(msg: &str) -> error::Error {
+    error::Error::Malformed(format!("Invalid deflate stream: {}", msg))
+}
+
+fn too_large(max_size: usize) -> error::Error {
+    error::Error::Malformed(format!("Invalid deflate stream: decompresses to more than {} bytes", max_size))
+}
+
+/// Reads bits LSB first out of a byte slice
+struct Bits<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    bitbuf: u32,
+    bitcnt: u32,
+}
+
+impl<'a> Bits<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Bits { bytes, offset: 0, bitbuf: 0, bitcnt: 0 }
+    }
+
+    /// Reads `need` (at most 16) bits
+    fn bits(&mut self, need: u32) -> error::Result<u32> {
+        let mut val = self.bitbuf;
+        while self.bitcnt < need {
+            let byte = *self.bytes.get(self.offset).ok_or_else(|| malformed("unexpected end of input"))?;
+            self.offset += 1;
+            val |= (byte as u32) << self.bitcnt;
+            self.bitcnt += 8;
+        }
+        self.bitbuf = val >> need;
+        self.bitcnt -= need;
+        Ok(val & ((1 << need) - 1))
+    }
+
+    /// Discards the remaining bits of the current byte
+    fn align(&mut self) {
+        self.bitbuf = 0;
+        self.bitcnt = 0;
+    }
+}
+
+/// A canonical Huffman decoding table
+struct Huffman {
+    /// Number of symbols of each code length
+    count: [u16; MAX_BITS + 1],
+    /// Symbols ordered by code
+    symbol: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> error::Result<Self> {
+        let mut count = [0u16; MAX_BITS + 1];
+        for &len in lengths {
+            count[len as usize] += 1;
+        }
+        if count[0] as usize == lengths.len() {
+            return Ok(Huffman { count, symbol: Vec::new() });
+        }
+        // reject oversubscribed code sets; incomplete ones are permitted (e.g., a single distance code)
+        let mut left: i32 = 1;
+        for &c in &count[1..] {
+            left <<= 1;
+            left -= c as i32;
+            if left < 0 {
+                return Err(malformed("oversubscribed huffman code"));
+            }
+        }
+        let mut offs = [0u16; MAX_BITS + 1];
+        for len in 1..MAX_BITS {
+            offs[len + 1] = offs[len] + count[len];
+        }
+        let mut symbol = vec![0u16; lengths.len()];
+        for (sym, &len) in lengths.iter().enumerate() {
+            if len != 0 {
+                symbol[offs[len as usize] as usize] = sym as u16;
+                offs[len as usize] += 1;
+            }
+        }
+        Ok(Huffman { count, symbol })
+    }
+
+    fn decode(&self, bits: &mut Bits) -> error::Result<u16> {
+        let mut code: i32 = 0;
+        let mut first: i32 = 0;
+        let mut index: i32 = 0;
+        for len in 1..(MAX_BITS + 1) {
+            code |= bits.bits(1)? as i32;
+            let count = self.count[len] as i32;
+            if code - count < first {
+                return self.symbol.get((index + (code - first)) as usize).cloned()
+                    .ok_or_else(|| malformed("bad huffman code"));
+            }
+            index += count;
+            first += count;
+            first <<= 1;
+            code <<= 1;
+        }
+        Err(malformed("bad huffman code"))
+    }
+}
+
+fn fixed_tables() -> error::Result<(Huffman, Huffman)> {
+    let mut lengths = [0u8; MAX_LITLEN_CODES];
+    for (i, len) in lengths.iter_mut().enumerate() {
+        *len = if i < 144 { 8 } else if i < 256 { 9 } else if i < 280 { 7 } else { 8 };
+    }
+    Ok((Huffman::new(&lengths)?, Huffman::new(&[5; MAX_DIST_CODES])?))
+}
+
+fn dynamic_tables(bits: &mut Bits) -> error::Result<(Huffman, Huffman)> {
+    let nlen = bits.bits(5)? as usize + 257;
+    let ndist = bits.bits(5)? as usize + 1;
+    let ncode = bits.bits(4)? as usize + 4;
+    if nlen > MAX_LITLEN_CODES || ndist > MAX_DIST_CODES {
+        return Err(malformed("bad dynamic block counts"));
+    }
+    let mut clens = [0u8; 19];
+    for &index in &CLEN_ORDER[..ncode] {
+        clens[index] = bits.bits(3)? as u8;
+    }
+    let clen = Huffman::new(&clens)?;
+    let mut lengths = [0u8; MAX_LITLEN_CODES + MAX_DIST_CODES];
+    let mut index = 0;
+    while index < nlen + ndist {
+        let sym = clen.decode(bits)?;
+        if sym < 16 {
+            lengths[index] = sym as u8;
+            index += 1;
+            continue;
+        }
+        let (len, repeat) = match sym {
+            16 => {
+                if index == 0 {
+                    return Err(malformed("repeat with no previous length"));
+                }
+                (lengths[index - 1], 3 + bits.bits(2)? as usize)
+            },
+            17 => (0, 3 + bits.bits(3)? as usize),
+            _ => (0, 11 + bits.bits(7)? as usize),
+        };
+        if index + repeat > nlen + ndist {
+            return Err(malformed("too many code lengths"));
+        }
+        for length in &mut lengths[index..index + repeat] {
+            *length = len;
+        }
+        index += repeat;
+    }
+    if lengths[256] == 0 {
+        return Err(malformed("missing end-of-block code"));
+    }
+    Ok((Huffman::new(&lengths[..nlen])?, Huffman::new(&lengths[nlen..nlen + ndist])?))
+}
+
+fn codes(bits: &mut Bits, out: &mut Vec<u8>, max_size: usize, litlen: &Huffman, dist: &Huffman) -> error::Result<()> {
+    loop {
+        let sym = litlen.decode(bits)? as usize;
+        if sym < 256 {
+            if out.len() >= max_size {
+                return Err(too_large(max_size));
+            }
+            out.push(sym as u8);
+        } else if sym == 256 {
+            return Ok(());
+        } else {
+            let sym = sym - 257;
+            if sym >= LENGTH_BASE.len() {
+                return Err(malformed("bad length symbol"));
+            }
+            let len = LENGTH_BASE[sym] as usize + bits.bits(LENGTH_EXTRA[sym] as u32)? as usize;
+            let sym = dist.decode(bits)? as usize;
+            if sym >= DIST_BASE.len() {
+                return Err(malformed("bad distance symbol"));
+            }
+            let distance = DIST_BASE[sym] as usize + bits.bits(DIST_EXTRA[sym] as u32)? as usize;
+            if distance > out.len() {
+                return Err(malformed("distance too far back"));
+            }
+            if len > max_size - out.len() {
+                return Err(too_large(max_size));
+            }
+            let start = out.len() - distance;
+            for i in 0..len {
+                let byte = out[start + i];
+                out.push(byte);
+            }
+        }
+    }
+}
+
+/// Decompresses the raw DEFLATE stream in `bytes`, returning the decompressed data and the
+/// number of input bytes consumed.
+///
+/// It is an error for the stream to decompress to more than `max_size` bytes, e.g., the size the
+/// (untrusted) header of a compressed section claims.
+pub fn inflate(bytes: &[u8], max_size: usize) -> error::Result<(Vec<u8>, usize)> {
+    let mut out = Vec::with_capacity(::core::cmp::min(max_size, bytes.len().saturating_mul(MAX_RATIO)));
+    let mut bits = Bits::new(bytes);
+    loop {
+        let last = bits.bits(1)?;
+        match bits.bits(2)? {
+            0 => {
+                bits.align();
+                let offset = bits.offset;
+                if offset + 4 > bytes.len() {
+                    return Err(malformed("unexpected end of input"));
+                }
+                let len = bytes[offset] as usize | (bytes[offset + 1] as usize) << 8;
+                let nlen = bytes[offset + 2] as usize | (bytes[offset + 3] as usize) << 8;
+                if len != !nlen & 0xffff {
+                    return Err(malformed("stored block length mismatch"));
+                }
+                let data = bytes.get(offset + 4..offset + 4 + len).ok_or_else(|| malformed("unexpected end of input"))?;
+                if len > max_size - out.len() {
+                    return Err(too_large(max_size));
+                }
+                out.extend_from_slice(data);
+                bits.offset = offset + 4 + len;
+            },
+            1 => {
+                let (litlen, dist) = fixed_tables()?;
+                codes(&mut bits, &mut out, max_size, &litlen, &dist)?;
+            },
+            2 => {
+                let (litlen, dist) = dynamic_tables(&mut bits)?;
+                codes(&mut bits, &mut out, max_size, &litlen, &dist)?;
+            },
+            _ => return Err(malformed("invalid block type")),
+        }
+        if last == 1 {
+            return Ok((out, bits.offset));
+        }
+    }
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in bytes.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+/// Decompresses the zlib stream in `bytes` to at most `max_size` bytes, verifying its header and
+/// Adler-32 checksum
+pub fn zlib_decompress(bytes: &[u8], max_size: usize) -> error::Result<Vec<u8>> {
+    if bytes.len() < 2 {
+        return Err(malformed("zlib stream is too short"));
+    }
+    let (cmf, flg) = (bytes[0], bytes[1]);
+    if cmf & 0xf != 8 || cmf >> 4 > 7 || ((cmf as u16) << 8 | flg as u16) % 31 != 0 {
+        return Err(malformed("bad zlib header"));
+    }
+    if flg & 0x20 != 0 {
+        return Err(malformed("zlib preset dictionaries are unsupported"));
+    }
+    let (out, consumed) = inflate(&bytes[2..], max_size)?;
+    let trailer = bytes.get(2 + consumed..2 + consumed + 4).ok_or_else(|| malformed("missing zlib checksum"))?;
+    let checksum = (trailer[0] as u32) << 24 | (trailer[1] as u32) << 16 | (trailer[2] as u32) << 8 | trailer[3] as u32;
+    if checksum != adler32(&out) {
+        return Err(malformed("zlib checksum mismatch"));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // zlib.compress(b"hello hello hello hello goblin\n", 9), a single fixed huffman block
+    const HELLO: [u8; 23] = [
+        0x78, 0xda, 0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0xc8, 0xc0, 0x20, 0xd3, 0xf3, 0x93,
+        0x72, 0x32, 0xf3, 0xb8, 0x00, 0xb9, 0xb1, 0x0b, 0x56];
+
+    #[test]
+    fn fixed_block() {
+        let out = zlib_decompress(&HELLO, 31).unwrap();
+        assert_eq!(&out[..], &b"hello hello hello hello goblin\n"[..]);
+        // the output is capped, in literals and matches
+        assert!(zlib_decompress(&HELLO, 30).is_err());
+        assert!(zlib_decompress(&HELLO, 8).is_err());
+    }
+
+    #[test]
+    fn stored_block() {
+        let bytes = [0x78, 0x01, 0x01, 0x03, 0x00, 0xfc, 0xff, b'a', b'b', b'c', 0x02, 0x4d, 0x01, 0x27];
+        assert_eq!(&zlib_decompress(&bytes, 3).unwrap()[..], b"abc");
+        assert!(zlib_decompress(&bytes, 2).is_err());
+    }
+
+    #[test]
+    fn dynamic_block() {
+        let bytes = [
+            0x78, 0xda, 0xed, 0xca, 0xb7, 0x11, 0x80, 0x30, 0x14, 0x05, 0xc1, 0x56, 0x5e, 0x6b, 0xf2,
+            0x5e, 0x5f, 0x5e, 0x40, 0xf5, 0x0c, 0x31, 0x31, 0x19, 0xe9, 0xde, 0x31, 0x4b, 0x4b, 0xa7,
+            0x21, 0x43, 0xe3, 0xae, 0x6c, 0x93, 0xa7, 0x8a, 0x5d, 0xf8, 0xca, 0x7e, 0x7e, 0xf3, 0xb0,
+            0x0a, 0x75, 0x3a, 0x11, 0xc0, 0x1b, 0xed, 0x0c, 0x4d, 0x07, 0xfc, 0x4c, 0xa5, 0x83, 0x96,
+            0x6a, 0x78, 0x72, 0x64, 0xd7, 0x09, 0x49, 0xe6, 0x9b, 0xf5, 0x06, 0x5c, 0xc1, 0xae, 0x71];
+        let mut expected: Vec<u8> = (0..300).map(|i| (i * 7 % 23) as u8 + b'a').collect();
+        for _ in 0..3 {
+            expected.extend_from_slice(b"the quick brown fox jumps over the lazy dog");
+        }
+        assert_eq!(zlib_decompress(&bytes, expected.len()).unwrap(), expected);
+    }
+
+    #[test]
+    fn bad_checksum() {
+        let mut bytes = HELLO;
+        bytes[HELLO.len() - 1] ^= 0xff;
+        assert!(zlib_decompress(&bytes, 31).is_err());
+        assert!(zlib_decompress(&HELLO[..10], 31).is_err());
+    }
+
+    /// The data compressed in `etc/deflate.rs`: runs of text between pseudo-random letters
+    fn input() -> Vec<u8> {
+        let phrase = b"goblin parses elf, mach-o and pe binaries. ";
+        let mut state = 0x1234567u32;
+        (0..1024).map(|i| {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            if (i / 64) % 3 == 0 { phrase[i % phrase.len()] } else { ((state >> 24) & 0x1f) as u8 + 0x41 }
+        }).collect()
+    }
+
+    #[test]
+    fn zlib_streams() {
+        // streams produced by zlib itself with different levels and strategies
+        let streams: Vec<Vec<u8>> = include!("../etc/deflate.rs");
+        let expected = input();
+        for stream in &streams {
+            assert_eq!(zlib_decompress(stream, expected.len()).unwrap(), expected);
+            assert!(zlib_decompress(stream, expected.len() - 1).is_err());
+        }
+        // corrupting or truncating a stream must be reported, never panic
+        let mut state = 0x9e37_79b9u32;
+        for stream in &streams {
+            for _ in 0..256 {
+                state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                let mut corrupt = stream.clone();
+                let index = (state >> 8) as usize % corrupt.len();
+                corrupt[index] ^= 1 << (state >> 29);
+                let _ = zlib_decompress(&corrupt, expected.len());
+                assert!(zlib_decompress(&stream[..index], expected.len()).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,791 @@
+//! Shared library dependency resolution, like `ldd`, without running the loader.
+//!
+//! A [Resolver](struct.Resolver.html) follows the search rules of each loader to find the libraries a
+//! binary depends on, and the libraries those depend on, under a configurable sysroot:
+//!
+//! * ELF: `DT_NEEDED`, searched in the `DT_RPATH` of the object and its loaders (unless it has a
+//!   `DT_RUNPATH`), the library paths (`LD_LIBRARY_PATH`), the `DT_RUNPATH`, the directories of
+//!   `/etc/ld.so.conf`, and the default directories; `$ORIGIN` and `$LIB` are expanded
+//! * Mach-O: the dylib install names, with `@executable_path/`, `@loader_path/` and `@rpath/`
+//!   (searched in the `LC_RPATH`s of the image and its loaders), and the fallback directories
+//! * PE: the imported DLLs, matched case insensitively in a configurable DLL search order
+//!
+//! Only libraries with the same format and machine as the root binary are considered, like the
+//! loaders skip e.g. 32-bit libraries when loading a 64-bit binary. The resulting
+//! [DependencyTree](struct.DependencyTree.html) reports the libraries which weren't found, and the
+//! imported symbols each library doesn't define.
+//!
+//! ```rust,no_run
+//! use goblin::ldd::{Resolver, Resolution};
+//!
+//! let tree = Resolver::default().resolve("/bin/ls").unwrap();
+//! for library in &tree.libraries {
+//!     for dependency in &library.dependencies {
+//!         match dependency.resolution {
+//!             Resolution::Found(index) => println!("{} => {:?}", dependency.name, tree.libraries[index].path),
+//!             Resolution::NotFound => println!("{} => not found", dependency.name),
+//!             Resolution::System => println!("{} => (system)", dependency.name),
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use error;
+use Object;
+use read_file;
+use elf;
+use elf::section_header::SHN_UNDEF;
+use elf::sym::{STB_GLOBAL, STB_WEAK, STB_GNU_UNIQUE};
+use mach;
+use mach::load_command::CommandVariant;
+use pe;
+use scroll::Pread;
+
+/// The default ELF library directories, searched last
+pub const ELF_DEFAULT_PATHS: [&'static str; 4] = ["/lib", "/usr/lib", "/lib64", "/usr/lib64"];
+/// The default dyld fallback directories, searched for the file name of a dylib which isn't found at its install name
+pub const DYLD_FALLBACK_PATHS: [&'static str; 2] = ["/usr/local/lib", "/usr/lib"];
+/// The ELF loader configuration, relative to the sysroot
+pub const LD_SO_CONF: &'static str = "/etc/ld.so.conf";
+/// The maximum number of symbolic links followed to resolve a path, like the `ELOOP` limit of Linux
+pub const MAX_SYMLINKS: usize = 40;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A location of the DLL search order
+pub enum DllSearch {
+    /// The directory of the root binary
+    ApplicationDirectory,
+    /// A directory, relative to the sysroot
+    Directory(PathBuf),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// How a dependency was resolved
+pub enum Resolution {
+    /// The dependency is the library at this index of `DependencyTree::libraries`
+    Found(usize),
+    /// The dependency wasn't found in any of the search paths
+    NotFound,
+    /// The dependency is provided by the system without a file: a Windows API set
+    /// (`api-ms-win-*`, `ext-ms-*`), or a system dylib which isn't in the sysroot, i.e., one which
+    /// is in the dyld shared cache
+    System,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A library required by another
+pub struct Dependency {
+    /// The name of the library, as it is required, e.g., `libc.so.6` or `@rpath/libfoo.dylib`
+    pub name: String,
+    /// How the library was resolved
+    pub resolution: Resolution,
+    /// The imported symbols bound to this library which it doesn't define (or re-export); ELF
+    /// imports are bound to a library by their symbol version
+    pub undefined_symbols: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A binary of the dependency tree
+pub struct Library {
+    /// The name of the library as it was first required, or the path of the root binary
+    pub name: String,
+    /// The path of the library, under the sysroot
+    pub path: PathBuf,
+    /// The index of the library which first required this one, `None` for the root binary
+    pub parent: Option<usize>,
+    /// The libraries this one requires, in order
+    pub dependencies: Vec<Dependency>,
+    /// The imported symbols which aren't bound to a dependency (e.g., unversioned ELF imports), and
+    /// which none of the libraries define
+    pub undefined_symbols: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The resolved dependencies of a binary
+pub struct DependencyTree {
+    /// The root binary, followed by the libraries it transitively requires, in load order
+    pub libraries: Vec<Library>,
+}
+
+impl DependencyTree {
+    /// The root binary
+    pub fn root(&self) -> &Library {
+        &self.libraries[0]
+    }
+    /// Returns the dependencies which weren't found, with the library requiring them
+    pub fn unresolved(&self) -> Vec<(&Library, &Dependency)> {
+        let mut unresolved = Vec::new();
+        for library in &self.libraries {
+            for dependency in &library.dependencies {
+                if dependency.resolution == Resolution::NotFound {
+                    unresolved.push((library, dependency));
+                }
+            }
+        }
+        unresolved
+    }
+    /// Whether all dependencies were found, and all imported symbols are defined
+    pub fn is_complete(&self) -> bool {
+        self.libraries.iter().all(|library| {
+            library.undefined_symbols.is_empty() && library.dependencies.iter().all(|dependency| {
+                dependency.resolution != Resolution::NotFound && dependency.undefined_symbols.is_empty()
+            })
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The format and machine of a binary, which the libraries must match
+enum Format {
+    Elf { is_64: bool, machine: u16 },
+    Mach { cputype: u32 },
+    PE { machine: u16 },
+}
+
+#[derive(Debug)]
+/// What the resolution needs of a binary
+struct Node {
+    format: Format,
+    needed: Vec<String>,
+    rpaths: Vec<String>,
+    runpaths: Vec<String>,
+    reexports: Vec<String>,
+    exports: HashSet<String>,
+    /// The imported symbols, with the library they're bound to, if any
+    imports: Vec<(Option<String>, String)>,
+}
+
+impl Node {
+    fn from_elf(elf: &elf::Elf, root: bool) -> Self {
+        let mut needed: Vec<String> = elf.libraries.iter().map(|lib| lib.to_string()).collect();
+        // the program interpreter is loaded like a dependency of the executable
+        if root {
+            if let Some(interpreter) = elf.interpreter {
+                needed.push(interpreter.to_string());
+            }
+        }
+        let mut exports = HashSet::new();
+        let mut imports = Vec::new();
+        for (index, sym) in elf.dynsyms.iter().enumerate() {
+            let name = match elf.dynstrtab.get(sym.st_name) {
+                Some(Ok(name)) if !name.is_empty() => name,
+                _ => continue,
+            };
+            let bind = sym.st_bind();
+            if sym.st_shndx == SHN_UNDEF as usize {
+                // weak undefined symbols may stay undefined
+                if bind == STB_GLOBAL {
+                    let file = elf.symbol_versions.get(index).and_then(|version| version.file);
+                    imports.push((file.map(|file| file.to_string()), name.to_string()));
+                }
+            } else if bind == STB_GLOBAL || bind == STB_WEAK || bind == STB_GNU_UNIQUE {
+                exports.insert(name.to_string());
+            }
+        }
+        Node {
+            format: Format::Elf { is_64: elf.is_64, machine: elf.header.e_machine },
+            needed,
+            rpaths: elf.rpaths.iter().map(|path| path.to_string()).collect(),
+            runpaths: elf.runpaths.iter().map(|path| path.to_string()).collect(),
+            reexports: Vec::new(),
+            exports,
+            imports,
+        }
+    }
+    fn from_mach(macho: &mach::MachO, bytes: &[u8]) -> error::Result<Self> {
+        let mut reexports = Vec::new();
+        for command in &macho.load_commands {
+            if let CommandVariant::ReexportDylib(dylib) = command.command {
+                reexports.push(bytes.pread::<&str>(command.offset + dylib.dylib.name as usize)?.to_string());
+            }
+        }
+        let exports = macho.exports()?.into_iter().map(|export| export.name).collect();
+        let imports = macho.imports()?.iter()
+            .filter(|import| !import.is_weak)
+            .map(|import| (Some(import.dylib.to_string()), import.name.to_string()))
+            .collect();
+        Ok(Node {
+            format: Format::Mach { cputype: macho.header.cputype },
+            // the first library is the image itself
+            needed: macho.libs.iter().skip(1).map(|lib| lib.to_string()).collect(),
+            rpaths: macho.rpaths.iter().map(|path| path.to_string()).collect(),
+            runpaths: Vec::new(),
+            reexports,
+            exports,
+            imports,
+        })
+    }
+    fn from_pe(pe: &pe::PE) -> Self {
+        Node {
+            format: Format::PE { machine: pe.header.coff_header.machine },
+            needed: pe.libraries.iter().map(|lib| lib.to_string()).collect(),
+            rpaths: Vec::new(),
+            runpaths: Vec::new(),
+            reexports: Vec::new(),
+            exports: pe.exports.iter().filter_map(|export| export.name).map(|name| name.to_string()).collect(),
+            // imports by ordinal can't be checked against the exported names
+            imports: pe.imports.iter()
+                .filter(|import| !import.name.starts_with("ORDINAL "))
+                .map(|import| (Some(import.dll.to_string()), import.name.to_string()))
+                .collect(),
+        }
+    }
+    /// Whether `name` refers to the dependency `dependency`
+    fn is_dependency(&self, name: &str, dependency: &str) -> bool {
+        match self.format {
+            Format::PE { .. } => eq_ignore_case(name.as_bytes(), dependency.as_bytes()),
+            // the interpreter is required by its path, but its symbols by its file name
+            _ => name == dependency || Path::new(dependency).file_name().map_or(false, |file| file == name),
+        }
+    }
+}
+
+/// Lowercases the ASCII letter `byte`, like `u8::to_ascii_lowercase`, which is newer than our minimum `rustc`
+fn ascii_lowercase(byte: u8) -> u8 {
+    if byte.wrapping_sub(b'A') < 26 { byte | 0x20 } else { byte }
+}
+
+/// Whether `a` and `b` are equal, ignoring the case of ASCII letters
+fn eq_ignore_case(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(&a, &b)| ascii_lowercase(a) == ascii_lowercase(b))
+}
+
+/// Whether `name` is a Windows API set, which the loader maps to a DLL without a file
+fn is_api_set(name: &str) -> bool {
+    let name = name.as_bytes();
+    name.len() >= 7 && (eq_ignore_case(&name[..7], b"api-ms-") || eq_ignore_case(&name[..7], b"ext-ms-"))
+}
+
+/// Whether `name` is the install name of a system dylib, which may only be in the dyld shared cache
+fn is_system_dylib(name: &str) -> bool {
+    name.starts_with("/usr/lib/") || name.starts_with("/System/Library/")
+}
+
+/// Finds `path`, matching the components which don't exist case insensitively
+fn find_ignore_case(path: &Path) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(path.to_path_buf());
+    }
+    let mut found = PathBuf::new();
+    for component in path.components() {
+        let next = found.join(component.as_os_str());
+        if next.exists() {
+            found = next;
+            continue;
+        }
+        let name = match component {
+            Component::Normal(name) => name.to_string_lossy(),
+            _ => return None,
+        };
+        let directory = if found.as_os_str().is_empty() { Path::new(".") } else { found.as_path() };
+        let entry = match fs::read_dir(directory) {
+            Ok(entries) => entries.filter_map(|entry| entry.ok())
+                .find(|entry| eq_ignore_case(entry.file_name().to_string_lossy().as_bytes(), name.as_bytes())),
+            Err(_) => None,
+        };
+        match entry {
+            Some(entry) => found.push(entry.file_name()),
+            None => return None,
+        }
+    }
+    if found.is_file() { Some(found) } else { None }
+}
+
+/// Whether the file name `name` matches `pattern`, which may contain a single `*`
+fn matches_glob(pattern: &str, name: &str) -> bool {
+    match pattern.find('*') {
+        Some(star) => {
+            let (prefix, suffix) = (&pattern[..star], &pattern[star + 1..]);
+            name.len() >= prefix.len() + suffix.len() && name.starts_with(prefix) && name.ends_with(suffix)
+        },
+        None => pattern == name,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Resolves the dependencies of binaries under a sysroot
+pub struct Resolver {
+    /// The directory the absolute paths of the search paths and install names are relative to, `/` by default
+    pub sysroot: PathBuf,
+    /// ELF: the directories searched before the `DT_RUNPATH`, like `LD_LIBRARY_PATH`
+    pub library_paths: Vec<PathBuf>,
+    /// ELF: the directories searched last, i.e., those of `/etc/ld.so.conf` and the default directories
+    pub elf_default_paths: Vec<PathBuf>,
+    /// Mach-O: the directories searched for the file name of a dylib which isn't found at its install name
+    pub dyld_fallback_paths: Vec<PathBuf>,
+    /// PE: where DLLs are searched, in order
+    pub dll_search_order: Vec<DllSearch>,
+}
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Resolver::new("/")
+    }
+}
+
+impl Resolver {
+    /// Creates a resolver for the sysroot `sysroot`, with the default search paths and the directories of its `/etc/ld.so.conf`
+    pub fn new<P: AsRef<Path>>(sysroot: P) -> Self {
+        let mut resolver = Resolver {
+            sysroot: sysroot.as_ref().to_path_buf(),
+            library_paths: Vec::new(),
+            elf_default_paths: Vec::new(),
+            dyld_fallback_paths: DYLD_FALLBACK_PATHS.iter().map(PathBuf::from).collect(),
+            dll_search_order: vec![
+                DllSearch::ApplicationDirectory,
+                DllSearch::Directory(PathBuf::from("/Windows/System32")),
+                DllSearch::Directory(PathBuf::from("/Windows/SysWOW64")),
+                DllSearch::Directory(PathBuf::from("/Windows/System")),
+                DllSearch::Directory(PathBuf::from("/Windows")),
+            ],
+        };
+        let mut paths = Vec::new();
+        resolver.read_ld_so_conf(Path::new(LD_SO_CONF), &mut paths, 0);
+        paths.extend(ELF_DEFAULT_PATHS.iter().map(PathBuf::from));
+        resolver.elf_default_paths = paths;
+        resolver
+    }
+    /// Appends the directories of the `ld.so.conf` file `conf` (relative to the sysroot) to `paths`,
+    /// following its `include`s
+    fn read_ld_so_conf(&self, conf: &Path, paths: &mut Vec<PathBuf>, depth: usize) {
+        // guard against include cycles
+        if depth > 8 {
+            return;
+        }
+        let contents = match self.real_path(self.host_path(conf)).map(read_file) {
+            Some(Ok(bytes)) => String::from_utf8_lossy(&bytes).into_owned(),
+            _ => return,
+        };
+        for line in contents.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.starts_with("include") && line[7..].starts_with(|c: char| c.is_whitespace()) {
+                for pattern in line[7..].split_whitespace() {
+                    let pattern = Path::new("/etc").join(pattern);
+                    let (directory, file) = match (pattern.parent(), pattern.file_name()) {
+                        (Some(directory), Some(file)) => (directory, file.to_string_lossy()),
+                        _ => continue,
+                    };
+                    let mut files: Vec<PathBuf> = match self.real_path(self.host_path(directory)).map(fs::read_dir) {
+                        Some(Ok(entries)) => entries.filter_map(|entry| entry.ok())
+                            .filter(|entry| matches_glob(&file, &entry.file_name().to_string_lossy()))
+                            .map(|entry| directory.join(entry.file_name()))
+                            .collect(),
+                        _ => continue,
+                    };
+                    files.sort();
+                    for file in files {
+                        self.read_ld_so_conf(&file, paths, depth + 1);
+                    }
+                }
+            } else {
+                for directory in line.split(|c: char| c.is_whitespace() || c == ',' || c == ':').filter(|path| !path.is_empty()) {
+                    // a directory may be suffixed with the libc5 library type, e.g., `/usr/lib=libc6`
+                    let directory = PathBuf::from(directory.split('=').next().unwrap_or(directory));
+                    if !paths.contains(&directory) {
+                        paths.push(directory);
+                    }
+                }
+            }
+        }
+    }
+    /// Returns where the absolute path `path` is under the sysroot; relative paths are unchanged
+    pub fn host_path<P: AsRef<Path>>(&self, path: P) -> PathBuf {
+        let path = path.as_ref();
+        match path.strip_prefix("/") {
+            Ok(relative) => self.sysroot.join(relative),
+            Err(_) => path.to_path_buf(),
+        }
+    }
+    /// Returns the path the loader sees for `path`, i.e., `path` made absolute under the sysroot
+    pub fn loader_path<P: AsRef<Path>>(&self, path: P) -> PathBuf {
+        let path = path.as_ref();
+        match path.strip_prefix(&self.sysroot) {
+            Ok(relative) => Path::new("/").join(relative),
+            Err(_) => path.to_path_buf(),
+        }
+    }
+    /// Returns the host path `path` with its symbolic links resolved like the loader sees them, or
+    /// `None` if it doesn't exist.
+    ///
+    /// Under the sysroot, absolute link targets are relative to the sysroot, and `..` doesn't leave
+    /// it, so a library can't be found outside of it; other paths are canonicalized on the host.
+    pub fn real_path<P: AsRef<Path>>(&self, path: P) -> Option<PathBuf> {
+        fn push_components(pending: &mut Vec<OsString>, path: &Path) {
+            for component in path.components().rev() {
+                match component {
+                    Component::Normal(name) => pending.push(name.to_os_string()),
+                    Component::ParentDir => pending.push(OsString::from("..")),
+                    _ => (),
+                }
+            }
+        }
+        let path = path.as_ref();
+        let relative = match path.strip_prefix(&self.sysroot) {
+            Ok(relative) => relative,
+            Err(_) => return fs::canonicalize(path).ok(),
+        };
+        let root = match fs::canonicalize(&self.sysroot) {
+            Ok(root) => root,
+            Err(_) => return None,
+        };
+        // the components left to resolve, last first
+        let mut pending = Vec::new();
+        push_components(&mut pending, relative);
+        let mut real = root.clone();
+        let mut depth = 0;
+        let mut links = 0;
+        while let Some(name) = pending.pop() {
+            if name == ".." {
+                if depth > 0 {
+                    real.pop();
+                    depth -= 1;
+                }
+                continue;
+            }
+            real.push(&name);
+            let is_symlink = match fs::symlink_metadata(&real) {
+                Ok(metadata) => metadata.file_type().is_symlink(),
+                Err(_) => return None,
+            };
+            if !is_symlink {
+                depth += 1;
+                continue;
+            }
+            links += 1;
+            if links > MAX_SYMLINKS {
+                return None;
+            }
+            let target = match fs::read_link(&real) {
+                Ok(target) => target,
+                Err(_) => return None,
+            };
+            real.pop();
+            if target.has_root() {
+                real = root.clone();
+                depth = 0;
+            }
+            push_components(&mut pending, &target);
+        }
+        Some(real)
+    }
+    /// Expands the dynamic string tokens of the ELF search path `path` of the library at `origin`
+    fn expand_elf(&self, path: &str, origin: &Path, is_64: bool) -> String {
+        let directory = self.loader_path(origin.parent().unwrap_or_else(|| Path::new("")));
+        let directory = directory.to_string_lossy();
+        let lib = if is_64 { "lib64" } else { "lib" };
+        path.replace("${ORIGIN}", &directory).replace("$ORIGIN", &directory)
+            .replace("${LIB}", lib).replace("$LIB", lib)
+    }
+    /// Expands the `@executable_path/` and `@loader_path/` prefixes of the Mach-O path `path`
+    fn expand_mach(&self, path: &str, executable: &Path, loader: &Path) -> PathBuf {
+        let expand = |prefix: &str, image: &Path| -> Option<PathBuf> {
+            if path.starts_with(prefix) {
+                let directory = self.loader_path(image.parent().unwrap_or_else(|| Path::new("")));
+                Some(directory.join(&path[prefix.len()..]))
+            } else {
+                None
+            }
+        };
+        expand("@executable_path/", executable)
+            .or_else(|| expand("@loader_path/", loader))
+            .unwrap_or_else(|| PathBuf::from(path))
+    }
+    /// Returns the paths the library `name`, required by the library at `index`, is looked up at, in order
+    fn candidates(&self, libraries: &[Library], nodes: &[Node], index: usize, name: &str) -> Vec<PathBuf> {
+        let node = &nodes[index];
+        let path = &libraries[index].path;
+        let mut candidates = Vec::new();
+        match node.format {
+            Format::Elf { is_64, .. } => {
+                if name.contains('/') {
+                    return vec![self.host_path(name)];
+                }
+                let mut directories = Vec::new();
+                // the DT_RPATHs of the library and its loaders are ignored if the library has a DT_RUNPATH
+                if node.runpaths.is_empty() {
+                    let mut loader = Some(index);
+                    while let Some(i) = loader {
+                        if nodes[i].runpaths.is_empty() {
+                            for rpath in &nodes[i].rpaths {
+                                directories.extend(self.expand_elf(rpath, &libraries[i].path, is_64).split(':').map(PathBuf::from));
+                            }
+                        }
+                        loader = libraries[i].parent;
+                    }
+                }
+                directories.extend(self.library_paths.iter().cloned());
+                for runpath in &node.runpaths {
+                    directories.extend(self.expand_elf(runpath, path, is_64).split(':').map(PathBuf::from));
+                }
+                directories.extend(self.elf_default_paths.iter().cloned());
+                for directory in directories {
+                    if !directory.as_os_str().is_empty() {
+                        candidates.push(self.host_path(directory.join(name)));
+                    }
+                }
+            },
+            Format::Mach { .. } => {
+                let executable = &libraries[0].path;
+                if name.starts_with("@rpath/") {
+                    let mut loader = Some(index);
+                    while let Some(i) = loader {
+                        for rpath in &nodes[i].rpaths {
+                            let directory = self.expand_mach(rpath, executable, &libraries[i].path);
+                            candidates.push(self.host_path(directory.join(&name["@rpath/".len()..])));
+                        }
+                        loader = libraries[i].parent;
+                    }
+                } else {
+                    candidates.push(self.host_path(self.expand_mach(name, executable, path)));
+                }
+                if let Some(file) = Path::new(name).file_name() {
+                    for directory in &self.dyld_fallback_paths {
+                        candidates.push(self.host_path(directory.join(file)));
+                    }
+                }
+            },
+            Format::PE { .. } => {
+                for location in &self.dll_search_order {
+                    let directory = match *location {
+                        DllSearch::ApplicationDirectory => libraries[0].path.parent().unwrap_or_else(|| Path::new("")).to_path_buf(),
+                        DllSearch::Directory(ref directory) => self.host_path(directory),
+                    };
+                    if let Some(candidate) = find_ignore_case(&directory.join(name)) {
+                        candidates.push(candidate);
+                    }
+                }
+            },
+        }
+        candidates
+    }
+    /// Parses the library `bytes`, if it's compatible with `format`
+    fn load(&self, bytes: &[u8], format: Format) -> Option<Node> {
+        let node = match Object::parse(bytes) {
+            Ok(Object::Elf(elf)) => if elf.is_lib { Some(Node::from_elf(&elf, false)) } else { None },
+            Ok(Object::Mach(mach::Mach::Binary(macho))) => Node::from_mach(&macho, bytes).ok(),
+            Ok(Object::Mach(mach::Mach::Fat(fat))) => {
+                let cputype = match format {
+                    Format::Mach { cputype } => cputype,
+                    _ => return None,
+                };
+                let index = fat.arches().ok().and_then(|arches| arches.iter().position(|arch| arch.cputype == cputype));
+                index.and_then(|index| fat.get(index).ok()).and_then(|macho| Node::from_mach(&macho, bytes).ok())
+            },
+            Ok(Object::PE(pe)) => if pe.is_lib { Some(Node::from_pe(&pe)) } else { None },
+            _ => None,
+        };
+        match node {
+            Some(node) if node.format == format => Some(node),
+            _ => None,
+        }
+    }
+    /// Whether the library at `index` defines `symbol`, itself or through the libraries it re-exports
+    fn defines(libraries: &[Library], nodes: &[Node], index: usize, symbol: &str, visited: &mut HashSet<usize>) -> bool {
+        if !visited.insert(index) {
+            return false;
+        }
+        if nodes[index].exports.contains(symbol) {
+            return true;
+        }
+        for dependency in &libraries[index].dependencies {
+            if let Resolution::Found(reexported) = dependency.resolution {
+                if nodes[index].reexports.contains(&dependency.name) && Resolver::defines(libraries, nodes, reexported, symbol, visited) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+    /// Resolves the dependency tree of the binary at `path`
+    pub fn resolve<P: AsRef<Path>>(&self, path: P) -> error::Result<DependencyTree> {
+        let path = path.as_ref();
+        let bytes = read_file(path)?;
+        let root = match Object::parse(&bytes)? {
+            Object::Elf(elf) => Node::from_elf(&elf, true),
+            // the first architecture of a fat binary
+            Object::Mach(mach::Mach::Binary(macho)) => Node::from_mach(&macho, &bytes)?,
+            Object::Mach(mach::Mach::Fat(fat)) => Node::from_mach(&fat.get(0)?, &bytes)?,
+            Object::PE(pe) => Node::from_pe(&pe),
+            _ => return Err(error::Error::Malformed(format!("{:?} is not an ELF, Mach-O or PE binary", path))),
+        };
+        let format = root.format;
+        let mut libraries = vec![Library {
+            name: path.to_string_lossy().into_owned(),
+            path: path.to_path_buf(),
+            parent: None,
+            dependencies: Vec::new(),
+            undefined_symbols: Vec::new(),
+        }];
+        let mut nodes = vec![root];
+        let mut loaded: HashMap<PathBuf, usize> = HashMap::new();
+        loaded.insert(self.real_path(path).unwrap_or_else(|| path.to_path_buf()), 0);
+
+        // breadth first, like the loaders
+        let mut index = 0;
+        while index < libraries.len() {
+            let mut dependencies = Vec::new();
+            for name in nodes[index].needed.clone() {
+                let mut resolution = Resolution::NotFound;
+                for candidate in self.candidates(&libraries, &nodes, index, &name) {
+                    // symbolic links are followed under the sysroot
+                    let key = match self.real_path(&candidate) {
+                        Some(key) => key,
+                        None => continue,
+                    };
+                    if let Some(&found) = loaded.get(&key) {
+                        resolution = Resolution::Found(found);
+                        break;
+                    }
+                    let node = match read_file(&key) {
+                        Ok(bytes) => self.load(&bytes, format),
+                        Err(_) => None,
+                    };
+                    if let Some(node) = node {
+                        resolution = Resolution::Found(libraries.len());
+                        loaded.insert(key, libraries.len());
+                        libraries.push(Library {
+                            name: name.clone(),
+                            path: candidate,
+                            parent: Some(index),
+                            dependencies: Vec::new(),
+                            undefined_symbols: Vec::new(),
+                        });
+                        nodes.push(node);
+                        break;
+                    }
+                }
+                if resolution == Resolution::NotFound {
+                    let system = match format {
+                        Format::PE { .. } => is_api_set(&name),
+                        Format::Mach { .. } => is_system_dylib(&name),
+                        Format::Elf { .. } => false,
+                    };
+                    if system {
+                        resolution = Resolution::System;
+                    }
+                }
+                dependencies.push(Dependency { name, resolution, undefined_symbols: Vec::new() });
+            }
+            libraries[index].dependencies = dependencies;
+            index += 1;
+        }
+
+        // check the imports once every library is loaded
+        for index in 0..libraries.len() {
+            let mut edges: Vec<Vec<String>> = vec![Vec::new(); libraries[index].dependencies.len()];
+            let mut unbound = Vec::new();
+            for import in &nodes[index].imports {
+                let (file, symbol) = (&import.0, &import.1);
+                let edge = file.as_ref().and_then(|file| {
+                    libraries[index].dependencies.iter().position(|dependency| nodes[index].is_dependency(file, &dependency.name))
+                });
+                match edge {
+                    Some(edge) => {
+                        // a dependency which is missing (or provided by the system) can't be checked
+                        if let Resolution::Found(found) = libraries[index].dependencies[edge].resolution {
+                            if !Resolver::defines(&libraries, &nodes, found, symbol, &mut HashSet::new()) {
+                                edges[edge].push(symbol.clone());
+                            }
+                        }
+                    },
+                    None => {
+                        let defined = (0..libraries.len()).any(|library| nodes[library].exports.contains(symbol));
+                        if !defined {
+                            unbound.push(symbol.clone());
+                        }
+                    },
+                }
+            }
+            for (dependency, mut symbols) in libraries[index].dependencies.iter_mut().zip(edges) {
+                symbols.sort();
+                symbols.dedup();
+                dependency.undefined_symbols = symbols;
+            }
+            unbound.sort();
+            unbound.dedup();
+            libraries[index].undefined_symbols = unbound;
+        }
+        Ok(DependencyTree { libraries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn search_paths() {
+        let resolver = Resolver::new("/nonexistent/sysroot");
+        assert_eq!(resolver.elf_default_paths, ELF_DEFAULT_PATHS.iter().map(PathBuf::from).collect::<Vec<_>>());
+        assert_eq!(resolver.host_path("/usr/lib/libc.so.6"), PathBuf::from("/nonexistent/sysroot/usr/lib/libc.so.6"));
+        assert_eq!(resolver.host_path("libc.so.6"), PathBuf::from("libc.so.6"));
+        assert_eq!(resolver.loader_path("/nonexistent/sysroot/usr/bin/ls"), PathBuf::from("/usr/bin/ls"));
+        let origin = Path::new("/nonexistent/sysroot/opt/app/bin/app");
+        assert_eq!(resolver.expand_elf("$ORIGIN/../$LIB:${ORIGIN}", origin, true), "/opt/app/bin/../lib64:/opt/app/bin");
+        assert_eq!(resolver.expand_mach("@loader_path/../Frameworks", origin, Path::new("/nonexistent/sysroot/opt/lib/libfoo.dylib")), PathBuf::from("/opt/lib/../Frameworks"));
+        assert_eq!(resolver.expand_mach("@executable_path/libfoo.dylib", origin, origin), PathBuf::from("/opt/app/bin/libfoo.dylib"));
+        assert!(matches_glob("*.conf", "libc.conf"));
+        assert!(!matches_glob("*.conf", "libc.conf.bak"));
+        assert!(is_api_set("API-MS-Win-Core-Synch-L1-2-0.dll"));
+        assert!(!is_api_set("kernel32.dll"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resolve_in_sysroot() {
+        use std::os::unix::fs::symlink;
+        use std::io::Write;
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let write = |path: PathBuf, bytes: &[u8]| fs::File::create(path).unwrap().write_all(bytes).unwrap();
+        // a fresh directory per run, so that concurrent runs don't race
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let sysroot = ::std::env::temp_dir().join(format!("goblin-ldd-{}-{}", now.as_secs(), now.subsec_nanos()));
+        // the executable requires libc.so.6 in its `$ORIGIN/lib` runpath, which is an absolute symbolic
+        // link to the library; the library, a copy of the executable, requires itself
+        let hello: Vec<u8> = include!("../etc/hello.rs");
+        fs::create_dir_all(sysroot.join("usr/bin/lib")).unwrap();
+        fs::create_dir_all(sysroot.join("usr/lib/real")).unwrap();
+        write(sysroot.join("usr/bin/app"), &hello);
+        write(sysroot.join("usr/lib/real/libc.so.6"), &hello);
+        symlink("/usr/lib/real/libc.so.6", sysroot.join("usr/bin/lib/libc.so.6")).unwrap();
+        // links which would leave the sysroot
+        symlink("/etc/passwd", sysroot.join("passwd")).unwrap();
+        symlink("../../../../../../../../etc", sysroot.join("usr/etc")).unwrap();
+        symlink("loop", sysroot.join("loop")).unwrap();
+
+        let mut resolver = Resolver::new(&sysroot);
+        resolver.elf_default_paths = vec![PathBuf::from("/usr/lib/real")];
+        let real = fs::canonicalize(&sysroot).unwrap();
+        assert_eq!(resolver.real_path(sysroot.join("usr/bin/lib/libc.so.6")), Some(real.join("usr/lib/real/libc.so.6")));
+        assert_eq!(resolver.real_path(sysroot.join("usr/bin/lib/../app")), Some(real.join("usr/bin/app")));
+        assert_eq!(resolver.real_path(sysroot.join("passwd")), None);
+        assert_eq!(resolver.real_path(sysroot.join("usr/etc/passwd")), None);
+        assert_eq!(resolver.real_path(sysroot.join("loop")), None);
+
+        let tree = resolver.resolve(sysroot.join("usr/bin/app")).unwrap();
+        assert_eq!(tree.libraries.len(), 2);
+        let root = tree.root();
+        assert_eq!(root.dependencies.len(), 2);
+        assert_eq!(root.dependencies[0].name, "libc.so.6");
+        assert_eq!(root.dependencies[0].resolution, Resolution::Found(1));
+        // the interpreter is missing
+        assert_eq!(root.dependencies[1].name, "/lib64/ld-linux-x86-64.so.2");
+        assert_eq!(root.dependencies[1].resolution, Resolution::NotFound);
+        // the library is found through the link, and isn't loaded again from the default path
+        let libc = &tree.libraries[1];
+        assert_eq!(libc.path, sysroot.join("usr/bin/lib/libc.so.6"));
+        assert_eq!(libc.parent, Some(0));
+        assert_eq!(libc.dependencies[0].resolution, Resolution::Found(1));
+        // the copy doesn't define the functions of libc
+        assert!(root.dependencies[0].undefined_symbols.contains(&"__printf_chk".to_string()));
+        assert_eq!(tree.unresolved().len(), 1);
+        assert!(!tree.is_complete());
+        fs::remove_dir_all(&sysroot).unwrap();
+    }
+}
@@ -130,6 +130,9 @@ pub mod error;
 
 pub mod strtab;
 
+#[cfg(feature = "compression")]
+mod inflate;
+
 /// Binary container size information and byte-order context
 pub mod container {
     use scroll;
@@ -324,6 +327,29 @@ if_everything! {
     }
 } // end if_endian_fd
 
+/// Rounds `value` up to the alignment `align`, a power of two; 0 and 1 mean unaligned.
+/// Returns `None` if `align` isn't a power of two or the rounded value overflows
+#[cfg(any(feature = "elf64", feature = "elf32"))]
+#[inline]
+fn align_up(value: u64, align: u64) -> Option<u64> {
+    if align <= 1 {
+        Some(value)
+    } else if !align.is_power_of_two() {
+        None
+    } else {
+        value.checked_add(align - 1).map(|value| value & !(align - 1))
+    }
+}
+
+/// Reads the whole file at `path`, like `std::fs::read`, which is newer than our minimum `rustc`
+#[cfg(all(feature = "std", feature = "elf32", feature = "elf64", feature = "endian_fd"))]
+fn read_file<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<alloc::vec::Vec<u8>> {
+    use std::io::Read;
+    let mut bytes = alloc::vec::Vec::new();
+    std::fs::File::open(path)?.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
 /////////////////////////
 // Binary Modules
 /////////////////////////
@@ -372,3 +398,11 @@ pub mod pe;
 
 #[cfg(feature = "archive")]
 pub mod archive;
+
+if_everything! {
+    pub mod hardening;
+    pub mod image;
+    pub mod symbolizer;
+    #[cfg(feature = "std")]
+    pub mod ldd;
+}
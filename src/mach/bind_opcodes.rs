@@ -38,6 +38,22 @@ pub const BIND_OPCODE_DO_BIND_ADD_ADDR_ULEB		: Opcode = 0xA0;
 pub const BIND_OPCODE_DO_BIND_ADD_ADDR_IMM_SCALED	: Opcode = 0xB0;
 pub const BIND_OPCODE_DO_BIND_ULEB_TIMES_SKIPPING_ULEB  : Opcode = 0xC0;
 
+// The following are used to encode rebasing information, i.e., the pointers which slide with the image
+pub const REBASE_TYPE_POINTER                           : u8     = 1;
+pub const REBASE_TYPE_TEXT_ABSOLUTE32                   : u8     = 2;
+pub const REBASE_TYPE_TEXT_PCREL32                      : u8     = 3;
+pub const REBASE_OPCODE_MASK                            : u8     = 0xF0;
+pub const REBASE_IMMEDIATE_MASK                         : u8     = 0x0F;
+pub const REBASE_OPCODE_DONE                            : Opcode = 0x00;
+pub const REBASE_OPCODE_SET_TYPE_IMM                    : Opcode = 0x10;
+pub const REBASE_OPCODE_SET_SEGMENT_AND_OFFSET_ULEB     : Opcode = 0x20;
+pub const REBASE_OPCODE_ADD_ADDR_ULEB                   : Opcode = 0x30;
+pub const REBASE_OPCODE_ADD_ADDR_IMM_SCALED             : Opcode = 0x40;
+pub const REBASE_OPCODE_DO_REBASE_IMM_TIMES             : Opcode = 0x50;
+pub const REBASE_OPCODE_DO_REBASE_ULEB_TIMES            : Opcode = 0x60;
+pub const REBASE_OPCODE_DO_REBASE_ADD_ADDR_ULEB         : Opcode = 0x70;
+pub const REBASE_OPCODE_DO_REBASE_ULEB_TIMES_SKIPPING_ULEB : Opcode = 0x80;
+
 pub fn opcode_to_str(opcode: Opcode) -> &'static str {
     match opcode {
         BIND_OPCODE_DONE => "BIND_OPCODE_DONE",
@@ -2,7 +2,7 @@
 use core::fmt;
 use alloc::vec::Vec;
 
-use scroll::{self, Pread, BE};
+use scroll::{self, Pread, BE, Uleb128};
 use scroll::ctx::SizeWith;
 
 use error;
@@ -55,6 +55,8 @@ pub struct MachO<'a> {
     pub symbols: Option<symbols::Symbols<'a>>,
     /// The dylibs this library depends on
     pub libs: Vec<&'a str>,
+    /// The runpath search paths of the `LC_RPATH` commands, which `@rpath/` install names are resolved against
+    pub rpaths: Vec<&'a str>,
     /// The entry point (as a virtual memory address), 0 if none
     pub entry: u64,
     /// Whether `entry` refers to an older `LC_UNIXTHREAD` instead of the newer `LC_MAIN` entrypoint
@@ -80,6 +82,7 @@ impl<'a> fmt::Debug for MachO<'a> {
             .field("entry",           &self.entry)
             .field("old_style_entry", &self.old_style_entry)
             .field("libs",            &self.libs)
+            .field("rpaths",          &self.rpaths)
             .field("name",            &self.name)
             .field("little_endian",   &self.little_endian)
             .field("is_64",           &self.is_64)
@@ -133,6 +136,30 @@ impl<'a> MachO<'a> {
             Ok(vec![])
         }
     }
+    /// Return the addresses of the functions listed in `LC_FUNCTION_STARTS` (if any), in ascending order
+    pub fn function_starts(&self) -> error::Result<Vec<u64>> {
+        let mut starts = Vec::new();
+        for cmd in &self.load_commands {
+            if let load_command::CommandVariant::FunctionStarts(command) = cmd.command {
+                let data: &[u8] = self.data.pread_with(command.dataoff as usize, command.datasize as usize)?;
+                // the ULEB128 deltas start from the beginning of the __TEXT segment, and are terminated by a 0
+                let mut address = self.segments.iter()
+                    .find(|s| &s.segname[0..7] == b"__TEXT\0")
+                    .map_or(0, |s| s.vmaddr);
+                let offset = &mut 0;
+                while *offset < data.len() {
+                    let delta = Uleb128::read(data, offset)?;
+                    if delta == 0 {
+                        break;
+                    }
+                    address = address.checked_add(delta)
+                        .ok_or_else(|| error::Error::Malformed(format!("Function start delta {:#x} overflows the address {:#x}", delta, address)))?;
+                    starts.push(address);
+                }
+            }
+        }
+        Ok(starts)
+    }
     /// Parses the Mach-o binary from `bytes` at `offset`
     pub fn parse(bytes: &'a [u8], mut offset: usize) -> error::Result<MachO<'a>> {
         let (magic, maybe_ctx) = parse_magic_and_ctx(bytes, offset)?;
@@ -148,6 +175,7 @@ impl<'a> MachO<'a> {
         let mut cmds: Vec<load_command::LoadCommand> = Vec::with_capacity(ncmds);
         let mut symbols = None;
         let mut libs = vec!["self"];
+        let mut rpaths = vec![];
         let mut export_trie = None;
         let mut bind_interpreter = None;
         let mut unixthread_entry_address = None;
@@ -175,6 +203,10 @@ impl<'a> MachO<'a> {
                 | load_command::CommandVariant::LazyLoadDylib  (command) => {
                     let lib = bytes.pread::<&str>(cmd.offset + command.dylib.name as usize)?;
                     libs.push(lib);
+                },
+                load_command::CommandVariant::Rpath(command) => {
+                    let rpath = bytes.pread::<&str>(cmd.offset + command.path as usize)?;
+                    rpaths.push(rpath);
                 },
                   load_command::CommandVariant::DyldInfo    (command)
                 | load_command::CommandVariant::DyldInfoOnly(command) => {
@@ -228,6 +260,7 @@ impl<'a> MachO<'a> {
             segments: segments,
             symbols: symbols,
             libs: libs,
+            rpaths: rpaths,
             export_trie: export_trie,
             bind_interpreter: bind_interpreter,
             entry: entry,
@@ -9,16 +9,20 @@ use pe::data_directories;
 pub struct DebugData<'a> {
     pub image_debug_directory: ImageDebugDirectory,
     pub codeview_pdb70_debug_info: Option<CodeviewPDB70DebugInfo<'a>>,
+    /// The `IMAGE_DLLCHARACTERISTICS_EX_*` flags, e.g., CET shadow stack compatibility, if any
+    pub ex_dll_characteristics: Option<u32>,
 }
 
 impl<'a> DebugData<'a> {
     pub fn parse(bytes: &'a [u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32) -> error::Result<Self> {
         let image_debug_directory = ImageDebugDirectory::parse(bytes, dd, sections, file_alignment)?;
         let codeview_pdb70_debug_info = CodeviewPDB70DebugInfo::parse(bytes, &image_debug_directory)?;
+        let ex_dll_characteristics = parse_ex_dll_characteristics(bytes, dd, sections, file_alignment);
 
         Ok(DebugData{
             image_debug_directory: image_debug_directory,
-            codeview_pdb70_debug_info: codeview_pdb70_debug_info
+            codeview_pdb70_debug_info: codeview_pdb70_debug_info,
+            ex_dll_characteristics: ex_dll_characteristics,
         })
     }
     
@@ -52,6 +56,36 @@ pub const IMAGE_DEBUG_TYPE_MISC: u32 = 4;
 pub const IMAGE_DEBUG_TYPE_EXCEPTION: u32 = 5;
 pub const IMAGE_DEBUG_TYPE_FIXUP: u32 = 6;
 pub const IMAGE_DEBUG_TYPE_BORLAND: u32 = 9;
+pub const IMAGE_DEBUG_TYPE_EX_DLLCHARACTERISTICS: u32 = 20;
+
+pub const SIZEOF_IMAGE_DEBUG_DIRECTORY: usize = 28;
+
+/// Image is compatible with CET shadow stacks
+pub const IMAGE_DLLCHARACTERISTICS_EX_CET_COMPAT: u32 = 0x01;
+/// Image is compatible with CET shadow stacks in strict mode
+pub const IMAGE_DLLCHARACTERISTICS_EX_CET_COMPAT_STRICT_MODE: u32 = 0x02;
+/// Image allows `SetThreadContext` to set instruction pointers not on the shadow stack
+pub const IMAGE_DLLCHARACTERISTICS_EX_CET_SET_CONTEXT_IP_VALIDATION_RELAXED_MODE: u32 = 0x04;
+/// Image allows dynamic APIs, e.g., to allocate executable memory, in process
+pub const IMAGE_DLLCHARACTERISTICS_EX_CET_DYNAMIC_APIS_ALLOW_IN_PROC: u32 = 0x08;
+
+/// Returns the extended DLL characteristics, from the `IMAGE_DEBUG_TYPE_EX_DLLCHARACTERISTICS`
+/// entry of the debug directory, if any
+fn parse_ex_dll_characteristics(bytes: &[u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32) -> Option<u32> {
+    utils::find_offset(dd.virtual_address as usize, sections, file_alignment).and_then(|mut offset| {
+        // the search ends at the first entry past the end of the file
+        for _ in 0..dd.size as usize / SIZEOF_IMAGE_DEBUG_DIRECTORY {
+            let idd: ImageDebugDirectory = match bytes.gread_with(&mut offset, scroll::LE) {
+                Ok(idd) => idd,
+                Err(_) => return None,
+            };
+            if idd.data_type == IMAGE_DEBUG_TYPE_EX_DLLCHARACTERISTICS {
+                return bytes.pread_with(idd.pointer_to_raw_data as usize, scroll::LE).ok();
+            }
+        }
+        None
+    })
+}
 
 impl ImageDebugDirectory {
     fn parse(bytes: &[u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32) -> error::Result<Self> {
@@ -118,3 +152,33 @@ impl<'a> CodeviewPDB70DebugInfo<'a> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    #[test]
+    fn ex_dll_characteristics() {
+        // a COFF entry and then the extended DLL characteristics
+        let sections = [utils::identity_section(0x200)];
+        let mut bytes = vec![0u8; 0x200];
+        let coff = ImageDebugDirectory { data_type: IMAGE_DEBUG_TYPE_COFF, ..Default::default() };
+        let ex = ImageDebugDirectory { data_type: IMAGE_DEBUG_TYPE_EX_DLLCHARACTERISTICS, size_of_data: 4, address_of_raw_data: 0x180, pointer_to_raw_data: 0x180, ..Default::default() };
+        bytes.pwrite_with(coff, 0x100, scroll::LE).unwrap();
+        bytes.pwrite_with(ex, 0x100 + SIZEOF_IMAGE_DEBUG_DIRECTORY, scroll::LE).unwrap();
+        bytes.pwrite_with(IMAGE_DLLCHARACTERISTICS_EX_CET_COMPAT, 0x180, scroll::LE).unwrap();
+        let dd = data_directories::DataDirectory { virtual_address: 0x100, size: 2 * SIZEOF_IMAGE_DEBUG_DIRECTORY as u32 };
+        let debug = DebugData::parse(&bytes, &dd, &sections, 0x200).unwrap();
+        assert_eq!(debug.image_debug_directory, coff);
+        assert_eq!(debug.codeview_pdb70_debug_info, None);
+        assert_eq!(debug.ex_dll_characteristics, Some(IMAGE_DLLCHARACTERISTICS_EX_CET_COMPAT));
+        // the directory only has the first entry
+        let first = data_directories::DataDirectory { size: SIZEOF_IMAGE_DEBUG_DIRECTORY as u32, ..dd };
+        assert_eq!(DebugData::parse(&bytes, &first, &sections, 0x200).unwrap().ex_dll_characteristics, None);
+        // a directory claiming more entries than the file has
+        bytes.pwrite_with(coff, 0x100 + SIZEOF_IMAGE_DEBUG_DIRECTORY, scroll::LE).unwrap();
+        let huge = data_directories::DataDirectory { size: !0, ..dd };
+        assert_eq!(DebugData::parse(&bytes, &huge, &sections, 0x200).unwrap().ex_dll_characteristics, None);
+    }
+}
@@ -0,0 +1,689 @@
+//! The exception directory (`.pdata`), the function table used to unwind the stack.
+//!
+//! On x86-64, each `RUNTIME_FUNCTION` entry gives the range of a function and the RVA of its
+//! `UNWIND_INFO`, which describes the effects of its prolog on the stack as unwind codes, in
+//! reverse order. Undoing them, and popping the return address, gives the caller's registers.
+//!
+//! On ARM64, the entries are 8 bytes: the RVA of the function and either the RVA of its
+//! `.xdata` record, or the packed unwind data itself, when the low 2 bits are not zero.
+//!
+//! See: https://docs.microsoft.com/en-us/cpp/build/exception-handling-x64
+
+use core::fmt;
+use scroll::{self, Pread};
+use alloc::vec::Vec;
+
+use error;
+
+use pe::section_table;
+use pe::utils;
+use pe::data_directories;
+use pe::header::{COFF_MACHINE_X86_64, COFF_MACHINE_ARM64};
+
+/// The function has no handler
+pub const UNW_FLAG_NHANDLER: u8 = 0;
+/// The function has a handler called when looking for a function handling the exception
+pub const UNW_FLAG_EHANDLER: u8 = 1;
+/// The function has a handler called when unwinding an exception
+pub const UNW_FLAG_UHANDLER: u8 = 2;
+/// The unwind info continues with the `RUNTIME_FUNCTION` of the function it is chained to
+pub const UNW_FLAG_CHAININFO: u8 = 4;
+
+/// Pushes a nonvolatile register, decrementing `rsp` by 8
+pub const UWOP_PUSH_NONVOL: u8 = 0;
+/// Allocates a large area on the stack, whose size is in the next 1 or 2 slots
+pub const UWOP_ALLOC_LARGE: u8 = 1;
+/// Allocates 8 to 128 bytes on the stack
+pub const UWOP_ALLOC_SMALL: u8 = 2;
+/// Establishes the frame pointer register, at an offset of `rsp`
+pub const UWOP_SET_FPREG: u8 = 3;
+/// Saves a nonvolatile register on the stack with a `mov`, at an offset in the next slot
+pub const UWOP_SAVE_NONVOL: u8 = 4;
+/// Saves a nonvolatile register on the stack with a `mov`, at an offset in the next 2 slots
+pub const UWOP_SAVE_NONVOL_FAR: u8 = 5;
+/// Describes an epilog, in version 2 of the unwind info
+pub const UWOP_EPILOG: u8 = 6;
+/// Unused
+pub const UWOP_SPARE_CODE: u8 = 7;
+/// Saves an XMM register on the stack, at an offset in the next slot
+pub const UWOP_SAVE_XMM128: u8 = 8;
+/// Saves an XMM register on the stack, at an offset in the next 2 slots
+pub const UWOP_SAVE_XMM128_FAR: u8 = 9;
+/// Pushes a machine frame, for a hardware exception or an interrupt
+pub const UWOP_PUSH_MACHFRAME: u8 = 10;
+
+/// The index of `rsp` in the x86-64 registers, in the order of the unwind codes
+pub const REGISTER_RSP: u8 = 4;
+
+/// Returns the name of the x86-64 integer register `register`, numbered as in the unwind codes
+pub fn register_to_str(register: u8) -> &'static str {
+    match register {
+        0 => "rax",
+        1 => "rcx",
+        2 => "rdx",
+        3 => "rbx",
+        4 => "rsp",
+        5 => "rbp",
+        6 => "rsi",
+        7 => "rdi",
+        8 => "r8",
+        9 => "r9",
+        10 => "r10",
+        11 => "r11",
+        12 => "r12",
+        13 => "r13",
+        14 => "r14",
+        15 => "r15",
+        _ => "unknown",
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+/// An x86-64 function table entry
+pub struct RuntimeFunction {
+    /// The RVA of the start of the function
+    pub begin_address: u32,
+    /// The RVA of the end of the function, exclusive
+    pub end_address: u32,
+    /// The RVA of the `UNWIND_INFO` of the function
+    pub unwind_info_address: u32,
+}
+
+pub const SIZEOF_RUNTIME_FUNCTION: usize = 12;
+
+impl RuntimeFunction {
+    /// Whether the function contains `rva`
+    pub fn contains(&self, rva: u32) -> bool {
+        self.begin_address <= rva && rva < self.end_address
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+/// An ARM64 function table entry
+pub struct Arm64RuntimeFunction {
+    /// The RVA of the start of the function
+    pub begin_address: u32,
+    /// The RVA of the `.xdata` record when the flag in the low 2 bits is 0, else the packed unwind data
+    pub unwind_data: u32,
+}
+
+pub const SIZEOF_ARM64_RUNTIME_FUNCTION: usize = 8;
+
+impl Arm64RuntimeFunction {
+    /// The flag in the low 2 bits: 0 for an `.xdata` record, 1 for packed unwind data and 2 for
+    /// packed unwind data of a fragment, without a prolog
+    pub fn flag(&self) -> u8 {
+        (self.unwind_data & 3) as u8
+    }
+    /// Whether the unwind data is packed in the entry
+    pub fn is_packed(&self) -> bool {
+        self.flag() != 0
+    }
+    /// The RVA of the `.xdata` record, if the unwind data isn't packed
+    pub fn unwind_info_address(&self) -> Option<u32> {
+        if self.is_packed() { None } else { Some(self.unwind_data) }
+    }
+    /// The length of the function in bytes, if the unwind data is packed; otherwise it is in the `.xdata` record
+    pub fn function_length(&self) -> Option<u32> {
+        if self.is_packed() { Some(((self.unwind_data >> 2) & 0x7ff) * 4) } else { None }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+/// The operation of an unwind code, as performed by the prolog
+pub enum UnwindOperation {
+    /// Pushes the register
+    PushNonVolatile(u8),
+    /// Allocates this many bytes on the stack
+    Alloc(u32),
+    /// Sets the frame register to `rsp` plus the frame offset
+    SetFramePointer,
+    /// Saves the register at this offset from the frame base
+    SaveNonVolatile(u8, u32),
+    /// Saves the XMM register at this offset from the frame base
+    SaveXmm128(u8, u32),
+    /// Pushes a machine frame, with an error code if true
+    PushMachineFrame(bool),
+    /// Describes an epilog (version 2), and has no effect when unwinding
+    Epilog,
+    /// An unknown operation, with its info
+    Unknown(u8, u8),
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+/// An unwind code
+pub struct UnwindCode {
+    /// The offset from the start of the function of the end of the prolog instruction
+    pub code_offset: u8,
+    pub operation: UnwindOperation,
+}
+
+/// Decodes the unwind codes in `slots`, the 16-bit slots of an `UNWIND_INFO`
+fn decode_unwind_codes(slots: &[u16]) -> error::Result<Vec<UnwindCode>> {
+    let mut codes = Vec::with_capacity(slots.len());
+    let mut i = 0;
+    while i < slots.len() {
+        let slot = slots[i];
+        let code_offset = slot as u8;
+        let op = ((slot >> 8) & 0xf) as u8;
+        let info = (slot >> 12) as u8;
+        let slot_at = |n: usize| {
+            slots.get(i + n).map(|&slot| u32::from(slot)).ok_or_else(|| error::Error::Malformed(format!("Unwind code {} at slot {} is truncated", op, i)))
+        };
+        let (operation, size) = match op {
+            UWOP_PUSH_NONVOL => (UnwindOperation::PushNonVolatile(info), 1),
+            UWOP_ALLOC_LARGE if info == 0 => (UnwindOperation::Alloc(slot_at(1)? * 8), 2),
+            UWOP_ALLOC_LARGE => (UnwindOperation::Alloc(slot_at(1)? | slot_at(2)? << 16), 3),
+            UWOP_ALLOC_SMALL => (UnwindOperation::Alloc(u32::from(info) * 8 + 8), 1),
+            UWOP_SET_FPREG => (UnwindOperation::SetFramePointer, 1),
+            UWOP_SAVE_NONVOL => (UnwindOperation::SaveNonVolatile(info, slot_at(1)? * 8), 2),
+            UWOP_SAVE_NONVOL_FAR => (UnwindOperation::SaveNonVolatile(info, slot_at(1)? | slot_at(2)? << 16), 3),
+            UWOP_EPILOG => (UnwindOperation::Epilog, 2),
+            UWOP_SPARE_CODE => (UnwindOperation::Unknown(op, info), 3),
+            UWOP_SAVE_XMM128 => (UnwindOperation::SaveXmm128(info, slot_at(1)? * 16), 2),
+            UWOP_SAVE_XMM128_FAR => (UnwindOperation::SaveXmm128(info, slot_at(1)? | slot_at(2)? << 16), 3),
+            UWOP_PUSH_MACHFRAME => (UnwindOperation::PushMachineFrame(info != 0), 1),
+            _ => (UnwindOperation::Unknown(op, info), 1),
+        };
+        codes.push(UnwindCode { code_offset, operation });
+        i += size;
+    }
+    Ok(codes)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// The x86-64 `UNWIND_INFO` of a function
+pub struct UnwindInfo {
+    /// The version, 1 or 2
+    pub version: u8,
+    /// The `UNW_FLAG_*` flags
+    pub flags: u8,
+    /// The size of the prolog in bytes
+    pub size_of_prolog: u8,
+    /// The frame register, 0 if the function doesn't use one
+    pub frame_register: u8,
+    /// The offset of the frame register from `rsp` when it was established, in units of 16 bytes
+    pub frame_offset: u8,
+    /// The unwind codes, in reverse order of the prolog
+    pub unwind_codes: Vec<UnwindCode>,
+    /// The RVA of the exception handler, with `UNW_FLAG_EHANDLER` or `UNW_FLAG_UHANDLER`
+    pub exception_handler: Option<u32>,
+    /// The RVA of the language-specific data following the exception handler
+    pub exception_data: Option<u32>,
+    /// The function whose unwind info this one continues, with `UNW_FLAG_CHAININFO`
+    pub chained_function: Option<RuntimeFunction>,
+}
+
+impl UnwindInfo {
+    /// Parses the unwind info at `offset` in `bytes`, which is at `rva` in the image
+    pub fn parse(bytes: &[u8], offset: usize, rva: u32) -> error::Result<Self> {
+        let mut current = offset;
+        let version_and_flags: u8 = bytes.gread(&mut current)?;
+        let version = version_and_flags & 0x7;
+        let flags = version_and_flags >> 3;
+        if version != 1 && version != 2 {
+            return Err(error::Error::Malformed(format!("Unsupported unwind info version {} at {:#x}", version, rva)));
+        }
+        let size_of_prolog: u8 = bytes.gread(&mut current)?;
+        let count_of_codes: u8 = bytes.gread(&mut current)?;
+        let frame: u8 = bytes.gread(&mut current)?;
+        let mut slots = Vec::with_capacity(count_of_codes as usize);
+        for _ in 0..count_of_codes {
+            slots.push(bytes.gread_with::<u16>(&mut current, scroll::LE)?);
+        }
+        // the slots are padded to an even count
+        if count_of_codes % 2 == 1 {
+            current += 2;
+        }
+        let mut exception_handler = None;
+        let mut exception_data = None;
+        let mut chained_function = None;
+        if flags & UNW_FLAG_CHAININFO != 0 {
+            chained_function = Some(bytes.gread_with(&mut current, scroll::LE)?);
+        } else if flags & (UNW_FLAG_EHANDLER | UNW_FLAG_UHANDLER) != 0 {
+            exception_handler = Some(bytes.gread_with(&mut current, scroll::LE)?);
+            exception_data = Some(rva + (current - offset) as u32);
+        }
+        Ok(UnwindInfo {
+            version,
+            flags,
+            size_of_prolog,
+            frame_register: frame & 0xf,
+            frame_offset: frame >> 4,
+            unwind_codes: decode_unwind_codes(&slots)?,
+            exception_handler,
+            exception_data,
+            chained_function,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+/// The integer registers of an x86-64 thread, for unwinding
+pub struct X64Context {
+    pub rip: u64,
+    /// The integer registers, numbered as in the unwind codes, i.e., `rax`, `rcx`, `rdx`, `rbx`,
+    /// `rsp`, `rbp`, `rsi`, `rdi` and `r8` to `r15`
+    pub registers: [u64; 16],
+}
+
+impl X64Context {
+    pub fn rsp(&self) -> u64 {
+        self.registers[REGISTER_RSP as usize]
+    }
+    pub fn set_rsp(&mut self, rsp: u64) {
+        self.registers[REGISTER_RSP as usize] = rsp;
+    }
+}
+
+/// Reads the 64-bit word at `address` with `read`
+fn read_u64<F: FnMut(u64) -> Option<u64>>(read: &mut F, address: u64) -> error::Result<u64> {
+    read(address).ok_or_else(|| error::Error::Malformed(format!("Cannot read the stack at {:#x}", address)))
+}
+
+/// The effects of an epilog on the registers
+struct Epilog {
+    /// `rsp` is set to this register plus this offset, by an `add` or a `lea`
+    rsp: Option<(u8, u64)>,
+    /// The registers popped, in order
+    pops: Vec<u8>,
+}
+
+/// Decodes the instructions in `code`, at `rva` in `function`, if they are an epilog: an optional
+/// `add rsp` or `lea rsp`, `pop`s, and a `ret` or a `jmp` out of the function; errors if `code` ends first
+fn decode_epilog(code: &[u8], rva: u32, function: &RuntimeFunction) -> scroll::Result<Option<Epilog>> {
+    let byte = |i: usize| code.pread::<u8>(i);
+    let dword = |i: usize| code.pread_with::<i32>(i, scroll::LE);
+    let mut epilog = Epilog { rsp: None, pops: Vec::new() };
+    let mut i = 0;
+    // the add or lea must have a REX.W prefix
+    if byte(0)? & 0xf8 == 0x48 {
+        let rex = byte(0)?;
+        let modrm = byte(2)?;
+        match byte(1)? {
+            // add rsp, imm32
+            0x81 if rex == 0x48 && modrm == 0xc4 => {
+                epilog.rsp = Some((REGISTER_RSP, dword(3)? as i64 as u64));
+                i = 7;
+            },
+            // add rsp, imm8
+            0x83 if rex == 0x48 && modrm == 0xc4 => {
+                epilog.rsp = Some((REGISTER_RSP, byte(3)? as i8 as i64 as u64));
+                i = 4;
+            },
+            // lea rsp, [reg + disp], without REX.R or REX.X, nor a SIB byte
+            0x8d if rex & 0x6 == 0 && (modrm >> 3) & 7 == 4 && modrm & 7 != 4 => {
+                let register = (modrm & 7) | (rex & 1) << 3;
+                match modrm >> 6 {
+                    1 => {
+                        epilog.rsp = Some((register, byte(3)? as i8 as i64 as u64));
+                        i = 4;
+                    },
+                    2 => {
+                        epilog.rsp = Some((register, dword(3)? as i64 as u64));
+                        i = 7;
+                    },
+                    _ => return Ok(None),
+                }
+            },
+            _ => return Ok(None),
+        }
+    }
+    loop {
+        let mut rex = 0;
+        if byte(i)? & 0xf0 == 0x40 {
+            rex = byte(i)?;
+            i += 1;
+        }
+        match byte(i)? {
+            // pop reg
+            op if op & 0xf8 == 0x58 => {
+                epilog.pops.push((op - 0x58) | (rex & 1) << 3);
+                i += 1;
+            },
+            // ret, ret imm16
+            0xc3 | 0xc2 => return Ok(Some(epilog)),
+            // rep ret
+            0xf3 if byte(i + 1)? == 0xc3 => return Ok(Some(epilog)),
+            // jmp rel32 and jmp rel8, to another function
+            op @ 0xe9 | op @ 0xeb => {
+                let (displacement, size) = if op == 0xe9 { (dword(i + 1)?, 5) } else { (i32::from(byte(i + 1)? as i8), 2) };
+                let target = (rva as i64 + i as i64 + size + i64::from(displacement)) as u32;
+                return Ok(if function.contains(target) { None } else { Some(epilog) });
+            },
+            // jmp [rip + disp32]
+            0xff if byte(i + 1)? == 0x25 => return Ok(Some(epilog)),
+            _ => return Ok(None),
+        }
+    }
+}
+
+/// Iterates over the x86-64 function table
+pub struct RuntimeFunctionIterator<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for RuntimeFunctionIterator<'a> {
+    type Item = RuntimeFunction;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.bytes.gread_with(&mut self.offset, scroll::LE).ok()
+    }
+}
+
+/// Iterates over the ARM64 function table
+pub struct Arm64RuntimeFunctionIterator<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for Arm64RuntimeFunctionIterator<'a> {
+    type Item = Arm64RuntimeFunction;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.bytes.gread_with(&mut self.offset, scroll::LE).ok()
+    }
+}
+
+/// The maximum number of chained unwind infos followed, as a guard against cycles
+const MAX_CHAIN_DEPTH: usize = 32;
+
+#[derive(PartialEq, Clone)]
+/// The exception directory
+pub struct ExceptionData<'a> {
+    bytes: &'a [u8],
+    functions: &'a [u8],
+    machine: u16,
+    sections: Vec<section_table::SectionTable>,
+    file_alignment: u32,
+}
+
+impl<'a> fmt::Debug for ExceptionData<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExceptionData")
+            .field("machine", &format_args!("{:#x}", self.machine))
+            .field("size", &format_args!("{:#x}", self.functions.len()))
+            .finish()
+    }
+}
+
+impl<'a> ExceptionData<'a> {
+    /// Parses the exception directory of an image of `machine`, x86-64 or ARM64
+    pub fn parse(bytes: &'a [u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32, machine: u16) -> error::Result<Self> {
+        let entry_size = match machine {
+            COFF_MACHINE_X86_64 => SIZEOF_RUNTIME_FUNCTION,
+            COFF_MACHINE_ARM64 => SIZEOF_ARM64_RUNTIME_FUNCTION,
+            _ => return Err(error::Error::Malformed(format!("Unsupported machine {:#x} for the exception directory", machine))),
+        };
+        let rva = dd.virtual_address as usize;
+        let offset = utils::find_offset(rva, sections, file_alignment).ok_or_else(|| error::Error::Malformed(format!("Cannot map exception table rva {:#x} into offset", rva)))?;
+        let size = dd.size as usize / entry_size * entry_size;
+        match bytes.get(offset..offset.saturating_add(size)) {
+            Some(functions) => Ok(ExceptionData { bytes, functions, machine, sections: sections.to_vec(), file_alignment }),
+            None => Err(error::Error::Malformed(format!("Exception table at {:#x} of size {:#x} is out of bounds", offset, dd.size))),
+        }
+    }
+    /// The machine of the function table
+    pub fn machine(&self) -> u16 {
+        self.machine
+    }
+    /// Returns an iterator over the x86-64 function table, which is empty for other machines
+    pub fn functions(&self) -> RuntimeFunctionIterator<'a> {
+        let bytes = if self.machine == COFF_MACHINE_X86_64 { self.functions } else { &[] };
+        RuntimeFunctionIterator { bytes, offset: 0 }
+    }
+    /// Returns an iterator over the ARM64 function table, which is empty for other machines
+    pub fn arm64_functions(&self) -> Arm64RuntimeFunctionIterator<'a> {
+        let bytes = if self.machine == COFF_MACHINE_ARM64 { self.functions } else { &[] };
+        Arm64RuntimeFunctionIterator { bytes, offset: 0 }
+    }
+    /// Returns the x86-64 function containing `rva`, with a binary search of the table, which is sorted
+    pub fn find_function(&self, rva: u32) -> Option<RuntimeFunction> {
+        if self.machine != COFF_MACHINE_X86_64 {
+            return None;
+        }
+        let (mut low, mut high) = (0, self.functions.len() / SIZEOF_RUNTIME_FUNCTION);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let function: RuntimeFunction = match self.functions.pread_with(middle * SIZEOF_RUNTIME_FUNCTION, scroll::LE) {
+                Ok(function) => function,
+                Err(_) => return None,
+            };
+            if rva < function.begin_address {
+                high = middle;
+            } else if rva >= function.end_address {
+                low = middle + 1;
+            } else {
+                return Some(function);
+            }
+        }
+        None
+    }
+    /// Returns the unwind info of `function`
+    pub fn get_unwind_info(&self, function: &RuntimeFunction) -> error::Result<UnwindInfo> {
+        let rva = function.unwind_info_address;
+        let offset = utils::find_offset(rva as usize, &self.sections, self.file_alignment).ok_or_else(|| error::Error::Malformed(format!("Cannot map unwind info rva {:#x} into offset", rva)))?;
+        UnwindInfo::parse(self.bytes, offset, rva)
+    }
+    /// Unwinds the frame of `context`, a thread of the image loaded at `image_base`, to the frame
+    /// of its caller, reading the memory of the thread's process with `read`, which returns the
+    /// 64-bit word at an address. Functions without an entry are leaf functions, which only have the
+    /// return address on the stack.
+    ///
+    /// Like the Windows unwinder, the epilog the frame may be executing is emulated, and the unwind
+    /// codes of a prolog in progress are only undone up to the instruction being executed.
+    pub fn unwind_frame<F>(&self, context: &mut X64Context, image_base: u64, mut read: F) -> error::Result<()> where F: FnMut(u64) -> Option<u64> {
+        if self.machine != COFF_MACHINE_X86_64 {
+            return Err(error::Error::Malformed(format!("Cannot unwind frames of machine {:#x}", self.machine)));
+        }
+        let rva = context.rip.wrapping_sub(image_base) as u32;
+        let mut function = match self.find_function(rva) {
+            Some(function) => function,
+            None => {
+                let rsp = context.rsp();
+                context.rip = read_u64(&mut read, rsp)?;
+                context.set_rsp(rsp.wrapping_add(8));
+                return Ok(());
+            },
+        };
+        let mut info = self.get_unwind_info(&function)?;
+        let mut offset = rva - function.begin_address;
+        if offset >= u32::from(info.size_of_prolog) {
+            let mut code = Vec::with_capacity(32);
+            for i in 0..4 {
+                match read(context.rip.wrapping_add(i * 8)) {
+                    Some(word) => code.extend((0..8).map(|byte| (word >> (byte * 8)) as u8)),
+                    None => break,
+                }
+            }
+            if let Ok(Some(epilog)) = decode_epilog(&code, rva, &function) {
+                if let Some((register, displacement)) = epilog.rsp {
+                    let rsp = context.registers[register as usize].wrapping_add(displacement);
+                    context.set_rsp(rsp);
+                }
+                for register in epilog.pops {
+                    let rsp = context.rsp();
+                    context.registers[register as usize] = read_u64(&mut read, rsp)?;
+                    context.set_rsp(rsp.wrapping_add(8));
+                }
+                let rsp = context.rsp();
+                context.rip = read_u64(&mut read, rsp)?;
+                context.set_rsp(rsp.wrapping_add(8));
+                return Ok(());
+            }
+        }
+        let mut machine_frame = false;
+        let mut depth = 0;
+        loop {
+            // the frame base, from which the registers were saved
+            let established = info.frame_register != 0 && (offset >= u32::from(info.size_of_prolog) || info.unwind_codes.iter().any(|code| {
+                code.operation == UnwindOperation::SetFramePointer && offset >= u32::from(code.code_offset)
+            }));
+            let frame = if established {
+                context.registers[info.frame_register as usize].wrapping_sub(u64::from(info.frame_offset) * 16)
+            } else {
+                context.rsp()
+            };
+            for code in &info.unwind_codes {
+                if offset < u32::from(code.code_offset) {
+                    continue;
+                }
+                let rsp = context.rsp();
+                match code.operation {
+                    UnwindOperation::PushNonVolatile(register) => {
+                        context.registers[register as usize] = read_u64(&mut read, rsp)?;
+                        context.set_rsp(rsp.wrapping_add(8));
+                    },
+                    UnwindOperation::Alloc(size) => context.set_rsp(rsp.wrapping_add(u64::from(size))),
+                    UnwindOperation::SetFramePointer => context.set_rsp(frame),
+                    UnwindOperation::SaveNonVolatile(register, displacement) => {
+                        context.registers[register as usize] = read_u64(&mut read, frame.wrapping_add(u64::from(displacement)))?;
+                    },
+                    UnwindOperation::PushMachineFrame(error_code) => {
+                        let rsp = if error_code { rsp.wrapping_add(8) } else { rsp };
+                        context.rip = read_u64(&mut read, rsp)?;
+                        context.set_rsp(read_u64(&mut read, rsp.wrapping_add(24))?);
+                        machine_frame = true;
+                    },
+                    UnwindOperation::SaveXmm128(..) | UnwindOperation::Epilog => (),
+                    UnwindOperation::Unknown(op, _) => return Err(error::Error::Malformed(format!("Unknown unwind code {} in the unwind info of function {:#x}", op, function.begin_address))),
+                }
+            }
+            match info.chained_function {
+                Some(chained) => {
+                    depth += 1;
+                    if depth > MAX_CHAIN_DEPTH {
+                        return Err(error::Error::Malformed(format!("Too many chained unwind infos for function {:#x}", function.begin_address)));
+                    }
+                    // the chained function's prolog has completed
+                    function = chained;
+                    info = self.get_unwind_info(&function)?;
+                    offset = ::core::u32::MAX;
+                },
+                None => break,
+            }
+        }
+        if !machine_frame {
+            let rsp = context.rsp();
+            context.rip = read_u64(&mut read, rsp)?;
+            context.set_rsp(rsp.wrapping_add(8));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    #[test]
+    fn unwind_frame() {
+        // the function table at 0x100, the unwind info at 0x200 and the function at 0x1000:
+        //   push rbp; push rbx; sub rsp, 0x28; lea rbp, [rsp + 0x20]; ...; add rsp, 0x28; pop rbx; pop rbp; ret
+        let mut bytes = vec![0u8; 0x1100];
+        let function = RuntimeFunction { begin_address: 0x1000, end_address: 0x1040, unwind_info_address: 0x200 };
+        bytes.pwrite_with(function, 0x100, scroll::LE).unwrap();
+        bytes.pwrite_with(RuntimeFunction { begin_address: 0x1040, end_address: 0x1050, unwind_info_address: 0x240 }, 0x10c, scroll::LE).unwrap();
+        let info = [
+            0x09, 0x0b, 0x04, 0x25,
+            0x0b, 0x03, 0x06, 0x42, 0x02, 0x30, 0x01, 0x50,
+            0x80, 0x10, 0, 0, 0xaa, 0xbb,
+        ];
+        bytes[0x200..0x200 + info.len()].copy_from_slice(&info);
+        // a chained unwind info, for a fragment of the function which saves r12 in the frame
+        let chained = [0x21, 0x00, 0x02, 0x00, 0x00, 0xc4, 0x02, 0x00, 0x00, 0x10, 0, 0, 0x40, 0x10, 0, 0, 0x00, 0x02, 0, 0];
+        bytes[0x240..0x240 + chained.len()].copy_from_slice(&chained);
+        let epilog = [0x48, 0x83, 0xc4, 0x28, 0x5b, 0x5d, 0xc3];
+        bytes[0x1030..0x1030 + epilog.len()].copy_from_slice(&epilog);
+        let section = utils::identity_section(0x1100);
+        let dd = data_directories::DataDirectory { virtual_address: 0x100, size: 24 };
+        let exceptions = ExceptionData::parse(&bytes, &dd, &[section], 0x200, COFF_MACHINE_X86_64).unwrap();
+        assert_eq!(exceptions.functions().count(), 2);
+        assert_eq!(exceptions.arm64_functions().count(), 0);
+        assert_eq!(exceptions.find_function(0x1010), Some(function));
+        assert_eq!(exceptions.find_function(0x1050), None);
+        assert_eq!(exceptions.find_function(0xfff), None);
+        let info = exceptions.get_unwind_info(&function).unwrap();
+        assert_eq!(info.version, 1);
+        assert_eq!(info.flags, UNW_FLAG_EHANDLER);
+        assert_eq!((info.frame_register, info.frame_offset), (5, 2));
+        assert_eq!(info.unwind_codes, vec![
+            UnwindCode { code_offset: 0x0b, operation: UnwindOperation::SetFramePointer },
+            UnwindCode { code_offset: 0x06, operation: UnwindOperation::Alloc(0x28) },
+            UnwindCode { code_offset: 0x02, operation: UnwindOperation::PushNonVolatile(3) },
+            UnwindCode { code_offset: 0x01, operation: UnwindOperation::PushNonVolatile(5) },
+        ]);
+        assert_eq!(info.exception_handler, Some(0x1080));
+        assert_eq!(info.exception_data, Some(0x210));
+
+        // the stack, from 0x8000: the frame, the saved rbx and rbp, and the return address
+        let image_base = 0x1_4000_0000;
+        let read = |address: u64| match address {
+            0x8010 => Some(0x12),
+            0x8028 => Some(0xb),
+            0x8030 => Some(0xbb),
+            0x8038 => Some(image_base + 0x2000),
+            _ if address >= image_base => bytes.pread_with(address.wrapping_sub(image_base) as usize, scroll::LE).ok(),
+            _ => None,
+        };
+        let mut caller = X64Context::default();
+        caller.rip = image_base + 0x2000;
+        caller.set_rsp(0x8040);
+        caller.registers[3] = 0xb;
+        caller.registers[5] = 0xbb;
+        // in the body, with rbp established
+        let mut context = X64Context::default();
+        context.rip = image_base + 0x1020;
+        context.set_rsp(0x8000);
+        context.registers[5] = 0x8020;
+        exceptions.unwind_frame(&mut context, image_base, &read).unwrap();
+        assert_eq!(context, caller);
+        // in the prolog, after the push of rbx
+        let mut context = X64Context::default();
+        context.rip = image_base + 0x1002;
+        context.set_rsp(0x8028);
+        context.registers[5] = 0xbb;
+        exceptions.unwind_frame(&mut context, image_base, &read).unwrap();
+        assert_eq!(context, caller);
+        // in the epilog, before the pop of rbx
+        let mut context = X64Context::default();
+        context.rip = image_base + 0x1034;
+        context.set_rsp(0x8028);
+        exceptions.unwind_frame(&mut context, image_base, &read).unwrap();
+        assert_eq!(context, caller);
+        // in the chained fragment, which saved r12 at rsp + 0x10
+        let chained = exceptions.find_function(0x1044).unwrap();
+        let info = exceptions.get_unwind_info(&chained).unwrap();
+        assert_eq!(info.unwind_codes, vec![UnwindCode { code_offset: 0, operation: UnwindOperation::SaveNonVolatile(12, 0x10) }]);
+        assert_eq!(info.chained_function, Some(function));
+        let mut context = X64Context::default();
+        context.rip = image_base + 0x1044;
+        context.set_rsp(0x8000);
+        context.registers[5] = 0x8020;
+        exceptions.unwind_frame(&mut context, image_base, &read).unwrap();
+        assert_eq!(context, X64Context { registers: { let mut registers = caller.registers; registers[12] = 0x12; registers }, ..caller });
+        // a leaf function
+        let mut context = X64Context::default();
+        context.rip = image_base + 0x1080;
+        context.set_rsp(0x8038);
+        context.registers[3] = 0xb;
+        context.registers[5] = 0xbb;
+        exceptions.unwind_frame(&mut context, image_base, &read).unwrap();
+        assert_eq!(context, caller);
+    }
+
+    #[test]
+    fn arm64_packed() {
+        let packed = Arm64RuntimeFunction { begin_address: 0x1000, unwind_data: 0x0400_0031 };
+        assert!(packed.is_packed());
+        assert_eq!(packed.function_length(), Some(0x30));
+        assert_eq!(packed.unwind_info_address(), None);
+        let unpacked = Arm64RuntimeFunction { begin_address: 0x1000, unwind_data: 0x2000 };
+        assert_eq!(unpacked.unwind_info_address(), Some(0x2000));
+        assert_eq!(unpacked.function_length(), None);
+    }
+}
@@ -47,6 +47,11 @@ pub const SIZEOF_COFF_HEADER: usize = 24;
 pub const COFF_MAGIC: u32 = 0x00004550;
 pub const COFF_MACHINE_X86: u16 = 0x14c;
 pub const COFF_MACHINE_X86_64: u16 = 0x8664;
+pub const COFF_MACHINE_ARM: u16 = 0x1c0;
+pub const COFF_MACHINE_THUMB: u16 = 0x1c2;
+/// ARM Thumb-2, i.e., Windows on ARM
+pub const COFF_MACHINE_ARMNT: u16 = 0x1c4;
+pub const COFF_MACHINE_ARM64: u16 = 0xaa64;
 
 impl CoffHeader {
     pub fn parse(bytes: &[u8], offset: &mut usize) -> error::Result<Self> {
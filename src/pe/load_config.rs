@@ -0,0 +1,553 @@
+//! The load configuration directory, which holds the security features of an image the loader
+//! and the runtime check: the `/GS` stack cookie, the SafeSEH handlers, and the Control Flow Guard
+//! (CFG) and CET tables of valid indirect branch targets.
+//!
+//! The directory has grown with each version of the linker; its leading `Size` field tells which
+//! fields are present, and the missing ones are 0.
+
+use core::cmp;
+use scroll::{self, Pread};
+use alloc::vec::Vec;
+
+use error;
+
+use pe::section_table;
+use pe::utils;
+use pe::data_directories;
+
+/// The module performs CFG checks
+pub const IMAGE_GUARD_CF_INSTRUMENTED: u32 = 0x0000_0100;
+/// The module performs CFG and write integrity checks
+pub const IMAGE_GUARD_CFW_INSTRUMENTED: u32 = 0x0000_0200;
+/// The module has a table of valid indirect call targets
+pub const IMAGE_GUARD_CF_FUNCTION_TABLE_PRESENT: u32 = 0x0000_0400;
+/// The module doesn't use the security cookie
+pub const IMAGE_GUARD_SECURITY_COOKIE_UNUSED: u32 = 0x0000_0800;
+/// The module supports read-only delay load IATs
+pub const IMAGE_GUARD_PROTECT_DELAYLOAD_IAT: u32 = 0x0000_1000;
+/// The delay load IAT is in its own section, which can be reprotected
+pub const IMAGE_GUARD_DELAYLOAD_IAT_IN_ITS_OWN_SECTION: u32 = 0x0000_2000;
+/// The module has suppressed exports
+pub const IMAGE_GUARD_CF_EXPORT_SUPPRESSION_INFO_PRESENT: u32 = 0x0000_4000;
+/// The module enables the suppression of exports
+pub const IMAGE_GUARD_CF_ENABLE_EXPORT_SUPPRESSION: u32 = 0x0000_8000;
+/// The module has a table of valid `longjmp` targets
+pub const IMAGE_GUARD_CF_LONGJUMP_TABLE_PRESENT: u32 = 0x0001_0000;
+/// The module has return flow guard instrumentation
+pub const IMAGE_GUARD_RF_INSTRUMENTED: u32 = 0x0002_0000;
+/// The module requests return flow guard to be enabled
+pub const IMAGE_GUARD_RF_ENABLE: u32 = 0x0004_0000;
+/// The module requests strict return flow guard
+pub const IMAGE_GUARD_RF_STRICT: u32 = 0x0008_0000;
+/// The module was built with retpoline support
+pub const IMAGE_GUARD_RETPOLINE_PRESENT: u32 = 0x0010_0000;
+/// The module has a table of valid exception handling continuation targets, for CET shadow stacks
+pub const IMAGE_GUARD_EH_CONTINUATION_TABLE_PRESENT: u32 = 0x0040_0000;
+/// The module was built with eXtended Flow Guard
+pub const IMAGE_GUARD_XFG_ENABLED: u32 = 0x0080_0000;
+/// The module has CastGuard instrumentation
+pub const IMAGE_GUARD_CASTGUARD_PRESENT: u32 = 0x0100_0000;
+/// The module has guarded `memcpy` instrumentation
+pub const IMAGE_GUARD_MEMCPY_PRESENT: u32 = 0x0200_0000;
+/// The number of metadata bytes following each RVA of the guard tables, in the top 4 bits
+pub const IMAGE_GUARD_CF_FUNCTION_TABLE_SIZE_MASK: u32 = 0xf000_0000;
+pub const IMAGE_GUARD_CF_FUNCTION_TABLE_SIZE_SHIFT: u32 = 28;
+
+/// The call target is explicitly suppressed, i.e., not valid
+pub const IMAGE_GUARD_FLAG_FID_SUPPRESSED: u8 = 0x01;
+/// The call target is an export suppressed until it is resolved with `GetProcAddress`
+pub const IMAGE_GUARD_FLAG_EXPORT_SUPPRESSED: u8 = 0x02;
+/// The call target is a language exception handler
+pub const IMAGE_GUARD_FLAG_FID_LANGEXCPTHANDLER: u8 = 0x04;
+/// The call target has an XFG type hash
+pub const IMAGE_GUARD_FLAG_FID_XFG: u8 = 0x08;
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageLoadConfigCodeIntegrity {
+    pub flags: u16,
+    pub catalog: u16,
+    pub catalog_offset: u32,
+    pub reserved: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageLoadConfigDirectory32 {
+    pub size: u32,
+    pub time_date_stamp: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+    pub global_flags_clear: u32,
+    pub global_flags_set: u32,
+    pub critical_section_default_timeout: u32,
+    pub de_commit_free_block_threshold: u32,
+    pub de_commit_total_free_threshold: u32,
+    pub lock_prefix_table: u32,
+    pub maximum_allocation_size: u32,
+    pub virtual_memory_threshold: u32,
+    pub process_heap_flags: u32,
+    pub process_affinity_mask: u32,
+    pub csd_version: u16,
+    pub dependent_load_flags: u16,
+    pub edit_list: u32,
+    pub security_cookie: u32,
+    pub se_handler_table: u32,
+    pub se_handler_count: u32,
+    pub guard_cf_check_function_pointer: u32,
+    pub guard_cf_dispatch_function_pointer: u32,
+    pub guard_cf_function_table: u32,
+    pub guard_cf_function_count: u32,
+    pub guard_flags: u32,
+    pub code_integrity: ImageLoadConfigCodeIntegrity,
+    pub guard_address_taken_iat_entry_table: u32,
+    pub guard_address_taken_iat_entry_count: u32,
+    pub guard_long_jump_target_table: u32,
+    pub guard_long_jump_target_count: u32,
+    pub dynamic_value_reloc_table: u32,
+    pub chpe_metadata_pointer: u32,
+    pub guard_rf_failure_routine: u32,
+    pub guard_rf_failure_routine_function_pointer: u32,
+    pub dynamic_value_reloc_table_offset: u32,
+    pub dynamic_value_reloc_table_section: u16,
+    pub reserved2: u16,
+    pub guard_rf_verify_stack_pointer_function_pointer: u32,
+    pub hot_patch_table_offset: u32,
+    pub reserved3: u32,
+    pub enclave_configuration_pointer: u32,
+    pub volatile_metadata_pointer: u32,
+    pub guard_eh_continuation_table: u32,
+    pub guard_eh_continuation_count: u32,
+    pub guard_xfg_check_function_pointer: u32,
+    pub guard_xfg_dispatch_function_pointer: u32,
+    pub guard_xfg_table_dispatch_function_pointer: u32,
+    pub cast_guard_os_determined_failure_mode: u32,
+    pub guard_memcpy_function_pointer: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageLoadConfigDirectory64 {
+    pub size: u32,
+    pub time_date_stamp: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+    pub global_flags_clear: u32,
+    pub global_flags_set: u32,
+    pub critical_section_default_timeout: u32,
+    pub de_commit_free_block_threshold: u64,
+    pub de_commit_total_free_threshold: u64,
+    pub lock_prefix_table: u64,
+    pub maximum_allocation_size: u64,
+    pub virtual_memory_threshold: u64,
+    pub process_affinity_mask: u64,
+    pub process_heap_flags: u32,
+    pub csd_version: u16,
+    pub dependent_load_flags: u16,
+    pub edit_list: u64,
+    pub security_cookie: u64,
+    pub se_handler_table: u64,
+    pub se_handler_count: u64,
+    pub guard_cf_check_function_pointer: u64,
+    pub guard_cf_dispatch_function_pointer: u64,
+    pub guard_cf_function_table: u64,
+    pub guard_cf_function_count: u64,
+    pub guard_flags: u32,
+    pub code_integrity: ImageLoadConfigCodeIntegrity,
+    pub guard_address_taken_iat_entry_table: u64,
+    pub guard_address_taken_iat_entry_count: u64,
+    pub guard_long_jump_target_table: u64,
+    pub guard_long_jump_target_count: u64,
+    pub dynamic_value_reloc_table: u64,
+    pub chpe_metadata_pointer: u64,
+    pub guard_rf_failure_routine: u64,
+    pub guard_rf_failure_routine_function_pointer: u64,
+    pub dynamic_value_reloc_table_offset: u32,
+    pub dynamic_value_reloc_table_section: u16,
+    pub reserved2: u16,
+    pub guard_rf_verify_stack_pointer_function_pointer: u64,
+    pub hot_patch_table_offset: u32,
+    pub reserved3: u32,
+    pub enclave_configuration_pointer: u64,
+    pub volatile_metadata_pointer: u64,
+    pub guard_eh_continuation_table: u64,
+    pub guard_eh_continuation_count: u64,
+    pub guard_xfg_check_function_pointer: u64,
+    pub guard_xfg_dispatch_function_pointer: u64,
+    pub guard_xfg_table_dispatch_function_pointer: u64,
+    pub cast_guard_os_determined_failure_mode: u64,
+    pub guard_memcpy_function_pointer: u64,
+}
+
+pub const SIZEOF_IMAGE_LOAD_CONFIG_DIRECTORY_32: usize = 192;
+pub const SIZEOF_IMAGE_LOAD_CONFIG_DIRECTORY_64: usize = 320;
+
+/// Unified 32/64-bit load configuration directory
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct ImageLoadConfigDirectory {
+    /// The size of the directory, which grows with the versions of the linker
+    pub size: u32,
+    pub time_date_stamp: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+    pub global_flags_clear: u32,
+    pub global_flags_set: u32,
+    pub critical_section_default_timeout: u32,
+    pub de_commit_free_block_threshold: u64,
+    pub de_commit_total_free_threshold: u64,
+    /// The VA of the list of addresses of `lock` prefixes, for uniprocessor systems
+    pub lock_prefix_table: u64,
+    pub maximum_allocation_size: u64,
+    pub virtual_memory_threshold: u64,
+    pub process_affinity_mask: u64,
+    pub process_heap_flags: u32,
+    pub csd_version: u16,
+    pub dependent_load_flags: u16,
+    pub edit_list: u64,
+    /// The VA of the stack cookie used by `/GS`
+    pub security_cookie: u64,
+    /// The VA of the sorted table of the RVAs of the valid SEH handlers (SafeSEH), on x86 only
+    pub se_handler_table: u64,
+    /// The number of SafeSEH handlers
+    pub se_handler_count: u64,
+    /// The VA of the pointer to the CFG check function
+    pub guard_cf_check_function_pointer: u64,
+    /// The VA of the pointer to the CFG dispatch function
+    pub guard_cf_dispatch_function_pointer: u64,
+    /// The VA of the sorted table of the RVAs of the valid indirect call targets
+    pub guard_cf_function_table: u64,
+    /// The number of valid indirect call targets
+    pub guard_cf_function_count: u64,
+    /// The `IMAGE_GUARD_*` flags
+    pub guard_flags: u32,
+    pub code_integrity: ImageLoadConfigCodeIntegrity,
+    /// The VA of the sorted table of the RVAs of the IAT entries whose address is taken
+    pub guard_address_taken_iat_entry_table: u64,
+    pub guard_address_taken_iat_entry_count: u64,
+    /// The VA of the sorted table of the RVAs of the valid `longjmp` targets
+    pub guard_long_jump_target_table: u64,
+    pub guard_long_jump_target_count: u64,
+    /// The VA of the dynamic value relocation table, superseded by its offset and section
+    pub dynamic_value_reloc_table: u64,
+    pub chpe_metadata_pointer: u64,
+    pub guard_rf_failure_routine: u64,
+    pub guard_rf_failure_routine_function_pointer: u64,
+    /// The offset of the dynamic value relocation table in its section
+    pub dynamic_value_reloc_table_offset: u32,
+    /// The 1-based index of the section of the dynamic value relocation table
+    pub dynamic_value_reloc_table_section: u16,
+    pub reserved2: u16,
+    pub guard_rf_verify_stack_pointer_function_pointer: u64,
+    pub hot_patch_table_offset: u32,
+    pub reserved3: u32,
+    pub enclave_configuration_pointer: u64,
+    pub volatile_metadata_pointer: u64,
+    /// The VA of the sorted table of the RVAs of the valid exception handling continuation targets, for CET shadow stacks
+    pub guard_eh_continuation_table: u64,
+    /// The number of valid exception handling continuation targets
+    pub guard_eh_continuation_count: u64,
+    pub guard_xfg_check_function_pointer: u64,
+    pub guard_xfg_dispatch_function_pointer: u64,
+    pub guard_xfg_table_dispatch_function_pointer: u64,
+    pub cast_guard_os_determined_failure_mode: u64,
+    pub guard_memcpy_function_pointer: u64,
+}
+
+impl From<ImageLoadConfigDirectory32> for ImageLoadConfigDirectory {
+    fn from(directory: ImageLoadConfigDirectory32) -> Self {
+        ImageLoadConfigDirectory {
+            size: directory.size,
+            time_date_stamp: directory.time_date_stamp,
+            major_version: directory.major_version,
+            minor_version: directory.minor_version,
+            global_flags_clear: directory.global_flags_clear,
+            global_flags_set: directory.global_flags_set,
+            critical_section_default_timeout: directory.critical_section_default_timeout,
+            de_commit_free_block_threshold: directory.de_commit_free_block_threshold as u64,
+            de_commit_total_free_threshold: directory.de_commit_total_free_threshold as u64,
+            lock_prefix_table: directory.lock_prefix_table as u64,
+            maximum_allocation_size: directory.maximum_allocation_size as u64,
+            virtual_memory_threshold: directory.virtual_memory_threshold as u64,
+            process_affinity_mask: directory.process_affinity_mask as u64,
+            process_heap_flags: directory.process_heap_flags,
+            csd_version: directory.csd_version,
+            dependent_load_flags: directory.dependent_load_flags,
+            edit_list: directory.edit_list as u64,
+            security_cookie: directory.security_cookie as u64,
+            se_handler_table: directory.se_handler_table as u64,
+            se_handler_count: directory.se_handler_count as u64,
+            guard_cf_check_function_pointer: directory.guard_cf_check_function_pointer as u64,
+            guard_cf_dispatch_function_pointer: directory.guard_cf_dispatch_function_pointer as u64,
+            guard_cf_function_table: directory.guard_cf_function_table as u64,
+            guard_cf_function_count: directory.guard_cf_function_count as u64,
+            guard_flags: directory.guard_flags,
+            code_integrity: directory.code_integrity,
+            guard_address_taken_iat_entry_table: directory.guard_address_taken_iat_entry_table as u64,
+            guard_address_taken_iat_entry_count: directory.guard_address_taken_iat_entry_count as u64,
+            guard_long_jump_target_table: directory.guard_long_jump_target_table as u64,
+            guard_long_jump_target_count: directory.guard_long_jump_target_count as u64,
+            dynamic_value_reloc_table: directory.dynamic_value_reloc_table as u64,
+            chpe_metadata_pointer: directory.chpe_metadata_pointer as u64,
+            guard_rf_failure_routine: directory.guard_rf_failure_routine as u64,
+            guard_rf_failure_routine_function_pointer: directory.guard_rf_failure_routine_function_pointer as u64,
+            dynamic_value_reloc_table_offset: directory.dynamic_value_reloc_table_offset,
+            dynamic_value_reloc_table_section: directory.dynamic_value_reloc_table_section,
+            reserved2: directory.reserved2,
+            guard_rf_verify_stack_pointer_function_pointer: directory.guard_rf_verify_stack_pointer_function_pointer as u64,
+            hot_patch_table_offset: directory.hot_patch_table_offset,
+            reserved3: directory.reserved3,
+            enclave_configuration_pointer: directory.enclave_configuration_pointer as u64,
+            volatile_metadata_pointer: directory.volatile_metadata_pointer as u64,
+            guard_eh_continuation_table: directory.guard_eh_continuation_table as u64,
+            guard_eh_continuation_count: directory.guard_eh_continuation_count as u64,
+            guard_xfg_check_function_pointer: directory.guard_xfg_check_function_pointer as u64,
+            guard_xfg_dispatch_function_pointer: directory.guard_xfg_dispatch_function_pointer as u64,
+            guard_xfg_table_dispatch_function_pointer: directory.guard_xfg_table_dispatch_function_pointer as u64,
+            cast_guard_os_determined_failure_mode: directory.cast_guard_os_determined_failure_mode as u64,
+            guard_memcpy_function_pointer: directory.guard_memcpy_function_pointer as u64,
+        }
+    }
+}
+
+impl From<ImageLoadConfigDirectory64> for ImageLoadConfigDirectory {
+    fn from(directory: ImageLoadConfigDirectory64) -> Self {
+        ImageLoadConfigDirectory {
+            size: directory.size,
+            time_date_stamp: directory.time_date_stamp,
+            major_version: directory.major_version,
+            minor_version: directory.minor_version,
+            global_flags_clear: directory.global_flags_clear,
+            global_flags_set: directory.global_flags_set,
+            critical_section_default_timeout: directory.critical_section_default_timeout,
+            de_commit_free_block_threshold: directory.de_commit_free_block_threshold,
+            de_commit_total_free_threshold: directory.de_commit_total_free_threshold,
+            lock_prefix_table: directory.lock_prefix_table,
+            maximum_allocation_size: directory.maximum_allocation_size,
+            virtual_memory_threshold: directory.virtual_memory_threshold,
+            process_affinity_mask: directory.process_affinity_mask,
+            process_heap_flags: directory.process_heap_flags,
+            csd_version: directory.csd_version,
+            dependent_load_flags: directory.dependent_load_flags,
+            edit_list: directory.edit_list,
+            security_cookie: directory.security_cookie,
+            se_handler_table: directory.se_handler_table,
+            se_handler_count: directory.se_handler_count,
+            guard_cf_check_function_pointer: directory.guard_cf_check_function_pointer,
+            guard_cf_dispatch_function_pointer: directory.guard_cf_dispatch_function_pointer,
+            guard_cf_function_table: directory.guard_cf_function_table,
+            guard_cf_function_count: directory.guard_cf_function_count,
+            guard_flags: directory.guard_flags,
+            code_integrity: directory.code_integrity,
+            guard_address_taken_iat_entry_table: directory.guard_address_taken_iat_entry_table,
+            guard_address_taken_iat_entry_count: directory.guard_address_taken_iat_entry_count,
+            guard_long_jump_target_table: directory.guard_long_jump_target_table,
+            guard_long_jump_target_count: directory.guard_long_jump_target_count,
+            dynamic_value_reloc_table: directory.dynamic_value_reloc_table,
+            chpe_metadata_pointer: directory.chpe_metadata_pointer,
+            guard_rf_failure_routine: directory.guard_rf_failure_routine,
+            guard_rf_failure_routine_function_pointer: directory.guard_rf_failure_routine_function_pointer,
+            dynamic_value_reloc_table_offset: directory.dynamic_value_reloc_table_offset,
+            dynamic_value_reloc_table_section: directory.dynamic_value_reloc_table_section,
+            reserved2: directory.reserved2,
+            guard_rf_verify_stack_pointer_function_pointer: directory.guard_rf_verify_stack_pointer_function_pointer,
+            hot_patch_table_offset: directory.hot_patch_table_offset,
+            reserved3: directory.reserved3,
+            enclave_configuration_pointer: directory.enclave_configuration_pointer,
+            volatile_metadata_pointer: directory.volatile_metadata_pointer,
+            guard_eh_continuation_table: directory.guard_eh_continuation_table,
+            guard_eh_continuation_count: directory.guard_eh_continuation_count,
+            guard_xfg_check_function_pointer: directory.guard_xfg_check_function_pointer,
+            guard_xfg_dispatch_function_pointer: directory.guard_xfg_dispatch_function_pointer,
+            guard_xfg_table_dispatch_function_pointer: directory.guard_xfg_table_dispatch_function_pointer,
+            cast_guard_os_determined_failure_mode: directory.cast_guard_os_determined_failure_mode,
+            guard_memcpy_function_pointer: directory.guard_memcpy_function_pointer,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+/// An entry of a guard table, e.g., a valid indirect call target
+pub struct GuardFunction {
+    /// The RVA of the target
+    pub rva: u32,
+    /// The `IMAGE_GUARD_FLAG_*` flags, from the first metadata byte
+    pub flags: u8,
+}
+
+/// Converts the virtual address `va` of an image at `image_base` to an RVA
+fn va_to_rva(va: u64, image_base: u64) -> error::Result<u32> {
+    match va.checked_sub(image_base) {
+        Some(rva) if rva <= u64::from(::core::u32::MAX) => Ok(rva as u32),
+        _ => Err(error::Error::Malformed(format!("Load config address {:#x} is outside the image at {:#x}", va, image_base))),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+/// The load configuration directory, with its tables
+pub struct LoadConfigData {
+    pub image_load_config_directory: ImageLoadConfigDirectory,
+    /// The RVAs of the SafeSEH handlers
+    pub se_handlers: Vec<u32>,
+    /// The valid indirect call targets of CFG
+    pub guard_cf_functions: Vec<GuardFunction>,
+    /// The IAT entries whose address is taken
+    pub guard_address_taken_iat_entries: Vec<GuardFunction>,
+    /// The valid `longjmp` targets
+    pub guard_long_jump_targets: Vec<GuardFunction>,
+    /// The valid exception handling continuation targets, for CET shadow stacks
+    pub guard_eh_continuations: Vec<GuardFunction>,
+    /// The RVA of the dynamic value relocation table, if any
+    pub dynamic_value_reloc_table_rva: Option<u32>,
+}
+
+impl LoadConfigData {
+    /// Parses the load configuration directory of a PE32 or, if `is_64`, PE32+ image with the
+    /// preferred base address `image_base`
+    pub fn parse(bytes: &[u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32, image_base: u64, is_64: bool) -> error::Result<Self> {
+        let rva = dd.virtual_address as usize;
+        let offset = utils::find_offset(rva, sections, file_alignment).ok_or_else(|| error::Error::Malformed(format!("Cannot map load config rva {:#x} into offset", rva)))?;
+        // the fields past the size of this version are 0
+        let size: u32 = bytes.pread_with(offset, scroll::LE)?;
+        let full_size = if is_64 { SIZEOF_IMAGE_LOAD_CONFIG_DIRECTORY_64 } else { SIZEOF_IMAGE_LOAD_CONFIG_DIRECTORY_32 };
+        let size = cmp::min(size as usize, full_size);
+        let mut directory = [0u8; SIZEOF_IMAGE_LOAD_CONFIG_DIRECTORY_64];
+        match bytes.get(offset..offset.saturating_add(size)) {
+            Some(data) => directory[..size].copy_from_slice(data),
+            None => return Err(error::Error::Malformed(format!("Load config at {:#x} of size {:#x} is out of bounds", offset, size))),
+        }
+        let image_load_config_directory: ImageLoadConfigDirectory = if is_64 {
+            directory.pread_with::<ImageLoadConfigDirectory64>(0, scroll::LE)?.into()
+        } else {
+            directory.pread_with::<ImageLoadConfigDirectory32>(0, scroll::LE)?.into()
+        };
+        let config = &image_load_config_directory;
+
+        let mut se_handlers = Vec::new();
+        if config.se_handler_table != 0 {
+            let rva = va_to_rva(config.se_handler_table, image_base)?;
+            let mut offset = utils::find_offset(rva as usize, sections, file_alignment).ok_or_else(|| error::Error::Malformed(format!("Cannot map SafeSEH handler table rva {:#x} into offset", rva)))?;
+            if config.se_handler_count > (bytes.len() / 4) as u64 {
+                return Err(error::Error::Malformed(format!("Too many SafeSEH handlers: {}", config.se_handler_count)));
+            }
+            for _ in 0..config.se_handler_count {
+                se_handlers.push(bytes.gread_with(&mut offset, scroll::LE)?);
+            }
+        }
+
+        let metadata_size = ((config.guard_flags & IMAGE_GUARD_CF_FUNCTION_TABLE_SIZE_MASK) >> IMAGE_GUARD_CF_FUNCTION_TABLE_SIZE_SHIFT) as usize;
+        let guard_table = |table: u64, count: u64, name: &str| -> error::Result<Vec<GuardFunction>> {
+            let mut functions = Vec::new();
+            if table == 0 {
+                return Ok(functions);
+            }
+            let rva = va_to_rva(table, image_base)?;
+            let mut offset = utils::find_offset(rva as usize, sections, file_alignment).ok_or_else(|| error::Error::Malformed(format!("Cannot map {} rva {:#x} into offset", name, rva)))?;
+            if count > (bytes.len() / (4 + metadata_size)) as u64 {
+                return Err(error::Error::Malformed(format!("Too many entries in the {}: {}", name, count)));
+            }
+            for _ in 0..count {
+                let rva = bytes.gread_with(&mut offset, scroll::LE)?;
+                let flags = if metadata_size > 0 { bytes.pread(offset)? } else { 0 };
+                offset += metadata_size;
+                functions.push(GuardFunction { rva, flags });
+            }
+            Ok(functions)
+        };
+        let guard_cf_functions = guard_table(config.guard_cf_function_table, config.guard_cf_function_count, "CFG function table")?;
+        let guard_address_taken_iat_entries = guard_table(config.guard_address_taken_iat_entry_table, config.guard_address_taken_iat_entry_count, "CFG IAT entry table")?;
+        let guard_long_jump_targets = guard_table(config.guard_long_jump_target_table, config.guard_long_jump_target_count, "CFG longjmp target table")?;
+        let guard_eh_continuations = guard_table(config.guard_eh_continuation_table, config.guard_eh_continuation_count, "EH continuation table")?;
+
+        let dynamic_value_reloc_table_rva = match config.dynamic_value_reloc_table_section {
+            0 if config.dynamic_value_reloc_table == 0 => None,
+            0 => Some(va_to_rva(config.dynamic_value_reloc_table, image_base)?),
+            section => match sections.get(section as usize - 1) {
+                Some(section) => Some(section.virtual_address.wrapping_add(config.dynamic_value_reloc_table_offset)),
+                None => return Err(error::Error::Malformed(format!("Dynamic value relocation table is in section {}, which doesn't exist", section))),
+            },
+        };
+
+        Ok(LoadConfigData {
+            image_load_config_directory,
+            se_handlers,
+            guard_cf_functions,
+            guard_address_taken_iat_entries,
+            guard_long_jump_targets,
+            guard_eh_continuations,
+            dynamic_value_reloc_table_rva,
+        })
+    }
+    /// Whether the image performs Control Flow Guard checks on its indirect calls
+    pub fn is_cfg_instrumented(&self) -> bool {
+        self.image_load_config_directory.guard_flags & IMAGE_GUARD_CF_INSTRUMENTED != 0
+    }
+    /// Whether the image has a SafeSEH handler table; only x86 images use one, the others have
+    /// table-based exception handling
+    pub fn has_safe_seh(&self) -> bool {
+        self.image_load_config_directory.se_handler_table != 0
+    }
+    /// Whether the image has a table of exception handling continuation targets, required by
+    /// strict CET shadow stacks
+    pub fn has_eh_continuation_table(&self) -> bool {
+        self.image_load_config_directory.guard_flags & IMAGE_GUARD_EH_CONTINUATION_TABLE_PRESENT != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    #[test]
+    fn parse_load_config() {
+        // a Windows 8.1 directory at 0x100, with CFG but without the fields from the longjmp table on,
+        // followed by garbage, and the CFG table at 0x300
+        let section = utils::identity_section(0x400);
+        let mut bytes = vec![0xffu8; 0x400];
+        let image_base = 0x1_8000_0000;
+        let directory = ImageLoadConfigDirectory64 {
+            size: 0x94,
+            security_cookie: image_base + 0x3000,
+            guard_cf_check_function_pointer: image_base + 0x2000,
+            guard_cf_function_table: image_base + 0x300,
+            guard_cf_function_count: 2,
+            guard_flags: IMAGE_GUARD_CF_INSTRUMENTED | IMAGE_GUARD_CF_FUNCTION_TABLE_PRESENT | 1 << IMAGE_GUARD_CF_FUNCTION_TABLE_SIZE_SHIFT,
+            ..Default::default()
+        };
+        let mut buffer = [0u8; SIZEOF_IMAGE_LOAD_CONFIG_DIRECTORY_64];
+        buffer.pwrite_with(directory, 0, scroll::LE).unwrap();
+        bytes[0x100..0x194].copy_from_slice(&buffer[..0x94]);
+        bytes[0x300..0x30a].copy_from_slice(&[0x00, 0x10, 0, 0, 0x00, 0x20, 0x10, 0, 0, IMAGE_GUARD_FLAG_EXPORT_SUPPRESSED]);
+        let dd = data_directories::DataDirectory { virtual_address: 0x100, size: 0x94 };
+        let config = LoadConfigData::parse(&bytes, &dd, &[section.clone()], 0x200, image_base, true).unwrap();
+        assert_eq!(config.image_load_config_directory, directory.into());
+        assert!(config.is_cfg_instrumented());
+        assert!(!config.has_safe_seh());
+        assert!(!config.has_eh_continuation_table());
+        assert_eq!(config.guard_cf_functions, vec![GuardFunction { rva: 0x1000, flags: 0 }, GuardFunction { rva: 0x1020, flags: IMAGE_GUARD_FLAG_EXPORT_SUPPRESSED }]);
+        assert_eq!(config.guard_long_jump_targets, vec![]);
+        assert_eq!(config.dynamic_value_reloc_table_rva, None);
+
+        // a PE32 directory of Windows XP, with SafeSEH
+        let mut bytes = vec![0u8; 0x400];
+        let image_base = 0x40_0000;
+        let directory = ImageLoadConfigDirectory32 {
+            size: 0x48,
+            security_cookie: image_base + 0x3000,
+            se_handler_table: image_base + 0x300,
+            se_handler_count: 2,
+            ..Default::default()
+        };
+        bytes.pwrite_with(directory, 0x100, scroll::LE).unwrap();
+        bytes.pwrite_with(0x1000u32, 0x300, scroll::LE).unwrap();
+        bytes.pwrite_with(0x1800u32, 0x304, scroll::LE).unwrap();
+        let config = LoadConfigData::parse(&bytes, &dd, &[section], 0x200, u64::from(image_base), false).unwrap();
+        assert_eq!(config.image_load_config_directory.security_cookie, u64::from(image_base) + 0x3000);
+        assert!(config.has_safe_seh());
+        assert!(!config.is_cfg_instrumented());
+        assert_eq!(config.se_handlers, vec![0x1000, 0x1800]);
+    }
+}
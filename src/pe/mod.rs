@@ -13,6 +13,12 @@ pub mod data_directories;
 pub mod export;
 pub mod import;
 pub mod debug;
+pub mod resource;
+pub mod relocation;
+pub mod exception;
+pub mod tls;
+pub mod load_config;
+pub mod symbol;
 mod utils;
 
 use error;
@@ -51,11 +57,25 @@ pub struct PE<'a> {
     /// The list of libraries which this binary imports symbols from
     pub libraries: Vec<&'a str>,
     /// Debug information, if any, contained in the PE header
-    pub debug_data: Option<debug::DebugData<'a>>
+    pub debug_data: Option<debug::DebugData<'a>>,
+    /// The resources, if any, e.g., the version information and the manifest
+    pub resource_data: Option<resource::ResourceData<'a>>,
+    /// The base relocations, applied when the image isn't loaded at its image base
+    pub relocation_data: Option<relocation::RelocationData<'a>>,
+    /// The function table used to unwind the stack, on x86-64 and ARM64
+    pub exception_data: Option<exception::ExceptionData<'a>>,
+    /// The thread-local storage template and the TLS callbacks, if any
+    pub tls_data: Option<tls::TlsData<'a>>,
+    /// The load configuration, with the security cookie, the SafeSEH handlers and the CFG tables, if any
+    pub load_config_data: Option<load_config::LoadConfigData>,
 }
 
 impl<'a> PE<'a> {
-    /// Reads a PE binary from the underlying `bytes`
+    /// Reads a PE binary from the underlying `bytes`.
+    ///
+    /// The headers, sections, imports and debug directory must be well formed. The other data
+    /// directories are best effort: one which fails to parse is left out, and the error is logged
+    /// with `debug!`, so that a malformed optional directory doesn't hide the rest of the binary.
     pub fn parse(bytes: &'a [u8]) -> error::Result<Self> {
         let header = header::Header::parse(bytes)?;
         debug!("{:#?}", header);
@@ -79,6 +99,11 @@ impl<'a> PE<'a> {
         let mut import_data = None;
         let mut libraries = vec![];
         let mut debug_data = None;
+        let mut resource_data = None;
+        let mut relocation_data = None;
+        let mut exception_data = None;
+        let mut tls_data = None;
+        let mut load_config_data = None;
         let mut is_64 = false;
         if let Some(optional_header) = header.optional_header {
             entry = optional_header.standard_fields.address_of_entry_point as usize;
@@ -87,12 +112,15 @@ impl<'a> PE<'a> {
             debug!("entry {:#x} image_base {:#x} is_64: {}", entry, image_base, is_64);
             let file_alignment = optional_header.windows_fields.file_alignment;
             if let &Some(export_table) = optional_header.data_directories.get_export_table() {
-                if let Ok(ed) = export::ExportData::parse(bytes, &export_table, &sections, file_alignment) {
-                    debug!("export data {:#?}", ed);
-                    exports = export::Export::parse(bytes, &ed, &sections, file_alignment)?;
-                    name = ed.name;
-                    debug!("name: {:#?}", name);
-                    export_data = Some(ed);
+                match export::ExportData::parse(bytes, &export_table, &sections, file_alignment) {
+                    Ok(ed) => {
+                        debug!("export data {:#?}", ed);
+                        exports = export::Export::parse(bytes, &ed, &sections, file_alignment)?;
+                        name = ed.name;
+                        debug!("name: {:#?}", name);
+                        export_data = Some(ed);
+                    },
+                    Err(err) => debug!("skipping the malformed export directory: {}", err),
                 }
             }
             debug!("exports: {:#?}", exports);
@@ -117,6 +145,48 @@ impl<'a> PE<'a> {
             if let &Some(debug_table) = optional_header.data_directories.get_debug_table() {
                 debug_data = Some(debug::DebugData::parse(bytes, &debug_table, &sections, file_alignment)?);
             }
+            if let &Some(resource_table) = optional_header.data_directories.get_resource_table() {
+                match resource::ResourceData::parse(bytes, &resource_table, &sections, file_alignment) {
+                    Ok(rd) => {
+                        debug!("resource data {:#?}", rd);
+                        resource_data = Some(rd);
+                    },
+                    Err(err) => debug!("skipping the malformed resource directory: {}", err),
+                }
+            }
+            if let &Some(relocation_table) = optional_header.data_directories.get_base_relocation_table() {
+                match relocation::RelocationData::parse(bytes, &relocation_table, &sections, file_alignment) {
+                    Ok(rd) => relocation_data = Some(rd),
+                    Err(err) => debug!("skipping the malformed base relocation directory: {}", err),
+                }
+            }
+            if let &Some(exception_table) = optional_header.data_directories.get_exception_table() {
+                match exception::ExceptionData::parse(bytes, &exception_table, &sections, file_alignment, header.coff_header.machine) {
+                    Ok(ed) => {
+                        debug!("exception data {:#?}", ed);
+                        exception_data = Some(ed);
+                    },
+                    Err(err) => debug!("skipping the malformed exception directory: {}", err),
+                }
+            }
+            if let &Some(tls_table) = optional_header.data_directories.get_tls_table() {
+                match tls::TlsData::parse(bytes, &tls_table, &sections, file_alignment, optional_header.windows_fields.image_base, is_64) {
+                    Ok(td) => {
+                        debug!("tls data {:#?}", td);
+                        tls_data = Some(td);
+                    },
+                    Err(err) => debug!("skipping the malformed TLS directory: {}", err),
+                }
+            }
+            if let &Some(load_config_table) = optional_header.data_directories.get_load_config_table() {
+                match load_config::LoadConfigData::parse(bytes, &load_config_table, &sections, file_alignment, optional_header.windows_fields.image_base, is_64) {
+                    Ok(lcd) => {
+                        debug!("load config data {:#?}", lcd);
+                        load_config_data = Some(lcd);
+                    },
+                    Err(err) => debug!("skipping the malformed load configuration directory: {}", err),
+                }
+            }
         }
         Ok( PE {
             header: header,
@@ -133,6 +203,11 @@ impl<'a> PE<'a> {
             imports: imports,
             libraries: libraries,
             debug_data: debug_data,
+            resource_data: resource_data,
+            relocation_data: relocation_data,
+            exception_data: exception_data,
+            tls_data: tls_data,
+            load_config_data: load_config_data,
         })
     }
 }
@@ -0,0 +1,340 @@
+//! The base relocations (`.reloc`), which the loader applies when it maps an image at an address
+//! other than its preferred image base.
+//!
+//! The relocations are grouped in blocks, one per 4K page: an `IMAGE_BASE_RELOCATION` header with
+//! the RVA of the page and the size of the block, followed by 16-bit entries, each with the type
+//! of the relocation in its top 4 bits and the offset in the page in its low 12 bits.
+
+use scroll::{self, Pread, Pwrite};
+use alloc::vec::Vec;
+
+use error;
+
+use pe::section_table;
+use pe::utils;
+use pe::data_directories;
+use pe::header::{COFF_MACHINE_ARM, COFF_MACHINE_THUMB, COFF_MACHINE_ARMNT};
+
+/// Padding, to align a block to 32 bits
+pub const IMAGE_REL_BASED_ABSOLUTE: u16 = 0;
+/// Adds the high 16 bits of the delta to the 16-bit word
+pub const IMAGE_REL_BASED_HIGH: u16 = 1;
+/// Adds the low 16 bits of the delta to the 16-bit word
+pub const IMAGE_REL_BASED_LOW: u16 = 2;
+/// Adds the delta to the 32-bit word
+pub const IMAGE_REL_BASED_HIGHLOW: u16 = 3;
+/// Adds the high 16 bits of the delta to the 16-bit word, rounding with the low 16 bits in the next entry
+pub const IMAGE_REL_BASED_HIGHADJ: u16 = 4;
+/// Adds the delta to the 32-bit value of an ARM `movw`/`movt` pair
+pub const IMAGE_REL_BASED_ARM_MOV32: u16 = 5;
+/// Adds the delta to the 32-bit value of a Thumb-2 `movw`/`movt` pair
+pub const IMAGE_REL_BASED_THUMB_MOV32: u16 = 7;
+/// Adds the delta to the 64-bit word
+pub const IMAGE_REL_BASED_DIR64: u16 = 10;
+
+/// Returns the name of the base relocation type `typ` of an image for `machine`
+pub fn type_to_str(typ: u16, machine: u16) -> &'static str {
+    let arm = machine == COFF_MACHINE_ARM || machine == COFF_MACHINE_THUMB || machine == COFF_MACHINE_ARMNT;
+    match typ {
+        IMAGE_REL_BASED_ABSOLUTE => "IMAGE_REL_BASED_ABSOLUTE",
+        IMAGE_REL_BASED_HIGH => "IMAGE_REL_BASED_HIGH",
+        IMAGE_REL_BASED_LOW => "IMAGE_REL_BASED_LOW",
+        IMAGE_REL_BASED_HIGHLOW => "IMAGE_REL_BASED_HIGHLOW",
+        IMAGE_REL_BASED_HIGHADJ => "IMAGE_REL_BASED_HIGHADJ",
+        IMAGE_REL_BASED_ARM_MOV32 if arm => "IMAGE_REL_BASED_ARM_MOV32",
+        IMAGE_REL_BASED_THUMB_MOV32 if arm => "IMAGE_REL_BASED_THUMB_MOV32",
+        IMAGE_REL_BASED_DIR64 => "IMAGE_REL_BASED_DIR64",
+        _ => "IMAGE_REL_BASED_UNKNOWN",
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageBaseRelocation {
+    /// The RVA of the page the block relocates
+    pub virtual_address: u32,
+    /// The size of the block, including this header
+    pub size_of_block: u32,
+}
+
+pub const SIZEOF_IMAGE_BASE_RELOCATION: usize = 8;
+
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+/// A base relocation
+pub struct BaseRelocation {
+    /// The type, e.g., `IMAGE_REL_BASED_DIR64`
+    pub typ: u16,
+    /// The RVA of the relocated word
+    pub rva: u32,
+    /// For `IMAGE_REL_BASED_HIGHADJ`, the low 16 bits of the 32-bit value, from the next entry
+    pub adjustment: u16,
+}
+
+impl BaseRelocation {
+    /// The number of bytes this relocation patches in an image of `machine`, or `None` if its type is unsupported
+    pub fn size(&self, machine: u16) -> Option<usize> {
+        let arm = machine == COFF_MACHINE_ARM || machine == COFF_MACHINE_THUMB || machine == COFF_MACHINE_ARMNT;
+        match self.typ {
+            IMAGE_REL_BASED_ABSOLUTE => Some(0),
+            IMAGE_REL_BASED_HIGH | IMAGE_REL_BASED_LOW | IMAGE_REL_BASED_HIGHADJ => Some(2),
+            IMAGE_REL_BASED_HIGHLOW => Some(4),
+            IMAGE_REL_BASED_DIR64 => Some(8),
+            IMAGE_REL_BASED_ARM_MOV32 | IMAGE_REL_BASED_THUMB_MOV32 if arm => Some(8),
+            _ => None,
+        }
+    }
+    /// Adds `delta` to the word this relocation patches, at `offset` of `bytes`, e.g., its RVA in an
+    /// image of `machine` indexed by RVA
+    pub fn apply(&self, bytes: &mut [u8], offset: usize, delta: u64, machine: u16) -> error::Result<()> {
+        let le = scroll::LE;
+        if self.size(machine).is_none() {
+            return Err(error::Error::Malformed(format!("Unsupported base relocation type {} at {:#x}", self.typ, self.rva)));
+        }
+        match self.typ {
+            IMAGE_REL_BASED_HIGH => {
+                let value = u32::from(bytes.pread_with::<u16>(offset, le)?) << 16;
+                bytes.pwrite_with((value.wrapping_add(delta as u32) >> 16) as u16, offset, le)?;
+            },
+            IMAGE_REL_BASED_LOW => {
+                let value: u16 = bytes.pread_with(offset, le)?;
+                bytes.pwrite_with(value.wrapping_add(delta as u16), offset, le)?;
+            },
+            IMAGE_REL_BASED_HIGHLOW => {
+                let value: u32 = bytes.pread_with(offset, le)?;
+                bytes.pwrite_with(value.wrapping_add(delta as u32), offset, le)?;
+            },
+            IMAGE_REL_BASED_HIGHADJ => {
+                let value = (u32::from(bytes.pread_with::<u16>(offset, le)?) << 16).wrapping_add(self.adjustment as i16 as u32);
+                let value = value.wrapping_add(delta as u32).wrapping_add(0x8000);
+                bytes.pwrite_with((value >> 16) as u16, offset, le)?;
+            },
+            IMAGE_REL_BASED_DIR64 => {
+                let value: u64 = bytes.pread_with(offset, le)?;
+                bytes.pwrite_with(value.wrapping_add(delta), offset, le)?;
+            },
+            IMAGE_REL_BASED_ARM_MOV32 => {
+                let (movw, movt): (u32, u32) = (bytes.pread_with(offset, le)?, bytes.pread_with(offset + 4, le)?);
+                let value = u32::from(arm_mov_immediate(movw)) | u32::from(arm_mov_immediate(movt)) << 16;
+                let value = value.wrapping_add(delta as u32);
+                bytes.pwrite_with(arm_set_mov_immediate(movw, value as u16), offset, le)?;
+                bytes.pwrite_with(arm_set_mov_immediate(movt, (value >> 16) as u16), offset + 4, le)?;
+            },
+            IMAGE_REL_BASED_THUMB_MOV32 => {
+                let mut halfwords = [0u16; 4];
+                for (i, halfword) in halfwords.iter_mut().enumerate() {
+                    *halfword = bytes.pread_with(offset + i * 2, le)?;
+                }
+                let value = u32::from(thumb_mov_immediate(halfwords[0], halfwords[1])) | u32::from(thumb_mov_immediate(halfwords[2], halfwords[3])) << 16;
+                let value = value.wrapping_add(delta as u32);
+                let (first, second) = thumb_set_mov_immediate(halfwords[0], halfwords[1], value as u16);
+                let (third, fourth) = thumb_set_mov_immediate(halfwords[2], halfwords[3], (value >> 16) as u16);
+                for (i, halfword) in [first, second, third, fourth].iter().enumerate() {
+                    bytes.pwrite_with(*halfword, offset + i * 2, le)?;
+                }
+            },
+            // padding
+            _ => (),
+        }
+        Ok(())
+    }
+}
+
+/// Iterates over the relocations of a block
+pub struct BaseRelocationIterator<'a> {
+    page: u32,
+    entries: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for BaseRelocationIterator<'a> {
+    type Item = BaseRelocation;
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry: u16 = match self.entries.gread_with(&mut self.offset, scroll::LE) {
+            Ok(entry) => entry,
+            Err(_) => return None,
+        };
+        let typ = entry >> 12;
+        let adjustment = if typ == IMAGE_REL_BASED_HIGHADJ {
+            self.entries.gread_with(&mut self.offset, scroll::LE).unwrap_or(0)
+        } else {
+            0
+        };
+        Some(BaseRelocation { typ, rva: self.page.wrapping_add(u32::from(entry & 0xfff)), adjustment })
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+/// A block of base relocations, for a page
+pub struct BaseRelocationBlock<'a> {
+    pub header: ImageBaseRelocation,
+    /// The 16-bit entries following the header
+    pub entries: &'a [u8],
+}
+
+impl<'a> BaseRelocationBlock<'a> {
+    /// Returns an iterator over the relocations of this block, including the `IMAGE_REL_BASED_ABSOLUTE` padding
+    pub fn relocations(&self) -> BaseRelocationIterator<'a> {
+        BaseRelocationIterator { page: self.header.virtual_address, entries: self.entries, offset: 0 }
+    }
+}
+
+/// Iterates over the blocks of the base relocation directory
+pub struct BaseRelocationBlockIterator<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for BaseRelocationBlockIterator<'a> {
+    type Item = error::Result<BaseRelocationBlock<'a>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset + SIZEOF_IMAGE_BASE_RELOCATION > self.bytes.len() {
+            return None;
+        }
+        let header: ImageBaseRelocation = match self.bytes.pread_with(self.offset, scroll::LE) {
+            Ok(header) => header,
+            Err(e) => return Some(Err(e.into())),
+        };
+        let size = header.size_of_block as usize;
+        let start = self.offset + SIZEOF_IMAGE_BASE_RELOCATION;
+        let end = self.offset.saturating_add(size);
+        if size < SIZEOF_IMAGE_BASE_RELOCATION || end > self.bytes.len() {
+            // stop at a malformed block
+            self.offset = self.bytes.len();
+            return Some(Err(error::Error::Malformed(format!("Base relocation block at {:#x} has invalid size {:#x}", header.virtual_address, size))));
+        }
+        self.offset = end;
+        Some(Ok(BaseRelocationBlock { header, entries: &self.bytes[start..end] }))
+    }
+}
+
+/// Returns the immediate of an ARM `movw`/`movt` instruction
+fn arm_mov_immediate(instruction: u32) -> u16 {
+    (((instruction >> 4) & 0xf000) | (instruction & 0xfff)) as u16
+}
+
+/// Replaces the immediate of an ARM `movw`/`movt` instruction with `immediate`
+fn arm_set_mov_immediate(instruction: u32, immediate: u16) -> u32 {
+    let immediate = u32::from(immediate);
+    (instruction & !0x000f_0fff) | ((immediate & 0xf000) << 4) | (immediate & 0xfff)
+}
+
+/// Returns the immediate of a Thumb-2 `movw`/`movt` instruction, with its halfwords in order
+fn thumb_mov_immediate(first: u16, second: u16) -> u16 {
+    ((first & 0xf) << 12) | (((first >> 10) & 1) << 11) | (((second >> 12) & 0x7) << 8) | (second & 0xff)
+}
+
+/// Replaces the immediate of a Thumb-2 `movw`/`movt` instruction with `immediate`
+fn thumb_set_mov_immediate(first: u16, second: u16, immediate: u16) -> (u16, u16) {
+    let first = (first & !0x040f) | (immediate >> 12) | (((immediate >> 11) & 1) << 10);
+    let second = (second & !0x70ff) | (((immediate >> 8) & 0x7) << 12) | (immediate & 0xff);
+    (first, second)
+}
+
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+/// The base relocation directory
+pub struct RelocationData<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> RelocationData<'a> {
+    pub fn parse(bytes: &'a [u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32) -> error::Result<Self> {
+        let rva = dd.virtual_address as usize;
+        let offset = utils::find_offset(rva, sections, file_alignment).ok_or_else(|| error::Error::Malformed(format!("Cannot map base relocation table rva {:#x} into offset", rva)))?;
+        match bytes.get(offset..offset.saturating_add(dd.size as usize)) {
+            Some(bytes) => Ok(RelocationData { bytes }),
+            None => Err(error::Error::Malformed(format!("Base relocation table at {:#x} of size {:#x} is out of bounds", offset, dd.size))),
+        }
+    }
+    /// Creates the base relocation directory with the contents `bytes`, e.g., read from a mapped image
+    pub fn new(bytes: &'a [u8]) -> Self {
+        RelocationData { bytes }
+    }
+    /// Returns an iterator over the blocks of relocations
+    pub fn blocks(&self) -> BaseRelocationBlockIterator<'a> {
+        BaseRelocationBlockIterator { bytes: self.bytes, offset: 0 }
+    }
+    /// Returns the relocations of all blocks, without the `IMAGE_REL_BASED_ABSOLUTE` padding
+    pub fn relocations(&self) -> error::Result<Vec<BaseRelocation>> {
+        let mut relocations = Vec::new();
+        for block in self.blocks() {
+            relocations.extend(block?.relocations().filter(|relocation| relocation.typ != IMAGE_REL_BASED_ABSOLUTE));
+        }
+        Ok(relocations)
+    }
+    /// Applies the relocations to `image`, a copy of an image of `machine` mapped at its image base
+    /// (i.e., indexed by RVA), to move it to `new_base`.
+    ///
+    /// All the blocks are checked before any relocation is applied, so `image` is left unchanged
+    /// if one of them is malformed, of an unsupported type or out of bounds.
+    pub fn rebase(&self, image: &mut [u8], image_base: u64, new_base: u64, machine: u16) -> error::Result<()> {
+        let delta = new_base.wrapping_sub(image_base);
+        let mut relocations = Vec::new();
+        for block in self.blocks() {
+            for relocation in block?.relocations() {
+                let size = relocation.size(machine)
+                    .ok_or_else(|| error::Error::Malformed(format!("Unsupported base relocation type {} at {:#x}", relocation.typ, relocation.rva)))?;
+                let rva = relocation.rva as usize;
+                if rva.checked_add(size).map_or(true, |end| end > image.len()) {
+                    return Err(error::Error::Malformed(format!("Base relocation at {:#x} is outside the image of size {:#x}", rva, image.len())));
+                }
+                relocations.push(relocation);
+            }
+        }
+        for relocation in relocations {
+            relocation.apply(image, relocation.rva as usize, delta, machine)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pe::header::COFF_MACHINE_X86_64;
+
+    #[test]
+    fn rebase() {
+        // a block for page 0x1000 with a DIR64, a HIGHLOW and an ABSOLUTE padding entry
+        let mut directory = vec![0u8; 16];
+        directory.pwrite_with(ImageBaseRelocation { virtual_address: 0x1000, size_of_block: 14 }, 0, scroll::LE).unwrap();
+        directory.pwrite_with(0xa010u16, 8, scroll::LE).unwrap();
+        directory.pwrite_with(0x3020u16, 10, scroll::LE).unwrap();
+        let data = RelocationData::new(&directory);
+        assert_eq!(data.relocations().unwrap(), vec![
+            BaseRelocation { typ: IMAGE_REL_BASED_DIR64, rva: 0x1010, adjustment: 0 },
+            BaseRelocation { typ: IMAGE_REL_BASED_HIGHLOW, rva: 0x1020, adjustment: 0 },
+        ]);
+        let mut image = vec![0u8; 0x1100];
+        image.pwrite_with(0x1_4000_2000u64, 0x1010, scroll::LE).unwrap();
+        image.pwrite_with(0x4000_3000u32, 0x1020, scroll::LE).unwrap();
+        data.rebase(&mut image, 0x1_4000_0000, 0x1_8000_0000, COFF_MACHINE_X86_64).unwrap();
+        assert_eq!(image.pread_with::<u64>(0x1010, scroll::LE).unwrap(), 0x1_8000_2000);
+        assert_eq!(image.pread_with::<u32>(0x1020, scroll::LE).unwrap(), 0x8000_3000);
+        // a relocation past the end of the image, after valid ones, leaves the image unchanged
+        let mut small = image[..0x1024].to_vec();
+        directory.pwrite_with(0xa020u16, 10, scroll::LE).unwrap();
+        assert!(RelocationData::new(&directory).rebase(&mut small, 0x1_8000_0000, 0x1_4000_0000, COFF_MACHINE_X86_64).is_err());
+        assert_eq!(&small[..], &image[..0x1024]);
+        // as does an unsupported type
+        directory.pwrite_with(0x5020u16, 10, scroll::LE).unwrap();
+        assert!(RelocationData::new(&directory).rebase(&mut image, 0x1_8000_0000, 0x1_4000_0000, COFF_MACHINE_X86_64).is_err());
+        assert_eq!(image.pread_with::<u64>(0x1010, scroll::LE).unwrap(), 0x1_8000_2000);
+        // the block claims more bytes than the directory has
+        directory.pwrite_with(0x20u32, 4, scroll::LE).unwrap();
+        assert!(RelocationData::new(&directory).relocations().is_err());
+    }
+
+    #[test]
+    fn mov32() {
+        // movw r0, #0x1234; movt r0, #0x5678
+        let (movw, movt) = (0xe301_0234, 0xe345_0678);
+        assert_eq!(arm_mov_immediate(movw), 0x1234);
+        assert_eq!(arm_mov_immediate(movt), 0x5678);
+        assert_eq!(arm_set_mov_immediate(movw, 0xabcd), 0xe30a_0bcd);
+        // movw r0, #0x1234; movt r0, #0x5678 in Thumb-2
+        assert_eq!(thumb_mov_immediate(0xf241, 0x2034), 0x1234);
+        assert_eq!(thumb_mov_immediate(0xf2c5, 0x6078), 0x5678);
+        assert_eq!(thumb_set_mov_immediate(0xf241, 0x2034, 0xabcd), (0xf64a, 0x30cd));
+    }
+}
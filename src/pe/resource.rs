@@ -0,0 +1,699 @@
+//! The resource directory (`.rsrc`), and decoders for the common resources: the version
+//! information, the manifest and the icons.
+//!
+//! The resources are a three level tree of directories, by type (e.g., `RT_ICON`), by name, and by
+//! language; each entry of a directory is identified either by an integer ID or by a UTF-16 name.
+//! The leaves are data entries, which point to the resource bytes by RVA.
+
+use core::cmp;
+use scroll::{self, Pread, Pwrite};
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use error;
+
+use pe::section_table;
+use pe::utils;
+use pe::data_directories;
+
+pub const RT_CURSOR: u16 = 1;
+pub const RT_BITMAP: u16 = 2;
+pub const RT_ICON: u16 = 3;
+pub const RT_MENU: u16 = 4;
+pub const RT_DIALOG: u16 = 5;
+pub const RT_STRING: u16 = 6;
+pub const RT_FONTDIR: u16 = 7;
+pub const RT_FONT: u16 = 8;
+pub const RT_ACCELERATOR: u16 = 9;
+pub const RT_RCDATA: u16 = 10;
+pub const RT_MESSAGETABLE: u16 = 11;
+pub const RT_GROUP_CURSOR: u16 = 12;
+pub const RT_GROUP_ICON: u16 = 14;
+pub const RT_VERSION: u16 = 16;
+pub const RT_DLGINCLUDE: u16 = 17;
+pub const RT_PLUGPLAY: u16 = 19;
+pub const RT_VXD: u16 = 20;
+pub const RT_ANICURSOR: u16 = 21;
+pub const RT_ANIICON: u16 = 22;
+pub const RT_HTML: u16 = 23;
+pub const RT_MANIFEST: u16 = 24;
+
+/// Returns the name of the resource type `id`
+pub fn type_to_str(id: u16) -> &'static str {
+    match id {
+        RT_CURSOR => "RT_CURSOR",
+        RT_BITMAP => "RT_BITMAP",
+        RT_ICON => "RT_ICON",
+        RT_MENU => "RT_MENU",
+        RT_DIALOG => "RT_DIALOG",
+        RT_STRING => "RT_STRING",
+        RT_FONTDIR => "RT_FONTDIR",
+        RT_FONT => "RT_FONT",
+        RT_ACCELERATOR => "RT_ACCELERATOR",
+        RT_RCDATA => "RT_RCDATA",
+        RT_MESSAGETABLE => "RT_MESSAGETABLE",
+        RT_GROUP_CURSOR => "RT_GROUP_CURSOR",
+        RT_GROUP_ICON => "RT_GROUP_ICON",
+        RT_VERSION => "RT_VERSION",
+        RT_DLGINCLUDE => "RT_DLGINCLUDE",
+        RT_PLUGPLAY => "RT_PLUGPLAY",
+        RT_VXD => "RT_VXD",
+        RT_ANICURSOR => "RT_ANICURSOR",
+        RT_ANIICON => "RT_ANIICON",
+        RT_HTML => "RT_HTML",
+        RT_MANIFEST => "RT_MANIFEST",
+        _ => "UNKNOWN_RT",
+    }
+}
+
+/// The high bit of a directory entry's name, set if it is an offset to a name instead of an ID,
+/// and of its offset, set if it is an offset to a subdirectory instead of a data entry
+pub const IMAGE_RESOURCE_HIGH_BIT: u32 = 0x8000_0000;
+
+/// The maximum number of data entries of a resource directory, bounding the work on malformed ones
+const MAX_ENTRIES: usize = 0x10000;
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageResourceDirectory {
+    pub characteristics: u32,
+    pub time_date_stamp: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+    pub number_of_named_entries: u16,
+    pub number_of_id_entries: u16,
+}
+
+pub const SIZEOF_IMAGE_RESOURCE_DIRECTORY: usize = 16;
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageResourceDirectoryEntry {
+    pub name_or_id: u32,
+    pub offset_to_data: u32,
+}
+
+pub const SIZEOF_IMAGE_RESOURCE_DIRECTORY_ENTRY: usize = 8;
+
+impl ImageResourceDirectoryEntry {
+    /// Whether the entry is identified by a name instead of an ID
+    pub fn is_named(&self) -> bool {
+        self.name_or_id & IMAGE_RESOURCE_HIGH_BIT != 0
+    }
+    /// The ID of the entry, if it isn't named
+    pub fn id(&self) -> Option<u16> {
+        if self.is_named() { None } else { Some(self.name_or_id as u16) }
+    }
+    /// Whether the entry points to a subdirectory instead of a data entry
+    pub fn is_directory(&self) -> bool {
+        self.offset_to_data & IMAGE_RESOURCE_HIGH_BIT != 0
+    }
+    /// The offset of the subdirectory or data entry, relative to the start of the resource directory
+    pub fn offset(&self) -> u32 {
+        self.offset_to_data & !IMAGE_RESOURCE_HIGH_BIT
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageResourceDataEntry {
+    /// The RVA of the resource data
+    pub offset_to_data: u32,
+    pub size: u32,
+    pub code_page: u32,
+    pub reserved: u32,
+}
+
+pub const SIZEOF_IMAGE_RESOURCE_DATA_ENTRY: usize = 16;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// The identifier of a resource directory entry
+pub enum ResourceId {
+    Id(u16),
+    Name(String),
+}
+
+impl ResourceId {
+    /// The ID, if this isn't a name
+    pub fn id(&self) -> Option<u16> {
+        match *self {
+            ResourceId::Id(id) => Some(id),
+            ResourceId::Name(_) => None,
+        }
+    }
+}
+
+/// Decodes the UTF-16 `bytes`, up to the first NUL
+fn decode_utf16(bytes: &[u8]) -> String {
+    let units: Vec<u16> = bytes.chunks(2)
+        .filter(|unit| unit.len() == 2)
+        .map(|unit| u16::from(unit[0]) | u16::from(unit[1]) << 8)
+        .take_while(|&unit| unit != 0)
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// A resource: a leaf of the resource directory
+pub struct ResourceEntry<'a> {
+    /// The type, e.g., `RT_VERSION`
+    pub type_id: ResourceId,
+    /// The name
+    pub name: ResourceId,
+    /// The language, e.g., `0x409` for US English
+    pub language: ResourceId,
+    pub data_entry: ImageResourceDataEntry,
+    /// The resource bytes
+    pub data: &'a [u8],
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+/// The resource directory, with its resources flattened in the order of the tree
+pub struct ResourceData<'a> {
+    pub image_resource_directory: ImageResourceDirectory,
+    pub entries: Vec<ResourceEntry<'a>>,
+}
+
+/// Walks the resource directory tree, collecting its data entries
+struct DirectoryWalker<'a, 's> {
+    bytes: &'a [u8],
+    /// The file offset of the resource directory, which the offsets in it are relative to
+    base: usize,
+    sections: &'s [section_table::SectionTable],
+    file_alignment: u32,
+    /// The offsets of the directories seen so far, to reject cycles
+    visited: BTreeSet<u32>,
+    /// The IDs of the type and name directories of the current directory
+    path: Vec<ResourceId>,
+    entries: Vec<ResourceEntry<'a>>,
+}
+
+impl<'a, 's> DirectoryWalker<'a, 's> {
+    fn parse_name(&self, entry: &ImageResourceDirectoryEntry) -> error::Result<ResourceId> {
+        if !entry.is_named() {
+            return Ok(ResourceId::Id(entry.name_or_id as u16));
+        }
+        // a length prefixed UTF-16 string
+        let offset = self.base + (entry.name_or_id & !IMAGE_RESOURCE_HIGH_BIT) as usize;
+        let length = self.bytes.pread_with::<u16>(offset, scroll::LE)? as usize;
+        match self.bytes.get(offset + 2..offset + 2 + length * 2) {
+            Some(name) => Ok(ResourceId::Name(decode_utf16(name))),
+            None => Err(error::Error::Malformed(format!("Resource name at {:#x} with length {} is out of bounds", offset, length))),
+        }
+    }
+    fn parse_data(&self, entry: &ImageResourceDirectoryEntry) -> error::Result<(ImageResourceDataEntry, &'a [u8])> {
+        let data_entry: ImageResourceDataEntry = self.bytes.pread_with(self.base + entry.offset() as usize, scroll::LE)?;
+        let rva = data_entry.offset_to_data as usize;
+        let data = utils::find_offset(rva, self.sections, self.file_alignment)
+            .and_then(|offset| self.bytes.get(offset..offset + data_entry.size as usize))
+            .ok_or_else(|| error::Error::Malformed(format!("Cannot map resource data rva {:#x} of size {:#x} into offset", rva, data_entry.size)))?;
+        Ok((data_entry, data))
+    }
+    fn parse_directory(&mut self, offset: u32) -> error::Result<()> {
+        if !self.visited.insert(offset) {
+            return Err(error::Error::Malformed(format!("Resource directory at {:#x} is referenced twice", offset)));
+        }
+        let directory_offset = self.base + offset as usize;
+        let directory: ImageResourceDirectory = self.bytes.pread_with(directory_offset, scroll::LE)?;
+        let count = directory.number_of_named_entries as usize + directory.number_of_id_entries as usize;
+        for i in 0..count {
+            let entry_offset = directory_offset + SIZEOF_IMAGE_RESOURCE_DIRECTORY + i * SIZEOF_IMAGE_RESOURCE_DIRECTORY_ENTRY;
+            let entry: ImageResourceDirectoryEntry = self.bytes.pread_with(entry_offset, scroll::LE)?;
+            let id = self.parse_name(&entry)?;
+            match (entry.is_directory(), self.path.len()) {
+                // the type and name levels
+                (true, 0) | (true, 1) => {
+                    self.path.push(id);
+                    self.parse_directory(entry.offset())?;
+                    self.path.pop();
+                },
+                // the language level
+                (false, 2) => {
+                    if self.entries.len() >= MAX_ENTRIES {
+                        return Err(error::Error::Malformed(format!("Resource directory has more than {} entries", MAX_ENTRIES)));
+                    }
+                    let (data_entry, data) = self.parse_data(&entry)?;
+                    self.entries.push(ResourceEntry {
+                        type_id: self.path[0].clone(),
+                        name: self.path[1].clone(),
+                        language: id,
+                        data_entry,
+                        data,
+                    });
+                },
+                _ => return Err(error::Error::Malformed(format!("Resource directory entry at {:#x} is at the wrong level {}", entry_offset, self.path.len()))),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ResourceData<'a> {
+    pub fn parse(bytes: &'a [u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32) -> error::Result<Self> {
+        let rva = dd.virtual_address as usize;
+        let offset = utils::find_offset(rva, sections, file_alignment).ok_or_else(|| error::Error::Malformed(format!("Cannot map ImageResourceDirectory rva {:#x} into offset", rva)))?;
+        let image_resource_directory = bytes.pread_with(offset, scroll::LE)?;
+        let mut walker = DirectoryWalker {
+            bytes,
+            base: offset,
+            sections,
+            file_alignment,
+            visited: BTreeSet::new(),
+            path: Vec::new(),
+            entries: Vec::new(),
+        };
+        walker.parse_directory(0)?;
+        Ok(ResourceData { image_resource_directory, entries: walker.entries })
+    }
+    /// Returns the resources of type `type_id`
+    pub fn entries_of_type(&self, type_id: u16) -> Vec<&ResourceEntry<'a>> {
+        self.entries.iter().filter(|entry| entry.type_id == ResourceId::Id(type_id)).collect()
+    }
+    /// Decodes the first `RT_VERSION` resource, if any
+    pub fn version_info(&self) -> error::Result<Option<VersionInfo>> {
+        match self.entries_of_type(RT_VERSION).first() {
+            Some(entry) => VersionInfo::parse(entry.data).map(Some),
+            None => Ok(None),
+        }
+    }
+    /// Returns the XML bytes of the first `RT_MANIFEST` resource, if any
+    pub fn manifest(&self) -> Option<&'a [u8]> {
+        self.entries_of_type(RT_MANIFEST).first().map(|entry| entry.data)
+    }
+    /// Returns the `RT_ICON` resource with the ID `id`, which a group icon refers to
+    pub fn icon(&self, id: u16) -> Option<&ResourceEntry<'a>> {
+        self.entries.iter().find(|entry| entry.type_id == ResourceId::Id(RT_ICON) && entry.name == ResourceId::Id(id))
+    }
+    /// Decodes the `RT_GROUP_ICON` resources
+    pub fn group_icons(&self) -> error::Result<Vec<GroupIcon>> {
+        self.entries_of_type(RT_GROUP_ICON).iter().map(|entry| GroupIcon::parse(entry.data)).collect()
+    }
+    /// Assembles the `.ico` file of the group icon `group`; returns `None` if one of its icons is missing
+    pub fn icon_file(&self, group: &GroupIcon) -> Option<Vec<u8>> {
+        let mut icons = Vec::with_capacity(group.entries.len());
+        for entry in &group.entries {
+            match self.icon(entry.id) {
+                Some(icon) => icons.push(icon.data),
+                None => return None,
+            }
+        }
+        let header_size = SIZEOF_ICON_DIR + group.entries.len() * SIZEOF_ICON_DIR_ENTRY;
+        let size = icons.iter().fold(header_size, |size, icon| size + icon.len());
+        let mut file = vec![0u8; size];
+        match write_icon_file(&mut file, group, &icons, header_size) {
+            Ok(()) => Some(file),
+            Err(_) => None,
+        }
+    }
+}
+
+/// Writes the `.ico` file of `group`, whose images are `icons`, into `file`, which is sized for them
+fn write_icon_file(file: &mut [u8], group: &GroupIcon, icons: &[&[u8]], header_size: usize) -> Result<(), scroll::Error> {
+    let mut offset = 0;
+    // ICONDIR
+    file.gwrite_with(0u16, &mut offset, scroll::LE)?;
+    file.gwrite_with(1u16, &mut offset, scroll::LE)?;
+    file.gwrite_with(group.entries.len() as u16, &mut offset, scroll::LE)?;
+    let mut image_offset = header_size;
+    for (entry, icon) in group.entries.iter().zip(icons) {
+        // ICONDIRENTRY: the group entry, with the file offset of the image instead of its ID
+        file.gwrite_with(entry.width, &mut offset, scroll::LE)?;
+        file.gwrite_with(entry.height, &mut offset, scroll::LE)?;
+        file.gwrite_with(entry.color_count, &mut offset, scroll::LE)?;
+        file.gwrite_with(entry.reserved, &mut offset, scroll::LE)?;
+        file.gwrite_with(entry.planes, &mut offset, scroll::LE)?;
+        file.gwrite_with(entry.bit_count, &mut offset, scroll::LE)?;
+        file.gwrite_with(icon.len() as u32, &mut offset, scroll::LE)?;
+        file.gwrite_with(image_offset as u32, &mut offset, scroll::LE)?;
+        file[image_offset..image_offset + icon.len()].copy_from_slice(icon);
+        image_offset += icon.len();
+    }
+    Ok(())
+}
+
+/// The signature of `VS_FIXEDFILEINFO`
+pub const VS_FFI_SIGNATURE: u32 = 0xfeef_04bd;
+
+pub const VS_FF_DEBUG: u32 = 0x01;
+pub const VS_FF_PRERELEASE: u32 = 0x02;
+pub const VS_FF_PATCHED: u32 = 0x04;
+pub const VS_FF_PRIVATEBUILD: u32 = 0x08;
+pub const VS_FF_INFOINFERRED: u32 = 0x10;
+pub const VS_FF_SPECIALBUILD: u32 = 0x20;
+
+pub const VFT_APP: u32 = 1;
+pub const VFT_DLL: u32 = 2;
+pub const VFT_DRV: u32 = 3;
+pub const VFT_FONT: u32 = 4;
+pub const VFT_VXD: u32 = 5;
+pub const VFT_STATIC_LIB: u32 = 7;
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct VsFixedFileInfo {
+    pub signature: u32,
+    pub struc_version: u32,
+    pub file_version_ms: u32,
+    pub file_version_ls: u32,
+    pub product_version_ms: u32,
+    pub product_version_ls: u32,
+    pub file_flags_mask: u32,
+    pub file_flags: u32,
+    pub file_os: u32,
+    pub file_type: u32,
+    pub file_subtype: u32,
+    pub file_date_ms: u32,
+    pub file_date_ls: u32,
+}
+
+pub const SIZEOF_VS_FIXED_FILE_INFO: usize = 52;
+
+impl VsFixedFileInfo {
+    /// The file version, e.g., `(10, 0, 19041, 1)`
+    pub fn file_version(&self) -> (u16, u16, u16, u16) {
+        ((self.file_version_ms >> 16) as u16, self.file_version_ms as u16, (self.file_version_ls >> 16) as u16, self.file_version_ls as u16)
+    }
+    /// The version of the product the file is distributed with
+    pub fn product_version(&self) -> (u16, u16, u16, u16) {
+        ((self.product_version_ms >> 16) as u16, self.product_version_ms as u16, (self.product_version_ls >> 16) as u16, self.product_version_ls as u16)
+    }
+    /// The flags which are valid in `file_flags_mask`, e.g., `VS_FF_DEBUG`
+    pub fn flags(&self) -> u32 {
+        self.file_flags & self.file_flags_mask
+    }
+}
+
+/// The maximum nesting of the version information blocks
+const MAX_VERSION_DEPTH: usize = 8;
+
+/// A block of the version information: a key, a value and children blocks
+struct VersionBlock<'a> {
+    key: String,
+    /// 1 for text, 0 for binary
+    value_type: u16,
+    value: &'a [u8],
+    children: Vec<VersionBlock<'a>>,
+}
+
+/// Rounds `offset` up to 4 bytes
+fn align4(offset: usize) -> usize {
+    (offset + 3) & !3
+}
+
+impl<'a> VersionBlock<'a> {
+    fn parse(bytes: &'a [u8], offset: usize, depth: usize) -> error::Result<Self> {
+        let length = bytes.pread_with::<u16>(offset, scroll::LE)? as usize;
+        let value_length = bytes.pread_with::<u16>(offset + 2, scroll::LE)? as usize;
+        let value_type = bytes.pread_with::<u16>(offset + 4, scroll::LE)?;
+        let end = offset + length;
+        if length < 6 || end > bytes.len() || depth > MAX_VERSION_DEPTH {
+            return Err(error::Error::Malformed(format!("Version information block at {:#x} with length {:#x} is malformed", offset, length)));
+        }
+        let block = &bytes[..end];
+        // the NUL-terminated UTF-16 key, padded to 4 bytes
+        let mut key_end = offset + 6;
+        while key_end + 2 <= end && (block[key_end] != 0 || block[key_end + 1] != 0) {
+            key_end += 2;
+        }
+        let key = decode_utf16(&block[offset + 6..key_end]);
+        let value_start = cmp::min(align4(key_end + 2), end);
+        // the length of a text value is in UTF-16 code units
+        let value_size = if value_type == 1 { value_length * 2 } else { value_length };
+        let value_end = cmp::min(value_start + value_size, end);
+        let value = &block[value_start..value_end];
+        let mut children = Vec::new();
+        let mut child = align4(value_end);
+        while child + 6 <= end {
+            let block = VersionBlock::parse(block, child, depth + 1)?;
+            let length = bytes.pread_with::<u16>(child, scroll::LE)? as usize;
+            children.push(block);
+            child = align4(child + length);
+        }
+        Ok(VersionBlock { key, value_type, value, children })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// A `StringTable` of the `StringFileInfo`: the strings for a language and code page
+pub struct StringTable {
+    /// The language and code page in hex, e.g., `040904b0` for US English and UTF-16
+    pub key: String,
+    /// The keys and values, e.g., `("CompanyName", "Microsoft Corporation")`
+    pub strings: Vec<(String, String)>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+/// The decoded `VS_VERSIONINFO` resource
+pub struct VersionInfo {
+    pub fixed_file_info: Option<VsFixedFileInfo>,
+    pub string_tables: Vec<StringTable>,
+    /// The language and code page pairs of the `VarFileInfo` translation
+    pub translations: Vec<(u16, u16)>,
+}
+
+impl VersionInfo {
+    pub fn parse(bytes: &[u8]) -> error::Result<Self> {
+        let root = VersionBlock::parse(bytes, 0, 0)?;
+        if root.key != "VS_VERSION_INFO" {
+            return Err(error::Error::Malformed(format!("Version information has the key {:?}, expected VS_VERSION_INFO", root.key)));
+        }
+        let mut info = VersionInfo::default();
+        if root.value.len() >= SIZEOF_VS_FIXED_FILE_INFO {
+            let fixed: VsFixedFileInfo = root.value.pread_with(0, scroll::LE)?;
+            if fixed.signature == VS_FFI_SIGNATURE {
+                info.fixed_file_info = Some(fixed);
+            }
+        }
+        for child in &root.children {
+            match child.key.as_str() {
+                "StringFileInfo" => {
+                    for table in &child.children {
+                        let strings = table.children.iter().map(|string| {
+                            let value = if string.value_type == 1 { decode_utf16(string.value) } else { String::new() };
+                            (string.key.clone(), value)
+                        }).collect();
+                        info.string_tables.push(StringTable { key: table.key.clone(), strings });
+                    }
+                },
+                "VarFileInfo" => {
+                    for var in child.children.iter().filter(|var| var.key == "Translation") {
+                        for pair in var.value.chunks(4).filter(|pair| pair.len() == 4) {
+                            info.translations.push((pair.pread_with(0, scroll::LE)?, pair.pread_with(2, scroll::LE)?));
+                        }
+                    }
+                },
+                _ => (),
+            }
+        }
+        Ok(info)
+    }
+    /// Returns the value of the string `key`, e.g., `ProductVersion` or `CompanyName`, from the first string table having it
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.string_tables.iter()
+            .flat_map(|table| table.strings.iter())
+            .find(|pair| pair.0 == key)
+            .map(|pair| pair.1.as_str())
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+/// `GRPICONDIRENTRY`: an icon of a group icon
+pub struct GroupIconEntry {
+    /// The width in pixels, 0 for 256
+    pub width: u8,
+    /// The height in pixels, 0 for 256
+    pub height: u8,
+    pub color_count: u8,
+    pub reserved: u8,
+    pub planes: u16,
+    pub bit_count: u16,
+    pub bytes_in_res: u32,
+    /// The ID of the `RT_ICON` resource
+    pub id: u16,
+}
+
+pub const SIZEOF_GROUP_ICON_ENTRY: usize = 14;
+/// The size of the `ICONDIR` header of an `.ico` file
+pub const SIZEOF_ICON_DIR: usize = 6;
+/// The size of an `ICONDIRENTRY` of an `.ico` file
+pub const SIZEOF_ICON_DIR_ENTRY: usize = 16;
+
+#[derive(Debug, PartialEq, Clone, Default)]
+/// The decoded `RT_GROUP_ICON` (or `RT_GROUP_CURSOR`) resource
+pub struct GroupIcon {
+    /// 1 for icons, 2 for cursors
+    pub resource_type: u16,
+    pub entries: Vec<GroupIconEntry>,
+}
+
+impl GroupIcon {
+    pub fn parse(bytes: &[u8]) -> error::Result<Self> {
+        let resource_type = bytes.pread_with::<u16>(2, scroll::LE)?;
+        let count = bytes.pread_with::<u16>(4, scroll::LE)? as usize;
+        let mut entries = Vec::with_capacity(count);
+        for i in 0..count {
+            entries.push(bytes.pread_with(6 + i * SIZEOF_GROUP_ICON_ENTRY, scroll::LE)?);
+        }
+        Ok(GroupIcon { resource_type, entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    /// Appends a version information block to `out`
+    fn block(out: &mut Vec<u8>, key: &str, value_type: u16, value: &[u8], children: &[u8]) {
+        let start = out.len();
+        let units: Vec<u16> = key.encode_utf16().chain(Some(0)).collect();
+        out.extend_from_slice(&[0; 6]);
+        for unit in units {
+            out.push(unit as u8);
+            out.push((unit >> 8) as u8);
+        }
+        while out.len() % 4 != 0 { out.push(0) }
+        out.extend_from_slice(value);
+        while out.len() % 4 != 0 { out.push(0) }
+        out.extend_from_slice(children);
+        let length = (out.len() - start) as u16;
+        let value_length = if value_type == 1 { value.len() / 2 } else { value.len() } as u16;
+        out.pwrite_with(length, start, scroll::LE).unwrap();
+        out.pwrite_with(value_length, start + 2, scroll::LE).unwrap();
+        out.pwrite_with(value_type, start + 4, scroll::LE).unwrap();
+    }
+
+    fn utf16(s: &str) -> Vec<u8> {
+        s.encode_utf16().chain(Some(0)).flat_map(|unit| vec![unit as u8, (unit >> 8) as u8]).collect()
+    }
+
+    #[test]
+    fn version_info() {
+        let mut strings = Vec::new();
+        block(&mut strings, "CompanyName", 1, &utf16("Goblin"), &[]);
+        block(&mut strings, "ProductVersion", 1, &utf16("1.2.3"), &[]);
+        let mut table = Vec::new();
+        block(&mut table, "040904b0", 1, &[], &strings);
+        let mut children = Vec::new();
+        block(&mut children, "StringFileInfo", 1, &[], &table);
+        let mut translation = Vec::new();
+        block(&mut translation, "Translation", 0, &[0x09, 0x04, 0xb0, 0x04], &[]);
+        block(&mut children, "VarFileInfo", 1, &[], &translation);
+        let mut fixed = vec![0u8; SIZEOF_VS_FIXED_FILE_INFO];
+        fixed.pwrite_with(VsFixedFileInfo {
+            signature: VS_FFI_SIGNATURE,
+            file_version_ms: 0x0001_0002,
+            file_version_ls: 0x0003_0004,
+            ..Default::default()
+        }, 0, scroll::LE).unwrap();
+        let mut root = Vec::new();
+        block(&mut root, "VS_VERSION_INFO", 0, &fixed, &children);
+
+        let info = VersionInfo::parse(&root).unwrap();
+        assert_eq!(info.fixed_file_info.unwrap().file_version(), (1, 2, 3, 4));
+        assert_eq!(info.get("CompanyName"), Some("Goblin"));
+        assert_eq!(info.get("ProductVersion"), Some("1.2.3"));
+        assert_eq!(info.get("FileDescription"), None);
+        assert_eq!(info.string_tables[0].key, "040904b0");
+        assert_eq!(info.translations, vec![(0x409, 0x4b0)]);
+        assert!(VersionInfo::parse(&root[..root.len() - 4]).is_err());
+    }
+
+    /// Writes a resource directory at `offset` of `bytes` with the `(name_or_id, offset_to_data)` entries
+    fn directory(bytes: &mut [u8], offset: usize, named: u16, entries: &[(u32, u32)]) {
+        let header = ImageResourceDirectory { number_of_named_entries: named, number_of_id_entries: (entries.len() - named as usize) as u16, ..Default::default() };
+        bytes.pwrite_with(header, offset, scroll::LE).unwrap();
+        for (i, &(name_or_id, offset_to_data)) in entries.iter().enumerate() {
+            let entry = ImageResourceDirectoryEntry { name_or_id, offset_to_data };
+            bytes.pwrite_with(entry, offset + SIZEOF_IMAGE_RESOURCE_DIRECTORY + i * SIZEOF_IMAGE_RESOURCE_DIRECTORY_ENTRY, scroll::LE).unwrap();
+        }
+    }
+
+    #[test]
+    fn resource_tree() {
+        // the resource directory at 0x100: an icon with the ID 7, and a group icon named MAIN referring to it
+        let sections = [utils::identity_section(0x400)];
+        let dd = data_directories::DataDirectory { virtual_address: 0x100, size: 0x200 };
+        let mut bytes = vec![0u8; 0x400];
+        directory(&mut bytes, 0x100, 0, &[(RT_ICON as u32, IMAGE_RESOURCE_HIGH_BIT | 0x40), (RT_GROUP_ICON as u32, IMAGE_RESOURCE_HIGH_BIT | 0x80)]);
+        directory(&mut bytes, 0x140, 0, &[(7, IMAGE_RESOURCE_HIGH_BIT | 0xc0)]);
+        directory(&mut bytes, 0x180, 1, &[(IMAGE_RESOURCE_HIGH_BIT | 0x1a0, IMAGE_RESOURCE_HIGH_BIT | 0x100)]);
+        directory(&mut bytes, 0x1c0, 0, &[(0x409, 0x140)]);
+        directory(&mut bytes, 0x200, 0, &[(0x409, 0x150)]);
+        bytes.pwrite_with(ImageResourceDataEntry { offset_to_data: 0x300, size: 8, ..Default::default() }, 0x240, scroll::LE).unwrap();
+        bytes.pwrite_with(ImageResourceDataEntry { offset_to_data: 0x320, size: 20, ..Default::default() }, 0x250, scroll::LE).unwrap();
+        bytes.pwrite_with(4u16, 0x2a0, scroll::LE).unwrap();
+        bytes.pwrite(&utf16("MAIN")[..8], 0x2a2).unwrap();
+        bytes[0x300..0x308].copy_from_slice(b"\x89PNG\r\n\x1a\n");
+        bytes[0x320..0x326].copy_from_slice(&[0, 0, 1, 0, 1, 0]);
+        bytes[0x326..0x334].copy_from_slice(&[16, 16, 0, 0, 1, 0, 32, 0, 8, 0, 0, 0, 7, 0]);
+
+        let resources = ResourceData::parse(&bytes, &dd, &sections, 0x200).unwrap();
+        assert_eq!(resources.entries.len(), 2);
+        let icon = &resources.entries[0];
+        assert_eq!((&icon.type_id, &icon.name, &icon.language), (&ResourceId::Id(RT_ICON), &ResourceId::Id(7), &ResourceId::Id(0x409)));
+        assert_eq!(icon.data, b"\x89PNG\r\n\x1a\n");
+        assert_eq!(resources.entries[1].name, ResourceId::Name("MAIN".to_string()));
+        assert_eq!(resources.icon(7), Some(icon));
+        let groups = resources.group_icons().unwrap();
+        assert_eq!(groups.len(), 1);
+        let file = resources.icon_file(&groups[0]).unwrap();
+        assert_eq!(file.len(), SIZEOF_ICON_DIR + SIZEOF_ICON_DIR_ENTRY + 8);
+        assert_eq!(&file[..6], &[0, 0, 1, 0, 1, 0]);
+        assert_eq!(file.pread_with::<u32>(SIZEOF_ICON_DIR + 8, scroll::LE).unwrap(), 8);
+        assert_eq!(file.pread_with::<u32>(SIZEOF_ICON_DIR + 12, scroll::LE).unwrap(), 22);
+        assert_eq!(&file[22..], b"\x89PNG\r\n\x1a\n");
+        // a group referring to a missing icon
+        let mut group = groups[0].clone();
+        group.entries[0].id = 8;
+        assert_eq!(resources.icon_file(&group), None);
+
+        // a name directory which is its own subdirectory
+        let mut cycle = bytes.clone();
+        directory(&mut cycle, 0x140, 0, &[(7, IMAGE_RESOURCE_HIGH_BIT | 0x40)]);
+        assert!(ResourceData::parse(&cycle, &dd, &sections, 0x200).is_err());
+        // a data entry at the type level
+        let mut misplaced = bytes.clone();
+        directory(&mut misplaced, 0x100, 0, &[(RT_ICON as u32, 0x140)]);
+        assert!(ResourceData::parse(&misplaced, &dd, &sections, 0x200).is_err());
+    }
+
+    #[test]
+    fn resource_entry_limit() {
+        // a language directory with more than MAX_ENTRIES entries, all for the same empty resource; the
+        // two named entries share their name
+        let count = MAX_ENTRIES + 1;
+        let data_entry = 0x40 + SIZEOF_IMAGE_RESOURCE_DIRECTORY + count * SIZEOF_IMAGE_RESOURCE_DIRECTORY_ENTRY;
+        let size = data_entry + SIZEOF_IMAGE_RESOURCE_DATA_ENTRY + 4;
+        let section = utils::identity_section(size as u32);
+        let dd = data_directories::DataDirectory { virtual_address: 0, size: size as u32 };
+        let mut bytes = vec![0u8; size];
+        directory(&mut bytes, 0, 0, &[(RT_RCDATA as u32, IMAGE_RESOURCE_HIGH_BIT | 0x18)]);
+        directory(&mut bytes, 0x18, 0, &[(1, IMAGE_RESOURCE_HIGH_BIT | 0x40)]);
+        let mut languages = vec![(IMAGE_RESOURCE_HIGH_BIT | (size as u32 - 4), data_entry as u32); 2];
+        languages.extend((0..count - 2).map(|id| (id as u32, data_entry as u32)));
+        directory(&mut bytes, 0x40, 2, &languages);
+        bytes.pwrite_with(ImageResourceDataEntry { offset_to_data: size as u32, size: 0, ..Default::default() }, data_entry, scroll::LE).unwrap();
+        assert!(ResourceData::parse(&bytes, &dd, &[section.clone()], 0x200).is_err());
+        // one entry less is fine
+        let header = ImageResourceDirectory { number_of_named_entries: 2, number_of_id_entries: (count - 3) as u16, ..Default::default() };
+        bytes.pwrite_with(header, 0x40, scroll::LE).unwrap();
+        assert_eq!(ResourceData::parse(&bytes, &dd, &[section], 0x200).unwrap().entries.len(), MAX_ENTRIES);
+    }
+
+    #[test]
+    fn group_icon() {
+        let mut bytes = vec![0, 0, 1, 0, 1, 0];
+        bytes.extend_from_slice(&[32, 32, 0, 0, 1, 0, 32, 0, 0xa8, 0x10, 0, 0, 7, 0]);
+        let group = GroupIcon::parse(&bytes).unwrap();
+        assert_eq!(group.resource_type, 1);
+        assert_eq!(group.entries, vec![GroupIconEntry { width: 32, height: 32, color_count: 0, reserved: 0, planes: 1, bit_count: 32, bytes_in_res: 0x10a8, id: 7 }]);
+    }
+}
@@ -0,0 +1,166 @@
+use scroll::{self, Pread};
+use error;
+use alloc::vec::Vec;
+
+use pe::header;
+
+/// The size of a symbol record in the COFF symbol table
+pub const SIZEOF_SYMBOL: usize = 18;
+
+/// The symbol isn't yet assigned a section (e.g., an external symbol)
+pub const IMAGE_SYM_UNDEFINED: i16 = 0;
+/// The symbol has an absolute value
+pub const IMAGE_SYM_ABSOLUTE: i16 = -1;
+/// The symbol provides general type or debugging information
+pub const IMAGE_SYM_DEBUG: i16 = -2;
+
+/// The symbol is a function, in the `typ` field
+pub const IMAGE_SYM_DTYPE_FUNCTION: u16 = 0x20;
+
+/// An external (public) symbol
+pub const IMAGE_SYM_CLASS_EXTERNAL: u8 = 2;
+/// A static symbol; with a value of 0 it's a section name
+pub const IMAGE_SYM_CLASS_STATIC: u8 = 3;
+/// A code label defined within the module
+pub const IMAGE_SYM_CLASS_LABEL: u8 = 6;
+/// The beginning or end of a function (`.bf` and `.ef`)
+pub const IMAGE_SYM_CLASS_FUNCTION: u8 = 101;
+/// The source file name, followed by the name in auxiliary records
+pub const IMAGE_SYM_CLASS_FILE: u8 = 103;
+
+/// A record of the COFF symbol table
+///
+/// https://docs.microsoft.com/en-us/windows/desktop/debug/pe-format#coff-symbol-table
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct Symbol {
+    /// The short name, or 4 zero bytes followed by the offset of the name in the string table
+    pub name: [u8; 8],
+    /// The value, whose meaning depends on the section number and storage class; usually an offset within the section
+    pub value: u32,
+    /// The one-based index of the symbol's section, or one of the `IMAGE_SYM_*` special values
+    pub section_number: i16,
+    /// The type of the symbol, see `IMAGE_SYM_DTYPE_*`
+    pub typ: u16,
+    /// The storage class, see `IMAGE_SYM_CLASS_*`
+    pub storage_class: u8,
+    /// The number of auxiliary records following this symbol
+    pub number_of_aux_symbols: u8,
+}
+
+impl Symbol {
+    pub fn parse(bytes: &[u8], offset: &mut usize) -> error::Result<Self> {
+        let mut symbol = Symbol::default();
+        for byte in symbol.name.iter_mut() {
+            *byte = bytes.gread_with(offset, scroll::LE)?;
+        }
+        symbol.value = bytes.gread_with(offset, scroll::LE)?;
+        symbol.section_number = bytes.gread_with(offset, scroll::LE)?;
+        symbol.typ = bytes.gread_with(offset, scroll::LE)?;
+        symbol.storage_class = bytes.gread_with(offset, scroll::LE)?;
+        symbol.number_of_aux_symbols = bytes.gread_with(offset, scroll::LE)?;
+        Ok(symbol)
+    }
+    /// Whether this symbol is a function
+    pub fn is_function(&self) -> bool {
+        self.typ & 0xf0 == IMAGE_SYM_DTYPE_FUNCTION
+    }
+    /// Returns the offset of the name in the string table, if the name isn't stored inline
+    pub fn name_offset(&self) -> Option<u32> {
+        if self.name[..4] == [0, 0, 0, 0] {
+            self.name.pread_with(4, scroll::LE).ok()
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// The COFF symbol table of a PE binary (usually only present in object files and MinGW builds), with its string table
+pub struct SymbolTable<'a> {
+    bytes: &'a [u8],
+    strings: &'a [u8],
+}
+
+impl<'a> SymbolTable<'a> {
+    /// Parses the symbol table and the string table following it, as described by the COFF header
+    pub fn parse(bytes: &'a [u8], coff_header: &header::CoffHeader) -> error::Result<Self> {
+        let offset = coff_header.pointer_to_symbol_table as usize;
+        let size = coff_header.number_of_symbol_table as usize * SIZEOF_SYMBOL;
+        let symbols: &[u8] = bytes.pread_with(offset, size)?;
+        let strings_offset = offset + size;
+        // the string table starts with its size, including the size field
+        let strings: &[u8] = match bytes.pread_with::<u32>(strings_offset, scroll::LE) {
+            Ok(strings_size) if strings_size >= 4 => bytes.pread_with(strings_offset, strings_size as usize)?,
+            _ => &[],
+        };
+        Ok(SymbolTable { bytes: symbols, strings })
+    }
+    /// The number of records, including the auxiliary records
+    pub fn len(&self) -> usize {
+        self.bytes.len() / SIZEOF_SYMBOL
+    }
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+    /// Returns the record at `index`; this may be an auxiliary record
+    pub fn get(&self, index: usize) -> Option<Symbol> {
+        let mut offset = index * SIZEOF_SYMBOL;
+        Symbol::parse(self.bytes, &mut offset).ok()
+    }
+    /// Returns the symbols, skipping the auxiliary records, with their indices and names
+    pub fn symbols(&self) -> Vec<(usize, &'a str, Symbol)> {
+        let mut symbols = Vec::new();
+        let mut index = 0;
+        while let Some(symbol) = self.get(index) {
+            let name = match symbol.name_offset() {
+                Some(offset) => self.strings.pread::<&str>(offset as usize).unwrap_or(""),
+                None => {
+                    let len = symbol.name.iter().position(|&byte| byte == 0).unwrap_or(symbol.name.len());
+                    self.bytes.pread_with::<&str>(index * SIZEOF_SYMBOL, ::scroll::ctx::StrCtx::Length(len)).unwrap_or("")
+                },
+            };
+            symbols.push((index, name, symbol));
+            index += 1 + symbol.number_of_aux_symbols as usize;
+        }
+        symbols
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    #[test]
+    fn parse_symbol_table() {
+        let mut bytes = vec![0u8; 8 + 3 * SIZEOF_SYMBOL + 4 + 23];
+        let coff_header = header::CoffHeader { pointer_to_symbol_table: 8, number_of_symbol_table: 3, ..Default::default() };
+        // a short name, followed by an auxiliary record
+        let mut offset = 8;
+        bytes[offset..offset + 5].copy_from_slice(b"_main");
+        bytes.pwrite_with(0x10u32, offset + 8, scroll::LE).unwrap();
+        bytes.pwrite_with(1i16, offset + 12, scroll::LE).unwrap();
+        bytes.pwrite_with(IMAGE_SYM_DTYPE_FUNCTION, offset + 14, scroll::LE).unwrap();
+        bytes[offset + 16] = IMAGE_SYM_CLASS_EXTERNAL;
+        bytes[offset + 17] = 1;
+        // a long name in the string table
+        offset += 2 * SIZEOF_SYMBOL;
+        bytes.pwrite_with(4u32, offset + 4, scroll::LE).unwrap();
+        bytes.pwrite_with(0x20u32, offset + 8, scroll::LE).unwrap();
+        bytes.pwrite_with(2i16, offset + 12, scroll::LE).unwrap();
+        bytes[offset + 16] = IMAGE_SYM_CLASS_STATIC;
+        offset += SIZEOF_SYMBOL;
+        bytes.pwrite_with(27u32, offset, scroll::LE).unwrap();
+        bytes[offset + 4..offset + 26].copy_from_slice(b"a_rather_long_function");
+
+        let table = SymbolTable::parse(&bytes, &coff_header).unwrap();
+        assert_eq!(table.len(), 3);
+        let symbols = table.symbols();
+        assert_eq!(symbols.len(), 2);
+        assert_eq!((symbols[0].0, symbols[0].1), (0, "_main"));
+        assert!(symbols[0].2.is_function());
+        assert_eq!(symbols[0].2.value, 0x10);
+        assert_eq!((symbols[1].0, symbols[1].1), (2, "a_rather_long_function"));
+        assert_eq!(symbols[1].2.section_number, 2);
+    }
+}
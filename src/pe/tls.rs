@@ -0,0 +1,216 @@
+//! The thread-local storage directory, which describes the template of the image's TLS block and
+//! the TLS callbacks, called by the loader on process and thread attach and detach, before the
+//! entry point.
+//!
+//! Unlike the other directories, it holds virtual addresses, which are converted to RVAs with the
+//! image base.
+
+use scroll::{self, Pread};
+use alloc::vec::Vec;
+
+use error;
+
+use pe::section_table;
+use pe::utils;
+use pe::data_directories;
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageTlsDirectory32 {
+    pub start_address_of_raw_data: u32,
+    pub end_address_of_raw_data: u32,
+    pub address_of_index: u32,
+    pub address_of_callbacks: u32,
+    pub size_of_zero_fill: u32,
+    pub characteristics: u32,
+}
+
+pub const SIZEOF_IMAGE_TLS_DIRECTORY_32: usize = 24;
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[derive(Pread, Pwrite, SizeWith)]
+pub struct ImageTlsDirectory64 {
+    pub start_address_of_raw_data: u64,
+    pub end_address_of_raw_data: u64,
+    pub address_of_index: u64,
+    pub address_of_callbacks: u64,
+    pub size_of_zero_fill: u32,
+    pub characteristics: u32,
+}
+
+pub const SIZEOF_IMAGE_TLS_DIRECTORY_64: usize = 40;
+
+/// Unified 32/64-bit TLS directory
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct ImageTlsDirectory {
+    /// The VA of the start of the TLS template
+    pub start_address_of_raw_data: u64,
+    /// The VA of the end of the TLS template, exclusive
+    pub end_address_of_raw_data: u64,
+    /// The VA of the variable the loader stores the TLS index of the image in
+    pub address_of_index: u64,
+    /// The VA of the null-terminated array of the VAs of the TLS callbacks
+    pub address_of_callbacks: u64,
+    /// The size of the zeroed data following the template in the TLS block
+    pub size_of_zero_fill: u32,
+    /// The alignment of the TLS block, as an `IMAGE_SCN_ALIGN_*` value
+    pub characteristics: u32,
+}
+
+impl From<ImageTlsDirectory32> for ImageTlsDirectory {
+    fn from(directory: ImageTlsDirectory32) -> Self {
+        ImageTlsDirectory {
+            start_address_of_raw_data: directory.start_address_of_raw_data as u64,
+            end_address_of_raw_data: directory.end_address_of_raw_data as u64,
+            address_of_index: directory.address_of_index as u64,
+            address_of_callbacks: directory.address_of_callbacks as u64,
+            size_of_zero_fill: directory.size_of_zero_fill,
+            characteristics: directory.characteristics,
+        }
+    }
+}
+
+impl From<ImageTlsDirectory64> for ImageTlsDirectory {
+    fn from(directory: ImageTlsDirectory64) -> Self {
+        ImageTlsDirectory {
+            start_address_of_raw_data: directory.start_address_of_raw_data,
+            end_address_of_raw_data: directory.end_address_of_raw_data,
+            address_of_index: directory.address_of_index,
+            address_of_callbacks: directory.address_of_callbacks,
+            size_of_zero_fill: directory.size_of_zero_fill,
+            characteristics: directory.characteristics,
+        }
+    }
+}
+
+impl ImageTlsDirectory {
+    /// The size of the TLS template
+    pub fn size_of_raw_data(&self) -> u64 {
+        self.end_address_of_raw_data.saturating_sub(self.start_address_of_raw_data)
+    }
+    /// The alignment of the TLS block in bytes, from the `IMAGE_SCN_ALIGN_*` bits of the
+    /// characteristics; 0 if unspecified
+    pub fn alignment(&self) -> u32 {
+        match (self.characteristics >> 20) & 0xf {
+            0 => 0,
+            n => 1 << (n - 1),
+        }
+    }
+}
+
+/// Converts the virtual address `va` of an image at `image_base` to an RVA; 0 stays 0, for an absent address
+fn va_to_rva(va: u64, image_base: u64) -> error::Result<u32> {
+    if va == 0 {
+        return Ok(0);
+    }
+    match va.checked_sub(image_base) {
+        Some(rva) if rva <= u64::from(::core::u32::MAX) => Ok(rva as u32),
+        _ => Err(error::Error::Malformed(format!("TLS address {:#x} is outside the image at {:#x}", va, image_base))),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+/// The TLS directory, with its addresses as RVAs
+pub struct TlsData<'a> {
+    pub image_tls_directory: ImageTlsDirectory,
+    /// The RVA of the TLS template
+    pub raw_data_rva: u32,
+    /// The TLS template, if it is in the file
+    pub raw_data: Option<&'a [u8]>,
+    /// The RVA of the variable the loader stores the TLS index of the image in
+    pub index_rva: u32,
+    /// The RVAs of the TLS callbacks, in the order they are called
+    pub callbacks: Vec<u32>,
+}
+
+impl<'a> TlsData<'a> {
+    /// Parses the TLS directory of a PE32 or, if `is_64`, PE32+ image with the preferred base address `image_base`
+    pub fn parse(bytes: &'a [u8], dd: &data_directories::DataDirectory, sections: &[section_table::SectionTable], file_alignment: u32, image_base: u64, is_64: bool) -> error::Result<Self> {
+        let rva = dd.virtual_address as usize;
+        let offset = utils::find_offset(rva, sections, file_alignment).ok_or_else(|| error::Error::Malformed(format!("Cannot map TLS directory rva {:#x} into offset", rva)))?;
+        let image_tls_directory: ImageTlsDirectory = if is_64 {
+            bytes.pread_with::<ImageTlsDirectory64>(offset, scroll::LE)?.into()
+        } else {
+            bytes.pread_with::<ImageTlsDirectory32>(offset, scroll::LE)?.into()
+        };
+        let raw_data_rva = va_to_rva(image_tls_directory.start_address_of_raw_data, image_base)?;
+        let raw_data = utils::find_offset(raw_data_rva as usize, sections, file_alignment).and_then(|offset| {
+            bytes.get(offset..offset.saturating_add(image_tls_directory.size_of_raw_data() as usize))
+        });
+        let index_rva = va_to_rva(image_tls_directory.address_of_index, image_base)?;
+        let mut callbacks = Vec::new();
+        let callbacks_rva = va_to_rva(image_tls_directory.address_of_callbacks, image_base)?;
+        if callbacks_rva != 0 {
+            let mut offset = utils::find_offset(callbacks_rva as usize, sections, file_alignment).ok_or_else(|| error::Error::Malformed(format!("Cannot map TLS callbacks rva {:#x} into offset", callbacks_rva)))?;
+            loop {
+                let callback = if is_64 {
+                    bytes.gread_with::<u64>(&mut offset, scroll::LE)?
+                } else {
+                    u64::from(bytes.gread_with::<u32>(&mut offset, scroll::LE)?)
+                };
+                if callback == 0 {
+                    break;
+                }
+                callbacks.push(va_to_rva(callback, image_base)?);
+            }
+        }
+        Ok(TlsData { image_tls_directory, raw_data_rva, raw_data, index_rva, callbacks })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scroll::Pwrite;
+
+    #[test]
+    fn parse_tls_directory() {
+        // the directory at 0x100, the callbacks at 0x200 and the template at 0x300
+        let section = utils::identity_section(0x400);
+        let mut bytes = vec![0u8; 0x400];
+        let image_base = 0x1_4000_0000;
+        let directory = ImageTlsDirectory64 {
+            start_address_of_raw_data: image_base + 0x300,
+            end_address_of_raw_data: image_base + 0x310,
+            address_of_index: image_base + 0x380,
+            address_of_callbacks: image_base + 0x200,
+            size_of_zero_fill: 0x8,
+            characteristics: 0x0050_0000,
+        };
+        bytes.pwrite_with(directory, 0x100, scroll::LE).unwrap();
+        bytes.pwrite_with(image_base + 0x1010, 0x200, scroll::LE).unwrap();
+        bytes.pwrite_with(image_base + 0x1000, 0x208, scroll::LE).unwrap();
+        let dd = data_directories::DataDirectory { virtual_address: 0x100, size: SIZEOF_IMAGE_TLS_DIRECTORY_64 as u32 };
+        let tls = TlsData::parse(&bytes, &dd, &[section.clone()], 0x200, image_base, true).unwrap();
+        assert_eq!(tls.image_tls_directory, directory.into());
+        assert_eq!(tls.image_tls_directory.size_of_raw_data(), 0x10);
+        assert_eq!(tls.image_tls_directory.alignment(), 16);
+        assert_eq!(tls.raw_data_rva, 0x300);
+        assert_eq!(tls.raw_data.map(|data| data.len()), Some(0x10));
+        assert_eq!(tls.index_rva, 0x380);
+        assert_eq!(tls.callbacks, vec![0x1010, 0x1000]);
+
+        // the same in a PE32 image
+        let mut bytes = vec![0u8; 0x400];
+        let image_base = 0x40_0000;
+        let directory = ImageTlsDirectory32 {
+            start_address_of_raw_data: image_base + 0x300,
+            end_address_of_raw_data: image_base + 0x300,
+            address_of_index: image_base + 0x380,
+            address_of_callbacks: image_base + 0x200,
+            size_of_zero_fill: 0x8,
+            characteristics: 0,
+        };
+        bytes.pwrite_with(directory, 0x100, scroll::LE).unwrap();
+        bytes.pwrite_with(image_base + 0x1010, 0x200, scroll::LE).unwrap();
+        let tls = TlsData::parse(&bytes, &dd, &[section.clone()], 0x200, u64::from(image_base), false).unwrap();
+        assert_eq!(tls.image_tls_directory.alignment(), 0);
+        assert_eq!(tls.raw_data, Some(&[][..]));
+        assert_eq!(tls.callbacks, vec![0x1010]);
+        // a callback below the image base
+        bytes.pwrite_with(0x1000u32, 0x204, scroll::LE).unwrap();
+        assert!(TlsData::parse(&bytes, &dd, &[section], 0x200, u64::from(image_base), false).is_err());
+    }
+}
@@ -39,6 +39,13 @@ fn section_read_size(section: &section_table::SectionTable, file_alignment: u32)
     }
 }
 
+/// Returns a section which maps the first `size` bytes of an image at RVA == offset, for tests
+/// which lay out a directory by hand
+#[cfg(test)]
+pub fn identity_section(size: u32) -> section_table::SectionTable {
+    section_table::SectionTable { virtual_size: size, size_of_raw_data: size, ..Default::default() }
+}
+
 fn rva2offset (rva: usize, section: &section_table::SectionTable) -> usize {
     (rva - section.virtual_address as usize) + aligned_pointer_to_raw_data(section.pointer_to_raw_data as usize)
 }
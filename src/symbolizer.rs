@@ -0,0 +1,295 @@
+//! Address to symbol resolution for ELF, PE and Mach-O binaries, e.g., to symbolize stack traces.
+//!
+//! A `Symbolizer` collects the defined symbols of a binary once, sorted by address, and then
+//! resolves an address to its enclosing symbol with a binary search. ELF symbols are sized by
+//! their `st_size`; a symbol without a size (Mach-O and PE symbols, and ELF assembly labels)
+//! extends up to the next symbol, Mach-O function start (`LC_FUNCTION_STARTS`) or section end,
+//! whichever comes first.
+//!
+//! ```rust,no_run
+//! use goblin::{symbolizer::Symbolizer, Object};
+//! use std::fs::File;
+//! use std::io::Read;
+//!
+//! let mut bytes = Vec::new();
+//! File::open("/lib/x86_64-linux-gnu/libc.so.6").unwrap().read_to_end(&mut bytes).unwrap();
+//! let object = Object::parse(&bytes).unwrap();
+//! let symbolizer = Symbolizer::new(&object, &bytes).unwrap();
+//! if let Some(location) = symbolizer.lookup(0x9894c) {
+//!     println!("{}", location); // e.g., malloc+0x1c
+//! }
+//! ```
+
+use core::fmt;
+use alloc::vec::Vec;
+
+use error;
+use Object;
+use elf;
+use elf::sym::{STT_SECTION, STT_FILE, STT_TLS, STT_FUNC};
+use elf::section_header::{SHN_UNDEF, SHN_ABS};
+use elf::program_header::PT_LOAD;
+use pe;
+use pe::symbol::{IMAGE_SYM_CLASS_EXTERNAL, IMAGE_SYM_CLASS_STATIC, IMAGE_SYM_CLASS_LABEL};
+use mach;
+use mach::symbols::N_SECT;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A symbol covering the address range `address..address + size`
+pub struct Symbol<'a> {
+    pub name: &'a str,
+    pub address: u64,
+    /// The size of the symbol; 0 if it's unknown, in which case the symbol only covers its address
+    pub size: u64,
+}
+
+impl<'a> Symbol<'a> {
+    /// Whether `address` is within this symbol
+    pub fn contains(&self, address: u64) -> bool {
+        address == self.address || (address > self.address && address - self.address < self.size)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The symbol enclosing an address and the address' offset within it, displayed as `name+0x1c`
+pub struct Location<'a> {
+    pub name: &'a str,
+    /// The address of the symbol
+    pub address: u64,
+    pub offset: u64,
+}
+
+impl<'a> fmt::Display for Location<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.offset == 0 {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{}+{:#x}", self.name, self.offset)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// An index of the symbols of a binary by address
+pub struct Symbolizer<'a> {
+    /// The symbols, sorted by address, with one symbol per address
+    symbols: Vec<Symbol<'a>>,
+}
+
+impl<'a> Symbolizer<'a> {
+    /// Builds the symbolizer for the binary `object`, parsed from `bytes`
+    pub fn new(object: &Object<'a>, bytes: &'a [u8]) -> error::Result<Self> {
+        match *object {
+            Object::Elf(ref elf) => Ok(Symbolizer::from_elf(elf)),
+            Object::PE(ref pe) => Symbolizer::from_pe(pe, bytes),
+            Object::Mach(mach::Mach::Binary(ref macho)) => Symbolizer::from_mach(macho),
+            Object::Mach(mach::Mach::Fat(_)) => Err(error::Error::Malformed("Cannot symbolize a fat Mach-O, symbolize one of its architectures".to_string())),
+            _ => Err(error::Error::Malformed("Cannot symbolize an object which isn't an ELF, PE or Mach-O binary".to_string())),
+        }
+    }
+    /// Builds the symbolizer from the symbol table and the dynamic symbol table of `elf`
+    pub fn from_elf(elf: &elf::Elf<'a>) -> Self {
+        let mut symbols = Vec::with_capacity(elf.syms.len() + elf.dynsyms.len());
+        let is_arm = elf.header.e_machine == elf::header::EM_ARM;
+        for &(syms, strtab) in &[(&elf.syms, &elf.strtab), (&elf.dynsyms, &elf.dynstrtab)] {
+            for sym in syms.iter() {
+                let st_type = sym.st_type();
+                if sym.st_shndx == SHN_UNDEF as usize || sym.st_shndx == SHN_ABS as usize || st_type == STT_SECTION || st_type == STT_FILE || st_type == STT_TLS {
+                    continue;
+                }
+                let name = match strtab.get(sym.st_name) {
+                    Some(Ok(name)) if !name.is_empty() => name,
+                    _ => continue,
+                };
+                // the lowest bit of the address of an ARM function selects the Thumb instruction set
+                let address = if is_arm && st_type == STT_FUNC { sym.st_value & !1 } else { sym.st_value };
+                symbols.push(Symbol { name, address, size: sym.st_size });
+            }
+        }
+        let mut boundaries: Vec<u64> = elf.section_headers.iter()
+            .filter(|shdr| shdr.is_alloc() && shdr.sh_addr != 0)
+            .map(|shdr| shdr.sh_addr.saturating_add(shdr.sh_size))
+            .collect();
+        boundaries.extend(elf.program_headers.iter().filter(|phdr| phdr.p_type == PT_LOAD).map(|phdr| phdr.p_vaddr.saturating_add(phdr.p_memsz)));
+        Symbolizer::from_symbols(symbols, boundaries)
+    }
+    /// Builds the symbolizer from the exports and the COFF symbol table (if any) of `pe`, parsed from `bytes`.
+    /// Addresses are virtual addresses, i.e., relative to the image base
+    pub fn from_pe(pe: &pe::PE<'a>, bytes: &'a [u8]) -> error::Result<Self> {
+        let image_base = pe.image_base as u64;
+        let mut symbols: Vec<Symbol<'a>> = pe.exports.iter()
+            .filter(|export| export.reexport.is_none())
+            .filter_map(|export| export.name.map(|name| Symbol { name, address: image_base.wrapping_add(export.rva as u64), size: 0 }))
+            .collect();
+        let coff_header = &pe.header.coff_header;
+        if coff_header.pointer_to_symbol_table != 0 && coff_header.number_of_symbol_table != 0 {
+            let table = pe::symbol::SymbolTable::parse(bytes, coff_header)?;
+            for (_, name, symbol) in table.symbols() {
+                let is_code_or_data = match symbol.storage_class {
+                    IMAGE_SYM_CLASS_EXTERNAL | IMAGE_SYM_CLASS_LABEL => true,
+                    // static symbols with auxiliary records are section definitions
+                    IMAGE_SYM_CLASS_STATIC => symbol.number_of_aux_symbols == 0,
+                    _ => false,
+                };
+                if !is_code_or_data || name.is_empty() || symbol.section_number <= 0 {
+                    continue;
+                }
+                if let Some(section) = pe.sections.get(symbol.section_number as usize - 1) {
+                    let address = image_base.wrapping_add(section.virtual_address as u64 + symbol.value as u64);
+                    symbols.push(Symbol { name, address, size: 0 });
+                }
+            }
+        }
+        let boundaries = pe.sections.iter()
+            .map(|section| image_base.saturating_add(section.virtual_address as u64 + section.virtual_size as u64))
+            .collect();
+        Ok(Symbolizer::from_symbols(symbols, boundaries))
+    }
+    /// Builds the symbolizer from the section symbols of `macho`, sized with its function starts
+    pub fn from_mach(macho: &mach::MachO<'a>) -> error::Result<Self> {
+        let mut symbols = Vec::new();
+        for symbol in macho.symbols() {
+            let (name, nlist) = symbol?;
+            if nlist.is_stab() || nlist.get_type() != N_SECT || name.is_empty() {
+                continue;
+            }
+            symbols.push(Symbol { name, address: nlist.n_value, size: 0 });
+        }
+        let mut boundaries = macho.function_starts()?;
+        for segment in &macho.segments {
+            for section in segment {
+                let (section, _) = section?;
+                boundaries.push(section.addr.saturating_add(section.size));
+            }
+        }
+        Ok(Symbolizer::from_symbols(symbols, boundaries))
+    }
+    /// Builds the symbolizer from `symbols`.
+    ///
+    /// Of the symbols at the same address, the first one with a size is kept. Symbols without a size
+    /// extend up to the next symbol or address in `boundaries`, e.g., the end of their section.
+    pub fn from_symbols(mut symbols: Vec<Symbol<'a>>, mut boundaries: Vec<u64>) -> Self {
+        // the sort is stable, so the order of the symbols at the same address is kept
+        symbols.sort_by_key(|symbol| (symbol.address, symbol.size == 0));
+        symbols.dedup_by_key(|symbol| symbol.address);
+        boundaries.extend(symbols.iter().map(|symbol| symbol.address));
+        boundaries.sort();
+        boundaries.dedup();
+        for symbol in symbols.iter_mut().filter(|symbol| symbol.size == 0) {
+            let next = match boundaries.binary_search(&symbol.address) {
+                Ok(index) => index + 1,
+                Err(index) => index,
+            };
+            if let Some(&end) = boundaries.get(next) {
+                symbol.size = end - symbol.address;
+            }
+        }
+        Symbolizer { symbols }
+    }
+    /// Returns the symbol enclosing `address`, and the offset of `address` within it.
+    ///
+    /// If symbols overlap, e.g., a label within a function, the closest one below `address` which
+    /// still contains it is returned.
+    pub fn lookup(&self, address: u64) -> Option<Location<'a>> {
+        let end = match self.symbols.binary_search_by_key(&address, |symbol| symbol.address) {
+            Ok(index) => index + 1,
+            Err(index) => index,
+        };
+        self.symbols[..end].iter().rev()
+            .find(|symbol| symbol.contains(address))
+            .map(|symbol| Location { name: symbol.name, address: symbol.address, offset: address - symbol.address })
+    }
+    /// The symbols, sorted by address
+    pub fn symbols(&self) -> &[Symbol<'a>] {
+        &self.symbols
+    }
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_sized_and_inferred_symbols() {
+        let symbols = vec![
+            Symbol { name: "main", address: 0x1040, size: 0x20 },
+            Symbol { name: "_start", address: 0x1000, size: 0 },
+            Symbol { name: "main_alias", address: 0x1040, size: 0 },
+            Symbol { name: "helper", address: 0x1080, size: 0 },
+        ];
+        let symbolizer = Symbolizer::from_symbols(symbols, vec![0x10a0]);
+        assert_eq!(symbolizer.len(), 3);
+        assert_eq!(symbolizer.lookup(0x1000).unwrap().to_string(), "_start");
+        // sized up to the next symbol
+        assert_eq!(symbolizer.lookup(0x103f).unwrap().to_string(), "_start+0x3f");
+        assert_eq!(symbolizer.lookup(0x105c).unwrap().to_string(), "main+0x1c");
+        // in the gap after the end of main
+        assert!(symbolizer.lookup(0x1060).is_none());
+        // sized up to the end of the section
+        assert_eq!(symbolizer.lookup(0x109f).unwrap(), Location { name: "helper", address: 0x1080, offset: 0x1f });
+        assert!(symbolizer.lookup(0x10a0).is_none());
+        assert!(symbolizer.lookup(0xfff).is_none());
+    }
+
+    #[test]
+    fn lookup_overlapping_symbols() {
+        let symbols = vec![
+            Symbol { name: "outer", address: 0x2000, size: 0x100 },
+            Symbol { name: "label", address: 0x2010, size: 0x10 },
+            Symbol { name: "inner", address: 0x2040, size: 0x20 },
+        ];
+        let symbolizer = Symbolizer::from_symbols(symbols, vec![]);
+        assert_eq!(symbolizer.lookup(0x2018).unwrap().to_string(), "label+0x8");
+        // past the end of the preceding symbols, but still within the enclosing one
+        assert_eq!(symbolizer.lookup(0x2020).unwrap().to_string(), "outer+0x20");
+        assert_eq!(symbolizer.lookup(0x2040).unwrap().to_string(), "inner");
+        assert_eq!(symbolizer.lookup(0x20ff).unwrap().to_string(), "outer+0xff");
+        assert!(symbolizer.lookup(0x2100).is_none());
+    }
+
+    #[test]
+    fn symbolize_elf() {
+        let bytes: Vec<u8> = include!("../etc/hello.rs");
+        let object = Object::parse(&bytes).unwrap();
+        let symbolizer = Symbolizer::new(&object, &bytes).unwrap();
+        assert_eq!(symbolizer.lookup(0x730).unwrap().to_string(), "main");
+        assert_eq!(symbolizer.lookup(0x8a3).unwrap().to_string(), "greet+0x33");
+        assert_eq!(symbolizer.lookup(0x780).unwrap(), Location { name: "_start", address: 0x780, offset: 0 });
+        // the undefined imports aren't symbols of the binary
+        assert!(symbolizer.symbols().iter().all(|symbol| symbol.name != "__printf_chk"));
+        assert!(symbolizer.lookup(0x8e8 + 0x9).is_none());
+    }
+
+    #[test]
+    fn symbolize_pe() {
+        let bytes: Vec<u8> = include!("../etc/hello_dll.rs");
+        let object = Object::parse(&bytes).unwrap();
+        let symbolizer = Symbolizer::new(&object, &bytes).unwrap();
+        // the exports and the static function from the COFF symbol table
+        assert_eq!(symbolizer.lookup(0x1_4000_1000).unwrap().to_string(), "add");
+        assert_eq!(symbolizer.lookup(0x1_4000_101c).unwrap().to_string(), "helper+0xc");
+        assert_eq!(symbolizer.lookup(0x1_4000_1020).unwrap().to_string(), "DllMain");
+        // the data symbol extends up to the end of .data
+        assert_eq!(symbolizer.lookup(0x1_4000_47ff).unwrap().to_string(), "table+0x17ff");
+        assert!(symbolizer.lookup(0x1_4000_4800).is_none());
+        assert!(symbolizer.lookup(0x1000).is_none());
+    }
+
+    #[test]
+    fn symbolize_mach() {
+        let bytes: Vec<u8> = include!("../etc/deadbeef_mach64.rs");
+        let object = Object::parse(&bytes).unwrap();
+        let symbolizer = Symbolizer::new(&object, &bytes).unwrap();
+        assert_eq!(symbolizer.len(), 2);
+        // the header symbol extends up to the first function start
+        assert_eq!(symbolizer.lookup(0x1_0000_0f3f).unwrap().to_string(), "__mh_execute_header+0xf3f");
+        assert_eq!(symbolizer.lookup(0x1_0000_0f50).unwrap().to_string(), "_main+0x10");
+        assert!(symbolizer.lookup(0x1_0000_0f40 + 0x34).is_none());
+    }
+}